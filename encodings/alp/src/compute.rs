@@ -1,16 +1,33 @@
+use vortex::array::ConstantArray;
 use vortex::compute::unary::{scalar_at, ScalarAtFn};
-use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
-use vortex::{Array, IntoArray};
+use vortex::compute::{
+    compare, filter, search_sorted, slice, take, ArrayCompute, CompareFn, FilterFn, SearchResult,
+    SearchSorted, SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
+};
+use vortex::{Array, ArrayDType, AsArray, IntoArray, IntoArrayVariant};
 use vortex_error::VortexResult;
+use vortex_expr::Operator;
 use vortex_scalar::Scalar;
 
 use crate::{match_each_alp_float_ptype, ALPArray, ALPFloat};
 
 impl ArrayCompute for ALPArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
@@ -36,6 +53,72 @@ impl ScalarAtFn for ALPArray {
     }
 }
 
+impl CompareFn for ALPArray {
+    /// Without patches every value is the decoding of its encoded integer, and encoding is
+    /// monotonic, so a literal that encodes exactly can be compared on the encoded integers.
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        let literal = ConstantArray::try_from(other)
+            .ok()
+            .map(|c| c.scalar().clone())
+            .filter(|s| !s.is_null() && self.patches().is_none());
+        if let Some(literal) = literal {
+            let literal = literal.cast(self.dtype())?;
+            let encoded_literal = match_each_alp_float_ptype!(self.ptype(), |$T| {
+                <$T as ALPFloat>::encode_single(<$T>::try_from(&literal)?, &self.exponents())
+                    .ok()
+                    .map(|encoded| Scalar::primitive(encoded, self.dtype().nullability()))
+            });
+            if let Some(encoded_literal) = encoded_literal {
+                return compare(
+                    &self.encoded(),
+                    &ConstantArray::new(encoded_literal, self.len()).into_array(),
+                    operator,
+                );
+            }
+        }
+
+        compare(
+            &self.clone().into_primitive()?.into_array(),
+            other,
+            operator,
+        )
+    }
+}
+
+impl SearchSortedFn for ALPArray {
+    /// Like [CompareFn], a needle that encodes exactly is searched among the encoded integers if
+    /// there are no patches.
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        if self.patches().is_none() && !value.is_null() {
+            let encoded_value = match_each_alp_float_ptype!(self.ptype(), |$T| {
+                <$T as ALPFloat>::encode_single(<$T>::try_from(value)?, &self.exponents())
+                    .ok()
+                    .map(|encoded| Scalar::primitive(encoded, self.dtype().nullability()))
+            });
+            if let Some(encoded_value) = encoded_value {
+                return search_sorted(&self.encoded(), encoded_value, side);
+            }
+        }
+
+        Ok(SearchSorted::search_sorted(
+            self.as_array_ref(),
+            value,
+            side,
+        ))
+    }
+}
+
+impl FilterFn for ALPArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        Ok(Self::try_new(
+            filter(&self.encoded(), predicate)?,
+            self.exponents(),
+            self.patches().map(|p| filter(&p, predicate)).transpose()?,
+        )?
+        .into_array())
+    }
+}
+
 impl TakeFn for ALPArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         // TODO(ngates): wrap up indices in an array that caches decompression?
@@ -58,3 +141,91 @@ impl SliceFn for ALPArray {
         .into_array())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{BoolArray, ConstantArray, PrimitiveArray};
+    use vortex::compute::{compare, filter, search_sorted_many, SearchResult, SearchSortedSide};
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_expr::Operator;
+
+    use crate::{alp_encode, ALPArray};
+
+    #[test]
+    fn compare_encoded() {
+        let alp = alp_encode(&PrimitiveArray::from(vec![1.25f64, 0.5, 3.75, 1.25])).unwrap();
+        assert!(alp.patches().is_none());
+        let alp = alp.into_array();
+
+        let matches = compare(
+            &alp,
+            &ConstantArray::new(1.25f64, 4).into_array(),
+            Operator::Eq,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            [true, false, false, true]
+        );
+
+        let matches = compare(
+            &alp,
+            &ConstantArray::new(1.25f64, 4).into_array(),
+            Operator::Gt,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            [false, false, true, false]
+        );
+
+        // Literals that do not encode exactly are compared on the decoded values.
+        let third = ConstantArray::new(1.0f64 / 3.0, 4).into_array();
+        let matches = compare(&alp, &third, Operator::Lt)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(matches.boolean_buffer().count_set_bits(), 0);
+    }
+
+    #[test]
+    fn filter_patched() {
+        let alp = alp_encode(&PrimitiveArray::from(vec![1.5f64, f64::NAN, 2.25, 0.5])).unwrap();
+        assert!(alp.patches().is_some());
+
+        let filtered = filter(
+            alp.array(),
+            BoolArray::from(vec![false, true, true, false]).array(),
+        )
+        .unwrap();
+        assert!(ALPArray::try_from(&filtered).is_ok());
+        let values = filtered.into_primitive().unwrap();
+        let values = values.maybe_null_slice::<f64>();
+        assert!(values[0].is_nan());
+        assert_eq!(values[1], 2.25);
+    }
+
+    #[test]
+    fn search_encoded() {
+        let alp = alp_encode(&PrimitiveArray::from(vec![0.5f64, 1.25, 1.25, 3.75])).unwrap();
+        assert!(alp.patches().is_none());
+        assert_eq!(
+            search_sorted_many(
+                &alp.into_array(),
+                &[1.25f64, 0.1, 3.75, 4.0],
+                SearchSortedSide::Right
+            )
+            .unwrap(),
+            vec![
+                SearchResult::Found(3),
+                SearchResult::NotFound(0),
+                SearchResult::Found(4),
+                SearchResult::NotFound(4)
+            ]
+        );
+    }
+}
@@ -1,9 +1,11 @@
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
-use arrow_buffer::BooleanBuffer;
+use arrow_buffer::{BooleanBuffer, NullBuffer};
 use num_traits::AsPrimitive;
 use vortex::compute::unary::{FillForwardFn, ScalarAtFn};
-use vortex::compute::{ArrayCompute, CompareFn, SliceFn, TakeFn};
+use vortex::compute::{
+    AndFn, AndKleeneFn, ArrayCompute, CompareFn, NotFn, OrFn, OrKleeneFn, SliceFn, TakeFn, XorFn,
+};
 use vortex::validity::{ArrayValidity, Validity};
 use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 use vortex_dtype::{match_each_integer_ptype, Nullability};
@@ -33,6 +35,30 @@ impl ArrayCompute for ByteBoolArray {
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
+
+    fn and(&self) -> Option<&dyn AndFn> {
+        Some(self)
+    }
+
+    fn or(&self) -> Option<&dyn OrFn> {
+        Some(self)
+    }
+
+    fn xor(&self) -> Option<&dyn XorFn> {
+        Some(self)
+    }
+
+    fn and_kleene(&self) -> Option<&dyn AndKleeneFn> {
+        Some(self)
+    }
+
+    fn or_kleene(&self) -> Option<&dyn OrKleeneFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
 }
 
 impl ScalarAtFn for ByteBoolArray {
@@ -133,6 +159,93 @@ impl CompareFn for ByteBoolArray {
     }
 }
 
+impl AndFn for ByteBoolArray {
+    fn and(&self, array: &Array) -> VortexResult<Array> {
+        self.logical_op(array, |l, r| l.zip(r).map(|(l, r)| l & r))
+    }
+}
+
+impl OrFn for ByteBoolArray {
+    fn or(&self, array: &Array) -> VortexResult<Array> {
+        self.logical_op(array, |l, r| l.zip(r).map(|(l, r)| l | r))
+    }
+}
+
+impl XorFn for ByteBoolArray {
+    fn xor(&self, array: &Array) -> VortexResult<Array> {
+        self.logical_op(array, |l, r| l.zip(r).map(|(l, r)| l ^ r))
+    }
+}
+
+impl AndKleeneFn for ByteBoolArray {
+    fn and_kleene(&self, array: &Array) -> VortexResult<Array> {
+        self.logical_op(array, |l, r| match (l, r) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        })
+    }
+}
+
+impl OrKleeneFn for ByteBoolArray {
+    fn or_kleene(&self, array: &Array) -> VortexResult<Array> {
+        self.logical_op(array, |l, r| match (l, r) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        })
+    }
+}
+
+impl NotFn for ByteBoolArray {
+    fn not(&self) -> VortexResult<Array> {
+        let negated = self
+            .maybe_null_slice()
+            .iter()
+            .map(|b| !b)
+            .collect::<Vec<_>>();
+        Self::try_from_vec(negated, self.validity()).map(Self::into_array)
+    }
+}
+
+impl ByteBoolArray {
+    /// Evaluate `op` on each pair of values, where `None` is a null.
+    fn logical_op(
+        &self,
+        other: &Array,
+        op: impl Fn(Option<bool>, Option<bool>) -> Option<bool>,
+    ) -> VortexResult<Array> {
+        let canonical = other.clone().into_bool()?;
+        let lhs_nulls = self.logical_validity().to_null_buffer()?;
+        let rhs_nulls = canonical.logical_validity().to_null_buffer()?;
+        let is_valid =
+            |nulls: &Option<NullBuffer>, idx| nulls.as_ref().map_or(true, |n| n.is_valid(idx));
+
+        let values = self
+            .maybe_null_slice()
+            .iter()
+            .zip(canonical.boolean_buffer().iter())
+            .enumerate()
+            .map(|(idx, (&l, r))| {
+                op(
+                    is_valid(&lhs_nulls, idx).then_some(l),
+                    is_valid(&rhs_nulls, idx).then_some(r),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if self.dtype().is_nullable() || other.dtype().is_nullable() {
+            Ok(Self::from(values).into_array())
+        } else {
+            Self::try_from_vec(
+                values.into_iter().map(|v| v.unwrap_or(false)).collect(),
+                Validity::NonNullable,
+            )
+            .map(Self::into_array)
+        }
+    }
+}
+
 impl FillForwardFn for ByteBoolArray {
     fn fill_forward(&self) -> VortexResult<Array> {
         let validity = self.logical_validity();
@@ -176,7 +289,7 @@ impl FillForwardFn for ByteBoolArray {
 #[cfg(test)]
 mod tests {
     use vortex::compute::unary::scalar_at;
-    use vortex::compute::{compare, slice};
+    use vortex::compute::{compare, not, or_kleene, slice, xor};
     use vortex::AsArray as _;
 
     use super::*;
@@ -249,4 +362,30 @@ mod tests {
         let s = scalar_at(&arr, 4).unwrap();
         assert!(s.is_null());
     }
+
+    #[test]
+    fn test_logical_ops() {
+        let lhs = ByteBoolArray::from(vec![Some(true), Some(false), None, None]).into_array();
+        let rhs = ByteBoolArray::from(vec![Some(false), Some(false), Some(true), Some(false)])
+            .into_array();
+
+        let values = |array: Array| {
+            (0..array.len())
+                .map(|i| scalar_at(&array, i).unwrap().value().as_bool().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            values(or_kleene(&lhs, &rhs).unwrap()),
+            vec![Some(true), Some(false), Some(true), None]
+        );
+        assert_eq!(
+            values(xor(&lhs, &rhs).unwrap()),
+            vec![Some(true), Some(false), None, None]
+        );
+        assert_eq!(
+            values(not(&lhs).unwrap()),
+            vec![Some(false), Some(true), None, None]
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use vortex::array::{PrimitiveArray, TemporalArray, TimeUnit};
 use vortex::compute::unary::try_cast;
-use vortex::{Array, IntoArray, IntoArrayVariant};
-use vortex_dtype::PType;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_bail, VortexResult};
 
 /// Compress a `TemporalArray` into day, second, and subsecond components.
@@ -9,17 +9,24 @@ use vortex_error::{vortex_bail, VortexResult};
 /// Splitting the components by granularity creates more small values, which enables better
 /// cascading compression.
 pub fn compress_temporal(array: TemporalArray) -> VortexResult<(Array, Array, Array)> {
+    if let Some(interval_unit) = array.temporal_metadata().interval_unit() {
+        vortex_bail!(InvalidArgument: "Cannot compress {interval_unit} interval data");
+    }
+
     // After this operation, timestamps will be PrimitiveArray<i64>
+    let values = array.temporal_values();
     let timestamps = try_cast(
-        &array.temporal_values().into_primitive()?.into_array(),
-        PType::I64.into(),
+        &values.clone().into_primitive()?.into_array(),
+        &DType::Primitive(PType::I64, values.dtype().nullability()),
     )?;
     let divisor = match array.temporal_metadata().time_unit() {
-        TimeUnit::Ns => 1_000_000_000,
-        TimeUnit::Us => 1_000_000,
-        TimeUnit::Ms => 1_000,
-        TimeUnit::S => 1,
-        TimeUnit::D => vortex_bail!(InvalidArgument: "Cannot compress day-level data"),
+        Some(TimeUnit::Ns) => 1_000_000_000,
+        Some(TimeUnit::Us) => 1_000_000,
+        Some(TimeUnit::Ms) => 1_000,
+        Some(TimeUnit::S) => 1,
+        Some(TimeUnit::D) | None => {
+            vortex_bail!(InvalidArgument: "Cannot compress day-level data")
+        }
     };
 
     let length = timestamps.len();
@@ -1,20 +1,31 @@
 use vortex::array::temporal::TemporalMetadata;
 use vortex::array::{PrimitiveArray, TemporalArray, TimeUnit};
 use vortex::compute::unary::{scalar_at, ScalarAtFn};
-use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
+use vortex::compute::{
+    filter, search_sorted, slice, take, ArrayCompute, FilterFn, SearchResult, SearchSorted,
+    SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
+};
 use vortex::validity::ArrayValidity;
-use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex::{Array, ArrayDType, AsArray, IntoArray, IntoArrayVariant};
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, VortexResult};
-use vortex_scalar::Scalar;
+use vortex_scalar::{ExtScalar, Scalar};
 
 use crate::DateTimePartsArray;
 
 impl ArrayCompute for DateTimePartsArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
@@ -36,6 +47,18 @@ impl TakeFn for DateTimePartsArray {
     }
 }
 
+impl FilterFn for DateTimePartsArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        Ok(Self::try_new(
+            self.dtype().clone(),
+            filter(&self.days(), predicate)?,
+            filter(&self.seconds(), predicate)?,
+            filter(&self.subsecond(), predicate)?,
+        )?
+        .into_array())
+    }
+}
+
 impl SliceFn for DateTimePartsArray {
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
         Ok(Self::try_new(
@@ -48,34 +71,77 @@ impl SliceFn for DateTimePartsArray {
     }
 }
 
-impl ScalarAtFn for DateTimePartsArray {
-    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
-        let DType::Extension(ext, nullability) = self.dtype().clone() else {
+impl DateTimePartsArray {
+    /// The number of time units in a second.
+    fn divisor(&self) -> VortexResult<i64> {
+        let DType::Extension(ext, _) = self.dtype() else {
             vortex_bail!(
                 "DateTimePartsArray must have extension dtype, found {}",
                 self.dtype()
             );
         };
 
-        let TemporalMetadata::Timestamp(time_unit, _) = TemporalMetadata::try_from(&ext)? else {
-            vortex_bail!("Metadata must be Timestamp, found {}", ext.id());
+        let (TemporalMetadata::Timestamp(time_unit, _) | TemporalMetadata::Duration(time_unit)) =
+            TemporalMetadata::try_from(ext)?
+        else {
+            vortex_bail!("Metadata must be Timestamp or Duration, found {}", ext.id());
         };
 
-        let divisor = match time_unit {
+        Ok(match time_unit {
             TimeUnit::Ns => 1_000_000_000,
             TimeUnit::Us => 1_000_000,
             TimeUnit::Ms => 1_000,
             TimeUnit::S => 1,
             TimeUnit::D => vortex_bail!("Invalid time unit D"),
-        };
+        })
+    }
+}
 
+impl ScalarAtFn for DateTimePartsArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        let divisor = self.divisor()?;
         let days: i64 = scalar_at(&self.days(), index)?.try_into()?;
         let seconds: i64 = scalar_at(&self.seconds(), index)?.try_into()?;
         let subseconds: i64 = scalar_at(&self.subsecond(), index)?.try_into()?;
 
         let scalar = days * 86_400 * divisor + seconds * divisor + subseconds;
 
-        Ok(Scalar::primitive(scalar, nullability))
+        Ok(Scalar::primitive(scalar, self.dtype().nullability()))
+    }
+}
+
+impl SearchSortedFn for DateTimePartsArray {
+    /// Timestamps are sorted by their days, then seconds, then subseconds, so each part narrows
+    /// down the range of the next.
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        let Some(timestamp) = ExtScalar::try_from(value)?.value().as_pvalue()? else {
+            return Ok(SearchSorted::search_sorted(
+                self.as_array_ref(),
+                value,
+                side,
+            ));
+        };
+        let timestamp = i64::try_from(timestamp)?;
+        let divisor = self.divisor()?;
+        let day = 86_400 * divisor;
+
+        let mut start = 0;
+        let mut stop = self.len();
+        for (part, needle) in [
+            (self.days(), timestamp / day),
+            (self.seconds(), (timestamp % day) / divisor),
+        ] {
+            let part = slice(&part, start, stop)?;
+            let first = match search_sorted(&part, needle, SearchSortedSide::Left)? {
+                SearchResult::Found(first) => first,
+                SearchResult::NotFound(index) => return Ok(SearchResult::NotFound(start + index)),
+            };
+            let last = search_sorted(&part, needle, SearchSortedSide::Right)?.to_index();
+            (start, stop) = (start + first, start + last);
+        }
+
+        let subsecond = slice(&self.subsecond(), start, stop)?;
+        Ok(search_sorted(&subsecond, (timestamp % day) % divisor, side)?.map(|i| i + start))
     }
 }
 
@@ -90,13 +156,18 @@ pub fn decode_to_temporal(array: &DateTimePartsArray) -> VortexResult<TemporalAr
     let Ok(temporal_metadata) = TemporalMetadata::try_from(&ext) else {
         vortex_bail!(ComputeError: "must decode TemporalMetadata from extension metadata");
     };
+    if let TemporalMetadata::Interval(interval_unit) = temporal_metadata {
+        vortex_bail!(InvalidArgument: "cannot decode into {interval_unit} interval");
+    }
 
-    let divisor = match temporal_metadata.time_unit() {
-        TimeUnit::Ns => 1_000_000_000,
-        TimeUnit::Us => 1_000_000,
-        TimeUnit::Ms => 1_000,
-        TimeUnit::S => 1,
-        TimeUnit::D => vortex_bail!(InvalidArgument: "cannot decode into TimeUnit::D"),
+    let (divisor, time_unit) = match temporal_metadata.time_unit() {
+        Some(TimeUnit::Ns) => (1_000_000_000, TimeUnit::Ns),
+        Some(TimeUnit::Us) => (1_000_000, TimeUnit::Us),
+        Some(TimeUnit::Ms) => (1_000, TimeUnit::Ms),
+        Some(TimeUnit::S) => (1, TimeUnit::S),
+        Some(TimeUnit::D) | None => {
+            vortex_bail!(InvalidArgument: "cannot decode into TimeUnit::D")
+        }
     };
 
     let days_buf = array.days().into_primitive()?;
@@ -111,18 +182,27 @@ pub fn decode_to_temporal(array: &DateTimePartsArray) -> VortexResult<TemporalAr
         .map(|((d, s), ss)| d * 86_400 * divisor + s * divisor + ss)
         .collect::<Vec<_>>();
 
-    Ok(TemporalArray::new_timestamp(
-        PrimitiveArray::from_vec(values, array.logical_validity().into_validity()).into_array(),
-        temporal_metadata.time_unit(),
-        temporal_metadata.time_zone().map(|s| s.to_string()),
-    ))
+    let values =
+        PrimitiveArray::from_vec(values, array.logical_validity().into_validity()).into_array();
+    Ok(match temporal_metadata {
+        TemporalMetadata::Duration(time_unit) => TemporalArray::new_duration(values, time_unit),
+        _ => TemporalArray::new_timestamp(
+            values,
+            time_unit,
+            temporal_metadata.time_zone().map(|s| s.to_string()),
+        ),
+    })
 }
 
 #[cfg(test)]
 mod test {
+    use vortex::array::temporal::TemporalMetadata;
     use vortex::array::{PrimitiveArray, TemporalArray, TimeUnit};
+    use vortex::compute::unary::scalar_at;
+    use vortex::compute::{search_sorted_many, SearchResult, SearchSortedSide};
     use vortex::{IntoArray, IntoArrayVariant};
     use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
 
     use crate::compute::decode_to_temporal;
     use crate::{compress_temporal, DateTimePartsArray};
@@ -161,4 +241,104 @@ mod test {
             raw_values.as_slice()
         );
     }
+
+    #[test]
+    fn test_roundtrip_nullable() {
+        let raw_millis =
+            PrimitiveArray::from_nullable_vec(vec![Some(86_400i64 + 1000 + 1), None]).into_array();
+        let temporal_array =
+            TemporalArray::new_timestamp(raw_millis, TimeUnit::Ms, Some("UTC".to_string()));
+
+        let (days, seconds, subseconds) = compress_temporal(temporal_array.clone()).unwrap();
+
+        let date_times = DateTimePartsArray::try_new(
+            DType::Extension(temporal_array.ext_dtype().clone(), Nullability::Nullable),
+            days,
+            seconds,
+            subseconds,
+        )
+        .unwrap();
+        let decoded = decode_to_temporal(&date_times).unwrap().temporal_values();
+
+        assert_eq!(
+            scalar_at(&decoded, 0).unwrap(),
+            Scalar::from(Some(86_400i64 + 1000 + 1))
+        );
+        assert!(scalar_at(&decoded, 1).unwrap().is_null());
+    }
+
+    #[test]
+    fn test_roundtrip_duration() {
+        let raw_values = vec![-86_400_001i64, 0, 90_061_001];
+        let temporal_array = TemporalArray::new_duration(
+            PrimitiveArray::from(raw_values.clone()).into_array(),
+            TimeUnit::Ms,
+        );
+
+        let (days, seconds, subseconds) = compress_temporal(temporal_array.clone()).unwrap();
+        let date_times = DateTimePartsArray::try_new(
+            DType::Extension(temporal_array.ext_dtype().clone(), Nullability::NonNullable),
+            days,
+            seconds,
+            subseconds,
+        )
+        .unwrap();
+
+        let decoded = decode_to_temporal(&date_times).unwrap();
+        assert_eq!(
+            decoded.temporal_metadata(),
+            &TemporalMetadata::Duration(TimeUnit::Ms)
+        );
+        assert_eq!(
+            decoded
+                .temporal_values()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i64>(),
+            raw_values.as_slice()
+        );
+    }
+
+    #[test]
+    fn search_sorted_parts() {
+        let temporal_array = TemporalArray::new_duration(
+            PrimitiveArray::from(vec![
+                -86_400_001i64,
+                -5,
+                0,
+                86_400_000,
+                86_400_000,
+                90_061_001,
+            ])
+            .into_array(),
+            TimeUnit::Ms,
+        );
+        let ext_dtype = temporal_array.ext_dtype().clone();
+        let (days, seconds, subseconds) = compress_temporal(temporal_array).unwrap();
+        let date_times = DateTimePartsArray::try_new(
+            DType::Extension(ext_dtype.clone(), Nullability::NonNullable),
+            days,
+            seconds,
+            subseconds,
+        )
+        .unwrap()
+        .into_array();
+
+        let needles = [-86_400_001i64, -1, 86_400_000, 86_400_001, 100_000_000]
+            .map(|v| Scalar::extension(ext_dtype.clone(), v.into()));
+        assert_eq!(
+            search_sorted_many(&date_times, &needles, SearchSortedSide::Left).unwrap(),
+            vec![
+                SearchResult::Found(0),
+                SearchResult::NotFound(2),
+                SearchResult::Found(3),
+                SearchResult::NotFound(5),
+                SearchResult::NotFound(6)
+            ]
+        );
+        assert_eq!(
+            search_sorted_many(&date_times, &needles[2..3], SearchSortedSide::Right).unwrap(),
+            vec![SearchResult::Found(5)]
+        );
+    }
 }
@@ -5,7 +5,7 @@ use hashbrown::hash_map::{Entry, RawEntryMut};
 use hashbrown::HashMap;
 use num_traits::AsPrimitive;
 use vortex::accessor::ArrayAccessor;
-use vortex::array::{PrimitiveArray, VarBinArray};
+use vortex::array::{PrimitiveArray, VarBinArray, VarBinViewArray};
 use vortex::validity::Validity;
 use vortex::{ArrayDType, IntoArray};
 use vortex_dtype::{match_each_native_ptype, DType, NativePType, ToBytes};
@@ -89,6 +89,13 @@ pub fn dict_encode_varbin(array: &VarBinArray) -> (PrimitiveArray, VarBinArray)
         .unwrap()
 }
 
+/// Dictionary encode varbinview array. Nulls in the original array are encoded in the dictionary.
+pub fn dict_encode_varbinview(array: &VarBinViewArray) -> (PrimitiveArray, VarBinArray) {
+    array
+        .with_iterator(|iter| dict_encode_typed_varbin(array.dtype().clone(), iter))
+        .unwrap()
+}
+
 fn lookup_bytes<'a, T: NativePType + AsPrimitive<usize>>(
     offsets: &'a [T],
     bytes: &'a [u8],
@@ -174,14 +181,16 @@ mod test {
     use std::str;
 
     use vortex::accessor::ArrayAccessor;
-    use vortex::array::{PrimitiveArray, VarBinArray};
+    use vortex::array::{PrimitiveArray, VarBinArray, VarBinViewArray};
     use vortex::compute::unary::scalar_at;
     use vortex::ToArray;
     use vortex_dtype::Nullability::Nullable;
     use vortex_dtype::{DType, PType};
     use vortex_scalar::Scalar;
 
-    use crate::compress::{dict_encode_typed_primitive, dict_encode_varbin};
+    use crate::compress::{
+        dict_encode_typed_primitive, dict_encode_varbin, dict_encode_varbinview,
+    };
 
     #[test]
     fn encode_primitive() {
@@ -264,6 +273,33 @@ mod test {
             .unwrap();
     }
 
+    #[test]
+    fn encode_varbinview_nulls() {
+        let arr = VarBinViewArray::from_iter_nullable_str([
+            Some("hello"),
+            None,
+            Some("a value that is too long to be inlined"),
+            Some("hello"),
+            None,
+            Some("a value that is too long to be inlined"),
+        ]);
+        let (codes, values) = dict_encode_varbinview(&arr);
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[1, 0, 2, 1, 0, 2]);
+        values
+            .with_iterator(|iter| {
+                assert_eq!(
+                    iter.map(|b| b.map(|v| unsafe { str::from_utf8_unchecked(v) }))
+                        .collect::<Vec<_>>(),
+                    vec![
+                        None,
+                        Some("hello"),
+                        Some("a value that is too long to be inlined")
+                    ]
+                );
+            })
+            .unwrap();
+    }
+
     #[test]
     fn repeated_values() {
         let arr = VarBinArray::from(vec!["a", "a", "b", "b", "a", "b", "a", "b"]);
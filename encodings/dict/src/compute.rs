@@ -1,25 +1,125 @@
-use vortex::compute::unary::{scalar_at, ScalarAtFn};
-use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
-use vortex::{Array, IntoArray};
-use vortex_error::VortexResult;
+use vortex::array::{BoolArray, ConstantArray, PrimitiveArray};
+use vortex::compute::unary::{fill_null, scalar_at, FillNullFn, ScalarAtFn};
+use vortex::compute::{
+    compare, filter, fold_case, group_ids, hash, is_in, min_max, search_sorted, slice,
+    sort_indices, string_length, string_match, take, weighted_sum, ArrayCompute, Case, CompareFn,
+    FilterFn, FoldCaseFn, GroupIdsFn, HashFn, IsInFn, MinMax, MinMaxFn, SearchResult, SearchSorted,
+    SearchSortedFn, SearchSortedSide, SliceFn, SortFn, SortOptions, StringLength, StringLengthFn,
+    StringMatchFn, StringMatcher, SumFn, TakeFn,
+};
+use vortex::stats::ArrayStatistics;
+use vortex::{Array, ArrayDType, AsArray, IntoArray, IntoArrayVariant, IntoCanonical};
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_expr::Operator;
 use vortex_scalar::Scalar;
 
 use crate::DictArray;
 
 impl ArrayCompute for DictArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn fold_case(&self) -> Option<&dyn FoldCaseFn> {
+        Some(self)
+    }
+
+    fn group_ids(&self) -> Option<&dyn GroupIdsFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
+    fn string_length(&self) -> Option<&dyn StringLengthFn> {
+        Some(self)
+    }
+
+    fn string_match(&self) -> Option<&dyn StringMatchFn> {
+        Some(self)
+    }
+
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 }
 
+impl DictArray {
+    /// The number of times each value is referenced by the codes.
+    fn value_counts(&self) -> VortexResult<Vec<u64>> {
+        let codes = self.codes().into_primitive()?;
+        let num_values = self.values().len();
+        let mut counts = vec![0u64; num_values];
+        match_each_integer_ptype!(codes.ptype(), |$P| {
+            for code in codes.maybe_null_slice::<$P>() {
+                let code = *code as usize;
+                *counts
+                    .get_mut(code)
+                    .ok_or_else(|| vortex_err!(OutOfBounds: code, 0, num_values))? += 1;
+            }
+        });
+        Ok(counts)
+    }
+}
+
+impl SumFn for DictArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        weighted_sum(&self.values(), &self.value_counts()?)
+    }
+}
+
+impl MinMaxFn for DictArray {
+    fn min_max(&self) -> VortexResult<MinMax> {
+        let counts = self.value_counts()?;
+        if counts.iter().all(|count| *count > 0) {
+            return min_max(&self.values());
+        }
+        let referenced = counts.iter().map(|count| *count > 0).collect::<Vec<_>>();
+        min_max(&filter(
+            &self.values(),
+            &BoolArray::from(referenced).into_array(),
+        )?)
+    }
+}
+
 impl ScalarAtFn for DictArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         let dict_index: usize = scalar_at(&self.codes(), index)?.as_ref().try_into()?;
@@ -37,6 +137,148 @@ impl TakeFn for DictArray {
     }
 }
 
+impl CompareFn for DictArray {
+    /// Comparing against a literal compares each dictionary value once and takes the result
+    /// through the codes.
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        let Ok(literal) = ConstantArray::try_from(other) else {
+            return compare(&self.clone().into_canonical()?.into(), other, operator);
+        };
+        let values = self.values();
+        let matches = compare(
+            &values,
+            &ConstantArray::new(literal.scalar().clone(), values.len()).into_array(),
+            operator,
+        )?;
+        take(&matches, &self.codes())
+    }
+}
+
+impl FilterFn for DictArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        Self::try_new(filter(&self.codes(), predicate)?, self.values()).map(|a| a.into_array())
+    }
+}
+
+/// String kernels evaluate each distinct value once and take the result through the codes.
+impl StringMatchFn for DictArray {
+    fn string_match(&self, matcher: &StringMatcher) -> VortexResult<Array> {
+        take(&string_match(&self.values(), matcher)?, &self.codes())
+    }
+}
+
+impl StringLengthFn for DictArray {
+    fn string_length(&self, unit: StringLength) -> VortexResult<Array> {
+        take(&string_length(&self.values(), unit)?, &self.codes())
+    }
+}
+
+impl FoldCaseFn for DictArray {
+    fn fold_case(&self, case: Case) -> VortexResult<Array> {
+        Self::try_new(self.codes(), fold_case(&self.values(), case)?).map(|a| a.into_array())
+    }
+}
+
+impl FillNullFn for DictArray {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array> {
+        Self::try_new(self.codes(), fill_null(&self.values(), fill_value)?).map(|a| a.into_array())
+    }
+}
+
+impl GroupIdsFn for DictArray {
+    /// Groups the dictionary values, then only the codes referencing them. Grouping the values
+    /// first keeps the result correct for dictionaries with unused or repeated values.
+    fn group_ids(&self) -> VortexResult<(PrimitiveArray, Array)> {
+        let (value_codes, value_uniques) = group_ids(&self.values())?;
+        let value_codes = take(&value_codes.into_array(), &self.codes())?;
+        let (codes, used) = group_ids(&value_codes)?;
+        Ok((codes, take(&value_uniques, &used)?))
+    }
+}
+
+impl HashFn for DictArray {
+    /// Hash each distinct value once.
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray> {
+        let hashes = hash(&self.values(), seed)?.into_array();
+        take(&hashes, &self.codes())?.into_primitive()
+    }
+}
+
+impl IsInFn for DictArray {
+    /// Only the dictionary values are tested, every code then takes the result of its value.
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        take(&is_in(&self.values(), values)?, &self.codes())
+    }
+}
+
+impl SearchSortedFn for DictArray {
+    /// A sorted array with sorted values has sorted codes, so the value is searched among the
+    /// values and its code among the codes.
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        let values = self.values();
+        if self.dtype().is_nullable() || values.statistics().compute_is_sorted() != Some(true) {
+            return Ok(SearchSorted::search_sorted(
+                self.as_array_ref(),
+                value,
+                side,
+            ));
+        }
+
+        let codes = self.codes();
+        // Values the codes don't refer to are not found, even though they are in the dictionary.
+        match (search_sorted(&values, value.clone(), side)?, side) {
+            (SearchResult::Found(code), SearchSortedSide::Right) => {
+                search_sorted(&codes, code - 1, SearchSortedSide::Right)
+            }
+            (SearchResult::Found(code), SearchSortedSide::Left) => {
+                search_sorted(&codes, code, SearchSortedSide::Left)
+            }
+            (SearchResult::NotFound(code), _) if code == values.len() => {
+                Ok(SearchResult::NotFound(self.len()))
+            }
+            (SearchResult::NotFound(code), _) => Ok(SearchResult::NotFound(
+                search_sorted(&codes, code, SearchSortedSide::Left)?.to_index(),
+            )),
+        }
+    }
+}
+
+impl SortFn for DictArray {
+    /// Sorts the codes by the rank of their value in the sorted dictionary. Equal values share a
+    /// rank, so that the sort stays stable when the dictionary holds duplicates.
+    fn sort_indices(&self, options: SortOptions) -> VortexResult<PrimitiveArray> {
+        let values = self.values();
+        let order = sort_indices(&values, options)?;
+        let mut ranks = vec![0u64; order.len()];
+        let mut rank = 0;
+        let mut previous: Option<Scalar> = None;
+        for &value in order.maybe_null_slice::<u64>() {
+            let scalar = scalar_at(&values, value as usize)?;
+            if previous
+                .as_ref()
+                .is_some_and(|previous| *previous != scalar)
+            {
+                rank += 1;
+            }
+            ranks[value as usize] = rank;
+            previous = Some(scalar);
+        }
+
+        let codes = self.codes().into_primitive()?;
+        let code_ranks = match_each_integer_ptype!(codes.ptype(), |$P| {
+            codes
+                .maybe_null_slice::<$P>()
+                .iter()
+                .map(|&code| ranks[code as usize])
+                .collect::<Vec<_>>()
+        });
+        sort_indices(
+            &PrimitiveArray::from(code_ranks).into_array(),
+            SortOptions::default(),
+        )
+    }
+}
+
 impl SliceFn for DictArray {
     // TODO(robert): Add function to trim the dictionary
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
@@ -46,12 +288,65 @@ impl SliceFn for DictArray {
 
 #[cfg(test)]
 mod test {
-    use vortex::array::{PrimitiveArray, VarBinArray};
+    use vortex::accessor::ArrayAccessor;
+    use vortex::array::{BoolArray, ConstantArray, PrimitiveArray, VarBinArray, VarBinViewArray};
+    use vortex::compute::{
+        compare, filter, hash, is_in, like, lower, max, min, search_sorted, search_sorted_many,
+        slice, sort, sort_indices, sum, value_counts, LikeOptions, SearchResult, SearchSortedSide,
+        SortOptions,
+    };
     use vortex::{IntoArray, IntoArrayVariant, ToArray};
     use vortex_dtype::{DType, Nullability};
+    use vortex_expr::Operator;
+    use vortex_scalar::Scalar;
 
     use crate::{dict_encode_typed_primitive, dict_encode_varbin, DictArray};
 
+    #[test]
+    fn sort_indices_duplicate_values() {
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![1u32, 0, 1, 0]).into_array(),
+            VarBinViewArray::from_iter_str(["a", "a"]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+        let indices = sort_indices(&dict, SortOptions::default()).unwrap();
+        assert_eq!(indices.maybe_null_slice::<u64>(), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn search_sorted_codes() {
+        let reference = PrimitiveArray::from(vec![1i32, 1, 3, 3, 3, 7, 9]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array())
+            .unwrap()
+            .into_array();
+        let sliced = slice(&dict, 0, 5).unwrap();
+
+        assert_eq!(
+            search_sorted_many(&dict, &[0i32, 3, 5, 9, 10], SearchSortedSide::Left).unwrap(),
+            vec![
+                SearchResult::NotFound(0),
+                SearchResult::Found(2),
+                SearchResult::NotFound(5),
+                SearchResult::Found(6),
+                SearchResult::NotFound(7)
+            ]
+        );
+        assert_eq!(
+            search_sorted(&dict, 9, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(7)
+        );
+        assert_eq!(
+            search_sorted(&sliced, 3, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(5)
+        );
+        assert_eq!(
+            search_sorted(&sliced, 7, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(5)
+        );
+    }
+
     #[test]
     fn flatten_nullable_primitive() {
         let reference = PrimitiveArray::from_nullable_vec(vec![
@@ -76,14 +371,162 @@ mod test {
         );
         let (codes, values) = dict_encode_varbin(&reference);
         let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
-        let flattened_dict = dict.to_array().into_varbin().unwrap();
+        let flattened_dict = dict.to_array().into_varbin_view().unwrap();
+        let flattened = flattened_dict
+            .with_iterator(|iter| iter.map(|v| v.map(|b| b.to_vec())).collect::<Vec<_>>())
+            .unwrap();
+        let expected = reference
+            .with_iterator(|iter| iter.map(|v| v.map(|b| b.to_vec())).collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(flattened, expected);
+    }
+
+    #[test]
+    fn aggregate_nullable_primitive() {
+        let reference =
+            PrimitiveArray::from_nullable_vec(vec![Some(42), Some(-9), None, Some(42), Some(7)]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array())
+            .unwrap()
+            .into_array();
+        assert_eq!(sum(&dict).unwrap(), Scalar::from(Some(82i64)));
+        assert_eq!(min(&dict).unwrap(), Scalar::from(Some(-9i32)));
+
+        // Values that are no longer referenced after slicing are ignored.
+        let sliced = slice(&dict, 2, 4).unwrap();
+        assert_eq!(sum(&sliced).unwrap(), Scalar::from(Some(42i64)));
+        assert_eq!(min(&sliced).unwrap(), Scalar::from(Some(42i32)));
+        assert_eq!(max(&sliced).unwrap(), Scalar::from(Some(42i32)));
+    }
+
+    #[test]
+    fn compare_and_filter_varbin() {
+        let reference = VarBinArray::from_iter(
+            vec![Some("a"), Some("b"), None, Some("a"), Some("c")],
+            DType::Utf8(Nullability::Nullable),
+        );
+        let (codes, values) = dict_encode_varbin(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array())
+            .unwrap()
+            .into_array();
+
+        let matches = compare(
+            &dict,
+            &ConstantArray::new("a", 5).into_array(),
+            Operator::Eq,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, false, true, false]
+        );
+
+        let filtered = filter(
+            &dict,
+            BoolArray::from(vec![false, true, true, false, true]).array(),
+        )
+        .unwrap();
+        assert!(DictArray::try_from(&filtered).is_ok());
+        let filtered = filtered
+            .into_varbin_view()
+            .unwrap()
+            .with_iterator(|iter| iter.map(|v| v.map(|b| b.to_vec())).collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(
+            filtered,
+            vec![Some(b"b".to_vec()), None, Some(b"c".to_vec())]
+        );
+    }
+
+    #[test]
+    fn string_kernels_varbin() {
+        let reference = VarBinArray::from_iter(
+            vec![
+                Some("Apple"),
+                Some("banana"),
+                None,
+                Some("Apple"),
+                Some("cherry"),
+            ],
+            DType::Utf8(Nullability::Nullable),
+        );
+        let (codes, values) = dict_encode_varbin(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array())
+            .unwrap()
+            .into_array();
+
+        let matches = like(&dict, "%an%", LikeOptions::default())
+            .unwrap()
+            .into_bool()
+            .unwrap();
         assert_eq!(
-            flattened_dict.offsets().into_primitive().unwrap().buffer(),
-            reference.offsets().into_primitive().unwrap().buffer()
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, false, false, false]
         );
+
+        let lowered = lower(&dict).unwrap();
+        assert!(DictArray::try_from(&lowered).is_ok());
+        let lowered = lowered
+            .into_varbin_view()
+            .unwrap()
+            .with_iterator(|iter| iter.map(|v| v.map(|b| b.to_vec())).collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(lowered[3], Some(b"apple".to_vec()));
+        assert_eq!(lowered[2], None);
+
+        let sorted = sort(&dict, SortOptions::default())
+            .unwrap()
+            .into_varbin_view()
+            .unwrap()
+            .with_iterator(|iter| iter.map(|v| v.map(|b| b.to_vec())).collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(
+            sorted,
+            vec![
+                Some(b"Apple".to_vec()),
+                Some(b"Apple".to_vec()),
+                Some(b"banana".to_vec()),
+                Some(b"cherry".to_vec()),
+                None
+            ]
+        );
+
+        let (uniques, counts) = value_counts(&slice(&dict, 1, 5).unwrap()).unwrap();
+        assert_eq!(counts.maybe_null_slice::<u64>(), &[1, 1, 1, 1]);
+        assert_eq!(
+            uniques
+                .into_varbin_view()
+                .unwrap()
+                .with_iterator(|iter| iter.map(|v| v.map(|b| b.to_vec())).collect::<Vec<_>>())
+                .unwrap(),
+            vec![
+                Some(b"banana".to_vec()),
+                None,
+                Some(b"Apple".to_vec()),
+                Some(b"cherry".to_vec())
+            ]
+        );
+
+        let members = is_in(
+            &dict,
+            &VarBinViewArray::from_iter_str(["cherry", "Apple"]).into_array(),
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            members.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, false, true, true]
+        );
+
+        let hashes = hash(&dict, 42).unwrap();
         assert_eq!(
-            flattened_dict.bytes().into_primitive().unwrap().buffer(),
-            reference.bytes().into_primitive().unwrap().buffer()
+            hashes.maybe_null_slice::<u64>(),
+            hash(&reference.into_array(), 42)
+                .unwrap()
+                .maybe_null_slice::<u64>()
         );
     }
 }
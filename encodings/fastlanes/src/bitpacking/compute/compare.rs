@@ -0,0 +1,214 @@
+use std::mem::size_of;
+
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
+use fastlanes::BitPacking;
+use vortex::array::{BoolArray, ConstantArray, SparseArray};
+use vortex::compute::{compare, CompareFn};
+use vortex::validity::{ArrayValidity, LogicalValidity};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_integer_ptype, match_each_unsigned_integer_ptype, NativePType};
+use vortex_error::VortexResult;
+use vortex_expr::Operator;
+
+use crate::BitPackedArray;
+
+impl CompareFn for BitPackedArray {
+    /// Without patches every value lies in `[0, 2^bit_width)`, so literals outside of that range
+    /// are answered without unpacking. Otherwise the values are unpacked and compared one chunk
+    /// at a time.
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        let literal = ConstantArray::try_from(other)
+            .ok()
+            .map(|c| c.scalar().clone())
+            .filter(|s| !s.is_null());
+        let Some(literal) = literal else {
+            return compare(
+                &self.clone().into_primitive()?.into_array(),
+                other,
+                operator,
+            );
+        };
+
+        if self.patches().is_none() {
+            let literal = match_each_integer_ptype!(self.ptype(), |$P| {
+                <$P>::try_from(&literal.cast(self.dtype())?)? as i128
+            });
+            let above = literal >= 1i128 << self.bit_width();
+            let below = literal < 0;
+            if above || below {
+                let holds = match operator {
+                    Operator::Eq => false,
+                    Operator::NotEq => true,
+                    Operator::Gt | Operator::Gte => below,
+                    Operator::Lt | Operator::Lte => above,
+                };
+                return Ok(if holds {
+                    match self.logical_validity() {
+                        LogicalValidity::AllValid(len) => {
+                            ConstantArray::new(true, len).into_array()
+                        }
+                        LogicalValidity::AllInvalid(len) => {
+                            ConstantArray::new(false, len).into_array()
+                        }
+                        LogicalValidity::Array(validity) => validity,
+                    }
+                } else {
+                    ConstantArray::new(false, self.len()).into_array()
+                });
+            }
+        }
+
+        let packed = self.packed().into_primitive()?;
+        let matches = match_each_unsigned_integer_ptype!(self.ptype(), |$P| {
+            compare_unpacked::<$P>(
+                self,
+                packed.maybe_null_slice::<$P>(),
+                <$P>::try_from(&literal.cast(self.dtype())?)?,
+                operator,
+            )?
+        });
+        let matches = match self.logical_validity().to_null_buffer()? {
+            Some(validity) => validity.inner() & &matches,
+            None => matches,
+        };
+        Ok(BoolArray::from(matches).into_array())
+    }
+}
+
+/// Compare every value against `literal`, unpacking a single chunk of 1024 values at a time.
+fn compare_unpacked<T: NativePType + BitPacking>(
+    array: &BitPackedArray,
+    packed: &[T],
+    literal: T,
+    operator: Operator,
+) -> VortexResult<BooleanBuffer> {
+    let predicate = operator.to_predicate::<T>();
+    let bit_width = array.bit_width();
+    let offset = array.offset();
+    let end = offset + array.len();
+    let elems_per_chunk = 128 * bit_width / size_of::<T>();
+
+    let mut matches = BooleanBufferBuilder::new(array.len());
+    let mut decoded = [T::zero(); 1024];
+    for chunk in 0..(end + 1023) / 1024 {
+        if bit_width > 0 {
+            let packed_chunk = &packed[chunk * elems_per_chunk..][0..elems_per_chunk];
+            unsafe { BitPacking::unchecked_unpack(bit_width, packed_chunk, &mut decoded) };
+        }
+        let start = offset.saturating_sub(chunk * 1024);
+        let stop = (end - chunk * 1024).min(1024);
+        for value in &decoded[start..stop] {
+            matches.append(predicate(value, &literal));
+        }
+    }
+
+    if let Some(patches) = array.patches() {
+        let patches = SparseArray::try_from(patches)?;
+        let values = patches.values().into_primitive()?;
+        for (index, value) in patches
+            .resolved_indices()
+            .into_iter()
+            .zip(values.maybe_null_slice::<T>())
+        {
+            matches.set_bit(index, predicate(value, &literal));
+        }
+    }
+    Ok(matches.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{ConstantArray, PrimitiveArray};
+    use vortex::compute::{compare, slice};
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_expr::Operator;
+
+    use crate::BitPackedArray;
+
+    #[test]
+    fn compare_out_of_range() {
+        let packed =
+            BitPackedArray::encode(&PrimitiveArray::from(vec![1u32, 5, 7]).into_array(), 3)
+                .unwrap()
+                .into_array();
+
+        let matches = compare(
+            &packed,
+            &ConstantArray::new(8u32, 3).into_array(),
+            Operator::Lt,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(matches.boolean_buffer().count_set_bits(), 3);
+
+        let matches = compare(
+            &packed,
+            &ConstantArray::new(100u32, 3).into_array(),
+            Operator::Eq,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(matches.boolean_buffer().count_set_bits(), 0);
+    }
+
+    #[test]
+    fn compare_sliced_with_patches() {
+        let values = (0u32..3000).map(|v| v % 100).collect::<Vec<_>>();
+        let packed = BitPackedArray::encode(&PrimitiveArray::from(values.clone()).into_array(), 5)
+            .unwrap()
+            .into_array();
+        let sliced = slice(&packed, 1100, 2500).unwrap();
+        let matches = compare(
+            &sliced,
+            &ConstantArray::new(40u32, sliced.len()).into_array(),
+            Operator::Gt,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap()
+        .boolean_buffer()
+        .iter()
+        .collect::<Vec<_>>();
+        let expected = values[1100..2500]
+            .iter()
+            .map(|v| *v > 40)
+            .collect::<Vec<_>>();
+        assert_eq!(matches, expected);
+    }
+
+    #[test]
+    fn compare_in_range() {
+        let packed =
+            BitPackedArray::encode(&PrimitiveArray::from(vec![1u32, 5, 7]).into_array(), 3)
+                .unwrap()
+                .into_array();
+
+        let matches = compare(
+            &packed,
+            &ConstantArray::new(5u32, 3).into_array(),
+            Operator::Gte,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            [false, true, true]
+        );
+
+        let matches = compare(
+            &packed,
+            &ConstantArray::new(7u32, 3).into_array(),
+            Operator::NotEq,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            [true, true, false]
+        );
+    }
+}
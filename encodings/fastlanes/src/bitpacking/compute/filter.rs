@@ -0,0 +1,56 @@
+use vortex::array::PrimitiveArray;
+use vortex::compute::{filter, take, FilterFn};
+use vortex::{Array, IntoArray, IntoArrayVariant};
+use vortex_error::VortexResult;
+
+use crate::BitPackedArray;
+
+impl FilterFn for BitPackedArray {
+    /// Sparse selections only unpack the chunks they touch, while dense selections unpack the
+    /// whole array and filter the primitive values.
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let mask = predicate.clone().into_bool()?;
+        let buffer = mask.boolean_buffer();
+        if buffer.count_set_bits() * 2 < self.len() {
+            let indices = buffer
+                .set_indices()
+                .map(|index| index as u64)
+                .collect::<Vec<_>>();
+            take(self.array(), &PrimitiveArray::from(indices).into_array())
+        } else {
+            filter(&self.clone().into_primitive()?.into_array(), predicate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{BoolArray, PrimitiveArray};
+    use vortex::compute::filter;
+    use vortex::{IntoArray, IntoArrayVariant};
+
+    use crate::BitPackedArray;
+
+    #[test]
+    fn filter_sparse_and_dense() {
+        let values = (0u32..3000).map(|v| v % 63).collect::<Vec<_>>();
+        let packed = BitPackedArray::encode(&PrimitiveArray::from(values.clone()).into_array(), 6)
+            .unwrap()
+            .into_array();
+
+        for step in [1000, 2] {
+            let predicate = BoolArray::from((0..3000).map(|i| i % step == 7).collect::<Vec<_>>());
+            let filtered = filter(&packed, predicate.array())
+                .unwrap()
+                .into_primitive()
+                .unwrap();
+            let expected = values
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| i % step == 7)
+                .map(|(_, v)| *v)
+                .collect::<Vec<_>>();
+            assert_eq!(filtered.maybe_null_slice::<u32>(), expected.as_slice());
+        }
+    }
+}
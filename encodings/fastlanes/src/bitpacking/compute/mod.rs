@@ -1,5 +1,5 @@
 use vortex::compute::unary::{scalar_at, ScalarAtFn};
-use vortex::compute::{ArrayCompute, SearchSortedFn, SliceFn, TakeFn};
+use vortex::compute::{ArrayCompute, CompareFn, FilterFn, SearchSortedFn, SliceFn, TakeFn};
 use vortex::ArrayDType;
 use vortex_error::{vortex_err, VortexResult};
 use vortex_scalar::Scalar;
@@ -7,11 +7,21 @@ use vortex_scalar::Scalar;
 use crate::bitpacking::compress::unpack_single;
 use crate::BitPackedArray;
 
+mod compare;
+mod filter;
 mod search_sorted;
 mod slice;
 mod take;
 
 impl ArrayCompute for BitPackedArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -1,16 +1,32 @@
+use vortex::array::ConstantArray;
 use vortex::compute::unary::{scalar_at, ScalarAtFn};
 use vortex::compute::{
-    search_sorted, slice, take, ArrayCompute, SearchResult, SearchSortedFn, SearchSortedSide,
-    SliceFn, TakeFn,
+    compare, count, filter, max, scalar_binary_numeric, scalar_numeric, search_sorted, slice, sum,
+    sum_dtype, take, ArrayCompute, BinaryOperator, CompareFn, FilterFn, OverflowMode,
+    ScalarNumericFn, SearchResult, SearchSortedFn, SearchSortedSide, SliceFn, SumFn, TakeFn,
 };
-use vortex::{Array, ArrayDType, IntoArray};
-use vortex_dtype::match_each_integer_ptype;
-use vortex_error::{vortex_bail, VortexResult};
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_integer_ptype, DType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::Operator;
 use vortex_scalar::{PrimitiveScalar, Scalar, ScalarValue};
 
-use crate::FoRArray;
+use crate::{BitPackedArray, FoRArray};
 
 impl ArrayCompute for FoRArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn scalar_numeric(&self) -> Option<&dyn ScalarNumericFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -23,11 +39,225 @@ impl ArrayCompute for FoRArray {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 }
 
+impl ScalarNumericFn for FoRArray {
+    /// Adding or subtracting a scalar only moves the reference. Values are decoded with wrapping
+    /// arithmetic, so this is exact in wrapping mode, while checked mode relies on the min and
+    /// max statistics to rule out overflow.
+    fn scalar_numeric(
+        &self,
+        rhs: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<Array> {
+        let moves_reference = matches!(operator, BinaryOperator::Add | BinaryOperator::Sub)
+            && !rhs.is_null()
+            && (self.dtype().is_nullable() || !rhs.dtype().is_nullable());
+        if !moves_reference
+            || (mode == OverflowMode::Checked && !self.stays_in_range(rhs, operator))
+        {
+            return scalar_numeric(
+                &self.clone().into_primitive()?.into_array(),
+                rhs,
+                operator,
+                mode,
+            );
+        }
+
+        let reference =
+            scalar_binary_numeric(self.reference(), rhs, operator, OverflowMode::Wrapping)?;
+        Self::try_new(self.encoded(), reference, self.shift()).map(|a| a.into_array())
+    }
+}
+
+impl SumFn for FoRArray {
+    /// Every value is `reference + (encoded << shift)`, so the sum is `n * reference` plus the
+    /// shifted sum of the encoded values, provided that no value wrapped when it was decoded.
+    fn sum(&self) -> VortexResult<Scalar> {
+        let encoded = self.encoded();
+        let dtype = sum_dtype(self.dtype())?;
+        let encoded_sum = sum(&encoded)?;
+        if encoded_sum.is_null() {
+            return Ok(Scalar::null(dtype));
+        }
+        if !self.decodes_exactly()? {
+            return sum(&self.clone().into_primitive()?.into_array());
+        }
+        let encoded_sum = u64::try_from(&encoded_sum)? as i128;
+        let valid = count(&encoded)? as i128;
+
+        let overflow = || vortex_err!("Sum overflows {}", dtype);
+        let reference = match_each_integer_ptype!(self.ptype(), |$P| {
+            <$P>::try_from(self.reference())? as i128
+        });
+        let shifted = 1i128
+            .checked_shl(self.shift() as u32)
+            .and_then(|factor| encoded_sum.checked_mul(factor))
+            .ok_or_else(overflow)?;
+        let total = reference
+            .checked_mul(valid)
+            .and_then(|base| base.checked_add(shifted))
+            .ok_or_else(overflow)?;
+        if self.ptype().is_signed_int() {
+            let total = i64::try_from(total).map_err(|_| overflow())?;
+            Ok(Scalar::primitive(total, dtype.nullability()))
+        } else {
+            let total = u64::try_from(total).map_err(|_| overflow())?;
+            Ok(Scalar::primitive(total, dtype.nullability()))
+        }
+    }
+}
+
+impl CompareFn for FoRArray {
+    /// Comparisons against a literal are evaluated on the encoded values, by subtracting the
+    /// reference from the literal and shifting the difference.
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        let literal = ConstantArray::try_from(other)
+            .ok()
+            .map(|c| c.scalar().clone())
+            .filter(|s| !s.is_null());
+        let exact = literal.is_some() && self.decodes_exactly()?;
+        let ordered = !matches!(operator, Operator::Eq | Operator::NotEq);
+        // Equality holds under wrapping since subtracting the reference is a bijection, but the
+        // order of the encoded values only matches if no value wrapped when decoded.
+        let Some(literal) = literal.filter(|_| exact || (!ordered && self.shift() == 0)) else {
+            return compare(
+                &self.clone().into_primitive()?.into_array(),
+                other,
+                operator,
+            );
+        };
+
+        let encoded = self.encoded();
+        let encoded_dtype =
+            DType::Primitive(self.ptype().to_unsigned(), encoded.dtype().nullability());
+        let (encoded_literal, operator) = if exact {
+            let (encoded_literal, operator) = self.encode_literal(&literal, operator)?;
+            (
+                Scalar::from(encoded_literal).cast(&encoded_dtype)?,
+                operator,
+            )
+        } else {
+            let encoded_literal = match_each_integer_ptype!(self.ptype(), |$P| {
+                let literal = <$P>::try_from(&literal.cast(self.dtype())?)?;
+                let reference = <$P>::try_from(self.reference())?;
+                Scalar::primitive(literal.wrapping_sub(reference), encoded_dtype.nullability())
+            });
+            (
+                encoded_literal.reinterpret_cast(self.ptype().to_unsigned()),
+                operator,
+            )
+        };
+        compare(
+            &encoded,
+            &ConstantArray::new(encoded_literal, self.len()).into_array(),
+            operator,
+        )
+    }
+}
+
+impl FoRArray {
+    /// The encoded literal and operator that compare the encoded values like `operator` compares
+    /// the decoded values against `literal`, provided that every value decodes exactly.
+    fn encode_literal(
+        &self,
+        literal: &Scalar,
+        operator: Operator,
+    ) -> VortexResult<(u64, Operator)> {
+        let delta = match_each_integer_ptype!(self.ptype(), |$P| {
+            <$P>::try_from(&literal.cast(self.dtype())?)? as i128
+                - <$P>::try_from(self.reference())? as i128
+        });
+        // Comparing against zero selects either every valid encoded value or none of them.
+        let (all, none) = ((0, Operator::Gte), (0, Operator::Lt));
+        if delta < 0 {
+            // Every value is greater than the literal.
+            return Ok(match operator {
+                Operator::NotEq | Operator::Gt | Operator::Gte => all,
+                Operator::Eq | Operator::Lt | Operator::Lte => none,
+            });
+        }
+
+        // A value `encoded << shift` lies above the literal if `encoded > quotient`, and only
+        // equals it if the literal has no remainder.
+        let quotient = (delta >> self.shift()) as u64;
+        let remainder = delta & ((1 << self.shift()) - 1) != 0;
+        Ok(match (operator, remainder) {
+            (Operator::Eq, true) => none,
+            (Operator::NotEq, true) => all,
+            (Operator::Gte, true) => (quotient, Operator::Gt),
+            (Operator::Lt, true) => (quotient, Operator::Lte),
+            (operator, _) => (quotient, operator),
+        })
+    }
+
+    /// Whether every value decodes to `reference + (encoded << shift)` without wrapping, which
+    /// holds for compressed arrays but not once wrapping arithmetic has moved the reference.
+    fn decodes_exactly(&self) -> VortexResult<bool> {
+        let Some(encoded_max) = self.encoded_max()? else {
+            return Ok(true);
+        };
+        let max = match_each_integer_ptype!(self.ptype(), |$P| {
+            let reference = <$P>::try_from(self.reference())? as i128;
+            1i128
+                .checked_shl(self.shift() as u32)
+                .and_then(|factor| (encoded_max as i128).checked_mul(factor))
+                .and_then(|shifted| shifted.checked_add(reference))
+                .filter(|max| *max <= <$P>::MAX as i128)
+        });
+        Ok(max.is_some())
+    }
+
+    /// An upper bound of the encoded values, read from their cached maximum or the bit width
+    /// they are packed with, so that they are not decompressed to find it.
+    fn encoded_max(&self) -> VortexResult<Option<u64>> {
+        let encoded = self.encoded();
+        let max = match (
+            encoded.statistics().get(Stat::Max),
+            BitPackedArray::try_from(&encoded),
+        ) {
+            (Some(max), _) => max,
+            (None, Ok(bitpacked)) => {
+                let packed_max = u64::MAX
+                    .checked_shr((64 - bitpacked.bit_width()) as u32)
+                    .unwrap_or(0);
+                let patches_max = bitpacked
+                    .patches()
+                    .map(|patches| max(&patches))
+                    .transpose()?
+                    .filter(|max| !max.is_null())
+                    .map(|max| u64::try_from(&max.cast(&sum_dtype(max.dtype())?)?))
+                    .transpose()?;
+                return Ok(Some(patches_max.map_or(packed_max, |p| p.max(packed_max))));
+            }
+            (None, Err(_)) => max(&encoded)?,
+        };
+        if max.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(u64::try_from(
+            &max.cast(&sum_dtype(encoded.dtype())?)?,
+        )?))
+    }
+
+    /// Whether applying the operator to the min and max values does not overflow.
+    fn stays_in_range(&self, rhs: &Scalar, operator: BinaryOperator) -> bool {
+        [Stat::Min, Stat::Max].into_iter().all(|stat| {
+            self.statistics().compute(stat).map_or(false, |bound| {
+                scalar_binary_numeric(&bound, rhs, operator, OverflowMode::Checked).is_ok()
+            })
+        })
+    }
+}
+
 impl TakeFn for FoRArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         Self::try_new(
@@ -39,6 +269,17 @@ impl TakeFn for FoRArray {
     }
 }
 
+impl FilterFn for FoRArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        Self::try_new(
+            filter(&self.encoded(), predicate)?,
+            self.reference().clone(),
+            self.shift(),
+        )
+        .map(|a| a.into_array())
+    }
+}
+
 impl ScalarAtFn for FoRArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         let encoded_scalar = scalar_at(&self.encoded(), index)?.reinterpret_cast(self.ptype());
@@ -93,10 +334,16 @@ impl SearchSortedFn for FoRArray {
 
 #[cfg(test)]
 mod test {
-    use vortex::array::PrimitiveArray;
+    use vortex::array::{ConstantArray, PrimitiveArray};
     use vortex::compute::unary::scalar_at;
-    use vortex::compute::{search_sorted, SearchResult, SearchSortedSide};
-    use vortex::IntoArray;
+    use vortex::compute::{
+        compare, scalar_numeric, search_sorted, sum, BinaryOperator, OverflowMode, SearchResult,
+        SearchSortedSide,
+    };
+    use vortex::stats::{ArrayStatistics, Stat};
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_expr::Operator;
+    use vortex_scalar::Scalar;
 
     use crate::{for_compress, FoRArray};
 
@@ -126,4 +373,150 @@ mod test {
             SearchResult::NotFound(0)
         );
     }
+
+    #[test]
+    fn for_add_scalar() {
+        let (child, min, shift) = for_compress(&PrimitiveArray::from(vec![11i32, 15, 19])).unwrap();
+        let forarr = FoRArray::try_new(child, min, shift).unwrap().into_array();
+
+        let wrapped = scalar_numeric(
+            &forarr,
+            &Scalar::from(-20i32),
+            BinaryOperator::Add,
+            OverflowMode::Wrapping,
+        )
+        .unwrap();
+        assert!(FoRArray::try_from(&wrapped).is_ok());
+        assert_eq!(
+            wrapped.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[-9, -5, -1]
+        );
+
+        // Checked arithmetic needs the bounds of the array to only move the reference.
+        forarr.statistics().set(Stat::Min, Scalar::from(11i32));
+        forarr.statistics().set(Stat::Max, Scalar::from(19i32));
+        let subtracted = scalar_numeric(
+            &forarr,
+            &Scalar::from(1i32),
+            BinaryOperator::Sub,
+            OverflowMode::Checked,
+        )
+        .unwrap();
+        assert!(FoRArray::try_from(&subtracted).is_ok());
+        assert_eq!(scalar_at(&subtracted, 2).unwrap(), 18.into());
+
+        assert!(scalar_numeric(
+            &forarr,
+            &Scalar::from(i32::MAX),
+            BinaryOperator::Add,
+            OverflowMode::Checked,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn for_sum() {
+        let (child, min, shift) = for_compress(&PrimitiveArray::from_nullable_vec(vec![
+            Some(-11i64),
+            None,
+            Some(40),
+        ]))
+        .unwrap();
+        let forarr = FoRArray::try_new(child, min, shift).unwrap().into_array();
+        assert_eq!(sum(&forarr).unwrap(), Scalar::from(Some(29i64)));
+
+        let (child, min, shift) = for_compress(&PrimitiveArray::from(vec![8u32, 16, 24])).unwrap();
+        assert!(shift > 0);
+        let forarr = FoRArray::try_new(child, min, shift).unwrap().into_array();
+        assert_eq!(sum(&forarr).unwrap(), Scalar::from(Some(48u64)));
+    }
+
+    #[test]
+    fn for_compare() {
+        let (child, min, shift) =
+            for_compress(&PrimitiveArray::from(vec![-5i32, 0, 7, 12])).unwrap();
+        let forarr = FoRArray::try_new(child, min, shift).unwrap().into_array();
+
+        let matches = compare(
+            &forarr,
+            &ConstantArray::new(0i32, 4).into_array(),
+            Operator::Gt,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            [false, false, true, true]
+        );
+
+        let matches = compare(
+            &forarr,
+            &ConstantArray::new(7i32, 4).into_array(),
+            Operator::Eq,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            [false, false, true, false]
+        );
+
+        // Literals below the reference are decided without decoding.
+        let below = ConstantArray::new(-10i32, 4).into_array();
+        let matches = compare(&forarr, &below, Operator::Lte)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(matches.boolean_buffer().count_set_bits(), 0);
+        let matches = compare(&forarr, &below, Operator::Gte)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(matches.boolean_buffer().count_set_bits(), 4);
+        let matches = compare(&forarr, &below, Operator::NotEq)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(matches.boolean_buffer().count_set_bits(), 4);
+    }
+
+    #[test]
+    fn for_compare_shifted() {
+        let values = [8u32, 16, 24, 40];
+        let (child, min, shift) = for_compress(&PrimitiveArray::from(values.to_vec())).unwrap();
+        assert_eq!(shift, 3);
+        let forarr = FoRArray::try_new(child, min, shift).unwrap().into_array();
+
+        for literal in [0, 7, 8, 17, 24, 39, 41] {
+            for operator in [
+                Operator::Eq,
+                Operator::NotEq,
+                Operator::Gt,
+                Operator::Gte,
+                Operator::Lt,
+                Operator::Lte,
+            ] {
+                let matches = compare(
+                    &forarr,
+                    &ConstantArray::new(literal, values.len()).into_array(),
+                    operator,
+                )
+                .unwrap()
+                .into_bool()
+                .unwrap();
+                let predicate = operator.to_predicate::<u32>();
+                let expected = values
+                    .iter()
+                    .map(|v| predicate(v, &literal))
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    matches.boolean_buffer().iter().collect::<Vec<_>>(),
+                    expected,
+                    "{operator} {literal}"
+                );
+            }
+        }
+    }
 }
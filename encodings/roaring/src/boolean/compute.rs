@@ -1,11 +1,12 @@
 use croaring::Bitmap;
+use vortex::array::BoolArray;
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{ArrayCompute, SliceFn};
-use vortex::{Array, IntoArray};
+use vortex::compute::{AndFn, AndKleeneFn, ArrayCompute, NotFn, OrFn, OrKleeneFn, SliceFn, XorFn};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
-use crate::RoaringBoolArray;
+use crate::{roaring_bool_encode, RoaringBoolArray};
 
 impl ArrayCompute for RoaringBoolArray {
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
@@ -15,6 +16,30 @@ impl ArrayCompute for RoaringBoolArray {
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
+
+    fn and(&self) -> Option<&dyn AndFn> {
+        Some(self)
+    }
+
+    fn or(&self) -> Option<&dyn OrFn> {
+        Some(self)
+    }
+
+    fn xor(&self) -> Option<&dyn XorFn> {
+        Some(self)
+    }
+
+    fn and_kleene(&self) -> Option<&dyn AndKleeneFn> {
+        Some(self)
+    }
+
+    fn or_kleene(&self) -> Option<&dyn OrKleeneFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
 }
 
 impl ScalarAtFn for RoaringBoolArray {
@@ -31,3 +56,99 @@ impl SliceFn for RoaringBoolArray {
         Self::try_new(bitmap, stop - start).map(|a| a.into_array())
     }
 }
+
+impl AndFn for RoaringBoolArray {
+    fn and(&self, array: &Array) -> VortexResult<Array> {
+        self.bitmap_op(array, Bitmap::and, AndFn::and)
+    }
+}
+
+impl OrFn for RoaringBoolArray {
+    fn or(&self, array: &Array) -> VortexResult<Array> {
+        self.bitmap_op(array, Bitmap::or, OrFn::or)
+    }
+}
+
+impl XorFn for RoaringBoolArray {
+    fn xor(&self, array: &Array) -> VortexResult<Array> {
+        self.bitmap_op(array, Bitmap::xor, XorFn::xor)
+    }
+}
+
+/// Roaring bitmaps have no nulls, so Kleene logic only differs when the other side is nullable.
+impl AndKleeneFn for RoaringBoolArray {
+    fn and_kleene(&self, array: &Array) -> VortexResult<Array> {
+        self.bitmap_op(array, Bitmap::and, AndKleeneFn::and_kleene)
+    }
+}
+
+impl OrKleeneFn for RoaringBoolArray {
+    fn or_kleene(&self, array: &Array) -> VortexResult<Array> {
+        self.bitmap_op(array, Bitmap::or, OrKleeneFn::or_kleene)
+    }
+}
+
+impl NotFn for RoaringBoolArray {
+    fn not(&self) -> VortexResult<Array> {
+        let bitmap = self.bitmap().flip(0..self.len() as u32);
+        Self::try_new(bitmap, self.len()).map(|a| a.into_array())
+    }
+}
+
+impl RoaringBoolArray {
+    /// Combine with the bitmap of another non-nullable array, or decode when it has nulls.
+    fn bitmap_op(
+        &self,
+        other: &Array,
+        op: impl Fn(&Bitmap, &Bitmap) -> Bitmap,
+        nullable: impl FnOnce(&BoolArray, &Array) -> VortexResult<Array>,
+    ) -> VortexResult<Array> {
+        let other = match Self::try_from(other) {
+            Ok(other) => other,
+            Err(_) if other.dtype().is_nullable() => {
+                return nullable(&self.clone().into_bool()?, other)
+            }
+            Err(_) => roaring_bool_encode(other.clone().into_bool()?)?,
+        };
+        Self::try_new(op(&self.bitmap(), &other.bitmap()), self.len()).map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::BoolArray;
+    use vortex::compute::unary::scalar_at;
+    use vortex::compute::{and_kleene, not, xor};
+    use vortex::IntoArray;
+
+    use crate::RoaringBoolArray;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn bitmap_logic() {
+        let lhs =
+            RoaringBoolArray::encode(BoolArray::from(vec![true, false, true, false]).into_array())
+                .unwrap();
+        let rhs =
+            RoaringBoolArray::encode(BoolArray::from(vec![true, true, false, false]).into_array())
+                .unwrap();
+
+        let result = RoaringBoolArray::try_from(xor(&lhs, &rhs).unwrap()).unwrap();
+        assert_eq!(result.bitmap().to_vec(), vec![1, 2]);
+
+        let result = RoaringBoolArray::try_from(not(&lhs).unwrap()).unwrap();
+        assert_eq!(result.bitmap().to_vec(), vec![1, 3]);
+
+        let nullable = BoolArray::from_iter([None, None, Some(true), None]).into_array();
+        let result = and_kleene(&lhs, &nullable).unwrap();
+        assert!(scalar_at(&result, 0).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&result, 1).unwrap().value().as_bool().unwrap(),
+            Some(false)
+        );
+        assert_eq!(
+            scalar_at(&result, 2).unwrap().value().as_bool().unwrap(),
+            Some(true)
+        );
+    }
+}
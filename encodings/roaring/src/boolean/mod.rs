@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use arrow_buffer::{BooleanBuffer, Buffer as ArrowBuffer};
+use arrow_buffer::BooleanBufferBuilder;
 pub use compress::*;
 pub use croaring::{Bitmap, Portable};
 use serde::{Deserialize, Serialize};
@@ -107,17 +107,14 @@ impl ArrayValidity for RoaringBoolArray {
 
 impl IntoCanonical for RoaringBoolArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
-        // TODO(ngates): benchmark the fastest conversion from BitMap.
-        //  Via bitset requires two copies.
-        let bitset = self
-            .bitmap()
-            .to_bitset()
-            .ok_or_else(|| vortex_err!("Failed to convert RoaringBitmap to Bitset"))?;
-
-        let bytes = &bitset.as_slice()[0..bitset.size_in_bytes()];
-        let buffer = ArrowBuffer::from_slice_ref(bytes);
+        let mut buffer = BooleanBufferBuilder::new(self.len());
+        buffer.append_n(self.len(), false);
+        for index in self.bitmap().iter() {
+            buffer.set_bit(index as usize, true);
+        }
+
         Ok(Canonical::Bool(BoolArray::try_new(
-            BooleanBuffer::new(buffer, 0, bitset.size_in_bits()),
+            buffer.finish(),
             match self.dtype().nullability() {
                 NonNullable => Validity::NonNullable,
                 Nullable => Validity::AllValid,
@@ -1,12 +1,21 @@
+use croaring::Bitmap;
+use num_traits::ToPrimitive;
+use vortex::accessor::ArrayAccessor;
+use vortex::array::BoolArray;
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::ArrayCompute;
-use vortex_dtype::PType;
+use vortex::compute::{ArrayCompute, IsInFn};
+use vortex::{Array, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_integer_ptype, PType};
 use vortex_error::{vortex_err, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::RoaringIntArray;
 
 impl ArrayCompute for RoaringIntArray {
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -28,3 +37,59 @@ impl ScalarAtFn for RoaringIntArray {
         Ok(scalar)
     }
 }
+
+impl IsInFn for RoaringIntArray {
+    /// Intersects the bitmap with the values, the members are then found by their rank.
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        let values = values.clone().into_primitive()?;
+        let other = match_each_integer_ptype!(values.ptype(), |$T| {
+            ArrayAccessor::<$T>::with_iterator(&values, |iter| {
+                iter.flatten().filter_map(|v| v.to_u32()).collect::<Bitmap>()
+            })?
+        });
+
+        let bitmap = self.bitmap();
+        let mut mask = vec![false; self.len()];
+        for value in bitmap.and(&other).iter() {
+            mask[bitmap.rank(value) as usize - 1] = true;
+        }
+        Ok(BoolArray::from(mask).into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex::compute::{filter, is_in};
+    use vortex::{IntoArray, IntoArrayVariant};
+
+    use crate::RoaringIntArray;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn is_in_bitmap() {
+        let ints = PrimitiveArray::from(vec![2u32, 12, 22, 32]).into_array();
+        let array = RoaringIntArray::encode(ints.clone()).unwrap();
+        let values = PrimitiveArray::from(vec![32u64, 5, 12]).into_array();
+
+        let members = is_in(&array, &values).unwrap();
+        assert_eq!(
+            members
+                .clone()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![false, true, false, true]
+        );
+        assert_eq!(
+            filter(&ints, &members)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>(),
+            &[12, 32]
+        );
+    }
+}
@@ -9,7 +9,7 @@ use vortex::{
     impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoArrayVariant,
     IntoCanonical,
 };
-use vortex_dtype::{DType, Nullability};
+use vortex_dtype::DType;
 use vortex_error::{vortex_bail, VortexResult};
 
 use crate::compress::runend_bool_decode;
@@ -42,6 +42,7 @@ impl RunEndBoolArray {
         if !ends.statistics().compute_is_strict_sorted().unwrap_or(true) {
             vortex_bail!("Ends array must be strictly sorted",);
         }
+        let dtype = DType::Bool(validity.nullability());
         let metadata = RunEndBoolMetadata {
             start,
             validity: validity.to_metadata(length)?,
@@ -57,13 +58,7 @@ impl RunEndBoolArray {
             children.push(a)
         }
 
-        Self::try_from_parts(
-            DType::Bool(Nullability::NonNullable),
-            length,
-            metadata,
-            children.into(),
-            StatsSet::new(),
-        )
+        Self::try_from_parts(dtype, length, metadata, children.into(), StatsSet::new())
     }
 
     pub fn find_physical_index(&self, index: usize) -> VortexResult<usize> {
@@ -79,7 +74,7 @@ impl RunEndBoolArray {
     pub fn validity(&self) -> Validity {
         self.metadata()
             .validity
-            .to_validity(self.array().child(2, &Validity::DTYPE, self.len()))
+            .to_validity(self.array().child(1, &Validity::DTYPE, self.len()))
     }
 
     #[inline]
@@ -155,10 +150,11 @@ impl ArrayStatisticsCompute for RunEndBoolArray {}
 mod test {
     use vortex::array::BoolArray;
     use vortex::compute::unary::scalar_at;
-    use vortex::compute::{slice, take};
+    use vortex::compute::{and, not, slice, take, xor};
     use vortex::validity::Validity;
     use vortex::{Array, ArrayDType, IntoArray, IntoCanonical, ToArray};
     use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
 
     use crate::RunEndBoolArray;
 
@@ -176,6 +172,39 @@ mod test {
         assert_eq!(scalar_at(arr.array(), 4).unwrap(), false.into());
     }
 
+    #[test]
+    fn merge_runs() {
+        // [t, t, f, f, f, t, f, t, t, t]
+        let lhs = RunEndBoolArray::try_new(
+            vec![2u32, 5, 6, 7, 10].into_array(),
+            true,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        // [f, f, f, f, t, t, t, t, f, f]
+        let rhs =
+            RunEndBoolArray::try_new(vec![4u8, 8, 10].into_array(), false, Validity::NonNullable)
+                .unwrap();
+
+        let xored = xor(lhs.array(), rhs.array()).unwrap();
+        let xored_runs = RunEndBoolArray::try_from(&xored).unwrap();
+        assert_eq!(xored_runs.ends().len(), 7);
+        assert_eq!(
+            to_bool_vec(&xored),
+            vec![true, true, false, false, true, false, true, false, true, true],
+        );
+
+        let sliced = slice(lhs.array(), 2, 8).unwrap();
+        assert_eq!(
+            to_bool_vec(&and(&sliced, &slice(rhs.array(), 1, 7).unwrap()).unwrap()),
+            vec![false, false, false, true, false, true],
+        );
+        assert_eq!(
+            to_bool_vec(&not(&sliced).unwrap()),
+            vec![true, true, true, false, true, false],
+        );
+    }
+
     #[test]
     fn slice_array() {
         let arr = slice(
@@ -249,6 +278,35 @@ mod test {
         assert_eq!(to_bool_vec(&arr), vec![true, true, false, true]);
     }
 
+    #[test]
+    fn nullable() {
+        // [t, t, null, f, t]
+        let arr = RunEndBoolArray::try_new(
+            vec![3u32, 4, 5].into_array(),
+            true,
+            Validity::from(vec![true, true, false, true, true]),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(arr.dtype(), &DType::Bool(Nullability::Nullable));
+        assert!(scalar_at(&arr, 2).unwrap().is_null());
+
+        let sliced = slice(&arr, 1, 4).unwrap();
+        assert!(scalar_at(&sliced, 1).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&sliced, 2).unwrap(),
+            Scalar::bool(false, Nullability::Nullable)
+        );
+
+        let all_valid =
+            RunEndBoolArray::try_new(vec![2u32, 5].into_array(), false, Validity::AllValid)
+                .unwrap();
+        let anded = and(all_valid.array(), &not(all_valid.array()).unwrap()).unwrap();
+        assert!(RunEndBoolArray::try_from(&anded).is_ok());
+        assert_eq!(anded.dtype(), &DType::Bool(Nullability::Nullable));
+        assert_eq!(to_bool_vec(&anded), vec![false; 5]);
+    }
+
     fn to_bool_vec(arr: &Array) -> Vec<bool> {
         arr.clone()
             .into_canonical()
@@ -1,7 +1,10 @@
-use vortex::array::BoolArray;
+use vortex::array::{BoolArray, PrimitiveArray};
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{slice, ArrayCompute, SliceFn, TakeFn};
-use vortex::{Array, IntoArray, IntoArrayVariant, ToArray};
+use vortex::compute::{
+    slice, AndFn, AndKleeneFn, ArrayCompute, NotFn, OrFn, OrKleeneFn, SliceFn, TakeFn, XorFn,
+};
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant, ToArray};
 use vortex_dtype::match_each_integer_ptype;
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
@@ -21,15 +24,39 @@ impl ArrayCompute for RunEndBoolArray {
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
+
+    fn and(&self) -> Option<&dyn AndFn> {
+        Some(self)
+    }
+
+    fn or(&self) -> Option<&dyn OrFn> {
+        Some(self)
+    }
+
+    fn xor(&self) -> Option<&dyn XorFn> {
+        Some(self)
+    }
+
+    fn and_kleene(&self) -> Option<&dyn AndKleeneFn> {
+        Some(self)
+    }
+
+    fn or_kleene(&self) -> Option<&dyn OrKleeneFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
 }
 
 impl ScalarAtFn for RunEndBoolArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
         let start = self.start();
-        Ok(Scalar::from(value_at_index(
-            self.find_physical_index(index)?,
-            start,
-        )))
+        Scalar::from(value_at_index(self.find_physical_index(index)?, start)).cast(self.dtype())
     }
 }
 
@@ -50,11 +77,12 @@ impl TakeFn for RunEndBoolArray {
                 .collect::<VortexResult<Vec<_>>>()?
         });
         let start = self.start();
-        Ok(BoolArray::from(
+        Ok(BoolArray::from_vec(
             physical_indices
                 .iter()
                 .map(|&it| value_at_index(it, start))
                 .collect::<Vec<_>>(),
+            self.validity().take(indices)?,
         )
         .to_array())
     }
@@ -68,10 +96,120 @@ impl SliceFn for RunEndBoolArray {
         Ok(Self::with_offset_and_size(
             slice(&self.ends(), slice_begin, slice_end + 1)?,
             value_at_index(slice_begin, self.start()),
-            self.validity().slice(slice_begin, slice_end + 1)?,
+            self.validity().slice(start, stop)?,
             stop - start,
             start,
         )?
         .into_array())
     }
 }
+
+impl AndFn for RunEndBoolArray {
+    fn and(&self, array: &Array) -> VortexResult<Array> {
+        self.merge_runs(array, |l, r| l & r, AndFn::and)
+    }
+}
+
+impl OrFn for RunEndBoolArray {
+    fn or(&self, array: &Array) -> VortexResult<Array> {
+        self.merge_runs(array, |l, r| l | r, OrFn::or)
+    }
+}
+
+impl XorFn for RunEndBoolArray {
+    fn xor(&self, array: &Array) -> VortexResult<Array> {
+        self.merge_runs(array, |l, r| l ^ r, XorFn::xor)
+    }
+}
+
+impl AndKleeneFn for RunEndBoolArray {
+    fn and_kleene(&self, array: &Array) -> VortexResult<Array> {
+        self.merge_runs(array, |l, r| l & r, AndKleeneFn::and_kleene)
+    }
+}
+
+impl OrKleeneFn for RunEndBoolArray {
+    fn or_kleene(&self, array: &Array) -> VortexResult<Array> {
+        self.merge_runs(array, |l, r| l | r, OrKleeneFn::or_kleene)
+    }
+}
+
+impl NotFn for RunEndBoolArray {
+    fn not(&self) -> VortexResult<Array> {
+        Ok(Self::with_offset_and_size(
+            self.ends(),
+            !self.start(),
+            self.validity(),
+            self.len(),
+            self.offset(),
+        )?
+        .into_array())
+    }
+}
+
+impl RunEndBoolArray {
+    /// The non-empty logical runs as pairs of exclusive end and value.
+    fn runs(&self) -> VortexResult<Vec<(usize, bool)>> {
+        let ends = self.ends().into_primitive()?;
+        let mut runs: Vec<(usize, bool)> = Vec::with_capacity(ends.len());
+        match_each_integer_ptype!(ends.ptype(), |$E| {
+            for (idx, end) in ends.maybe_null_slice::<$E>().iter().enumerate() {
+                let end = (*end as usize).saturating_sub(self.offset()).min(self.len());
+                if end > runs.last().map_or(0, |(prev, _)| *prev) {
+                    runs.push((end, value_at_index(idx, self.start())));
+                }
+            }
+        });
+        Ok(runs)
+    }
+
+    /// Without nulls on either side every operation is decided run by run, otherwise we decode.
+    fn merge_runs(
+        &self,
+        other: &Array,
+        op: impl Fn(bool, bool) -> bool,
+        decoded: impl FnOnce(&BoolArray, &Array) -> VortexResult<Array>,
+    ) -> VortexResult<Array> {
+        let other = match Self::try_from(other) {
+            Ok(other)
+                if !self.is_empty()
+                    && self.logical_validity().all_valid()
+                    && other.logical_validity().all_valid() =>
+            {
+                other
+            }
+            _ => return decoded(&self.clone().into_bool()?, other),
+        };
+
+        let (lhs, rhs) = (self.runs()?, other.runs()?);
+        let (mut l, mut r) = (0, 0);
+        let mut ends: Vec<u64> = Vec::with_capacity(lhs.len() + rhs.len());
+        let mut values: Vec<bool> = Vec::with_capacity(lhs.len() + rhs.len());
+        while l < lhs.len() && r < rhs.len() {
+            let end = lhs[l].0.min(rhs[r].0);
+            let value = op(lhs[l].1, rhs[r].1);
+            if values.last() == Some(&value) {
+                if let Some(last) = ends.last_mut() {
+                    *last = end as u64;
+                }
+            } else {
+                ends.push(end as u64);
+                values.push(value);
+            }
+            l += usize::from(lhs[l].0 == end);
+            r += usize::from(rhs[r].0 == end);
+        }
+
+        let validity = if self.dtype().is_nullable() || other.dtype().is_nullable() {
+            Validity::AllValid
+        } else {
+            Validity::NonNullable
+        };
+        Self::try_new(
+            PrimitiveArray::from(ends).into_array(),
+            values.first().copied().unwrap_or(false),
+            validity,
+        )
+        .map(|a| a.into_array())
+    }
+}
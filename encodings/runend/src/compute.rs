@@ -1,29 +1,379 @@
-use vortex::array::PrimitiveArray;
-use vortex::compute::unary::{scalar_at, ScalarAtFn};
-use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
-use vortex::{Array, IntoArray, IntoArrayVariant};
+use vortex::array::{BoolArray, ConstantArray, PrimitiveArray};
+use vortex::compute::unary::{fill_null, scalar_at, FillNullFn, IsNullFn, ScalarAtFn};
+use vortex::compute::{
+    compare, filter, group_ids, min_max, not, search_sorted, slice, sort_indices, take,
+    weighted_sum, ArrayCompute, CompareFn, FilterFn, GroupIdsFn, MinMax, MinMaxFn, SearchResult,
+    SearchSorted, SearchSortedFn, SearchSortedSide, SliceFn, SortFn, SortOptions, SumFn, TakeFn,
+};
+use vortex::validity::{filter_validity, ArrayValidity, LogicalValidity, Validity};
+use vortex::{Array, ArrayDType, AsArray, IntoArray, IntoArrayVariant, IntoCanonical};
 use vortex_dtype::match_each_integer_ptype;
 use vortex_error::{vortex_bail, VortexResult};
+use vortex_expr::Operator;
+use vortex_runend_bool::RunEndBoolArray;
 use vortex_scalar::Scalar;
 
 use crate::RunEndArray;
 
 impl ArrayCompute for RunEndArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn group_ids(&self) -> Option<&dyn GroupIdsFn> {
+        Some(self)
+    }
+
+    fn is_null(&self) -> Option<&dyn IsNullFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 }
 
+impl RunEndArray {
+    /// The logical index where a run starts, clamped to the offset and length.
+    fn run_start(&self, run: usize) -> VortexResult<usize> {
+        let start = match run {
+            0 => 0,
+            _ => usize::try_from(&scalar_at(&self.ends(), run - 1)?)?,
+        };
+        Ok(start.saturating_sub(self.offset()).min(self.len()))
+    }
+
+    /// The number of logical elements covered by each run, after applying the offset and length.
+    fn run_lengths(&self) -> VortexResult<Vec<u64>> {
+        let ends = self.ends().into_primitive()?;
+        let start = self.offset() as u64;
+        let stop = start + self.len() as u64;
+        let mut prev_end = 0u64;
+        Ok(match_each_integer_ptype!(ends.ptype(), |$P| {
+            ends.maybe_null_slice::<$P>()
+                .iter()
+                .map(|end| {
+                    let end = *end as u64;
+                    let length = end.min(stop).saturating_sub(prev_end.max(start));
+                    prev_end = end;
+                    length
+                })
+                .collect()
+        }))
+    }
+
+    /// The number of valid logical elements covered by each run.
+    fn valid_run_lengths(&self) -> VortexResult<Vec<u64>> {
+        let run_lengths = self.run_lengths()?;
+        let Some(nulls) = self.logical_validity().to_null_buffer()? else {
+            return Ok(run_lengths);
+        };
+        let mut start = 0;
+        Ok(run_lengths
+            .into_iter()
+            .map(|length| {
+                let valid = nulls.inner().slice(start, length as usize).count_set_bits();
+                start += length as usize;
+                valid as u64
+            })
+            .collect())
+    }
+
+    /// Whether the runs can be aggregated directly, which requires every element to be valid.
+    fn all_valid(&self) -> bool {
+        matches!(self.logical_validity(), LogicalValidity::AllValid(_))
+    }
+}
+
+impl SearchSortedFn for RunEndArray {
+    /// Searches the run values, and returns the logical index where the resulting run starts.
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        if !matches!(self.validity(), Validity::NonNullable | Validity::AllValid) {
+            return Ok(SearchSorted::search_sorted(
+                self.as_array_ref(),
+                value,
+                side,
+            ));
+        }
+
+        let run = search_sorted(&self.values(), value.clone(), side)?;
+        let index = self.run_start(run.to_index())?;
+        // A slice can keep a run past its end, which is equal but not part of the array.
+        Ok(match (run, side) {
+            (SearchResult::Found(run), SearchSortedSide::Left)
+                if self.run_start(run)? < self.len() =>
+            {
+                SearchResult::Found(index)
+            }
+            (SearchResult::Found(run), SearchSortedSide::Right)
+                if self.run_start(run - 1)? < self.len() =>
+            {
+                SearchResult::Found(index)
+            }
+            _ => SearchResult::NotFound(index),
+        })
+    }
+}
+
+impl IsNullFn for RunEndArray {
+    fn is_null(&self) -> VortexResult<Array> {
+        match self.logical_validity() {
+            LogicalValidity::AllValid(len) => Ok(ConstantArray::new(false, len).into_array()),
+            LogicalValidity::AllInvalid(len) => Ok(ConstantArray::new(true, len).into_array()),
+            LogicalValidity::Array(validity) => not(&validity),
+        }
+    }
+}
+
+impl FillNullFn for RunEndArray {
+    /// Splits the runs where their elements change between valid and null, and fills the null
+    /// runs.
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array> {
+        let nulls = match self.logical_validity() {
+            LogicalValidity::AllValid(_) => None,
+            LogicalValidity::AllInvalid(len) => {
+                return Ok(ConstantArray::new(fill_value.clone(), len).into_array())
+            }
+            LogicalValidity::Array(validity) => Some(validity.into_bool()?.boolean_buffer()),
+        };
+
+        let mut ends = Vec::new();
+        let mut runs = Vec::new();
+        let mut valid = Vec::new();
+        let mut start = 0;
+        for (run, length) in self.run_lengths()?.into_iter().enumerate() {
+            let stop = start + length as usize;
+            for idx in start..stop {
+                let is_valid = nulls.as_ref().map_or(true, |nulls| nulls.value(idx));
+                // Neighbouring null elements are filled alike, even across runs.
+                if valid.last() == Some(&is_valid) && (idx > start || !is_valid) {
+                    if let Some(end) = ends.last_mut() {
+                        *end += 1;
+                    }
+                } else {
+                    ends.push(idx as u64 + 1);
+                    runs.push(run as u64);
+                    valid.push(is_valid);
+                }
+            }
+            start = stop;
+        }
+
+        let values =
+            take(&self.values(), &PrimitiveArray::from(runs).into_array())?.into_primitive()?;
+        let values = PrimitiveArray::new(
+            values.buffer().clone(),
+            values.ptype(),
+            Validity::from(valid),
+        );
+        Ok(Self::with_offset_and_size(
+            PrimitiveArray::from(ends).into_array(),
+            fill_null(&values.into_array(), fill_value)?,
+            Validity::NonNullable,
+            self.len(),
+            0,
+        )?
+        .into_array())
+    }
+}
+
+impl GroupIdsFn for RunEndArray {
+    /// Groups the values of the runs that are not sliced away, and repeats their group ids.
+    fn group_ids(&self) -> VortexResult<(PrimitiveArray, Array)> {
+        if !self.all_valid() {
+            return group_ids(&self.clone().into_canonical()?.into());
+        }
+
+        let lengths = self.run_lengths()?;
+        let runs = lengths
+            .iter()
+            .enumerate()
+            .filter(|(_, &length)| length > 0)
+            .map(|(run, _)| run as u64)
+            .collect::<Vec<_>>();
+        let (run_codes, uniques) = group_ids(&take(
+            &self.values(),
+            &PrimitiveArray::from(runs.clone()).into_array(),
+        )?)?;
+
+        let mut codes = Vec::with_capacity(self.len());
+        for (&run, &code) in runs.iter().zip(run_codes.maybe_null_slice::<u64>()) {
+            codes.extend(std::iter::repeat(code).take(lengths[run as usize] as usize));
+        }
+        Ok((PrimitiveArray::from(codes), uniques))
+    }
+}
+
+impl SortFn for RunEndArray {
+    /// Sorts the run values and expands each run in order, which keeps the sort stable as a run
+    /// covers consecutive positions.
+    fn sort_indices(&self, options: SortOptions) -> VortexResult<PrimitiveArray> {
+        if !self.all_valid() {
+            return sort_indices(&self.clone().into_canonical()?.into(), options);
+        }
+
+        let lengths = self.run_lengths()?;
+        let starts = lengths
+            .iter()
+            .scan(0u64, |start, length| {
+                let run_start = *start;
+                *start += length;
+                Some(run_start)
+            })
+            .collect::<Vec<_>>();
+        let order = sort_indices(&self.values(), options)?;
+        let mut indices = Vec::with_capacity(self.len());
+        for &run in order.maybe_null_slice::<u64>() {
+            let run = run as usize;
+            indices.extend(starts[run]..starts[run] + lengths[run]);
+        }
+        Ok(PrimitiveArray::from(indices))
+    }
+}
+
+impl CompareFn for RunEndArray {
+    /// Comparing against a literal compares each run value once, producing a run-end encoded
+    /// boolean array with adjacent runs of the same result merged.
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        let literal = ConstantArray::try_from(other).ok();
+        let Some(literal) = literal.filter(|_| self.all_valid() && !self.is_empty()) else {
+            return compare(&self.clone().into_canonical()?.into(), other, operator);
+        };
+
+        let values = self.values();
+        let matches = compare(
+            &values,
+            &ConstantArray::new(literal.scalar().clone(), values.len()).into_array(),
+            operator,
+        )?
+        .into_bool()?;
+
+        let mut ends = Vec::new();
+        let mut start = None;
+        let mut previous = None;
+        let mut end = 0u64;
+        for (matched, length) in matches.boolean_buffer().iter().zip(self.run_lengths()?) {
+            if length == 0 {
+                continue;
+            }
+            end += length;
+            if previous == Some(matched) {
+                if let Some(last) = ends.last_mut() {
+                    *last = end;
+                }
+            } else {
+                ends.push(end);
+                start.get_or_insert(matched);
+                previous = Some(matched);
+            }
+        }
+
+        RunEndBoolArray::try_new(
+            PrimitiveArray::from(ends).into_array(),
+            start.unwrap_or(false),
+            Validity::NonNullable,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl FilterFn for RunEndArray {
+    /// Each run keeps as many elements as the predicate selects from it, dropping runs that
+    /// lose all of their elements.
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let mask = predicate.clone().into_bool()?;
+        let buffer = mask.boolean_buffer();
+        if buffer.count_set_bits() == 0 {
+            return filter(&self.clone().into_canonical()?.into(), predicate);
+        }
+
+        let mut ends = Vec::new();
+        let mut keep = Vec::new();
+        let mut position = 0;
+        let mut end = 0u64;
+        for length in self.run_lengths()? {
+            let selected = buffer.slice(position, length as usize).count_set_bits() as u64;
+            position += length as usize;
+            if selected > 0 {
+                end += selected;
+                ends.push(end);
+            }
+            keep.push(selected > 0);
+        }
+
+        Self::try_new(
+            PrimitiveArray::from(ends).into_array(),
+            filter(&self.values(), &BoolArray::from(keep).into_array())?,
+            filter_validity(self.validity(), predicate)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl SumFn for RunEndArray {
+    /// Each value is weighted by the number of valid elements in its run.
+    fn sum(&self) -> VortexResult<Scalar> {
+        weighted_sum(&self.values(), &self.valid_run_lengths()?)
+    }
+}
+
+impl MinMaxFn for RunEndArray {
+    fn min_max(&self) -> VortexResult<MinMax> {
+        if !self.all_valid() {
+            return min_max(&self.clone().into_canonical()?.into());
+        }
+        let run_lengths = self.run_lengths()?;
+        if run_lengths.iter().all(|length| *length > 0) {
+            return min_max(&self.values());
+        }
+        let non_empty = run_lengths
+            .iter()
+            .map(|length| *length > 0)
+            .collect::<Vec<_>>();
+        min_max(&filter(
+            &self.values(),
+            &BoolArray::from(non_empty).into_array(),
+        )?)
+    }
+}
+
 impl ScalarAtFn for RunEndArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
         scalar_at(&self.values(), self.find_physical_index(index)?)
     }
 }
@@ -59,7 +409,7 @@ impl SliceFn for RunEndArray {
         Ok(Self::with_offset_and_size(
             slice(&self.ends(), slice_begin, slice_end + 1)?,
             slice(&self.values(), slice_begin, slice_end + 1)?,
-            self.validity().slice(slice_begin, slice_end + 1)?,
+            self.validity().slice(start, stop)?,
             stop - start,
             start,
         )?
@@ -69,10 +419,17 @@ impl SliceFn for RunEndArray {
 
 #[cfg(test)]
 mod test {
-    use vortex::array::PrimitiveArray;
-    use vortex::compute::take;
-    use vortex::compute::unary::scalar_at;
-    use vortex::{Array, IntoArray, IntoArrayVariant, ToArray};
+    use vortex::array::{BoolArray, ConstantArray, PrimitiveArray};
+    use vortex::compute::unary::{fill_null, scalar_at};
+    use vortex::compute::{
+        compare, filter, group_ids, max, min, search_sorted, search_sorted_many, slice, sort,
+        sort_indices, sum, take, SearchResult, SearchSortedSide, SortOptions,
+    };
+    use vortex::validity::Validity;
+    use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant, ToArray};
+    use vortex_expr::Operator;
+    use vortex_runend_bool::RunEndBoolArray;
+    use vortex_scalar::Scalar;
 
     use crate::RunEndArray;
 
@@ -84,6 +441,126 @@ mod test {
         .into_array()
     }
 
+    #[test]
+    fn ree_fill_null() {
+        let array = RunEndArray::encode(
+            PrimitiveArray::from_nullable_vec(vec![
+                Some(1i32),
+                Some(1),
+                None,
+                None,
+                Some(1),
+                Some(2),
+                None,
+            ])
+            .into_array(),
+        )
+        .unwrap()
+        .into_array();
+        let filled = fill_null(&slice(&array, 1, 7).unwrap(), &Scalar::from(9i32)).unwrap();
+        assert!(!filled.dtype().is_nullable());
+
+        let filled = RunEndArray::try_from(filled).unwrap();
+        assert_eq!(
+            filled
+                .ends()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[1, 3, 4, 5, 6]
+        );
+        assert_eq!(
+            filled.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 9, 9, 1, 2, 9]
+        );
+    }
+
+    #[test]
+    fn ree_search_sorted() {
+        let array = RunEndArray::try_new(
+            PrimitiveArray::from(vec![2u32, 5, 6, 9]).into_array(),
+            PrimitiveArray::from(vec![1i32, 3, 4, 7]).into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let search = |array: &Array, value: i32, side| search_sorted(array, value, side).unwrap();
+
+        assert_eq!(
+            search(&array, 3, SearchSortedSide::Left),
+            SearchResult::Found(2)
+        );
+        assert_eq!(
+            search(&array, 3, SearchSortedSide::Right),
+            SearchResult::Found(5)
+        );
+        assert_eq!(
+            search(&array, 2, SearchSortedSide::Left),
+            SearchResult::NotFound(2)
+        );
+        assert_eq!(
+            search(&array, 8, SearchSortedSide::Right),
+            SearchResult::NotFound(9)
+        );
+
+        // The slice keeps the run of 4 past its end.
+        let sliced = slice(&array, 3, 5).unwrap();
+        assert_eq!(
+            search(&sliced, 3, SearchSortedSide::Left),
+            SearchResult::Found(0)
+        );
+        assert_eq!(
+            search(&sliced, 4, SearchSortedSide::Left),
+            SearchResult::NotFound(2)
+        );
+        assert_eq!(
+            search(&sliced, 4, SearchSortedSide::Right),
+            SearchResult::NotFound(2)
+        );
+        assert_eq!(
+            search_sorted_many(&array, &[0i32, 4, 7], SearchSortedSide::Left).unwrap(),
+            vec![
+                SearchResult::NotFound(0),
+                SearchResult::Found(5),
+                SearchResult::Found(6)
+            ]
+        );
+    }
+
+    #[test]
+    fn ree_group_ids() {
+        let sliced = slice(&ree_array(), 4, 10).unwrap();
+        let (codes, uniques) = group_ids(&sliced).unwrap();
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[0, 0, 1, 1, 2, 2]);
+        assert_eq!(
+            uniques.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[4, 2, 5]
+        );
+    }
+
+    #[test]
+    fn ree_sort() {
+        let sliced = slice(&ree_array(), 2, 10).unwrap();
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        assert_eq!(
+            sort_indices(&sliced, options)
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[6, 7, 1, 2, 3, 4, 5, 0]
+        );
+        assert_eq!(
+            sort(&sliced, SortOptions::default())
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            &[1, 2, 2, 4, 4, 4, 5, 5]
+        );
+    }
+
     #[test]
     fn ree_take() {
         let taken = take(&ree_array(), PrimitiveArray::from(vec![9, 8, 1, 3]).array()).unwrap();
@@ -113,4 +590,102 @@ mod test {
         let scalar = scalar_at(&ree_array(), 11).unwrap();
         assert_eq!(scalar, 5.into());
     }
+
+    #[test]
+    fn ree_sum() {
+        assert_eq!(sum(&ree_array()).unwrap(), Scalar::from(Some(39i64)));
+        let sliced = slice(&ree_array(), 2, 7).unwrap();
+        assert_eq!(sum(&sliced).unwrap(), Scalar::from(Some(15i64)));
+    }
+
+    #[test]
+    fn ree_sum_nulls() {
+        let array = RunEndArray::encode(
+            PrimitiveArray::from_nullable_vec(vec![
+                Some(1i32),
+                Some(1),
+                None,
+                Some(4),
+                Some(4),
+                None,
+                None,
+            ])
+            .into_array(),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(sum(&array).unwrap(), Scalar::from(Some(10i64)));
+        let sliced = slice(&array, 1, 4).unwrap();
+        assert_eq!(sum(&sliced).unwrap(), Scalar::from(Some(5i64)));
+    }
+
+    #[test]
+    fn ree_min_max_sliced() {
+        // The slice ends at the start of the run of 2s, which must not count towards the minimum.
+        let sliced = slice(&ree_array(), 3, 6).unwrap();
+        assert_eq!(min(&sliced).unwrap(), Scalar::from(Some(4i32)));
+        assert_eq!(max(&sliced).unwrap(), Scalar::from(Some(4i32)));
+    }
+
+    #[test]
+    fn ree_compare() {
+        let sliced = slice(&ree_array(), 2, 11).unwrap();
+        let matches = compare(
+            &sliced,
+            &ConstantArray::new(4, sliced.len()).into_array(),
+            Operator::Gt,
+        )
+        .unwrap();
+        let run_end_bool = RunEndBoolArray::try_from(&matches).unwrap();
+        // The runs of 1, 4 and 2 all compare false and are merged.
+        assert_eq!(run_end_bool.ends().len(), 2);
+        assert_eq!(
+            matches
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![false, false, false, false, false, false, true, true, true]
+        );
+    }
+
+    #[test]
+    fn ree_filter() {
+        let predicate = BoolArray::from(
+            [
+                true, false, false, false, false, false, true, true, false, true, false, true,
+            ]
+            .to_vec(),
+        );
+        let filtered = filter(&ree_array(), predicate.array()).unwrap();
+        assert!(RunEndArray::try_from(&filtered).is_ok());
+        assert_eq!(
+            filtered.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 2, 2, 5, 5]
+        );
+    }
+
+    #[test]
+    fn ree_filter_nulls() {
+        let array = RunEndArray::encode(
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(1), Some(3), None])
+                .into_array(),
+        )
+        .unwrap()
+        .into_array();
+        let predicate = BoolArray::from(vec![false, true, true, true, false]);
+        let filtered = filter(&array, predicate.array()).unwrap();
+        assert!(RunEndArray::try_from(&filtered).is_ok());
+        assert_eq!(
+            (0..filtered.len())
+                .map(|i| scalar_at(&filtered, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::null(array.dtype().clone()),
+                Scalar::from(Some(1i32)),
+                Scalar::from(Some(3i32)),
+            ]
+        );
+    }
 }
@@ -1,67 +1,266 @@
+use vortex::array::ConstantArray;
 use vortex::compute::unary::{scalar_at, ScalarAtFn};
-use vortex::compute::{slice, ArrayCompute, SliceFn};
-use vortex::{Array, IntoArray};
+use vortex::compute::{
+    compare, filter, search_sorted, slice, ArrayCompute, CompareFn, FilterFn, SearchResult,
+    SearchSorted, SearchSortedFn, SearchSortedSide, SliceFn,
+};
+use vortex::{Array, ArrayDType, AsArray, IntoArray, IntoArrayVariant};
 use vortex_dtype::PType;
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::Operator;
 use vortex_scalar::{PrimitiveScalar, Scalar};
 use zigzag::ZigZag as ExternalZigZag;
 
 use crate::ZigZagArray;
 
 impl ArrayCompute for ZigZagArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
 }
 
+impl ZigZagArray {
+    /// The encoded value of a non-null scalar of the array's dtype.
+    fn encode_scalar(&self, value: &Scalar) -> VortexResult<Scalar> {
+        let nullability = self.dtype().nullability();
+        Ok(match PType::try_from(self.dtype())? {
+            PType::I8 => Scalar::primitive(i8::encode(i8::try_from(value)?), nullability),
+            PType::I16 => Scalar::primitive(i16::encode(i16::try_from(value)?), nullability),
+            PType::I32 => Scalar::primitive(i32::encode(i32::try_from(value)?), nullability),
+            PType::I64 => Scalar::primitive(i64::encode(i64::try_from(value)?), nullability),
+            ptype => vortex_bail!("ZigZag cannot encode {}", ptype),
+        })
+    }
+}
+
 impl ScalarAtFn for ZigZagArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         let scalar = scalar_at(&self.encoded(), index)?;
         if scalar.is_null() {
-            return Ok(scalar);
+            return Ok(Scalar::null(self.dtype().clone()));
         }
 
+        let nullability = self.dtype().nullability();
         let pscalar = PrimitiveScalar::try_from(&scalar)?;
         match pscalar.ptype() {
-            PType::U8 => Ok(i8::decode(pscalar.typed_value::<u8>().ok_or_else(|| {
-                vortex_err!(
-                    "Cannot decode provided scalar: expected u8, got ptype {}",
-                    pscalar.ptype()
-                )
-            })?)
-            .into()),
-            PType::U16 => Ok(i16::decode(pscalar.typed_value::<u16>().ok_or_else(|| {
-                vortex_err!(
-                    "Cannot decode provided scalar: expected u16, got ptype {}",
-                    pscalar.ptype()
-                )
-            })?)
-            .into()),
-            PType::U32 => Ok(i32::decode(pscalar.typed_value::<u32>().ok_or_else(|| {
-                vortex_err!(
-                    "Cannot decode provided scalar: expected u32, got ptype {}",
-                    pscalar.ptype()
-                )
-            })?)
-            .into()),
-            PType::U64 => Ok(i64::decode(pscalar.typed_value::<u64>().ok_or_else(|| {
-                vortex_err!(
-                    "Cannot decode provided scalar: expected u64, got ptype {}",
-                    pscalar.ptype()
-                )
-            })?)
-            .into()),
+            PType::U8 => Ok(Scalar::primitive(
+                i8::decode(pscalar.typed_value::<u8>().ok_or_else(|| {
+                    vortex_err!(
+                        "Cannot decode provided scalar: expected u8, got ptype {}",
+                        pscalar.ptype()
+                    )
+                })?),
+                nullability,
+            )),
+            PType::U16 => Ok(Scalar::primitive(
+                i16::decode(pscalar.typed_value::<u16>().ok_or_else(|| {
+                    vortex_err!(
+                        "Cannot decode provided scalar: expected u16, got ptype {}",
+                        pscalar.ptype()
+                    )
+                })?),
+                nullability,
+            )),
+            PType::U32 => Ok(Scalar::primitive(
+                i32::decode(pscalar.typed_value::<u32>().ok_or_else(|| {
+                    vortex_err!(
+                        "Cannot decode provided scalar: expected u32, got ptype {}",
+                        pscalar.ptype()
+                    )
+                })?),
+                nullability,
+            )),
+            PType::U64 => Ok(Scalar::primitive(
+                i64::decode(pscalar.typed_value::<u64>().ok_or_else(|| {
+                    vortex_err!(
+                        "Cannot decode provided scalar: expected u64, got ptype {}",
+                        pscalar.ptype()
+                    )
+                })?),
+                nullability,
+            )),
             _ => unreachable!(),
         }
     }
 }
 
+impl CompareFn for ZigZagArray {
+    /// ZigZag encoding does not preserve order, so only equality against a literal is evaluated
+    /// on the encoded values.
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        let literal = ConstantArray::try_from(other)
+            .ok()
+            .map(|c| c.scalar().clone())
+            .filter(|s| !s.is_null());
+        let Some(literal) = literal.filter(|_| matches!(operator, Operator::Eq | Operator::NotEq))
+        else {
+            return compare(
+                &self.clone().into_primitive()?.into_array(),
+                other,
+                operator,
+            );
+        };
+
+        let encoded_literal = self.encode_scalar(&literal.cast(self.dtype())?)?;
+        compare(
+            &self.encoded(),
+            &ConstantArray::new(encoded_literal, self.len()).into_array(),
+            operator,
+        )
+    }
+}
+
+impl SearchSortedFn for ZigZagArray {
+    /// Sorted values start with the negative values, whose codes decrease, followed by the
+    /// non-negative values, whose codes increase. Non-negative needles are searched among the
+    /// codes of the latter.
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        if value.is_null() {
+            return Ok(SearchSorted::search_sorted(
+                self.as_array_ref(),
+                value,
+                side,
+            ));
+        }
+
+        // Scalars of different dtypes, including nullability, do not compare.
+        let value = &value.cast(self.dtype())?;
+        let zero = Scalar::from(0i8).cast(self.dtype())?;
+        let negatives =
+            SearchSorted::search_sorted(self.as_array_ref(), &zero, SearchSortedSide::Left)
+                .to_index();
+        if value < &zero {
+            return Ok(SearchSorted::search_sorted(
+                &slice(self.as_array_ref(), 0, negatives)?,
+                value,
+                side,
+            ));
+        }
+
+        let non_negatives = slice(&self.encoded(), negatives, self.len())?;
+        Ok(search_sorted(&non_negatives, self.encode_scalar(value)?, side)?.map(|i| i + negatives))
+    }
+}
+
+impl FilterFn for ZigZagArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        Ok(Self::try_new(filter(&self.encoded(), predicate)?)?.into_array())
+    }
+}
+
 impl SliceFn for ZigZagArray {
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
         Ok(Self::try_new(slice(&self.encoded(), start, stop)?)?.into_array())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{ConstantArray, PrimitiveArray};
+    use vortex::compute::{compare, search_sorted_many, SearchResult, SearchSortedSide};
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_expr::Operator;
+
+    use crate::ZigZagArray;
+
+    #[test]
+    fn compare_encoded() {
+        let zigzag =
+            ZigZagArray::encode(&PrimitiveArray::from(vec![-3i32, 0, 3, -3]).into_array()).unwrap();
+
+        let matches = compare(
+            &zigzag,
+            &ConstantArray::new(-3i32, 4).into_array(),
+            Operator::Eq,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            [true, false, false, true]
+        );
+
+        let matches = compare(
+            &zigzag,
+            &ConstantArray::new(3i32, 4).into_array(),
+            Operator::NotEq,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            [true, true, false, true]
+        );
+
+        let matches = compare(
+            &zigzag,
+            &ConstantArray::new(0i32, 4).into_array(),
+            Operator::Lt,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            [true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn search_sorted_encoded() {
+        let zigzag = ZigZagArray::encode(
+            &PrimitiveArray::from(vec![-5i64, -2, -2, 0, 3, 3, 8]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            search_sorted_many(&zigzag, &[-2i64, -6, -1, 0, 3, 9], SearchSortedSide::Left).unwrap(),
+            vec![
+                SearchResult::Found(1),
+                SearchResult::NotFound(0),
+                SearchResult::NotFound(3),
+                SearchResult::Found(3),
+                SearchResult::Found(4),
+                SearchResult::NotFound(7)
+            ]
+        );
+        assert_eq!(
+            search_sorted_many(&zigzag, &[-2i64, 3], SearchSortedSide::Right).unwrap(),
+            vec![SearchResult::Found(3), SearchResult::Found(6)]
+        );
+    }
+
+    #[test]
+    fn search_sorted_nullable() {
+        let zigzag = ZigZagArray::encode(
+            &PrimitiveArray::from_nullable_vec(vec![Some(-5i32), Some(-2), Some(0), Some(3)])
+                .into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            search_sorted_many(&zigzag, &[-2i32, -1, 3], SearchSortedSide::Left).unwrap(),
+            vec![
+                SearchResult::Found(1),
+                SearchResult::NotFound(2),
+                SearchResult::Found(3)
+            ]
+        );
+    }
+}
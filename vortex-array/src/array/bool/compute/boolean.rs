@@ -1,11 +1,13 @@
 use arrow_arith::boolean;
 use arrow_array::cast::AsArray as _;
+use arrow_array::{Array as _, BooleanArray};
+use arrow_buffer::NullBuffer;
 use vortex_error::VortexResult;
 
 use crate::array::BoolArray;
 use crate::arrow::FromArrowArray as _;
-use crate::compute::{AndFn, OrFn};
-use crate::{Array, IntoCanonical};
+use crate::compute::{AndFn, AndKleeneFn, NotFn, OrFn, OrKleeneFn, XorFn};
+use crate::{Array, ArrayDType, IntoCanonical};
 
 impl OrFn for BoolArray {
     fn or(&self, array: &Array) -> VortexResult<Array> {
@@ -34,3 +36,50 @@ impl AndFn for BoolArray {
         Ok(Array::from_arrow(&array, true))
     }
 }
+
+impl XorFn for BoolArray {
+    fn xor(&self, array: &Array) -> VortexResult<Array> {
+        arrow_binary(self, array, |lhs, rhs| {
+            Ok(BooleanArray::new(
+                lhs.values() ^ rhs.values(),
+                NullBuffer::union(lhs.nulls(), rhs.nulls()),
+            ))
+        })
+    }
+}
+
+impl AndKleeneFn for BoolArray {
+    fn and_kleene(&self, array: &Array) -> VortexResult<Array> {
+        arrow_binary(self, array, |lhs, rhs| Ok(boolean::and_kleene(lhs, rhs)?))
+    }
+}
+
+impl OrKleeneFn for BoolArray {
+    fn or_kleene(&self, array: &Array) -> VortexResult<Array> {
+        arrow_binary(self, array, |lhs, rhs| Ok(boolean::or_kleene(lhs, rhs)?))
+    }
+}
+
+impl NotFn for BoolArray {
+    fn not(&self) -> VortexResult<Array> {
+        let array = self.clone().into_canonical()?.into_arrow();
+        let array = boolean::not(array.as_boolean())?;
+
+        Ok(Array::from_arrow(&array, self.dtype().is_nullable()))
+    }
+}
+
+/// The result is nullable if either side is.
+fn arrow_binary(
+    lhs: &BoolArray,
+    rhs: &Array,
+    op: impl FnOnce(&BooleanArray, &BooleanArray) -> VortexResult<BooleanArray>,
+) -> VortexResult<Array> {
+    let nullable = lhs.dtype().is_nullable() || rhs.dtype().is_nullable();
+
+    let lhs = lhs.clone().into_canonical()?.into_arrow();
+    let rhs = rhs.clone().into_canonical()?.into_arrow();
+    let array = op(lhs.as_boolean(), rhs.as_boolean())?;
+
+    Ok(Array::from_arrow(&array, nullable))
+}
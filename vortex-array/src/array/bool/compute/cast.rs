@@ -0,0 +1,54 @@
+use num_traits::{One, Zero};
+use vortex_dtype::{match_each_native_ptype, DType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::decimal::number_to_decimal;
+use crate::array::{BoolArray, DecimalArray, PrimitiveArray, VarBinViewArray};
+use crate::compute::unary::{cast_validity, CastFn, CastMode};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray};
+
+/// Booleans cast to one and zero, or to the strings `true` and `false`.
+impl CastFn for BoolArray {
+    fn cast(&self, dtype: &DType, mode: CastMode) -> VortexResult<Array> {
+        let validity = cast_validity(self.validity(), dtype.nullability());
+        let values = self.boolean_buffer();
+
+        match dtype {
+            DType::Bool(_) => Ok(BoolArray::try_new(values, validity)?.into_array()),
+            DType::Primitive(ptype, _) => match_each_native_ptype!(ptype, |$T| {
+                Ok(PrimitiveArray::from_vec(
+                    values
+                        .iter()
+                        .map(|b| if b { <$T>::one() } else { <$T>::zero() })
+                        .collect::<Vec<_>>(),
+                    validity,
+                )
+                .into_array())
+            }),
+            DType::Decimal(decimal, _) => {
+                let one = number_to_decimal(1u8, *decimal, mode)?;
+                Ok(DecimalArray::from_vec(
+                    values.iter().map(|b| if b { one } else { 0 }).collect(),
+                    *decimal,
+                    validity,
+                )
+                .into_array())
+            }
+            DType::Utf8(_) => {
+                let nulls = self.logical_validity().to_null_buffer()?;
+                Ok(VarBinViewArray::from_iter(
+                    values.iter().enumerate().map(|(idx, b)| {
+                        nulls
+                            .as_ref()
+                            .map_or(true, |n| n.is_valid(idx))
+                            .then_some(if b { "true" } else { "false" })
+                    }),
+                    dtype.clone(),
+                )
+                .into_array())
+            }
+            _ => vortex_bail!("Cannot cast {} to {}", self.dtype(), dtype),
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use arrow_buffer::BooleanBuffer;
 use vortex_dtype::Nullability;
 use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
 
 use crate::array::BoolArray;
-use crate::compute::unary::FillForwardFn;
+use crate::compute::unary::{FillForwardFn, FillNullFn};
 use crate::validity::{ArrayValidity, Validity};
 use crate::{Array, ArrayDType, IntoArray};
 
@@ -47,6 +48,18 @@ impl FillForwardFn for BoolArray {
     }
 }
 
+impl FillNullFn for BoolArray {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array> {
+        let values = self.boolean_buffer();
+        let filled = match self.logical_validity().to_null_buffer()? {
+            None => values,
+            Some(nulls) if bool::try_from(fill_value)? => &values | &!nulls.inner(),
+            Some(nulls) => &values & nulls.inner(),
+        };
+        Ok(Self::try_new(filled, Validity::NonNullable)?.into_array())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::array::BoolArray;
@@ -0,0 +1,21 @@
+use vortex_error::VortexResult;
+
+use crate::array::{BoolArray, PrimitiveArray};
+use crate::compute::group::group_values;
+use crate::compute::{GroupIdsFn, HashFn};
+use crate::validity::ArrayValidity;
+use crate::{Array, AsArray};
+
+impl GroupIdsFn for BoolArray {
+    fn group_ids(&self) -> VortexResult<(PrimitiveArray, Array)> {
+        let nulls = self.logical_validity().to_null_buffer()?;
+        let is_valid = |idx: usize| nulls.as_ref().map_or(true, |n| n.is_valid(idx));
+        let values = self.boolean_buffer();
+        group_values(self.as_array_ref(), &self.hash(0)?, |a, b| {
+            match (is_valid(a), is_valid(b)) {
+                (true, true) => values.value(a) == values.value(b),
+                (a_valid, b_valid) => a_valid == b_valid,
+            }
+        })
+    }
+}
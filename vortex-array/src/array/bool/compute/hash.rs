@@ -0,0 +1,17 @@
+use vortex_error::VortexResult;
+
+use crate::array::{BoolArray, PrimitiveArray};
+use crate::compute::hash::{hash_number, hash_values};
+use crate::compute::HashFn;
+use crate::validity::ArrayValidity;
+
+impl HashFn for BoolArray {
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray> {
+        Ok(hash_values(
+            self.boolean_buffer().iter(),
+            self.logical_validity().to_null_buffer()?,
+            seed,
+            |v| hash_number(seed, v as u8),
+        ))
+    }
+}
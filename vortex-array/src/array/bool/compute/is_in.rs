@@ -0,0 +1,12 @@
+use vortex_error::VortexResult;
+
+use crate::array::BoolArray;
+use crate::compute::is_in::is_in_keys;
+use crate::compute::IsInFn;
+use crate::{Array, IntoArrayVariant};
+
+impl IsInFn for BoolArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        is_in_keys(self, &values.clone().into_bool()?, |&v: &bool| v)
+    }
+}
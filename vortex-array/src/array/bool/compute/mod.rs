@@ -1,17 +1,28 @@
 use crate::array::BoolArray;
-use crate::compute::unary::{FillForwardFn, ScalarAtFn};
-use crate::compute::{ArrayCompute, CompareFn, SliceFn, TakeFn};
+use crate::compute::unary::{CastFn, FillForwardFn, FillNullFn, ScalarAtFn};
+use crate::compute::{
+    ArrayCompute, CompareFn, FilterFn, GroupIdsFn, HashFn, IsInFn, SliceFn, SortFn, TakeFn,
+};
 
 mod boolean;
+mod cast;
 mod compare;
 mod fill;
 mod filter;
 mod flatten;
+mod group;
+mod hash;
+mod is_in;
 mod scalar_at;
 mod slice;
+mod sort;
 mod take;
 
 impl ArrayCompute for BoolArray {
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
     fn compare(&self) -> Option<&dyn CompareFn> {
         Some(self)
     }
@@ -20,6 +31,26 @@ impl ArrayCompute for BoolArray {
         Some(self)
     }
 
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn group_ids(&self) -> Option<&dyn GroupIdsFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -28,6 +59,10 @@ impl ArrayCompute for BoolArray {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
@@ -39,4 +74,20 @@ impl ArrayCompute for BoolArray {
     fn or(&self) -> Option<&dyn crate::compute::OrFn> {
         Some(self)
     }
+
+    fn xor(&self) -> Option<&dyn crate::compute::XorFn> {
+        Some(self)
+    }
+
+    fn and_kleene(&self) -> Option<&dyn crate::compute::AndKleeneFn> {
+        Some(self)
+    }
+
+    fn or_kleene(&self) -> Option<&dyn crate::compute::OrKleeneFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn crate::compute::NotFn> {
+        Some(self)
+    }
 }
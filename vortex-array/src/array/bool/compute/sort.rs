@@ -0,0 +1,18 @@
+use vortex_error::VortexResult;
+
+use crate::array::{BoolArray, PrimitiveArray};
+use crate::compute::sort::sort_by;
+use crate::compute::{SortFn, SortOptions};
+use crate::validity::ArrayValidity;
+
+impl SortFn for BoolArray {
+    fn sort_indices(&self, options: SortOptions) -> VortexResult<PrimitiveArray> {
+        let values = self.boolean_buffer();
+        Ok(sort_by(
+            values.len(),
+            self.logical_validity().to_null_buffer()?.as_ref(),
+            options,
+            |a, b| values.value(a).cmp(&values.value(b)),
+        ))
+    }
+}
@@ -1,22 +1,24 @@
 use arrow_buffer::{BooleanBuffer, Buffer, MutableBuffer};
 use itertools::Itertools;
-use vortex_dtype::{DType, Nullability, PType, StructDType};
+use vortex_dtype::{DType, DecimalDType, MapDType, Nullability, PType, StructDType, UnionMode};
 use vortex_error::{vortex_bail, vortex_err, ErrString, VortexResult};
 
-use crate::accessor::ArrayAccessor;
 use crate::array::chunked::ChunkedArray;
+use crate::array::decimal::DecimalArray;
 use crate::array::extension::ExtensionArray;
+use crate::array::fixed_size_list::FixedSizeListArray;
+use crate::array::list::ListArray;
+use crate::array::map::MapArray;
 use crate::array::null::NullArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::struct_::StructArray;
-use crate::array::varbin::builder::VarBinBuilder;
-use crate::array::varbin::VarBinArray;
+use crate::array::union::UnionArray;
+use crate::array::varbinview::VarBinViewArray;
 use crate::array::BoolArray;
+use crate::compute::slice;
 use crate::validity::Validity;
 use crate::variants::StructArrayTrait;
-use crate::{
-    Array, ArrayDType, ArrayValidity, Canonical, IntoArray, IntoArrayVariant, IntoCanonical,
-};
+use crate::{Array, ArrayDType, Canonical, IntoArray, IntoArrayVariant, IntoCanonical};
 
 impl IntoCanonical for ChunkedArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
@@ -43,8 +45,9 @@ pub(crate) fn try_canonicalize_chunks(
     match dtype {
         // Structs can have their internal field pointers swizzled to push the chunking down
         // one level internally without copying or decompressing any data.
-        DType::Struct(struct_dtype, _) => {
-            let struct_array = swizzle_struct_chunks(chunks.as_slice(), struct_dtype)?;
+        DType::Struct(struct_dtype, nullability) => {
+            let struct_array =
+                swizzle_struct_chunks(chunks.as_slice(), struct_dtype, *nullability)?;
             Ok(Canonical::Struct(struct_array))
         }
 
@@ -89,15 +92,38 @@ pub(crate) fn try_canonicalize_chunks(
             let chunked_storage =
                 ChunkedArray::try_new(storage_chunks, storage_dtype)?.into_array();
 
-            Ok(Canonical::Extension(ExtensionArray::new(
+            Ok(Canonical::Extension(ExtensionArray::try_new(
                 ext_dtype.clone(),
                 chunked_storage,
-            )))
+            )?))
         }
 
-        // TODO(aduffy): better list support
-        DType::List(..) => {
-            todo!()
+        // Lists are repacked into a single set of offsets, while the element arrays of each chunk
+        // are pushed down into a ChunkedArray without copying.
+        DType::List(element_dtype, nullability) => {
+            let list_array = pack_lists(chunks.as_slice(), element_dtype, *nullability)?;
+            Ok(Canonical::List(list_array))
+        }
+
+        // Fixed size lists have no offsets, so the element arrays of each chunk are simply pushed
+        // down into a ChunkedArray.
+        DType::FixedSizeList(element_dtype, list_size, nullability) => {
+            let list_array =
+                pack_fixed_size_lists(chunks.as_slice(), element_dtype, *list_size, *nullability)?;
+            Ok(Canonical::FixedSizeList(list_array))
+        }
+
+        // Maps are repacked like lists, with their keys and values pushed down into ChunkedArrays.
+        DType::Map(map_dtype, nullability) => {
+            let map_array = pack_maps(chunks.as_slice(), map_dtype, *nullability)?;
+            Ok(Canonical::Map(map_array))
+        }
+
+        // Unions chunk each of their variants, rebasing the offsets of dense unions so they point
+        // into the chunked variants.
+        DType::Union(..) => {
+            let union_array = pack_unions(chunks.as_slice(), dtype)?;
+            Ok(Canonical::Union(union_array))
         }
 
         DType::Bool(nullability) => {
@@ -108,13 +134,13 @@ pub(crate) fn try_canonicalize_chunks(
             let prim_array = pack_primitives(chunks.as_slice(), *ptype, *nullability)?;
             Ok(Canonical::Primitive(prim_array))
         }
-        DType::Utf8(nullability) => {
-            let varbin_array = pack_varbin(chunks.as_slice(), dtype, *nullability)?;
-            Ok(Canonical::VarBin(varbin_array))
+        DType::Decimal(decimal_dtype, nullability) => {
+            let decimal_array = pack_decimals(chunks.as_slice(), *decimal_dtype, *nullability)?;
+            Ok(Canonical::Decimal(decimal_array))
         }
-        DType::Binary(nullability) => {
-            let varbin_array = pack_varbin(chunks.as_slice(), dtype, *nullability)?;
-            Ok(Canonical::VarBin(varbin_array))
+        DType::Utf8(nullability) | DType::Binary(nullability) => {
+            let views_array = pack_views(chunks.as_slice(), dtype, *nullability)?;
+            Ok(Canonical::VarBinView(views_array))
         }
         DType::Null => {
             let len = chunks.iter().map(|chunk| chunk.len()).sum();
@@ -132,14 +158,12 @@ pub(crate) fn try_canonicalize_chunks(
 fn swizzle_struct_chunks(
     chunks: &[Array],
     struct_dtype: &StructDType,
+    nullability: Nullability,
 ) -> VortexResult<StructArray> {
+    let validity = validity_from_chunks(chunks, nullability);
     let chunks: Vec<StructArray> = chunks.iter().map(StructArray::try_from).try_collect()?;
 
     let len = chunks.iter().map(|chunk| chunk.len()).sum();
-    let validity = chunks
-        .iter()
-        .map(|chunk| chunk.logical_validity())
-        .collect::<Validity>();
 
     let mut field_arrays = Vec::new();
 
@@ -200,29 +224,208 @@ fn pack_primitives(
     ))
 }
 
-/// Builds a new [VarBinArray] by repacking the values from the chunks into a single
+/// Builds a new [DecimalArray] by repacking the values from the chunks into a single
 /// contiguous array.
 ///
 /// It is expected this function is only called from [try_canonicalize_chunks], and thus all chunks have
 /// been checked to have the same DType already.
-fn pack_varbin(
+fn pack_decimals(
+    chunks: &[Array],
+    decimal_dtype: DecimalDType,
+    nullability: Nullability,
+) -> VortexResult<DecimalArray> {
+    let len: usize = chunks.iter().map(|chunk| chunk.len()).sum();
+    let validity = validity_from_chunks(chunks, nullability);
+    let mut buffer = MutableBuffer::with_capacity(len * size_of::<i128>());
+    for chunk in chunks {
+        let chunk = chunk.clone().into_decimal()?;
+        buffer.extend_from_slice(chunk.buffer());
+    }
+
+    DecimalArray::try_new(Buffer::from(buffer).into(), decimal_dtype, validity)
+}
+
+/// Builds a new [VarBinViewArray] by concatenating the views of each chunk and rebasing their
+/// buffer indices. The data buffers of the chunks are reused without copying.
+///
+/// It is expected this function is only called from [try_canonicalize_chunks], and thus all chunks have
+/// been checked to have the same DType already.
+fn pack_views(
     chunks: &[Array],
     dtype: &DType,
-    _nullability: Nullability,
-) -> VortexResult<VarBinArray> {
+    nullability: Nullability,
+) -> VortexResult<VarBinViewArray> {
     let len = chunks.iter().map(|chunk| chunk.len()).sum();
-    let mut builder = VarBinBuilder::<i32>::with_capacity(len);
+    let validity = validity_from_chunks(chunks, nullability);
+    let mut views = Vec::with_capacity(len);
+    let mut buffers = Vec::new();
+
+    for chunk in chunks {
+        let chunk = chunk.clone().into_varbin_view()?;
+        let offset = buffers.len() as u32;
+        views.extend(
+            chunk
+                .view_slice()
+                .iter()
+                .map(|view| view.rebase_buffer(offset)),
+        );
+        buffers.extend(chunk.buffers());
+    }
+
+    VarBinViewArray::from_views(&views, buffers, dtype.clone(), validity)
+}
+
+/// Builds a new [ListArray] by rebasing the offsets of each chunk, and chunking the elements
+/// of each chunk into a single [ChunkedArray].
+///
+/// It is expected this function is only called from [try_canonicalize_chunks], and thus all chunks have
+/// been checked to have the same DType already.
+fn pack_lists(
+    chunks: &[Array],
+    element_dtype: &DType,
+    nullability: Nullability,
+) -> VortexResult<ListArray> {
+    let len: usize = chunks.iter().map(|chunk| chunk.len()).sum();
+    let validity = validity_from_chunks(chunks, nullability);
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0u64);
+    let mut element_chunks = Vec::with_capacity(chunks.len());
 
     for chunk in chunks {
-        let chunk = chunk.clone().into_varbin()?;
-        chunk.with_iterator(|iter| {
-            for datum in iter {
-                builder.push(datum);
+        let chunk = chunk.clone().into_list()?;
+        let first_offset = chunk.offset_at(0);
+        let base = offsets.last().copied().unwrap_or_default();
+        for i in 1..=chunk.len() {
+            offsets.push(base + (chunk.offset_at(i) - first_offset) as u64);
+        }
+        element_chunks.push(slice(
+            &chunk.elements(),
+            first_offset,
+            chunk.offset_at(chunk.len()),
+        )?);
+    }
+
+    ListArray::try_new(
+        PrimitiveArray::from(offsets).into_array(),
+        ChunkedArray::try_new(element_chunks, element_dtype.clone())?.into_array(),
+        validity,
+    )
+}
+
+/// Builds a new [FixedSizeListArray] by chunking the elements of each chunk into a single
+/// [ChunkedArray].
+///
+/// It is expected this function is only called from [try_canonicalize_chunks], and thus all chunks have
+/// been checked to have the same DType already.
+fn pack_fixed_size_lists(
+    chunks: &[Array],
+    element_dtype: &DType,
+    list_size: u32,
+    nullability: Nullability,
+) -> VortexResult<FixedSizeListArray> {
+    let len: usize = chunks.iter().map(|chunk| chunk.len()).sum();
+    let validity = validity_from_chunks(chunks, nullability);
+    let element_chunks = chunks
+        .iter()
+        .map(|chunk| chunk.clone().into_fixed_size_list().map(|l| l.elements()))
+        .collect::<VortexResult<Vec<_>>>()?;
+
+    FixedSizeListArray::try_new(
+        ChunkedArray::try_new(element_chunks, element_dtype.clone())?.into_array(),
+        list_size,
+        len,
+        validity,
+    )
+}
+
+/// Builds a new [MapArray] by rebasing the offsets of each chunk, and chunking the keys and values
+/// of each chunk into a single [ChunkedArray] each.
+///
+/// It is expected this function is only called from [try_canonicalize_chunks], and thus all chunks have
+/// been checked to have the same DType already.
+fn pack_maps(
+    chunks: &[Array],
+    map_dtype: &MapDType,
+    nullability: Nullability,
+) -> VortexResult<MapArray> {
+    let len: usize = chunks.iter().map(|chunk| chunk.len()).sum();
+    let validity = validity_from_chunks(chunks, nullability);
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0u64);
+    let mut key_chunks = Vec::with_capacity(chunks.len());
+    let mut value_chunks = Vec::with_capacity(chunks.len());
+
+    for chunk in chunks {
+        let chunk = chunk.clone().into_map()?;
+        let first_offset = chunk.offset_at(0);
+        let last_offset = chunk.offset_at(chunk.len());
+        let base = offsets.last().copied().unwrap_or_default();
+        for i in 1..=chunk.len() {
+            offsets.push(base + (chunk.offset_at(i) - first_offset) as u64);
+        }
+        key_chunks.push(slice(&chunk.keys(), first_offset, last_offset)?);
+        value_chunks.push(slice(&chunk.values(), first_offset, last_offset)?);
+    }
+
+    MapArray::try_new(
+        PrimitiveArray::from(offsets).into_array(),
+        ChunkedArray::try_new(key_chunks, map_dtype.key_dtype().clone())?.into_array(),
+        ChunkedArray::try_new(value_chunks, map_dtype.value_dtype().clone())?.into_array(),
+        map_dtype.keys_sorted(),
+        validity,
+    )
+}
+
+/// Builds a new [UnionArray] by concatenating the type ids of each chunk, and chunking each of
+/// the variants into a single [ChunkedArray]. Dense offsets are rebased by the length of the
+/// preceding chunks of the variant they point into.
+///
+/// It is expected this function is only called from [try_canonicalize_chunks], and thus all chunks have
+/// been checked to have the same DType already.
+fn pack_unions(chunks: &[Array], dtype: &DType) -> VortexResult<UnionArray> {
+    let DType::Union(union_dtype, nullability) = dtype else {
+        vortex_bail!(MismatchedTypes: "union", dtype);
+    };
+    let nvariants = union_dtype.dtypes().len();
+    let len: usize = chunks.iter().map(|chunk| chunk.len()).sum();
+    let mut type_ids = Vec::with_capacity(len);
+    let mut offsets = (union_dtype.mode() == UnionMode::Dense).then(|| Vec::with_capacity(len));
+    let mut variant_chunks = vec![Vec::with_capacity(chunks.len()); nvariants];
+    let mut variant_bases = vec![0u64; nvariants];
+    let mut validity = Vec::with_capacity(chunks.len());
+
+    for chunk in chunks {
+        let chunk = chunk.clone().into_union()?;
+        validity.push(chunk.validity().to_logical(chunk.len()));
+        if let Some(offsets) = offsets.as_mut() {
+            for (type_id, offset) in chunk.variant_indices()? {
+                offsets.push(variant_bases[type_id as usize] + offset as u64);
             }
-        })?;
+        }
+        type_ids.extend_from_slice(chunk.type_ids().into_primitive()?.maybe_null_slice::<u8>());
+        for (idx, variant) in chunk.variants().enumerate() {
+            variant_bases[idx] += variant.len() as u64;
+            variant_chunks[idx].push(variant);
+        }
     }
 
-    Ok(builder.finish(dtype.clone()))
+    let validity = match nullability {
+        Nullability::NonNullable => Validity::NonNullable,
+        Nullability::Nullable => validity.into_iter().collect(),
+    };
+    let variants = variant_chunks
+        .into_iter()
+        .zip(union_dtype.dtypes().iter())
+        .map(|(chunks, dtype)| ChunkedArray::try_new(chunks, dtype.clone()).map(|a| a.into_array()))
+        .collect::<VortexResult<Vec<_>>>()?;
+
+    UnionArray::try_new(
+        dtype.clone(),
+        PrimitiveArray::from(type_ids).into_array(),
+        offsets.map(|offsets| PrimitiveArray::from(offsets).into_array()),
+        variants,
+        validity,
+    )
 }
 
 fn validity_from_chunks(chunks: &[Array], nullability: Nullability) -> Validity {
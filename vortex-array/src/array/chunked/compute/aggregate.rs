@@ -0,0 +1,24 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::ChunkedArray;
+use crate::compute::{add_sums, min_max, sum, sum_dtype, MinMax, MinMaxFn, SumFn};
+use crate::ArrayDType;
+
+impl SumFn for ChunkedArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        self.chunks()
+            .try_fold(Scalar::null(sum_dtype(self.dtype())?), |total, chunk| {
+                add_sums(&total, &sum(&chunk)?)
+            })
+    }
+}
+
+impl MinMaxFn for ChunkedArray {
+    fn min_max(&self) -> VortexResult<MinMax> {
+        self.chunks()
+            .try_fold(MinMax::null(self.dtype()), |acc, chunk| {
+                Ok(acc.merge(min_max(&chunk)?))
+            })
+    }
+}
@@ -0,0 +1,53 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::ChunkedArray;
+use crate::compute::arithmetic::numeric_result_dtype;
+use crate::compute::{
+    binary_numeric, negate, scalar_numeric, slice, BinaryNumericFn, BinaryOperator, NegateFn,
+    OverflowMode, ScalarNumericFn,
+};
+use crate::{Array, ArrayDType, IntoArray};
+
+impl BinaryNumericFn for ChunkedArray {
+    fn binary_numeric(
+        &self,
+        rhs: &Array,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<Array> {
+        let mut offset = 0;
+        let mut chunks = Vec::with_capacity(self.nchunks());
+        for chunk in self.chunks() {
+            let rhs_chunk = slice(rhs, offset, offset + chunk.len())?;
+            offset += chunk.len();
+            chunks.push(binary_numeric(&chunk, &rhs_chunk, operator, mode)?);
+        }
+        Ok(Self::try_new(chunks, numeric_result_dtype(self.dtype(), rhs.dtype()))?.into_array())
+    }
+}
+
+impl ScalarNumericFn for ChunkedArray {
+    fn scalar_numeric(
+        &self,
+        rhs: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<Array> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| scalar_numeric(&chunk, rhs, operator, mode))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(Self::try_new(chunks, numeric_result_dtype(self.dtype(), rhs.dtype()))?.into_array())
+    }
+}
+
+impl NegateFn for ChunkedArray {
+    fn negate(&self, mode: OverflowMode) -> VortexResult<Array> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| negate(&chunk, mode))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(Self::try_new(chunks, self.dtype().clone())?.into_array())
+    }
+}
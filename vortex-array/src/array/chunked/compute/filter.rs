@@ -0,0 +1,65 @@
+use vortex_error::VortexResult;
+
+use crate::array::ChunkedArray;
+use crate::compute::{filter, slice, FilterFn};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+impl FilterFn for ChunkedArray {
+    /// Filters each chunk by its slice of the predicate, dropping chunks that end up empty.
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let predicate = predicate.clone().into_bool()?;
+        let buffer = predicate.boolean_buffer();
+        let mut offset = 0;
+        let mut chunks = Vec::with_capacity(self.nchunks());
+        for chunk in self.chunks() {
+            let selected = buffer.slice(offset, chunk.len()).count_set_bits();
+            let chunk_predicate = slice(predicate.array(), offset, offset + chunk.len())?;
+            offset += chunk.len();
+            if selected == 0 {
+                continue;
+            }
+            chunks.push(if selected == chunk.len() {
+                chunk
+            } else {
+                filter(&chunk, &chunk_predicate)?
+            });
+        }
+        Self::try_new(chunks, self.dtype().clone()).map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{BoolArray, ChunkedArray, PrimitiveArray};
+    use crate::compute::filter;
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn filter_drops_empty_chunks() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from(vec![1u8, 2]).into_array(),
+                PrimitiveArray::from(vec![3u8, 4]).into_array(),
+                PrimitiveArray::from(vec![5u8, 6]).into_array(),
+            ],
+            DType::Primitive(PType::U8, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+        let predicate = BoolArray::from(vec![true, true, false, false, false, true]);
+
+        let filtered =
+            ChunkedArray::try_from(filter(&chunked, predicate.array()).unwrap()).unwrap();
+        assert_eq!(filtered.nchunks(), 2);
+        assert_eq!(
+            filtered
+                .into_array()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u8>(),
+            &[1, 2, 6]
+        );
+    }
+}
@@ -1,25 +1,67 @@
-use vortex_dtype::DType;
+use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_err, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::array::chunked::ChunkedArray;
-use crate::compute::unary::{scalar_at, try_cast, CastFn, ScalarAtFn, SubtractScalarFn};
-use crate::compute::{ArrayCompute, SliceFn, TakeFn};
+use crate::array::PrimitiveArray;
+use crate::compute::unary::{
+    fill_null, is_null, scalar_at, try_cast_with_mode, CastFn, CastMode, FillNullFn, IsNullFn,
+    ScalarAtFn,
+};
+use crate::compute::{
+    hash, is_in, ArrayCompute, BinaryNumericFn, FilterFn, HashFn, IsInFn, MinMaxFn, NegateFn,
+    ScalarNumericFn, SliceFn, SumFn, TakeFn,
+};
 use crate::{Array, IntoArray};
 
+mod aggregate;
+mod arithmetic;
+mod filter;
 mod slice;
 mod take;
 
 impl ArrayCompute for ChunkedArray {
+    fn binary_numeric(&self) -> Option<&dyn BinaryNumericFn> {
+        Some(self)
+    }
+
     fn cast(&self) -> Option<&dyn CastFn> {
         Some(self)
     }
 
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
+    fn is_null(&self) -> Option<&dyn IsNullFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
+    fn negate(&self) -> Option<&dyn NegateFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
-    fn subtract_scalar(&self) -> Option<&dyn SubtractScalarFn> {
+    fn scalar_numeric(&self) -> Option<&dyn ScalarNumericFn> {
         Some(self)
     }
 
@@ -27,6 +69,10 @@ impl ArrayCompute for ChunkedArray {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
@@ -44,11 +90,51 @@ impl ScalarAtFn for ChunkedArray {
     }
 }
 
+impl HashFn for ChunkedArray {
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray> {
+        let mut hashes = Vec::with_capacity(self.len());
+        for chunk in self.chunks() {
+            hashes.extend_from_slice(hash(&chunk, seed)?.maybe_null_slice::<u64>());
+        }
+        Ok(PrimitiveArray::from(hashes))
+    }
+}
+
+impl IsNullFn for ChunkedArray {
+    fn is_null(&self) -> VortexResult<Array> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| is_null(&chunk))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(ChunkedArray::try_new(chunks, DType::Bool(Nullability::NonNullable))?.into_array())
+    }
+}
+
+impl IsInFn for ChunkedArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| is_in(&chunk, values))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(ChunkedArray::try_new(chunks, DType::Bool(Nullability::NonNullable))?.into_array())
+    }
+}
+
+impl FillNullFn for ChunkedArray {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| fill_null(&chunk, fill_value))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(ChunkedArray::try_new(chunks, fill_value.dtype().clone())?.into_array())
+    }
+}
+
 impl CastFn for ChunkedArray {
-    fn cast(&self, dtype: &DType) -> VortexResult<Array> {
+    fn cast(&self, dtype: &DType, mode: CastMode) -> VortexResult<Array> {
         let mut cast_chunks = Vec::new();
         for chunk in self.chunks() {
-            cast_chunks.push(try_cast(&chunk, dtype)?);
+            cast_chunks.push(try_cast_with_mode(&chunk, dtype, mode)?);
         }
 
         Ok(ChunkedArray::try_new(cast_chunks, dtype.clone())?.into_array())
@@ -19,7 +19,13 @@ impl SliceFn for ChunkedArray {
             }
         }
 
-        let mut chunks = (offset_chunk..length_chunk + 1)
+        // A stop index on a chunk boundary does not include any of the following chunk.
+        let end_chunk = if length_in_last_chunk == 0 {
+            length_chunk
+        } else {
+            length_chunk + 1
+        };
+        let mut chunks = (offset_chunk..end_chunk)
             .map(|i| {
                 self.chunk(i)
                     .ok_or_else(|| vortex_err!(OutOfBounds: i, 0, self.nchunks()))
@@ -29,10 +35,10 @@ impl SliceFn for ChunkedArray {
             *c = slice(c, offset_in_first_chunk, c.len())?;
         }
 
-        if length_in_last_chunk == 0 {
-            chunks.pop();
-        } else if let Some(c) = chunks.last_mut() {
-            *c = slice(c, 0, length_in_last_chunk)?;
+        if length_in_last_chunk != 0 {
+            if let Some(c) = chunks.last_mut() {
+                *c = slice(c, 0, length_in_last_chunk)?;
+            }
         }
 
         Self::try_new(chunks, self.dtype().clone()).map(|a| a.into_array())
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use vortex_dtype::PType;
+use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_err, VortexResult};
 use vortex_scalar::Scalar;
 
@@ -26,7 +26,10 @@ impl TakeFn for ChunkedArray {
         }
 
         // FIXME(ngates): this is wrong, need to canonicalise
-        let indices = PrimitiveArray::try_from(try_cast(indices, PType::U64.into())?)?;
+        let indices = PrimitiveArray::try_from(try_cast(
+            indices,
+            &DType::Primitive(PType::U64, indices.dtype().nullability()),
+        )?)?;
 
         // While the chunk idx remains the same, accumulate a list of chunk indices.
         let mut chunks = Vec::new();
@@ -97,8 +100,11 @@ fn take_strict_sorted(chunked: &ChunkedArray, indices: &Array) -> VortexResult<A
         } else {
             // Note. this try_cast (memory copy) is unnecessary, could instead upcast in the subtract fn.
             //  and avoid an extra
-            let u64_chunk_indices = try_cast(&chunk_indices, PType::U64.into())
-                .expect("safe to upcast since all indices are positive");
+            let u64_chunk_indices = try_cast(
+                &chunk_indices,
+                &DType::Primitive(PType::U64, chunk_indices.dtype().nullability()),
+            )
+            .expect("safe to upcast since all indices are positive");
 
             subtract_scalar(&u64_chunk_indices, &chunk_begin.into())?
         };
@@ -7,10 +7,9 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use vortex_dtype::{DType, Nullability, PType};
 use vortex_error::{vortex_bail, VortexResult};
-use vortex_scalar::Scalar;
 
 use crate::array::primitive::PrimitiveArray;
-use crate::compute::unary::{scalar_at, subtract_scalar, SubtractScalarFn};
+use crate::compute::unary::scalar_at;
 use crate::compute::{search_sorted, SearchResult, SearchSortedSide};
 use crate::iter::{ArrayIterator, ArrayIteratorAdapter};
 use crate::stats::StatsSet;
@@ -166,24 +165,23 @@ impl ArrayValidity for ChunkedArray {
     }
 }
 
-impl SubtractScalarFn for ChunkedArray {
-    fn subtract_scalar(&self, to_subtract: &Scalar) -> VortexResult<Array> {
-        let chunks = self
-            .chunks()
-            .map(|chunk| subtract_scalar(&chunk, to_subtract))
-            .collect::<VortexResult<Vec<_>>>()?;
-        Ok(Self::try_new(chunks, self.dtype().clone())?.into_array())
-    }
-}
-
 #[cfg(test)]
 mod test {
-    use vortex_dtype::{DType, NativePType, Nullability, PType};
+    use vortex_dtype::{DType, NativePType, Nullability, PType, UnionDType, UnionMode};
 
+    use crate::accessor::ArrayAccessor;
+    use crate::array::bool::BoolArray;
     use crate::array::chunked::ChunkedArray;
+    use crate::array::fixed_size_list::FixedSizeListArray;
+    use crate::array::list::ListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::union::UnionArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::array::varbinview::VarBinViewArray;
     use crate::compute::slice;
-    use crate::compute::unary::subtract_scalar;
-    use crate::{Array, IntoArray, IntoArrayVariant, ToArray};
+    use crate::compute::unary::{scalar_at, subtract_scalar};
+    use crate::validity::Validity;
+    use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, ToArray};
 
     fn chunked_array() -> ChunkedArray {
         ChunkedArray::try_new(
@@ -235,6 +233,14 @@ mod test {
         assert_equal_slices(slice(chunked_array().array(), 7, 8).unwrap(), &[8u64]);
     }
 
+    #[test]
+    pub fn slice_to_end() {
+        assert_equal_slices(
+            slice(chunked_array().array(), 4, 9).unwrap(),
+            &[5u64, 6, 7, 8, 9],
+        );
+    }
+
     #[test]
     fn test_scalar_subtract() {
         let chunked = chunked_array();
@@ -269,4 +275,159 @@ mod test {
             .to_vec();
         assert_eq!(results, &[6u64, 7, 8]);
     }
+
+    #[test]
+    fn canonicalize_lists() {
+        let list = ListArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 3, 5]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let chunked = ChunkedArray::try_new(
+            vec![slice(&list, 1, 3).unwrap(), slice(&list, 0, 1).unwrap()],
+            list.dtype().clone(),
+        )
+        .unwrap();
+
+        let canonical = chunked.into_array().into_list().unwrap().into_array();
+        assert_eq!(canonical.len(), 3);
+        assert_eq!(
+            Vec::<i32>::try_from(&scalar_at(&canonical, 0).unwrap()).unwrap(),
+            vec![3]
+        );
+        assert_eq!(
+            Vec::<i32>::try_from(&scalar_at(&canonical, 1).unwrap()).unwrap(),
+            vec![4, 5]
+        );
+        assert_eq!(
+            Vec::<i32>::try_from(&scalar_at(&canonical, 2).unwrap()).unwrap(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn canonicalize_strings() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                VarBinViewArray::from_iter_nullable_str([
+                    Some("a string too long to be inlined"),
+                    None,
+                ])
+                .into_array(),
+                VarBinArray::from_iter(
+                    [Some("short"), Some("another string too long to be inlined")],
+                    DType::Utf8(Nullability::Nullable),
+                )
+                .into_array(),
+            ],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .unwrap();
+
+        let canonical = chunked.into_array().into_varbin_view().unwrap();
+        assert_eq!(canonical.buffers().len(), 2);
+        let values = canonical
+            .with_iterator(|iter| {
+                iter.map(|v| v.map(|b| String::from_utf8(b.to_vec()).unwrap()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(
+            values,
+            vec![
+                Some("a string too long to be inlined".to_string()),
+                None,
+                Some("short".to_string()),
+                Some("another string too long to be inlined".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn canonicalize_fixed_size_lists() {
+        let list = FixedSizeListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5, 6]).into_array(),
+            2,
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let chunked = ChunkedArray::try_new(
+            vec![slice(&list, 2, 3).unwrap(), slice(&list, 0, 2).unwrap()],
+            list.dtype().clone(),
+        )
+        .unwrap();
+
+        let canonical = chunked
+            .into_array()
+            .into_fixed_size_list()
+            .unwrap()
+            .into_array();
+        assert_eq!(canonical.len(), 3);
+        assert_eq!(
+            Vec::<i32>::try_from(&scalar_at(&canonical, 0).unwrap()).unwrap(),
+            vec![5, 6]
+        );
+        assert_eq!(
+            Vec::<i32>::try_from(&scalar_at(&canonical, 2).unwrap()).unwrap(),
+            vec![3, 4]
+        );
+    }
+
+    #[test]
+    fn canonicalize_dense_unions() {
+        let dtype = DType::Union(
+            UnionDType::try_new(
+                ["a".into(), "b".into()].into(),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::NonNullable),
+                    DType::Bool(Nullability::NonNullable),
+                ],
+                UnionMode::Dense,
+            )
+            .unwrap(),
+            Nullability::NonNullable,
+        );
+        let chunk = |values: Vec<i32>| {
+            UnionArray::try_new(
+                dtype.clone(),
+                PrimitiveArray::from(vec![0u8, 1, 0]).into_array(),
+                Some(PrimitiveArray::from(vec![0u32, 0, 1]).into_array()),
+                vec![
+                    PrimitiveArray::from(values).into_array(),
+                    BoolArray::from(vec![true]).into_array(),
+                ],
+                Validity::NonNullable,
+            )
+            .unwrap()
+            .into_array()
+        };
+        let chunked = ChunkedArray::try_new(vec![chunk(vec![1, 2]), chunk(vec![3, 4])], dtype)
+            .unwrap()
+            .into_array();
+
+        let union = chunked.into_union().unwrap();
+        assert_eq!(union.len(), 6);
+        assert_eq!(
+            union
+                .variant_indices()
+                .unwrap()
+                .into_iter()
+                .map(|(_, offset)| offset)
+                .collect::<Vec<_>>(),
+            vec![0, 0, 1, 2, 1, 3]
+        );
+        assert_eq!(
+            union
+                .variant(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            &[1, 2, 3, 4]
+        );
+    }
 }
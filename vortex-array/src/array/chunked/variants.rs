@@ -2,8 +2,9 @@ use vortex_dtype::DType;
 
 use crate::array::chunked::ChunkedArray;
 use crate::variants::{
-    ArrayVariants, BinaryArrayTrait, BoolArrayTrait, ExtensionArrayTrait, ListArrayTrait,
-    NullArrayTrait, PrimitiveArrayTrait, StructArrayTrait, Utf8ArrayTrait,
+    ArrayVariants, BinaryArrayTrait, BoolArrayTrait, DecimalArrayTrait, ExtensionArrayTrait,
+    FixedSizeListArrayTrait, ListArrayTrait, MapArrayTrait, NullArrayTrait, PrimitiveArrayTrait,
+    StructArrayTrait, UnionArrayTrait, Utf8ArrayTrait,
 };
 use crate::{Array, ArrayDType, IntoArray};
 
@@ -21,6 +22,10 @@ impl ArrayVariants for ChunkedArray {
         matches!(self.dtype(), DType::Primitive(..)).then_some(self)
     }
 
+    fn as_decimal_array(&self) -> Option<&dyn DecimalArrayTrait> {
+        matches!(self.dtype(), DType::Decimal(..)).then_some(self)
+    }
+
     fn as_utf8_array(&self) -> Option<&dyn Utf8ArrayTrait> {
         matches!(self.dtype(), DType::Utf8(_)).then_some(self)
     }
@@ -37,6 +42,18 @@ impl ArrayVariants for ChunkedArray {
         matches!(self.dtype(), DType::List(..)).then_some(self)
     }
 
+    fn as_fixed_size_list_array(&self) -> Option<&dyn FixedSizeListArrayTrait> {
+        matches!(self.dtype(), DType::FixedSizeList(..)).then_some(self)
+    }
+
+    fn as_union_array(&self) -> Option<&dyn UnionArrayTrait> {
+        matches!(self.dtype(), DType::Union(..)).then_some(self)
+    }
+
+    fn as_map_array(&self) -> Option<&dyn MapArrayTrait> {
+        matches!(self.dtype(), DType::Map(..)).then_some(self)
+    }
+
     fn as_extension_array(&self) -> Option<&dyn ExtensionArrayTrait> {
         matches!(self.dtype(), DType::Extension(..)).then_some(self)
     }
@@ -56,6 +73,8 @@ impl BoolArrayTrait for ChunkedArray {
 
 impl PrimitiveArrayTrait for ChunkedArray {}
 
+impl DecimalArrayTrait for ChunkedArray {}
+
 impl Utf8ArrayTrait for ChunkedArray {}
 
 impl BinaryArrayTrait for ChunkedArray {}
@@ -83,4 +102,10 @@ impl StructArrayTrait for ChunkedArray {
 
 impl ListArrayTrait for ChunkedArray {}
 
+impl FixedSizeListArrayTrait for ChunkedArray {}
+
+impl UnionArrayTrait for ChunkedArray {}
+
+impl MapArrayTrait for ChunkedArray {}
+
 impl ExtensionArrayTrait for ChunkedArray {}
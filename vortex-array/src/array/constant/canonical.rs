@@ -1,13 +1,14 @@
 use std::iter;
 
-use vortex_dtype::{match_each_native_ptype, DType, Nullability, PType};
-use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_dtype::{match_each_native_ptype, Nullability, PType};
+use vortex_error::{vortex_err, VortexResult};
 use vortex_scalar::{BoolScalar, Utf8Scalar};
 
 use crate::array::constant::ConstantArray;
 use crate::array::primitive::PrimitiveArray;
-use crate::array::varbin::VarBinArray;
+use crate::array::varbinview::VarBinViewArray;
 use crate::array::BoolArray;
+use crate::builders::builder_with_capacity;
 use crate::validity::Validity;
 use crate::{ArrayDType, Canonical, IntoCanonical};
 
@@ -29,15 +30,20 @@ impl IntoCanonical for ConstantArray {
         }
 
         if let Ok(s) = Utf8Scalar::try_from(self.scalar()) {
-            let const_value = s
-                .value()
-                .ok_or_else(|| vortex_err!("Constant UTF-8 array has null value"))?;
-            let bytes = const_value.as_bytes();
-
-            return Ok(Canonical::VarBin(VarBinArray::from_iter(
-                iter::repeat(Some(bytes)).take(self.len()),
-                DType::Utf8(validity.nullability()),
-            )));
+            let const_value = s.value();
+            let value = const_value.as_ref().map(|v| v.as_str());
+
+            return Ok(Canonical::VarBinView(match validity.nullability() {
+                Nullability::NonNullable => VarBinViewArray::from_iter_str(
+                    iter::repeat(value.ok_or_else(|| {
+                        vortex_err!("Constant non-nullable UTF-8 array has null value")
+                    })?)
+                    .take(self.len()),
+                ),
+                Nullability::Nullable => {
+                    VarBinViewArray::from_iter_nullable_str(iter::repeat(value).take(self.len()))
+                }
+            }));
         }
 
         if let Ok(ptype) = PType::try_from(self.scalar().dtype()) {
@@ -49,6 +55,11 @@ impl IntoCanonical for ConstantArray {
             });
         }
 
-        vortex_bail!("Unsupported scalar type {}", self.dtype())
+        // Any other scalar is repeated through the builder of its dtype.
+        let mut builder = builder_with_capacity(self.dtype(), self.len());
+        for _ in 0..self.len() {
+            builder.append_scalar(self.scalar())?;
+        }
+        builder.finish()?.into_canonical()
     }
 }
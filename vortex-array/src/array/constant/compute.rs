@@ -2,22 +2,66 @@ use std::cmp::Ordering;
 use std::sync::Arc;
 
 use arrow_array::Datum;
-use vortex_dtype::Nullability;
-use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_dtype::{DType, Nullability};
+use vortex_error::{vortex_err, VortexResult};
 use vortex_expr::Operator;
 use vortex_scalar::Scalar;
 
 use crate::array::constant::ConstantArray;
+use crate::array::PrimitiveArray;
 use crate::arrow::FromArrowArray;
-use crate::compute::unary::{scalar_at, ScalarAtFn};
+use crate::compute::unary::{scalar_at, FillNullFn, IsNullFn, ScalarAtFn};
 use crate::compute::{
-    scalar_cmp, AndFn, ArrayCompute, CompareFn, FilterFn, OrFn, SearchResult, SearchSortedFn,
-    SearchSortedSide, SliceFn, TakeFn,
+    binary_numeric, hash, is_in, scalar_binary_numeric, scalar_cmp, scalar_negate, sum_repeated,
+    AndFn, AndKleeneFn, ArrayCompute, BinaryNumericFn, BinaryOperator, CompareFn, FilterFn,
+    GroupIdsFn, HashFn, IsInFn, MinMax, MinMaxFn, NegateFn, NotFn, OrFn, OrKleeneFn, OverflowMode,
+    ScalarNumericFn, SearchResult, SearchSortedFn, SearchSortedSide, SliceFn, SortFn, SortOptions,
+    SumFn, TakeFn, XorFn,
 };
 use crate::stats::{ArrayStatistics, Stat};
-use crate::{Array, ArrayDType, AsArray, IntoArray, IntoCanonical};
+use crate::{Array, ArrayDType, AsArray, IntoArray, IntoArrayVariant, IntoCanonical};
 
 impl ArrayCompute for ConstantArray {
+    fn binary_numeric(&self) -> Option<&dyn BinaryNumericFn> {
+        Some(self)
+    }
+
+    fn group_ids(&self) -> Option<&dyn GroupIdsFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
+    fn is_null(&self) -> Option<&dyn IsNullFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn negate(&self) -> Option<&dyn NegateFn> {
+        Some(self)
+    }
+
+    fn scalar_numeric(&self) -> Option<&dyn ScalarNumericFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -30,6 +74,14 @@ impl ArrayCompute for ConstantArray {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
@@ -45,6 +97,117 @@ impl ArrayCompute for ConstantArray {
     fn or(&self) -> Option<&dyn OrFn> {
         Some(self)
     }
+
+    fn xor(&self) -> Option<&dyn XorFn> {
+        Some(self)
+    }
+
+    fn and_kleene(&self) -> Option<&dyn AndKleeneFn> {
+        Some(self)
+    }
+
+    fn or_kleene(&self) -> Option<&dyn OrKleeneFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
+}
+
+impl BinaryNumericFn for ConstantArray {
+    fn binary_numeric(
+        &self,
+        rhs: &Array,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<Array> {
+        // Constant right-hand sides are handled as scalars by `binary_numeric`.
+        binary_numeric(
+            &self.clone().into_primitive()?.into_array(),
+            rhs,
+            operator,
+            mode,
+        )
+    }
+}
+
+impl ScalarNumericFn for ConstantArray {
+    fn scalar_numeric(
+        &self,
+        rhs: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<Array> {
+        Ok(Self::new(
+            scalar_binary_numeric(self.scalar(), rhs, operator, mode)?,
+            self.len(),
+        )
+        .into_array())
+    }
+}
+
+impl NegateFn for ConstantArray {
+    fn negate(&self, mode: OverflowMode) -> VortexResult<Array> {
+        Ok(Self::new(scalar_negate(self.scalar(), mode)?, self.len()).into_array())
+    }
+}
+
+impl SumFn for ConstantArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        sum_repeated(self.scalar(), self.len())
+    }
+}
+
+impl MinMaxFn for ConstantArray {
+    fn min_max(&self) -> VortexResult<MinMax> {
+        if self.is_empty() {
+            return Ok(MinMax::null(self.dtype()));
+        }
+        MinMax::of(self.scalar())
+    }
+}
+
+impl IsNullFn for ConstantArray {
+    fn is_null(&self) -> VortexResult<Array> {
+        Ok(Self::new(self.scalar().is_null(), self.len()).into_array())
+    }
+}
+
+impl FillNullFn for ConstantArray {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array> {
+        let scalar = if self.scalar().is_null() {
+            fill_value.clone()
+        } else {
+            self.scalar().cast(fill_value.dtype())?
+        };
+        Ok(Self::new(scalar, self.len()).into_array())
+    }
+}
+
+impl GroupIdsFn for ConstantArray {
+    fn group_ids(&self) -> VortexResult<(PrimitiveArray, Array)> {
+        Ok((
+            PrimitiveArray::from(vec![0u64; self.len()]),
+            Self::new(self.scalar().clone(), self.len().min(1)).into_array(),
+        ))
+    }
+}
+
+impl HashFn for ConstantArray {
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray> {
+        let single = Array::from(Self::new(self.scalar().clone(), 1).into_canonical()?);
+        let value = hash(&single, seed)?.maybe_null_slice::<u64>()[0];
+        Ok(PrimitiveArray::from(vec![value; self.len()]))
+    }
+}
+
+impl IsInFn for ConstantArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        let single = Array::from(Self::new(self.scalar().clone(), 1).into_canonical()?);
+        let member = scalar_at(&is_in(&single, values)?, 0)?;
+        Ok(Self::new(member, self.len()).into_array())
+    }
 }
 
 impl ScalarAtFn for ConstantArray {
@@ -53,6 +216,14 @@ impl ScalarAtFn for ConstantArray {
     }
 }
 
+impl SortFn for ConstantArray {
+    fn sort_indices(&self, _options: SortOptions) -> VortexResult<PrimitiveArray> {
+        Ok(PrimitiveArray::from(
+            (0..self.len() as u64).collect::<Vec<_>>(),
+        ))
+    }
+}
+
 impl TakeFn for ConstantArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         Ok(Self::new(self.scalar().clone(), indices.len()).into_array())
@@ -128,8 +299,11 @@ impl AndFn for ConstantArray {
         constant_array_bool_impl(
             self,
             array,
-            |(l, r)| l & r,
-            |other, this| other.with_dyn(|other| other.and().map(|other| other.and(this))),
+            |l, r| l.zip(r).map(|(l, r)| l & r),
+            |other, this| match other.with_dyn(|other| other.and().map(|other| other.and(this))) {
+                Some(result) => result,
+                None => AndFn::and(&other.clone().into_bool()?, this),
+            },
         )
     }
 }
@@ -139,35 +313,115 @@ impl OrFn for ConstantArray {
         constant_array_bool_impl(
             self,
             array,
-            |(l, r)| l | r,
-            |other, this| other.with_dyn(|other| other.or().map(|other| other.or(this))),
+            |l, r| l.zip(r).map(|(l, r)| l | r),
+            |other, this| match other.with_dyn(|other| other.or().map(|other| other.or(this))) {
+                Some(result) => result,
+                None => OrFn::or(&other.clone().into_bool()?, this),
+            },
         )
     }
 }
 
+impl XorFn for ConstantArray {
+    fn xor(&self, array: &Array) -> VortexResult<Array> {
+        constant_array_bool_impl(
+            self,
+            array,
+            |l, r| l.zip(r).map(|(l, r)| l ^ r),
+            |other, this| match other.with_dyn(|other| other.xor().map(|other| other.xor(this))) {
+                Some(result) => result,
+                None => XorFn::xor(&other.clone().into_bool()?, this),
+            },
+        )
+    }
+}
+
+impl AndKleeneFn for ConstantArray {
+    fn and_kleene(&self, array: &Array) -> VortexResult<Array> {
+        constant_array_bool_impl(
+            self,
+            array,
+            |l, r| match (l, r) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            |other, this| match other
+                .with_dyn(|other| other.and_kleene().map(|other| other.and_kleene(this)))
+            {
+                Some(result) => result,
+                None => AndKleeneFn::and_kleene(&other.clone().into_bool()?, this),
+            },
+        )
+    }
+}
+
+impl OrKleeneFn for ConstantArray {
+    fn or_kleene(&self, array: &Array) -> VortexResult<Array> {
+        constant_array_bool_impl(
+            self,
+            array,
+            |l, r| match (l, r) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            |other, this| match other
+                .with_dyn(|other| other.or_kleene().map(|other| other.or_kleene(this)))
+            {
+                Some(result) => result,
+                None => OrKleeneFn::or_kleene(&other.clone().into_bool()?, this),
+            },
+        )
+    }
+}
+
+impl NotFn for ConstantArray {
+    fn not(&self) -> VortexResult<Array> {
+        let scalar = match self.scalar().value().as_bool()? {
+            Some(b) => Scalar::bool(!b, self.dtype().nullability()),
+            None => Scalar::null(self.dtype().clone()),
+        };
+        Ok(ConstantArray::new(scalar, self.len()).into_array())
+    }
+}
+
 fn constant_array_bool_impl(
     constant_array: &ConstantArray,
     other: &Array,
-    bool_op: impl Fn((bool, bool)) -> bool,
-    fallback_fn: impl Fn(&Array, &Array) -> Option<VortexResult<Array>>,
+    bool_op: impl Fn(Option<bool>, Option<bool>) -> Option<bool>,
+    fallback_fn: impl Fn(&Array, &Array) -> VortexResult<Array>,
 ) -> VortexResult<Array> {
-    // If the right side is constant
-    if let Some(true) = other.statistics().get_as::<bool>(Stat::IsConstant) {
-        let lhs = constant_array.scalar().value().as_bool()?;
-        let rhs = scalar_at(other, 0)?.value().as_bool()?;
-
-        let scalar = match lhs.zip(rhs).map(bool_op) {
-            Some(b) => Scalar::bool(b, Nullability::Nullable),
-            None => Scalar::null(constant_array.dtype().as_nullable()),
-        };
-
-        Ok(ConstantArray::new(scalar, constant_array.len()).into_array())
+    let nullability = (constant_array.dtype().is_nullable() || other.dtype().is_nullable()).into();
+    let lhs = constant_array.scalar().value().as_bool()?;
+    let rhs = if let Ok(other) = ConstantArray::try_from(other) {
+        Some(other.scalar().value().as_bool()?)
+    } else if let Some(true) = other.statistics().get_as::<bool>(Stat::IsConstant) {
+        Some(scalar_at(other, 0)?.value().as_bool()?)
     } else {
-        // try and use a the rhs specialized implementation if it exists
-        match fallback_fn(other, constant_array.as_array_ref()) {
-            Some(r) => r,
-            None => vortex_bail!("Operation is not supported"),
+        None
+    };
+
+    // The result is constant if the right side is constant, or if the left side alone decides it.
+    let result = match rhs {
+        Some(rhs) => Some(bool_op(lhs, rhs)),
+        None => {
+            let result = bool_op(lhs, None);
+            (bool_op(lhs, Some(true)) == result && bool_op(lhs, Some(false)) == result)
+                .then_some(result)
         }
+    };
+
+    match result {
+        Some(result) => {
+            let scalar = match result {
+                Some(b) => Scalar::bool(b, nullability),
+                None => Scalar::null(DType::Bool(Nullability::Nullable)),
+            };
+            Ok(ConstantArray::new(scalar, constant_array.len()).into_array())
+        }
+        // Use the other side's specialized implementation if it exists
+        None => fallback_fn(other, constant_array.as_array_ref()),
     }
 }
 
@@ -178,7 +432,9 @@ mod test {
     use crate::array::constant::ConstantArray;
     use crate::array::BoolArray;
     use crate::compute::unary::scalar_at;
-    use crate::compute::{and, or, search_sorted, SearchResult, SearchSortedSide};
+    use crate::compute::{
+        and, and_kleene, not, or, or_kleene, search_sorted, SearchResult, SearchSortedSide,
+    };
     use crate::{Array, IntoArray, IntoArrayVariant};
 
     #[test]
@@ -241,4 +497,32 @@ mod test {
         assert!(v2.unwrap());
         assert!(!v3.unwrap());
     }
+
+    #[test]
+    fn test_kleene_short_circuit() {
+        let nulls = BoolArray::from_iter([None, Some(true), None]).into_array();
+
+        let falses = and_kleene(&ConstantArray::new(false, 3).into_array(), &nulls).unwrap();
+        assert!(ConstantArray::try_from(&falses).is_ok());
+        assert_eq!(
+            scalar_at(&falses, 0).unwrap().value().as_bool().unwrap(),
+            Some(false)
+        );
+
+        let trues = or_kleene(&ConstantArray::new(true, 3).into_array(), &nulls).unwrap();
+        assert!(ConstantArray::try_from(&trues).is_ok());
+        assert_eq!(
+            scalar_at(&trues, 2).unwrap().value().as_bool().unwrap(),
+            Some(true)
+        );
+
+        let passthrough = and_kleene(&ConstantArray::new(true, 3).into_array(), &nulls).unwrap();
+        assert!(scalar_at(&passthrough, 0).unwrap().is_null());
+
+        let negated = not(&ConstantArray::new(true, 3).into_array()).unwrap();
+        assert_eq!(
+            scalar_at(&negated, 1).unwrap().value().as_bool().unwrap(),
+            Some(false)
+        );
+    }
 }
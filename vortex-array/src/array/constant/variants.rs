@@ -5,8 +5,9 @@ use vortex_scalar::StructScalar;
 
 use crate::array::constant::ConstantArray;
 use crate::variants::{
-    ArrayVariants, BinaryArrayTrait, BoolArrayTrait, ExtensionArrayTrait, ListArrayTrait,
-    NullArrayTrait, PrimitiveArrayTrait, StructArrayTrait, Utf8ArrayTrait,
+    ArrayVariants, BinaryArrayTrait, BoolArrayTrait, DecimalArrayTrait, ExtensionArrayTrait,
+    FixedSizeListArrayTrait, ListArrayTrait, MapArrayTrait, NullArrayTrait, PrimitiveArrayTrait,
+    StructArrayTrait, UnionArrayTrait, Utf8ArrayTrait,
 };
 use crate::{Array, ArrayDType, IntoArray};
 
@@ -24,6 +25,10 @@ impl ArrayVariants for ConstantArray {
         matches!(self.dtype(), DType::Primitive(..)).then_some(self)
     }
 
+    fn as_decimal_array(&self) -> Option<&dyn DecimalArrayTrait> {
+        matches!(self.dtype(), DType::Decimal(..)).then_some(self)
+    }
+
     fn as_utf8_array(&self) -> Option<&dyn Utf8ArrayTrait> {
         matches!(self.dtype(), DType::Utf8(_)).then_some(self)
     }
@@ -40,6 +45,18 @@ impl ArrayVariants for ConstantArray {
         matches!(self.dtype(), DType::List(..)).then_some(self)
     }
 
+    fn as_fixed_size_list_array(&self) -> Option<&dyn FixedSizeListArrayTrait> {
+        matches!(self.dtype(), DType::FixedSizeList(..)).then_some(self)
+    }
+
+    fn as_union_array(&self) -> Option<&dyn UnionArrayTrait> {
+        matches!(self.dtype(), DType::Union(..)).then_some(self)
+    }
+
+    fn as_map_array(&self) -> Option<&dyn MapArrayTrait> {
+        matches!(self.dtype(), DType::Map(..)).then_some(self)
+    }
+
     fn as_extension_array(&self) -> Option<&dyn ExtensionArrayTrait> {
         matches!(self.dtype(), DType::Extension(..)).then_some(self)
     }
@@ -75,6 +92,8 @@ impl BoolArrayTrait for ConstantArray {
 
 impl PrimitiveArrayTrait for ConstantArray {}
 
+impl DecimalArrayTrait for ConstantArray {}
+
 impl Utf8ArrayTrait for ConstantArray {}
 
 impl BinaryArrayTrait for ConstantArray {}
@@ -90,6 +109,12 @@ impl StructArrayTrait for ConstantArray {
 
 impl ListArrayTrait for ConstantArray {}
 
+impl FixedSizeListArrayTrait for ConstantArray {}
+
+impl UnionArrayTrait for ConstantArray {}
+
+impl MapArrayTrait for ConstantArray {}
+
 impl ExtensionArrayTrait for ConstantArray {}
 
 #[cfg(test)]
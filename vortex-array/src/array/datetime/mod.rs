@@ -4,9 +4,11 @@ use arrow_schema::DataType;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde::{Deserialize, Serialize};
 pub use temporal::TemporalArray;
-use vortex_dtype::ExtDType;
+use vortex_dtype::{DType, ExtDType, Nullability, PType, StructDType};
 
-use crate::array::datetime::temporal::{TemporalMetadata, DATE_ID, TIMESTAMP_ID, TIME_ID};
+use crate::array::datetime::temporal::{
+    TemporalMetadata, DATE_ID, DURATION_ID, INTERVAL_ID, TIMESTAMP_ID, TIME_ID,
+};
 
 pub mod temporal;
 
@@ -45,9 +47,69 @@ impl Display for TimeUnit {
     }
 }
 
+/// The calendar granularity of an interval, mirroring the interval types supported by Arrow.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    IntoPrimitive,
+    TryFromPrimitive,
+)]
+#[repr(u8)]
+pub enum IntervalUnit {
+    /// A number of months, stored as `i32`.
+    YearMonth,
+    /// A number of days and milliseconds, stored as a struct of two `i32` fields.
+    DayTime,
+    /// A number of months, days and nanoseconds, stored as a struct of `i32`, `i32` and `i64`
+    /// fields.
+    MonthDayNano,
+}
+
+impl IntervalUnit {
+    /// The DType of the storage array backing a `vortex.interval` array with this unit.
+    pub fn storage_dtype(&self, nullability: Nullability) -> DType {
+        let field = |ptype| DType::Primitive(ptype, Nullability::NonNullable);
+        match self {
+            Self::YearMonth => DType::Primitive(PType::I32, nullability),
+            Self::DayTime => DType::Struct(
+                StructDType::new(
+                    ["days".into(), "milliseconds".into()].into(),
+                    vec![field(PType::I32), field(PType::I32)],
+                ),
+                nullability,
+            ),
+            Self::MonthDayNano => DType::Struct(
+                StructDType::new(
+                    ["months".into(), "days".into(), "nanoseconds".into()].into(),
+                    vec![field(PType::I32), field(PType::I32), field(PType::I64)],
+                ),
+                nullability,
+            ),
+        }
+    }
+}
+
+impl Display for IntervalUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::YearMonth => write!(f, "year_month"),
+            Self::DayTime => write!(f, "day_time"),
+            Self::MonthDayNano => write!(f, "month_day_nano"),
+        }
+    }
+}
+
 /// Construct an extension type from the provided temporal Arrow type.
 ///
-/// Supported types are Date32, Date64, Time32, Time64, Timestamp.
+/// Supported types are Date32, Date64, Time32, Time64, Timestamp, Duration and Interval.
 pub fn make_temporal_ext_dtype(data_type: &DataType) -> ExtDType {
     assert!(data_type.is_temporal(), "Must receive a temporal DataType");
 
@@ -83,6 +145,20 @@ pub fn make_temporal_ext_dtype(data_type: &DataType) -> ExtDType {
             DATE_ID.clone(),
             Some(TemporalMetadata::Date(TimeUnit::Ms).into()),
         ),
+        DataType::Duration(time_unit) => {
+            let time_unit = TimeUnit::from(time_unit);
+            ExtDType::new(
+                DURATION_ID.clone(),
+                Some(TemporalMetadata::Duration(time_unit).into()),
+            )
+        }
+        DataType::Interval(interval_unit) => {
+            let interval_unit = IntervalUnit::from(interval_unit);
+            ExtDType::new(
+                INTERVAL_ID.clone(),
+                Some(TemporalMetadata::Interval(interval_unit).into()),
+            )
+        }
         _ => unimplemented!("we should fix this"),
     }
 }
@@ -1,12 +1,15 @@
 use lazy_static::lazy_static;
 use vortex_dtype::{DType, ExtDType, ExtID};
 
-use crate::array::datetime::TimeUnit;
+use crate::array::datetime::{IntervalUnit, TimeUnit};
 use crate::array::extension::ExtensionArray;
 use crate::{Array, ArrayDType, ArrayData, IntoArray};
 
+mod extension;
 mod from;
 
+pub(crate) use extension::temporal_extension_types;
+
 #[cfg(test)]
 mod test;
 
@@ -14,6 +17,8 @@ lazy_static! {
     pub static ref DATE_ID: ExtID = ExtID::from("vortex.date");
     pub static ref TIME_ID: ExtID = ExtID::from("vortex.time");
     pub static ref TIMESTAMP_ID: ExtID = ExtID::from("vortex.timestamp");
+    pub static ref DURATION_ID: ExtID = ExtID::from("vortex.duration");
+    pub static ref INTERVAL_ID: ExtID = ExtID::from("vortex.interval");
 }
 
 pub fn is_temporal_ext_type(id: &ExtID) -> bool {
@@ -21,6 +26,8 @@ pub fn is_temporal_ext_type(id: &ExtID) -> bool {
         x if x == DATE_ID.as_ref() => true,
         x if x == TIME_ID.as_ref() => true,
         x if x == TIMESTAMP_ID.as_ref() => true,
+        x if x == DURATION_ID.as_ref() => true,
+        x if x == INTERVAL_ID.as_ref() => true,
         _ => false,
     }
 }
@@ -33,17 +40,31 @@ pub enum TemporalMetadata {
     Time(TimeUnit),
     Date(TimeUnit),
     Timestamp(TimeUnit, Option<String>),
+    Duration(TimeUnit),
+    Interval(IntervalUnit),
 }
 
 impl TemporalMetadata {
     /// Retrieve the time unit associated with the array.
     ///
-    /// All temporal arrays have a single intrinsic time unit for all of its numeric values.
-    pub fn time_unit(&self) -> TimeUnit {
+    /// All temporal arrays other than intervals have a single intrinsic time unit for all of its
+    /// numeric values, intervals have an [IntervalUnit] instead and return `None`.
+    pub fn time_unit(&self) -> Option<TimeUnit> {
         match self {
             TemporalMetadata::Time(time_unit)
             | TemporalMetadata::Date(time_unit)
-            | TemporalMetadata::Timestamp(time_unit, _) => *time_unit,
+            | TemporalMetadata::Timestamp(time_unit, _)
+            | TemporalMetadata::Duration(time_unit) => Some(*time_unit),
+            TemporalMetadata::Interval(_) => None,
+        }
+    }
+
+    /// Access the interval unit of the metadata, if it describes an interval.
+    pub fn interval_unit(&self) -> Option<IntervalUnit> {
+        if let TemporalMetadata::Interval(interval_unit) = self {
+            Some(*interval_unit)
+        } else {
+            None
         }
     }
 
@@ -70,6 +91,8 @@ impl TemporalMetadata {
 /// * `Timestamp`
 /// * `Date32`
 /// * `Date64`
+/// * `Duration`
+/// * `Interval`
 ///
 /// Anything that can be constructed and held in a `TemporalArray` can also be zero-copy converted
 /// back to the relevant Arrow datatype.
@@ -199,6 +222,65 @@ impl TemporalArray {
             temporal_metadata,
         }
     }
+
+    /// Create a new `TemporalArray` holding signed `i64` elapsed times in the given unit.
+    ///
+    /// This is equivalent to the data described by the `Duration` data type from Arrow.
+    ///
+    /// # Panics
+    ///
+    /// If `array` does not hold Primitive i64 data, the function will panic.
+    ///
+    /// If the time_unit is days, the function will panic.
+    pub fn new_duration(array: Array, time_unit: TimeUnit) -> Self {
+        assert_width!(i64, array);
+        assert_ne!(
+            time_unit,
+            TimeUnit::D,
+            "invalid unit D for vortex.duration data"
+        );
+
+        let temporal_metadata = TemporalMetadata::Duration(time_unit);
+        Self {
+            ext: ExtensionArray::new(
+                ExtDType::new(DURATION_ID.clone(), Some(temporal_metadata.clone().into())),
+                array,
+            ),
+            temporal_metadata,
+        }
+    }
+
+    /// Create a new `TemporalArray` holding calendar intervals.
+    ///
+    /// The storage layout depends on the unit, see [IntervalUnit::storage_dtype]:
+    ///
+    /// * `YearMonth` intervals are `i32` month counts
+    /// * `DayTime` intervals are a struct of `i32` days and `i32` milliseconds
+    /// * `MonthDayNano` intervals are a struct of `i32` months, `i32` days and `i64` nanoseconds
+    ///
+    /// This is equivalent to the data described by the `Interval` data type from Arrow.
+    ///
+    /// # Panics
+    ///
+    /// If the array's DType does not match the storage DType of the interval unit, it panics.
+    pub fn new_interval(array: Array, interval_unit: IntervalUnit) -> Self {
+        let expected = interval_unit.storage_dtype(array.dtype().nullability());
+        assert_eq!(
+            array.dtype(),
+            &expected,
+            "invalid storage dtype {} for {interval_unit} interval, expected {expected}",
+            array.dtype()
+        );
+
+        let temporal_metadata = TemporalMetadata::Interval(interval_unit);
+        Self {
+            ext: ExtensionArray::new(
+                ExtDType::new(INTERVAL_ID.clone(), Some(temporal_metadata.clone().into())),
+                array,
+            ),
+            temporal_metadata,
+        }
+    }
 }
 
 impl TemporalArray {
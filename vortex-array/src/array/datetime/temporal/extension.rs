@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use arrow_schema::{DataType, TimeUnit as ArrowTimeUnit};
+use vortex_dtype::{DType, ExtDType, ExtID, Nullability, PType};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::datetime::temporal::{
+    is_temporal_ext_type, TemporalMetadata, DATE_ID, DURATION_ID, INTERVAL_ID, TIMESTAMP_ID,
+    TIME_ID,
+};
+use crate::array::datetime::{IntervalUnit, TemporalArray, TimeUnit};
+use crate::array::extension::{ExtensionArray, ExtensionType, ExtensionTypeRef};
+use crate::array::PrimitiveArray;
+use crate::canonical::temporal_to_arrow;
+use crate::compute::unary::{try_cast_with_mode, CastMode};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+/// The built-in temporal extension types, which map onto the native Arrow temporal types.
+#[derive(Debug)]
+struct TemporalExtensionType {
+    id: ExtID,
+}
+
+pub(crate) fn temporal_extension_types() -> Vec<ExtensionTypeRef> {
+    [
+        &*DATE_ID,
+        &*TIME_ID,
+        &*TIMESTAMP_ID,
+        &*DURATION_ID,
+        &*INTERVAL_ID,
+    ]
+    .into_iter()
+    .map(|id| Arc::new(TemporalExtensionType { id: id.clone() }) as ExtensionTypeRef)
+    .collect()
+}
+
+/// The storage DType required for temporal values with the given metadata.
+fn storage_dtype(metadata: &TemporalMetadata, nullability: Nullability) -> VortexResult<DType> {
+    let ptype = match metadata {
+        TemporalMetadata::Time(TimeUnit::S | TimeUnit::Ms) => PType::I32,
+        TemporalMetadata::Time(TimeUnit::Us | TimeUnit::Ns) => PType::I64,
+        TemporalMetadata::Date(TimeUnit::D) => PType::I32,
+        TemporalMetadata::Date(TimeUnit::Ms) => PType::I64,
+        TemporalMetadata::Timestamp(time_unit, _) | TemporalMetadata::Duration(time_unit)
+            if *time_unit != TimeUnit::D =>
+        {
+            PType::I64
+        }
+        TemporalMetadata::Interval(interval_unit) => {
+            return Ok(interval_unit.storage_dtype(nullability))
+        }
+        _ => vortex_bail!("invalid time unit for {:?}", metadata),
+    };
+    Ok(DType::Primitive(ptype, nullability))
+}
+
+impl ExtensionType for TemporalExtensionType {
+    fn id(&self) -> &ExtID {
+        &self.id
+    }
+
+    fn validate_storage(&self, ext_dtype: &ExtDType, storage_dtype: &DType) -> VortexResult<()> {
+        let metadata = TemporalMetadata::try_from(ext_dtype)?;
+        let expected = self::storage_dtype(&metadata, storage_dtype.nullability())?;
+        if storage_dtype != &expected {
+            vortex_bail!(MismatchedTypes: expected, storage_dtype);
+        }
+        Ok(())
+    }
+
+    fn storage_dtype(&self, ext_dtype: &ExtDType, nullability: Nullability) -> Option<DType> {
+        let metadata = TemporalMetadata::try_from(ext_dtype).ok()?;
+        self::storage_dtype(&metadata, nullability).ok()
+    }
+
+    fn arrow_data_type(&self, ext_dtype: &ExtDType) -> VortexResult<DataType> {
+        let arrow_time_unit = |time_unit: TimeUnit| -> VortexResult<ArrowTimeUnit> {
+            Ok(match time_unit {
+                TimeUnit::S => ArrowTimeUnit::Second,
+                TimeUnit::Ms => ArrowTimeUnit::Millisecond,
+                TimeUnit::Us => ArrowTimeUnit::Microsecond,
+                TimeUnit::Ns => ArrowTimeUnit::Nanosecond,
+                TimeUnit::D => vortex_bail!("invalid time_unit {time_unit} for {}", self.id),
+            })
+        };
+
+        Ok(match TemporalMetadata::try_from(ext_dtype)? {
+            TemporalMetadata::Time(time_unit) => match time_unit {
+                TimeUnit::S | TimeUnit::Ms => DataType::Time32(arrow_time_unit(time_unit)?),
+                _ => DataType::Time64(arrow_time_unit(time_unit)?),
+            },
+            TemporalMetadata::Date(time_unit) => match time_unit {
+                TimeUnit::D => DataType::Date32,
+                TimeUnit::Ms => DataType::Date64,
+                _ => vortex_bail!("invalid time_unit {time_unit} for {}", self.id),
+            },
+            TemporalMetadata::Timestamp(time_unit, tz) => {
+                DataType::Timestamp(arrow_time_unit(time_unit)?, tz.map(Arc::from))
+            }
+            TemporalMetadata::Duration(time_unit) => {
+                DataType::Duration(arrow_time_unit(time_unit)?)
+            }
+            TemporalMetadata::Interval(interval_unit) => DataType::Interval(interval_unit.into()),
+        })
+    }
+
+    fn to_arrow(&self, array: &ExtensionArray) -> VortexResult<ArrayRef> {
+        temporal_to_arrow(TemporalArray::try_from(array.clone())?)
+    }
+
+    fn arrow_metadata(&self, _ext_dtype: &ExtDType) -> HashMap<String, String> {
+        // Temporal types are native Arrow types, they need no extension metadata.
+        HashMap::new()
+    }
+
+    fn fmt_scalar(&self, scalar: &Scalar, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let DType::Extension(ext_dtype, _) = scalar.dtype() else {
+            return Err(std::fmt::Error);
+        };
+        let metadata = TemporalMetadata::try_from(ext_dtype).map_err(|_| std::fmt::Error)?;
+        if scalar.value().is_null() {
+            return write!(f, "null");
+        }
+
+        match metadata {
+            TemporalMetadata::Interval(IntervalUnit::YearMonth) => {
+                let months = scalar
+                    .value()
+                    .as_pvalue()
+                    .map_err(|_| std::fmt::Error)?
+                    .and_then(|v| i64::try_from(v).ok())
+                    .ok_or(std::fmt::Error)?;
+                write!(f, "{months} months")
+            }
+            TemporalMetadata::Interval(interval_unit) => {
+                let parts = scalar
+                    .value()
+                    .as_list()
+                    .map_err(|_| std::fmt::Error)?
+                    .ok_or(std::fmt::Error)?;
+                let part = |i: usize| {
+                    parts[i]
+                        .as_pvalue()
+                        .map_err(|_| std::fmt::Error)?
+                        .and_then(|v| i64::try_from(v).ok())
+                        .ok_or(std::fmt::Error)
+                };
+                match interval_unit {
+                    IntervalUnit::DayTime => write!(f, "{} days {} ms", part(0)?, part(1)?),
+                    _ => write!(f, "{} months {} days {} ns", part(0)?, part(1)?, part(2)?),
+                }
+            }
+            _ => {
+                let value = scalar
+                    .value()
+                    .as_pvalue()
+                    .map_err(|_| std::fmt::Error)?
+                    .and_then(|v| i64::try_from(v).ok())
+                    .ok_or(std::fmt::Error)?;
+                write!(f, "{value}{}", metadata.time_unit().ok_or(std::fmt::Error)?)?;
+                match metadata.time_zone() {
+                    Some(tz) => write!(f, " {tz}"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+
+    fn cast(
+        &self,
+        array: &ExtensionArray,
+        dtype: &DType,
+        mode: CastMode,
+    ) -> Option<VortexResult<Array>> {
+        match dtype {
+            // Temporal values can be cast to their raw numeric representation.
+            DType::Primitive(..) => Some(try_cast_with_mode(&array.storage(), dtype, mode)),
+            DType::Extension(ext_dtype, nullability) if is_temporal_ext_type(ext_dtype.id()) => {
+                Some(cast_temporal(array, ext_dtype, *nullability, mode))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Convert between the units of dates and timestamps, durations, or times of day.
+///
+/// Converting to a coarser unit rounds toward negative infinity, so timestamps before the epoch
+/// land on the right date.
+fn cast_temporal(
+    array: &ExtensionArray,
+    ext_dtype: &ExtDType,
+    nullability: Nullability,
+    mode: CastMode,
+) -> VortexResult<Array> {
+    let from = TemporalMetadata::try_from(array.ext_dtype())?;
+    let to = TemporalMetadata::try_from(ext_dtype)?;
+    match (&from, &to) {
+        (
+            TemporalMetadata::Date(_) | TemporalMetadata::Timestamp(..),
+            TemporalMetadata::Date(_) | TemporalMetadata::Timestamp(..),
+        )
+        | (TemporalMetadata::Duration(_), TemporalMetadata::Duration(_))
+        | (TemporalMetadata::Time(_), TemporalMetadata::Time(_)) => {}
+        _ => vortex_bail!("Cannot cast {:?} to {:?}", from, to),
+    }
+    let (Some(from_unit), Some(to_unit)) = (from.time_unit(), to.time_unit()) else {
+        vortex_bail!("Cannot cast {:?} to {:?}", from, to)
+    };
+
+    let storage = array.storage();
+    let values = try_cast_with_mode(
+        &storage,
+        &DType::Primitive(PType::I64, storage.dtype().nullability()),
+        mode,
+    )?
+    .into_primitive()?;
+    let nulls = array.logical_validity().to_null_buffer()?;
+    let converted = values
+        .maybe_null_slice::<i64>()
+        .iter()
+        .enumerate()
+        .map(|(idx, &v)| {
+            if !nulls.as_ref().map_or(true, |n| n.is_valid(idx)) {
+                return Ok(0);
+            }
+            match to {
+                TemporalMetadata::Date(_) => convert_unit(
+                    convert_unit(v, from_unit, TimeUnit::D, mode)?,
+                    TimeUnit::D,
+                    to_unit,
+                    mode,
+                ),
+                _ => convert_unit(v, from_unit, to_unit, mode),
+            }
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+
+    let storage = try_cast_with_mode(
+        &PrimitiveArray::from_vec(converted, values.validity()).into_array(),
+        &storage_dtype(&to, nullability)?,
+        mode,
+    )?;
+    ExtensionArray::try_new(ext_dtype.clone(), storage).map(|a| a.into_array())
+}
+
+fn convert_unit(value: i64, from: TimeUnit, to: TimeUnit, mode: CastMode) -> VortexResult<i64> {
+    let (from_nanos, to_nanos) = (unit_nanos(from), unit_nanos(to));
+    if from_nanos < to_nanos {
+        return Ok(value.div_euclid(to_nanos / from_nanos));
+    }
+
+    match (value.checked_mul(from_nanos / to_nanos), mode) {
+        (Some(value), _) => Ok(value),
+        (None, CastMode::Safe) => {
+            vortex_bail!(ComputeError: "Failed to convert {} from {} to {}", value, from, to)
+        }
+        (None, CastMode::Saturating) => Ok(if value < 0 { i64::MIN } else { i64::MAX }),
+    }
+}
+
+fn unit_nanos(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Ns => 1,
+        TimeUnit::Us => 1_000,
+        TimeUnit::Ms => 1_000_000,
+        TimeUnit::S => 1_000_000_000,
+        TimeUnit::D => 86_400_000_000_000,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_schema::{DataType, TimeUnit as ArrowTimeUnit};
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::extension::{find_extension_type, ScalarDisplay};
+    use crate::array::{PrimitiveArray, TemporalArray, TimeUnit};
+    use crate::compute::unary::{scalar_at, try_cast, try_cast_with_mode, CastMode};
+    use crate::validity::Validity;
+    use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn test_temporal_registered() {
+        let array = TemporalArray::new_duration(
+            PrimitiveArray::from_vec(vec![100i64, -5], Validity::NonNullable).into_array(),
+            TimeUnit::Ms,
+        );
+        let ext_type = find_extension_type(array.ext_dtype().id()).unwrap();
+
+        assert_eq!(
+            ext_type.arrow_data_type(array.ext_dtype()).unwrap(),
+            DataType::Duration(ArrowTimeUnit::Millisecond)
+        );
+        assert!(ext_type
+            .validate_storage(
+                array.ext_dtype(),
+                &DType::Primitive(PType::I32, Nullability::NonNullable)
+            )
+            .is_err());
+
+        let array = Array::from(array);
+        assert_eq!(
+            ScalarDisplay(&scalar_at(&array, 1).unwrap()).to_string(),
+            "-5ms"
+        );
+
+        let cast = try_cast(
+            &array,
+            &DType::Primitive(PType::I64, Nullability::NonNullable),
+        )
+        .unwrap();
+        assert_eq!(scalar_at(&cast, 0).unwrap(), Scalar::from(100i64));
+    }
+
+    #[test]
+    fn test_cast_timestamp_to_date() {
+        let day_ms = 86_400_000i64;
+        let timestamps = Array::from(TemporalArray::new_timestamp(
+            PrimitiveArray::from_vec(vec![day_ms + 1, -1], Validity::NonNullable).into_array(),
+            TimeUnit::Ms,
+            None,
+        ));
+        let date = TemporalArray::new_date(
+            PrimitiveArray::from_vec(vec![0i32], Validity::NonNullable).into_array(),
+            TimeUnit::D,
+        );
+
+        let dates = try_cast(&timestamps, Array::from(date).dtype()).unwrap();
+        let days = TemporalArray::try_from(&dates)
+            .unwrap()
+            .temporal_values()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(days.maybe_null_slice::<i32>(), &[1, -1]);
+
+        let nanos = TemporalArray::new_timestamp(
+            PrimitiveArray::from_vec(vec![0i64], Validity::NonNullable).into_array(),
+            TimeUnit::Ns,
+            None,
+        );
+        let far_future = Array::from(TemporalArray::new_timestamp(
+            PrimitiveArray::from_vec(vec![i64::MAX / 1000], Validity::NonNullable).into_array(),
+            TimeUnit::Ms,
+            None,
+        ));
+        let nanos_dtype = Array::from(nanos).dtype().clone();
+        assert!(try_cast(&far_future, &nanos_dtype).is_err());
+        let saturated =
+            try_cast_with_mode(&far_future, &nanos_dtype, CastMode::Saturating).unwrap();
+        assert_eq!(
+            scalar_at(&saturated, 0).unwrap(),
+            Scalar::extension(
+                TemporalArray::try_from(&saturated)
+                    .unwrap()
+                    .ext_dtype()
+                    .clone(),
+                Scalar::from(i64::MAX)
+            )
+        );
+    }
+}
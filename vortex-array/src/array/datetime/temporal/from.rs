@@ -1,8 +1,10 @@
 use vortex_dtype::{ExtDType, ExtMetadata};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
-use crate::array::datetime::temporal::{TemporalMetadata, DATE_ID, TIMESTAMP_ID, TIME_ID};
-use crate::array::datetime::{TemporalArray, TimeUnit};
+use crate::array::datetime::temporal::{
+    TemporalMetadata, DATE_ID, DURATION_ID, INTERVAL_ID, TIMESTAMP_ID, TIME_ID,
+};
+use crate::array::datetime::{IntervalUnit, TemporalArray, TimeUnit};
 use crate::array::extension::ExtensionArray;
 use crate::Array;
 
@@ -17,6 +19,8 @@ impl TryFrom<&ExtDType> for TemporalMetadata {
             x if x == TIME_ID.as_ref() => decode_time_metadata(metadata),
             x if x == DATE_ID.as_ref() => decode_date_metadata(metadata),
             x if x == TIMESTAMP_ID.as_ref() => decode_timestamp_metadata(metadata),
+            x if x == DURATION_ID.as_ref() => decode_duration_metadata(metadata),
+            x if x == INTERVAL_ID.as_ref() => decode_interval_metadata(metadata),
             _ => {
                 vortex_bail!(InvalidArgument: "ExtDType must be one of the known temporal types")
             }
@@ -38,6 +42,20 @@ fn decode_time_metadata(ext_meta: &ExtMetadata) -> VortexResult<TemporalMetadata
     Ok(TemporalMetadata::Time(time_unit))
 }
 
+fn decode_duration_metadata(ext_meta: &ExtMetadata) -> VortexResult<TemporalMetadata> {
+    let tag = ext_meta.as_ref()[0];
+    let time_unit =
+        TimeUnit::try_from(tag).map_err(|e| vortex_err!(ComputeError: "invalid unit tag: {e}"))?;
+    Ok(TemporalMetadata::Duration(time_unit))
+}
+
+fn decode_interval_metadata(ext_meta: &ExtMetadata) -> VortexResult<TemporalMetadata> {
+    let tag = ext_meta.as_ref()[0];
+    let interval_unit = IntervalUnit::try_from(tag)
+        .map_err(|e| vortex_err!(ComputeError: "invalid interval unit tag: {e}"))?;
+    Ok(TemporalMetadata::Interval(interval_unit))
+}
+
 fn decode_timestamp_metadata(ext_meta: &ExtMetadata) -> VortexResult<TemporalMetadata> {
     let tag = ext_meta.as_ref()[0];
     let time_unit =
@@ -94,13 +112,20 @@ impl From<TemporalMetadata> for ExtMetadata {
         match value {
             // Time32/Time64 and Date32/Date64 only need to encode the unit in their metadata
             // The unit also unambiguously maps to the integer width of the backing array for all.
-            TemporalMetadata::Time(time_unit) | TemporalMetadata::Date(time_unit) => {
+            TemporalMetadata::Time(time_unit)
+            | TemporalMetadata::Date(time_unit)
+            | TemporalMetadata::Duration(time_unit) => {
                 let mut meta = Vec::new();
                 let unit_tag: u8 = time_unit.into();
                 meta.push(unit_tag);
 
                 ExtMetadata::from(meta.as_slice())
             }
+            // Intervals only need their unit, which also determines the storage layout.
+            TemporalMetadata::Interval(interval_unit) => {
+                let unit_tag: u8 = interval_unit.into();
+                ExtMetadata::from([unit_tag].as_slice())
+            }
             // Store both the time unit and zone in the metadata
             TemporalMetadata::Timestamp(time_unit, time_zone) => {
                 let mut meta = Vec::new();
@@ -1,9 +1,11 @@
 use vortex_dtype::{ExtDType, ExtMetadata};
 
-use crate::array::datetime::temporal::{TemporalMetadata, TIMESTAMP_ID};
-use crate::array::datetime::{TemporalArray, TimeUnit};
+use crate::array::datetime::temporal::{TemporalMetadata, INTERVAL_ID, TIMESTAMP_ID};
+use crate::array::datetime::{IntervalUnit, TemporalArray, TimeUnit};
 use crate::array::primitive::PrimitiveArray;
+use crate::array::struct_::StructArray;
 use crate::validity::Validity;
+use crate::variants::StructArrayTrait;
 use crate::{IntoArray, IntoArrayVariant};
 
 macro_rules! test_temporal_roundtrip {
@@ -17,7 +19,7 @@ macro_rules! test_temporal_roundtrip {
             prims.maybe_null_slice::<$prim>(),
             vec![100 as $prim].as_slice(),
         );
-        assert_eq!(temporal.temporal_metadata().time_unit(), $unit);
+        assert_eq!(temporal.temporal_metadata().time_unit(), Some($unit));
     }};
 }
 
@@ -168,3 +170,78 @@ fn test_timestamp_fails_i32() {
 
     let _ = TemporalArray::new_timestamp(ts_array.clone(), TimeUnit::S, None);
 }
+
+// Duration conformance tests
+test_success_case!(
+    test_roundtrip_duration_s,
+    i64,
+    TemporalArray::new_duration,
+    TimeUnit::S
+);
+test_success_case!(
+    test_roundtrip_duration_ns,
+    i64,
+    TemporalArray::new_duration,
+    TimeUnit::Ns
+);
+test_fail_case!(
+    test_fail_duration_i32,
+    i32,
+    TemporalArray::new_duration,
+    TimeUnit::Ms
+);
+test_fail_case!(
+    test_fail_duration_days,
+    i64,
+    TemporalArray::new_duration,
+    TimeUnit::D
+);
+
+#[test]
+fn test_roundtrip_interval_metadata() {
+    for unit in [
+        IntervalUnit::YearMonth,
+        IntervalUnit::DayTime,
+        IntervalUnit::MonthDayNano,
+    ] {
+        let meta: ExtMetadata = TemporalMetadata::Interval(unit).into();
+        let temporal_metadata =
+            TemporalMetadata::try_from(&ExtDType::new(INTERVAL_ID.clone(), Some(meta))).unwrap();
+        assert_eq!(temporal_metadata, TemporalMetadata::Interval(unit));
+        assert_eq!(temporal_metadata.interval_unit(), Some(unit));
+    }
+}
+
+#[test]
+fn test_interval_day_time() {
+    let storage = StructArray::from_fields(&[
+        (
+            "days",
+            PrimitiveArray::from_vec(vec![1i32, -2], Validity::NonNullable).into_array(),
+        ),
+        (
+            "milliseconds",
+            PrimitiveArray::from_vec(vec![500i32, 0], Validity::NonNullable).into_array(),
+        ),
+    ]);
+    let temporal = TemporalArray::new_interval(storage.into_array(), IntervalUnit::DayTime);
+    assert_eq!(
+        temporal.temporal_metadata(),
+        &TemporalMetadata::Interval(IntervalUnit::DayTime)
+    );
+
+    let values = temporal.temporal_values().into_struct().unwrap();
+    let days = values
+        .field_by_name("days")
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(days.maybe_null_slice::<i32>(), &[1, -2]);
+}
+
+#[test]
+#[should_panic]
+fn test_interval_fails_wrong_storage() {
+    let storage = PrimitiveArray::from_vec(vec![1i64], Validity::NonNullable).into_array();
+    let _ = TemporalArray::new_interval(storage, IntervalUnit::YearMonth);
+}
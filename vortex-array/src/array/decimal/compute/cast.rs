@@ -0,0 +1,246 @@
+use num_traits::ToPrimitive;
+use vortex_dtype::{match_each_native_ptype, DType, DecimalDType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::array::{BoolArray, DecimalArray, PrimitiveArray, VarBinViewArray};
+use crate::compute::unary::{cast_number, cast_validity, CastFn, CastMode};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray};
+
+impl CastFn for DecimalArray {
+    fn cast(&self, dtype: &DType, mode: CastMode) -> VortexResult<Array> {
+        let from = self.decimal_dtype();
+        let nulls = self.logical_validity().to_null_buffer()?;
+        let is_valid = |idx: usize| nulls.as_ref().map_or(true, |n| n.is_valid(idx));
+        let validity = cast_validity(self.validity(), dtype.nullability());
+        let values = self.maybe_null_slice();
+
+        match dtype {
+            DType::Decimal(to, _) => {
+                let rescaled = values
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, &v)| {
+                        if is_valid(idx) {
+                            rescale(v, from.scale(), *to, mode)
+                        } else {
+                            Ok(0)
+                        }
+                    })
+                    .collect::<VortexResult<Vec<_>>>()?;
+                Ok(DecimalArray::from_vec(rescaled, *to, validity).into_array())
+            }
+            DType::Primitive(ptype, _) if ptype.is_float() => {
+                let divisor = 10f64.powi(from.scale() as i32);
+                match_each_native_ptype!(ptype, |$T| {
+                    let floats = values
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, &v)| {
+                            if is_valid(idx) {
+                                cast_number::<f64, $T>(v as f64 / divisor, mode)
+                            } else {
+                                Ok(<$T>::default())
+                            }
+                        })
+                        .collect::<VortexResult<Vec<_>>>()?;
+                    Ok(PrimitiveArray::from_vec(floats, validity).into_array())
+                })
+            }
+            DType::Primitive(ptype, _) => {
+                let integral = DecimalDType::try_new(from.precision(), 0)?;
+                match_each_native_ptype!(ptype, |$T| {
+                    let ints = values
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, &v)| {
+                            if !is_valid(idx) {
+                                return Ok(<$T>::default());
+                            }
+                            let v = rescale(v, from.scale(), integral, mode)?;
+                            match <$T as num_traits::NumCast>::from(v) {
+                                Some(v) => Ok(v),
+                                None => cast_number::<f64, $T>(v as f64, mode),
+                            }
+                        })
+                        .collect::<VortexResult<Vec<_>>>()?;
+                    Ok(PrimitiveArray::from_vec(ints, validity).into_array())
+                })
+            }
+            DType::Bool(_) => Ok(BoolArray::try_new(
+                values.iter().map(|&v| v != 0).collect(),
+                validity,
+            )?
+            .into_array()),
+            DType::Utf8(_) => Ok(VarBinViewArray::from_iter(
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, &v)| is_valid(idx).then(|| format_decimal(v, from.scale()))),
+                dtype.clone(),
+            )
+            .into_array()),
+            _ => vortex_bail!("Cannot cast {} to {}", self.dtype(), dtype),
+        }
+    }
+}
+
+/// Move an unscaled value from `scale` to the scale of `to` and check it fits the precision.
+///
+/// Dropping non-zero fractional digits fails in [CastMode::Safe] and rounds toward zero in
+/// [CastMode::Saturating].
+pub(crate) fn rescale(
+    value: i128,
+    scale: u8,
+    to: DecimalDType,
+    mode: CastMode,
+) -> VortexResult<i128> {
+    let rescaled = if to.scale() >= scale {
+        10i128
+            .checked_pow((to.scale() - scale) as u32)
+            .and_then(|factor| value.checked_mul(factor))
+            .unwrap_or(if value < 0 { i128::MIN } else { i128::MAX })
+    } else {
+        let factor = 10i128.checked_pow((scale - to.scale()) as u32);
+        let exact = factor.map_or(value == 0, |factor| value % factor == 0);
+        if mode == CastMode::Safe && !exact {
+            vortex_bail!(
+                ComputeError: "Failed to cast decimal value {} to scale {} without losing digits",
+                format_decimal(value, scale),
+                to.scale()
+            );
+        }
+        factor.map_or(0, |factor| value / factor)
+    };
+    fit_precision(rescaled, to, mode)
+}
+
+/// Convert a number into the unscaled representation of `to`, rounding floats to the nearest
+/// representable value.
+pub(crate) fn number_to_decimal<T: ToPrimitive + std::fmt::Display>(
+    value: T,
+    to: DecimalDType,
+    mode: CastMode,
+) -> VortexResult<i128> {
+    if let Some(int) = value.to_i128() {
+        if value.to_f64().map_or(true, |f| f.fract() == 0.0) {
+            return rescale(int, 0, to, mode);
+        }
+    }
+
+    let float = value
+        .to_f64()
+        .ok_or_else(|| vortex_err!(ComputeError: "Failed to cast {} to decimal({})", value, to))?;
+    if float.is_nan() && mode == CastMode::Safe {
+        vortex_bail!(ComputeError: "Failed to cast {} to decimal({})", value, to);
+    }
+    // Float to int `as` casts saturate, and map NaN to zero.
+    fit_precision(
+        (float * 10f64.powi(to.scale() as i32)).round() as i128,
+        to,
+        mode,
+    )
+}
+
+fn fit_precision(value: i128, to: DecimalDType, mode: CastMode) -> VortexResult<i128> {
+    let max = 10i128.pow(to.precision() as u32) - 1;
+    match mode {
+        _ if (-max..=max).contains(&value) => Ok(value),
+        CastMode::Safe => Err(vortex_err!(
+            ComputeError: "Failed to cast {} to decimal({})",
+            value,
+            to
+        )),
+        CastMode::Saturating => Ok(value.clamp(-max, max)),
+    }
+}
+
+/// Parse a decimal literal such as `-12.50` into the unscaled representation of `to`.
+pub(crate) fn parse_decimal(
+    value: &str,
+    to: DecimalDType,
+    mode: CastMode,
+) -> Option<VortexResult<i128>> {
+    let value = value.trim();
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (integral, fractional) = digits.split_once('.').unwrap_or((digits, ""));
+    if integral.is_empty() && fractional.is_empty()
+        || !integral
+            .bytes()
+            .chain(fractional.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let unscaled = format!("{integral}{fractional}")
+        .parse::<i128>()
+        .unwrap_or(i128::MAX);
+    let scale = u8::try_from(fractional.len()).unwrap_or(u8::MAX);
+    Some(rescale(
+        if negative { -unscaled } else { unscaled },
+        scale,
+        to,
+        mode,
+    ))
+}
+
+pub(crate) fn format_decimal(value: i128, scale: u8) -> String {
+    let scale = scale as usize;
+    if scale == 0 {
+        return value.to_string();
+    }
+
+    let digits = format!("{:0>width$}", value.unsigned_abs(), width = scale + 1);
+    let (integral, fractional) = digits.split_at(digits.len() - scale);
+    let sign = if value < 0 { "-" } else { "" };
+    format!("{}{}.{}", sign, integral, fractional)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, DecimalDType, Nullability, PType};
+
+    use crate::array::DecimalArray;
+    use crate::compute::unary::{try_cast, try_cast_with_mode, CastMode};
+    use crate::validity::Validity;
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn cast_decimal() {
+        let decimal = DecimalDType::try_new(5, 2).unwrap();
+        let array = DecimalArray::from_vec(vec![12345, -50, 1200], decimal, Validity::NonNullable)
+            .into_array();
+
+        let narrow = DType::Decimal(
+            DecimalDType::try_new(3, 0).unwrap(),
+            Nullability::NonNullable,
+        );
+        assert!(try_cast(&array, &narrow).is_err());
+        let saturated = try_cast_with_mode(&array, &narrow, CastMode::Saturating).unwrap();
+        assert_eq!(
+            DecimalArray::try_from(saturated)
+                .unwrap()
+                .maybe_null_slice(),
+            &[123, 0, 12]
+        );
+
+        let floats = try_cast(
+            &array,
+            &DType::Primitive(PType::F64, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+        assert_eq!(floats.maybe_null_slice::<f64>(), &[123.45, -0.5, 12.0]);
+
+        let strings = try_cast(&array, &DType::Utf8(Nullability::NonNullable))
+            .unwrap()
+            .into_varbin_view()
+            .unwrap();
+        assert_eq!(strings.bytes_at(1).unwrap(), b"-0.50");
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+
+use vortex_error::VortexResult;
+use vortex_scalar::{DecimalScalar, Scalar};
+
+use crate::array::decimal::DecimalArray;
+use crate::array::{BoolArray, PrimitiveArray};
+use crate::compute::group::group_values;
+use crate::compute::hash::{hash_bytes, hash_values};
+use crate::compute::sort::sort_by;
+use crate::compute::unary::{CastFn, FillNullFn, ScalarAtFn};
+use crate::compute::{
+    ArrayCompute, FilterFn, GroupIdsFn, HashFn, IsInFn, SliceFn, SortFn, SortOptions, TakeFn,
+};
+use crate::validity::{filter_validity, ArrayValidity, Validity};
+use crate::{Array, ArrayDType, AsArray, IntoArray, IntoArrayVariant};
+
+mod cast;
+
+pub(crate) use cast::{number_to_decimal, parse_decimal};
+
+impl ArrayCompute for DecimalArray {
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn group_ids(&self) -> Option<&dyn GroupIdsFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for DecimalArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if self.is_valid(index) {
+            Ok(Scalar::decimal(
+                self.maybe_null_slice()[index],
+                self.decimal_dtype(),
+                self.dtype().nullability(),
+            ))
+        } else {
+            Ok(Scalar::null(self.dtype().clone()))
+        }
+    }
+}
+
+impl FillNullFn for DecimalArray {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array> {
+        let fill = DecimalScalar::try_from(fill_value)?
+            .value()
+            .unwrap_or_default();
+        let values = self.maybe_null_slice();
+        let filled = match self.logical_validity().to_null_buffer()? {
+            None => values.to_vec(),
+            Some(nulls) => values
+                .iter()
+                .zip(nulls.iter())
+                .map(|(&v, valid)| if valid { v } else { fill })
+                .collect(),
+        };
+        Ok(
+            DecimalArray::from_vec(filled, self.decimal_dtype(), Validity::NonNullable)
+                .into_array(),
+        )
+    }
+}
+
+impl GroupIdsFn for DecimalArray {
+    fn group_ids(&self) -> VortexResult<(PrimitiveArray, Array)> {
+        let nulls = self.logical_validity().to_null_buffer()?;
+        let is_valid = |idx: usize| nulls.as_ref().map_or(true, |n| n.is_valid(idx));
+        let values = self.maybe_null_slice();
+        group_values(self.as_array_ref(), &HashFn::hash(self, 0)?, |a, b| match (
+            is_valid(a),
+            is_valid(b),
+        ) {
+            (true, true) => values[a] == values[b],
+            (a_valid, b_valid) => a_valid == b_valid,
+        })
+    }
+}
+
+impl HashFn for DecimalArray {
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray> {
+        Ok(hash_values(
+            self.maybe_null_slice().iter(),
+            self.logical_validity().to_null_buffer()?,
+            seed,
+            |v| hash_bytes(seed, &v.to_le_bytes()),
+        ))
+    }
+}
+
+impl IsInFn for DecimalArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        let values = values.clone().into_decimal()?;
+        let value_nulls = values.logical_validity().to_null_buffer()?;
+        let set = values
+            .maybe_null_slice()
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| value_nulls.as_ref().map_or(true, |n| n.is_valid(*idx)))
+            .map(|(_, v)| v)
+            .collect::<HashSet<_>>();
+
+        let nulls = self.logical_validity().to_null_buffer()?;
+        let mask = self
+            .maybe_null_slice()
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| nulls.as_ref().map_or(true, |n| n.is_valid(idx)) && set.contains(v))
+            .collect::<Vec<_>>();
+        Ok(BoolArray::from(mask).into_array())
+    }
+}
+
+impl SliceFn for DecimalArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        let byte_width = size_of::<i128>();
+        Ok(DecimalArray::try_new(
+            self.buffer().slice(start * byte_width..stop * byte_width),
+            self.decimal_dtype(),
+            self.validity().slice(start, stop)?,
+        )?
+        .into_array())
+    }
+}
+
+impl SortFn for DecimalArray {
+    fn sort_indices(&self, options: SortOptions) -> VortexResult<PrimitiveArray> {
+        let values = self.maybe_null_slice();
+        Ok(sort_by(
+            values.len(),
+            self.logical_validity().to_null_buffer()?.as_ref(),
+            options,
+            |a, b| values[a].cmp(&values[b]),
+        ))
+    }
+}
+
+impl TakeFn for DecimalArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let validity = self.validity().take(indices)?;
+        let indices = indices.clone().into_primitive()?;
+        let values = self.maybe_null_slice();
+        let taken = (0..indices.len())
+            .map(|i| values[indices.get_as_cast::<u64>(i) as usize])
+            .collect();
+        Ok(DecimalArray::from_vec(taken, self.decimal_dtype(), validity).into_array())
+    }
+}
+
+impl FilterFn for DecimalArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let validity = filter_validity(self.validity(), predicate)?;
+        let predicate = predicate.clone().into_bool()?.boolean_buffer();
+        let values = self.maybe_null_slice();
+        let filtered = predicate.set_indices().map(|idx| values[idx]).collect();
+        Ok(DecimalArray::from_vec(filtered, self.decimal_dtype(), validity).into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_buffer::Buffer;
+    use vortex_dtype::DecimalDType;
+    use vortex_scalar::{DecimalScalar, Scalar};
+
+    use crate::array::{BoolArray, DecimalArray, PrimitiveArray};
+    use crate::compute::unary::scalar_at;
+    use crate::compute::{filter, slice, take};
+    use crate::validity::Validity;
+    use crate::{Array, IntoArray};
+
+    fn decimal_array() -> DecimalArray {
+        DecimalArray::from_nullable_vec(
+            vec![Some(12345), None, Some(-500), Some(7)],
+            DecimalDType::try_new(10, 2).unwrap(),
+        )
+    }
+
+    fn values(array: &Array) -> Vec<Option<i128>> {
+        (0..array.len())
+            .map(|i| {
+                let scalar: Scalar = scalar_at(array, i).unwrap();
+                DecimalScalar::try_from(&scalar).unwrap().value()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn unaligned_buffer() {
+        let mut bytes = vec![0u8];
+        bytes.extend_from_slice(&12345i128.to_le_bytes());
+        bytes.extend_from_slice(&(-7i128).to_le_bytes());
+        let buffer = Buffer::from(bytes).slice(1..33);
+        let dtype = DecimalDType::try_new(10, 2).unwrap();
+
+        let array = DecimalArray::try_new(buffer.clone(), dtype, Validity::NonNullable).unwrap();
+        assert_eq!(values(&array.into_array()), vec![Some(12345), Some(-7)]);
+        assert!(DecimalArray::try_new(buffer.slice(0..17), dtype, Validity::NonNullable).is_err());
+    }
+
+    #[test]
+    fn decimal_scalar_at() {
+        let array = decimal_array().into_array();
+        assert_eq!(scalar_at(&array, 0).unwrap().to_string(), "123.45");
+        assert_eq!(values(&array), vec![Some(12345), None, Some(-500), Some(7)]);
+    }
+
+    #[test]
+    fn decimal_slice_take_filter() {
+        let array = decimal_array().into_array();
+        assert_eq!(
+            values(&slice(&array, 1, 3).unwrap()),
+            vec![None, Some(-500)]
+        );
+        assert_eq!(
+            values(&take(&array, PrimitiveArray::from(vec![3u32, 0, 1]).array()).unwrap()),
+            vec![Some(7), Some(12345), None]
+        );
+        assert_eq!(
+            values(
+                &filter(
+                    &array,
+                    BoolArray::from(vec![true, true, false, true]).array()
+                )
+                .unwrap()
+            ),
+            vec![Some(12345), None, Some(7)]
+        );
+    }
+}
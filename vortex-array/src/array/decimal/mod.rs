@@ -0,0 +1,144 @@
+use arrow_buffer::{Buffer as ArrowBuffer, MutableBuffer};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use vortex_buffer::Buffer;
+use vortex_dtype::{DType, DecimalDType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::stats::{ArrayStatisticsCompute, StatsSet};
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::variants::{ArrayVariants, DecimalArrayTrait};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{
+    impl_encoding, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoCanonical, TypedArray,
+};
+
+mod compute;
+
+pub(crate) use compute::{number_to_decimal, parse_decimal};
+
+impl_encoding!("vortex.decimal", 7u16, Decimal);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DecimalMetadata {
+    validity: ValidityMetadata,
+}
+
+impl DecimalArray {
+    /// Create a new DecimalArray from a buffer of unscaled little-endian `i128` values.
+    ///
+    /// Buffers that are not aligned to `i128`, such as those read from IPC, are copied.
+    pub fn try_new(
+        buffer: Buffer,
+        decimal_dtype: DecimalDType,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        let byte_width = size_of::<i128>();
+        if buffer.len() % byte_width != 0 {
+            vortex_bail!(
+                "Decimal buffer of {} bytes is not a whole number of i128 values",
+                buffer.len()
+            );
+        }
+        // SAFETY: every bit pattern is a valid i128.
+        let (prefix, ..) = unsafe { buffer.as_ref().align_to::<i128>() };
+        let buffer = if prefix.is_empty() {
+            buffer
+        } else {
+            let values = buffer
+                .as_ref()
+                .chunks_exact(byte_width)
+                .map(|bytes| i128::from_le_bytes(bytes.try_into().unwrap_or_default()))
+                .collect::<Vec<_>>();
+            ArrowBuffer::from(MutableBuffer::from(values)).into()
+        };
+        let length = buffer.len() / byte_width;
+
+        Ok(Self {
+            typed: TypedArray::try_from_parts(
+                DType::Decimal(decimal_dtype, validity.nullability()),
+                length,
+                DecimalMetadata {
+                    validity: validity.to_metadata(length)?,
+                },
+                Some(buffer),
+                validity.into_array().into_iter().collect_vec().into(),
+                StatsSet::new(),
+            )?,
+        })
+    }
+
+    pub fn from_vec(values: Vec<i128>, decimal_dtype: DecimalDType, validity: Validity) -> Self {
+        Self::try_new(
+            ArrowBuffer::from(MutableBuffer::from(values)).into(),
+            decimal_dtype,
+            validity,
+        )
+        .expect("should be valid")
+    }
+
+    pub fn from_nullable_vec(values: Vec<Option<i128>>, decimal_dtype: DecimalDType) -> Self {
+        let elems: Vec<i128> = values.iter().map(|v| v.unwrap_or_default()).collect();
+        let validity = Validity::from(values.iter().map(|v| v.is_some()).collect::<Vec<_>>());
+        Self::from_vec(elems, decimal_dtype, validity)
+    }
+
+    pub fn decimal_dtype(&self) -> DecimalDType {
+        let DType::Decimal(decimal_dtype, _) = self.dtype() else {
+            unreachable!("DecimalArray must have a decimal dtype")
+        };
+        *decimal_dtype
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(0, &Validity::DTYPE, self.len()))
+    }
+
+    pub fn buffer(&self) -> &Buffer {
+        self.array().buffer().expect("missing buffer")
+    }
+
+    /// The unscaled values of the array, including those at null positions.
+    pub fn maybe_null_slice(&self) -> &[i128] {
+        let (prefix, values, suffix) = unsafe { self.buffer().as_ref().align_to::<i128>() };
+        assert!(prefix.is_empty() && suffix.is_empty());
+        values
+    }
+}
+
+impl ArrayTrait for DecimalArray {}
+
+impl ArrayVariants for DecimalArray {
+    fn as_decimal_array(&self) -> Option<&dyn DecimalArrayTrait> {
+        Some(self)
+    }
+}
+
+impl DecimalArrayTrait for DecimalArray {}
+
+impl IntoCanonical for DecimalArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        Ok(Canonical::Decimal(self))
+    }
+}
+
+impl ArrayValidity for DecimalArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for DecimalArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_buffer(self.buffer())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for DecimalArray {}
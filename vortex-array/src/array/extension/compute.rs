@@ -1,17 +1,37 @@
-use vortex_error::VortexResult;
+use vortex_dtype::DType;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_expr::Operator;
 use vortex_scalar::Scalar;
 
-use crate::array::extension::ExtensionArray;
-use crate::compute::unary::{scalar_at, CastFn, ScalarAtFn};
-use crate::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
-use crate::{Array, IntoArray};
+use crate::array::extension::{find_extension_type, Extension, ExtensionArray};
+use crate::array::PrimitiveArray;
+use crate::compute::unary::{scalar_at, CastFn, CastMode, ScalarAtFn};
+use crate::compute::{
+    arrow_compare, group_ids, hash, is_in, slice, sort_indices, take, ArrayCompute, CompareFn,
+    GroupIdsFn, HashFn, IsInFn, SliceFn, SortFn, SortOptions, TakeFn,
+};
+use crate::{Array, ArrayDef, IntoArray, IntoArrayVariant};
 
 impl ArrayCompute for ExtensionArray {
     fn cast(&self) -> Option<&dyn CastFn> {
-        // It's not possible to cast an extension array to another type.
-        // TODO(ngates): we should allow some extension arrays to implement a callback
-        //  to support this
-        None
+        // Casting is delegated to the registered extension type, if any.
+        Some(self)
+    }
+
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn group_ids(&self) -> Option<&dyn GroupIdsFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
     }
 
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
@@ -22,11 +42,61 @@ impl ArrayCompute for ExtensionArray {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 }
 
+impl CastFn for ExtensionArray {
+    fn cast(&self, dtype: &DType, mode: CastMode) -> VortexResult<Array> {
+        find_extension_type(self.id())
+            .and_then(|ext_type| ext_type.cast(self, dtype, mode))
+            .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "cast", Extension::ID)))
+    }
+}
+
+impl CompareFn for ExtensionArray {
+    fn compare(&self, array: &Array, operator: Operator) -> VortexResult<Array> {
+        find_extension_type(self.id())
+            .and_then(|ext_type| ext_type.compare(self, array, operator))
+            .unwrap_or_else(|| arrow_compare(self.array(), array, operator))
+    }
+}
+
+impl GroupIdsFn for ExtensionArray {
+    fn group_ids(&self) -> VortexResult<(PrimitiveArray, Array)> {
+        let (codes, uniques) = group_ids(&self.storage())?;
+        Ok((
+            codes,
+            Self::try_new(self.ext_dtype().clone(), uniques)?.into_array(),
+        ))
+    }
+}
+
+impl HashFn for ExtensionArray {
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray> {
+        hash(&self.storage(), seed)
+    }
+}
+
+impl IsInFn for ExtensionArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        is_in(&self.storage(), &values.clone().into_extension()?.storage())
+    }
+}
+
+impl SortFn for ExtensionArray {
+    fn sort_indices(&self, options: SortOptions) -> VortexResult<PrimitiveArray> {
+        find_extension_type(self.id())
+            .and_then(|ext_type| ext_type.sort_indices(self, options))
+            .unwrap_or_else(|| sort_indices(&self.storage(), options))
+    }
+}
+
 impl ScalarAtFn for ExtensionArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         Ok(Scalar::extension(
@@ -38,16 +108,17 @@ impl ScalarAtFn for ExtensionArray {
 
 impl SliceFn for ExtensionArray {
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
-        Ok(Self::new(
+        Self::try_new(
             self.ext_dtype().clone(),
             slice(&self.storage(), start, stop)?,
         )
-        .into_array())
+        .map(|a| a.into_array())
     }
 }
 
 impl TakeFn for ExtensionArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
-        Ok(Self::new(self.ext_dtype().clone(), take(&self.storage(), indices)?).into_array())
+        Self::try_new(self.ext_dtype().clone(), take(&self.storage(), indices)?)
+            .map(|a| a.into_array())
     }
 }
@@ -1,3 +1,4 @@
+pub use registry::*;
 use serde::{Deserialize, Serialize};
 use vortex_dtype::{DType, ExtDType, ExtID};
 use vortex_error::VortexResult;
@@ -9,6 +10,7 @@ use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use crate::{impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoCanonical};
 
 mod compute;
+mod registry;
 
 impl_encoding!("vortex.ext", 16u16, Extension);
 
@@ -18,7 +20,22 @@ pub struct ExtensionMetadata {
 }
 
 impl ExtensionArray {
+    /// Create a new extension array, see [ExtensionArray::try_new] for a fallible version.
+    ///
+    /// # Panics
+    ///
+    /// If the storage is invalid for the registered [ExtensionType] of the extension ID.
     pub fn new(ext_dtype: ExtDType, storage: Array) -> Self {
+        Self::try_new(ext_dtype, storage).expect("Invalid ExtensionArray")
+    }
+
+    /// Create a new extension array, validating the storage dtype against the registered
+    /// [ExtensionType] for the extension ID, if there is one.
+    pub fn try_new(ext_dtype: ExtDType, storage: Array) -> VortexResult<Self> {
+        if let Some(ext_type) = find_extension_type(ext_dtype.id()) {
+            ext_type.validate_storage(&ext_dtype, storage.dtype())?;
+        }
+
         Self::try_from_parts(
             DType::Extension(ext_dtype, storage.dtype().nullability()),
             storage.len(),
@@ -28,7 +45,6 @@ impl ExtensionArray {
             [storage].into(),
             Default::default(),
         )
-        .expect("Invalid ExtensionArray")
     }
 
     pub fn storage(&self) -> Array {
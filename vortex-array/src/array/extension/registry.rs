@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter, Write};
+use std::sync::{Arc, RwLock};
+
+use arrow_array::ArrayRef;
+use arrow_schema::DataType;
+use lazy_static::lazy_static;
+use vortex_dtype::{DType, ExtDType, ExtID, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::Operator;
+use vortex_scalar::Scalar;
+
+use crate::array::extension::ExtensionArray;
+use crate::array::PrimitiveArray;
+use crate::compute::unary::CastMode;
+use crate::compute::SortOptions;
+use crate::{Array, Context, IntoCanonical};
+
+/// Arrow field metadata key holding the name of an Arrow extension type.
+pub const ARROW_EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+/// Arrow field metadata key holding the serialized metadata of an Arrow extension type.
+pub const ARROW_EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
+/// Arrow field metadata key holding hex encoded extension metadata that is not valid UTF-8.
+pub const VORTEX_EXTENSION_METADATA_HEX_KEY: &str = "vortex:extension:metadata_hex";
+
+pub type ExtensionTypeRef = Arc<dyn ExtensionType>;
+
+/// The behaviour of an extension type, looked up by its [ExtID].
+///
+/// An [ExtDType] only carries an ID and opaque metadata. Registering an `ExtensionType` for that
+/// ID with [register_extension_type] tells Vortex how to validate, display and convert arrays of
+/// the type to and from Arrow.
+pub trait ExtensionType: Debug + Send + Sync {
+    fn id(&self) -> &ExtID;
+
+    /// Check that `storage_dtype` is a valid storage type for arrays of `ext_dtype`.
+    fn validate_storage(&self, ext_dtype: &ExtDType, storage_dtype: &DType) -> VortexResult<()>;
+
+    /// The storage DType of arrays of `ext_dtype`, if it is determined by the extension type.
+    ///
+    /// Builders rely on this to create the storage of an extension array before any values have
+    /// been appended.
+    fn storage_dtype(&self, _ext_dtype: &ExtDType, _nullability: Nullability) -> Option<DType> {
+        None
+    }
+
+    /// The Arrow data type produced by [ExtensionType::to_arrow] for arrays of `ext_dtype`.
+    fn arrow_data_type(&self, ext_dtype: &ExtDType) -> VortexResult<DataType>;
+
+    /// Convert an array of this type into an Arrow array.
+    ///
+    /// By default the storage array is converted as-is.
+    fn to_arrow(&self, array: &ExtensionArray) -> VortexResult<ArrayRef> {
+        Ok(array.storage().into_canonical()?.into_arrow())
+    }
+
+    /// The metadata attached to Arrow fields holding this type.
+    ///
+    /// By default this identifies the field as an Arrow extension type and stores the metadata
+    /// bytes as a string, hex encoding them under [VORTEX_EXTENSION_METADATA_HEX_KEY] if they are
+    /// not valid UTF-8. Types that map onto native Arrow types should return no metadata.
+    fn arrow_metadata(&self, ext_dtype: &ExtDType) -> HashMap<String, String> {
+        let mut metadata =
+            HashMap::from([(ARROW_EXTENSION_NAME_KEY.to_string(), self.id().to_string())]);
+        if let Some(ext_metadata) = ext_dtype.metadata() {
+            match std::str::from_utf8(ext_metadata.as_ref()) {
+                Ok(m) => metadata.insert(ARROW_EXTENSION_METADATA_KEY.to_string(), m.to_string()),
+                Err(_) => metadata.insert(
+                    VORTEX_EXTENSION_METADATA_HEX_KEY.to_string(),
+                    ext_metadata
+                        .as_ref()
+                        .iter()
+                        .fold(String::new(), |mut hex, b| {
+                            let _ = write!(hex, "{b:02x}");
+                            hex
+                        }),
+                ),
+            };
+        }
+        metadata
+    }
+
+    /// Rebuild the [ExtDType] from the metadata of an Arrow field, the inverse of
+    /// [ExtensionType::arrow_metadata].
+    fn ext_dtype_from_arrow(&self, metadata: &HashMap<String, String>) -> VortexResult<ExtDType> {
+        let ext_metadata = match metadata.get(VORTEX_EXTENSION_METADATA_HEX_KEY) {
+            Some(hex) => Some(decode_hex(hex)?),
+            None => metadata
+                .get(ARROW_EXTENSION_METADATA_KEY)
+                .map(|m| m.as_bytes().to_vec()),
+        };
+        Ok(ExtDType::new(
+            self.id().clone(),
+            ext_metadata.map(|m| m.as_slice().into()),
+        ))
+    }
+
+    /// Format a scalar of this type.
+    ///
+    /// By default primitive storage values are written as-is, other values only show the type ID.
+    fn fmt_scalar(&self, scalar: &Scalar, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_storage_value(self.id(), scalar, f)
+    }
+
+    /// Cast an array of this type to `dtype`, or `None` if the cast is not supported.
+    fn cast(
+        &self,
+        _array: &ExtensionArray,
+        _dtype: &DType,
+        _mode: CastMode,
+    ) -> Option<VortexResult<Array>> {
+        None
+    }
+
+    /// Compare an array of this type with another array of the same type, or `None` to compare
+    /// their Arrow representations.
+    fn compare(
+        &self,
+        _array: &ExtensionArray,
+        _other: &Array,
+        _operator: Operator,
+    ) -> Option<VortexResult<Array>> {
+        None
+    }
+
+    /// Sort an array of this type, or `None` to sort by the storage values.
+    fn sort_indices(
+        &self,
+        _array: &ExtensionArray,
+        _options: SortOptions,
+    ) -> Option<VortexResult<PrimitiveArray>> {
+        None
+    }
+}
+
+lazy_static! {
+    static ref EXTENSION_TYPES: RwLock<HashMap<ExtID, ExtensionTypeRef>> = RwLock::new(
+        Context::default()
+            .extension_types()
+            .map(|ext_type| (ext_type.id().clone(), ext_type))
+            .collect()
+    );
+}
+
+/// Register an extension type, replacing any type previously registered with the same ID.
+///
+/// Prefer adding the type to a [Context] with [Context::with_extension_type] and calling
+/// [Context::register_extension_types].
+pub fn register_extension_type(ext_type: ExtensionTypeRef) {
+    register_extension_types([ext_type]);
+}
+
+pub(crate) fn register_extension_types<E: IntoIterator<Item = ExtensionTypeRef>>(ext_types: E) {
+    EXTENSION_TYPES
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .extend(ext_types.into_iter().map(|e| (e.id().clone(), e)));
+}
+
+/// Look up the registered extension type for `id`.
+pub fn find_extension_type(id: &ExtID) -> Option<ExtensionTypeRef> {
+    EXTENSION_TYPES
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(id)
+        .cloned()
+}
+
+fn decode_hex(hex: &str) -> VortexResult<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        vortex_bail!("Invalid hex encoded extension metadata {}", hex);
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|b| u8::from_str_radix(b, 16).ok())
+                .ok_or_else(|| vortex_err!("Invalid hex encoded extension metadata {}", hex))
+        })
+        .collect()
+}
+
+fn fmt_storage_value(id: &ExtID, scalar: &Scalar, f: &mut Formatter<'_>) -> std::fmt::Result {
+    if scalar.value().is_null() {
+        return write!(f, "null");
+    }
+    match scalar.value().as_pvalue() {
+        Ok(Some(value)) => write!(f, "{id}({value})"),
+        _ => write!(f, "{id}(..)"),
+    }
+}
+
+/// Displays a scalar, formatting extension scalars with their registered [ExtensionType].
+pub struct ScalarDisplay<'a>(pub &'a Scalar);
+
+impl Display for ScalarDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0.dtype() {
+            DType::Extension(ext_dtype, _) => match find_extension_type(ext_dtype.id()) {
+                Some(ext_type) => ext_type.fmt_scalar(self.0, f),
+                None => fmt_storage_value(ext_dtype.id(), self.0, f),
+            },
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::{ArrayRef, RecordBatch};
+    use arrow_schema::DataType;
+    use vortex_dtype::{DType, ExtDType, ExtID, Nullability, PType};
+    use vortex_error::{vortex_bail, VortexResult};
+    use vortex_scalar::Scalar;
+
+    use super::*;
+    use crate::array::{PrimitiveArray, StructArray};
+    use crate::arrow::FromArrowArray;
+    use crate::compute::unary::scalar_at;
+    use crate::validity::Validity;
+    use crate::variants::StructArrayTrait;
+    use crate::{ArrayDType, IntoArray, IntoCanonical};
+
+    /// Monetary amounts in cents, with the currency code as metadata.
+    #[derive(Debug)]
+    struct MoneyType(ExtID);
+
+    impl ExtensionType for MoneyType {
+        fn id(&self) -> &ExtID {
+            &self.0
+        }
+
+        fn validate_storage(&self, _: &ExtDType, storage_dtype: &DType) -> VortexResult<()> {
+            if !matches!(storage_dtype, DType::Primitive(PType::I64, _)) {
+                vortex_bail!(MismatchedTypes: "i64", storage_dtype);
+            }
+            Ok(())
+        }
+
+        fn arrow_data_type(&self, _: &ExtDType) -> VortexResult<DataType> {
+            Ok(DataType::Int64)
+        }
+
+        fn fmt_scalar(&self, scalar: &Scalar, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let DType::Extension(ext_dtype, _) = scalar.dtype() else {
+                return Err(std::fmt::Error);
+            };
+            let currency = ext_dtype.metadata().ok_or(std::fmt::Error)?;
+            let cents = scalar
+                .value()
+                .as_pvalue()
+                .map_err(|_| std::fmt::Error)?
+                .and_then(|v| i64::try_from(v).ok())
+                .ok_or(std::fmt::Error)?;
+            write!(
+                f,
+                "{cents} cents {}",
+                String::from_utf8_lossy(currency.as_ref())
+            )
+        }
+    }
+
+    #[test]
+    fn test_custom_extension_type() {
+        let id = ExtID::from("test.money");
+        let ctx = Context::default().with_extension_type(Arc::new(MoneyType(id.clone())));
+        assert!(ctx.lookup_extension_type(&id).is_some());
+        ctx.register_extension_types();
+        let ext_dtype = ExtDType::new(id.clone(), Some(b"USD".as_slice().into()));
+
+        assert!(ExtensionArray::try_new(
+            ext_dtype.clone(),
+            PrimitiveArray::from(vec![1i32]).into_array()
+        )
+        .is_err());
+
+        let money = ExtensionArray::new(
+            ext_dtype.clone(),
+            PrimitiveArray::from_vec(vec![150i64, -25], Validity::NonNullable).into_array(),
+        )
+        .into_array();
+        assert_eq!(
+            ScalarDisplay(&scalar_at(&money, 0).unwrap()).to_string(),
+            "150 cents USD"
+        );
+
+        let table = StructArray::from_fields(&[("price", money)]);
+        let arrow: ArrayRef = table.into_canonical().unwrap().into_arrow();
+        let field = &arrow.as_struct().fields()[0];
+        assert_eq!(field.data_type(), &DataType::Int64);
+        assert_eq!(
+            field.metadata().get(ARROW_EXTENSION_NAME_KEY).unwrap(),
+            "test.money"
+        );
+        assert_eq!(
+            field.metadata().get(ARROW_EXTENSION_METADATA_KEY).unwrap(),
+            "USD"
+        );
+
+        let roundtrip = crate::Array::from_arrow(arrow, false);
+        let price = StructArray::try_from(roundtrip)
+            .unwrap()
+            .field_by_name("price")
+            .unwrap();
+        assert_eq!(
+            price.dtype(),
+            &DType::Extension(ext_dtype, Nullability::NonNullable)
+        );
+    }
+
+    #[test]
+    fn test_binary_metadata_roundtrip() {
+        let id = ExtID::from("test.binary_money");
+        register_extension_type(Arc::new(MoneyType(id.clone())));
+        let ext_dtype = ExtDType::new(id.clone(), Some([0xffu8, 0x00, 0x7f].as_slice().into()));
+        let ext_type = find_extension_type(&id).unwrap();
+
+        let metadata = ext_type.arrow_metadata(&ext_dtype);
+        assert_eq!(
+            metadata.get(VORTEX_EXTENSION_METADATA_HEX_KEY).unwrap(),
+            "ff007f"
+        );
+        assert_eq!(ext_type.ext_dtype_from_arrow(&metadata).unwrap(), ext_dtype);
+    }
+
+    #[test]
+    fn test_record_batch_extension_column() {
+        let id = ExtID::from("test.batch_money");
+        register_extension_type(Arc::new(MoneyType(id.clone())));
+        let ext_dtype = ExtDType::new(id, Some(b"EUR".as_slice().into()));
+        let money = ExtensionArray::new(
+            ext_dtype.clone(),
+            PrimitiveArray::from_vec(vec![1i64, 2], Validity::NonNullable).into_array(),
+        );
+
+        let batch = RecordBatch::from(StructArray::from_fields(&[("price", money.into_array())]));
+        let price = StructArray::try_from(crate::Array::from(batch))
+            .unwrap()
+            .field_by_name("price")
+            .unwrap();
+        assert_eq!(
+            price.dtype(),
+            &DType::Extension(ext_dtype, Nullability::NonNullable)
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use vortex_error::VortexResult;
+use vortex_scalar::{Scalar, ScalarValue};
+
+use crate::array::fixed_size_list::FixedSizeListArray;
+use crate::array::list::filter_by_take;
+use crate::compute::unary::{scalar_at, ScalarAtFn};
+use crate::compute::{ArrayCompute, FilterFn, SliceFn, TakeFn};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType};
+
+mod slice;
+mod take;
+
+impl ArrayCompute for FixedSizeListArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl FilterFn for FixedSizeListArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        filter_by_take(self, predicate)
+    }
+}
+
+impl ScalarAtFn for FixedSizeListArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+
+        let elements = self.elements_at(index)?;
+        let values = (0..elements.len())
+            .map(|i| scalar_at(&elements, i).map(|s| s.into_value()))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(Scalar::new(
+            self.dtype().clone(),
+            ScalarValue::List(values.into()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::fixed_size_list::FixedSizeListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::BoolArray;
+    use crate::compute::filter;
+    use crate::compute::unary::scalar_at;
+    use crate::validity::Validity;
+    use crate::IntoArray;
+
+    #[test]
+    fn test_scalar_at() {
+        let list = FixedSizeListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5, 6]).into_array(),
+            2,
+            3,
+            Validity::Array(BoolArray::from(vec![true, false, true]).into_array()),
+        )
+        .unwrap()
+        .into_array();
+
+        assert_eq!(
+            Vec::<i32>::try_from(&scalar_at(&list, 0).unwrap()).unwrap(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            scalar_at(&list, 1).unwrap(),
+            Scalar::null(DType::FixedSizeList(
+                DType::Primitive(PType::I32, Nullability::NonNullable).into(),
+                2,
+                Nullability::Nullable
+            ))
+        );
+        assert_eq!(
+            Vec::<i32>::try_from(&scalar_at(&list, 2).unwrap()).unwrap(),
+            vec![5, 6]
+        );
+    }
+
+    #[test]
+    fn test_filter() {
+        let list = FixedSizeListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5, 6]).into_array(),
+            2,
+            3,
+            Validity::AllValid,
+        )
+        .unwrap()
+        .into_array();
+
+        let filtered = filter(
+            &list,
+            &BoolArray::from(vec![false, true, true]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(
+            Vec::<i32>::try_from(&scalar_at(&filtered, 0).unwrap()).unwrap(),
+            vec![3, 4]
+        );
+    }
+}
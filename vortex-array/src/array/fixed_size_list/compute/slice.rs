@@ -0,0 +1,18 @@
+use vortex_error::VortexResult;
+
+use crate::array::fixed_size_list::FixedSizeListArray;
+use crate::compute::{slice, SliceFn};
+use crate::{Array, IntoArray};
+
+impl SliceFn for FixedSizeListArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        let list_size = self.list_size() as usize;
+        Self::try_new(
+            slice(&self.elements(), start * list_size, stop * list_size)?,
+            self.list_size(),
+            stop - start,
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
@@ -0,0 +1,74 @@
+use num_traits::AsPrimitive;
+use vortex_dtype::{match_each_integer_ptype, NativePType};
+use vortex_error::VortexResult;
+
+use crate::array::fixed_size_list::FixedSizeListArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::{take, TakeFn};
+use crate::{Array, IntoArray, IntoArrayVariant};
+
+impl TakeFn for FixedSizeListArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let indices = indices.clone().into_primitive()?;
+        let element_indices = match_each_integer_ptype!(indices.ptype(), |$I| {
+            element_indices(indices.maybe_null_slice::<$I>(), self.list_size())
+        });
+
+        Self::try_new(
+            take(
+                &self.elements(),
+                &PrimitiveArray::from(element_indices).into_array(),
+            )?,
+            self.list_size(),
+            indices.len(),
+            self.validity().take(&indices.into_array())?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+/// Expand list indices into the indices of the elements covered by each list.
+fn element_indices<I: NativePType + AsPrimitive<u64>>(indices: &[I], list_size: u32) -> Vec<u64> {
+    let list_size = list_size as u64;
+    indices
+        .iter()
+        .flat_map(|idx| {
+            let start = idx.as_() * list_size;
+            start..start + list_size
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::fixed_size_list::FixedSizeListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::take;
+    use crate::validity::Validity;
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn take_lists() {
+        let list = FixedSizeListArray::try_new(
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5, 6]).into_array(),
+            2,
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let taken =
+            FixedSizeListArray::try_from(take(&list, &vec![2u32, 0, 2].into_array()).unwrap())
+                .unwrap();
+        assert_eq!(taken.len(), 3);
+        assert_eq!(
+            taken
+                .elements()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            [5, 6, 1, 2, 5, 6]
+        );
+    }
+}
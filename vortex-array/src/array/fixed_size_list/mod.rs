@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::compute::slice;
+use crate::stats::{ArrayStatisticsCompute, StatsSet};
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::variants::{ArrayVariants, FixedSizeListArrayTrait};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoCanonical};
+
+mod compute;
+
+impl_encoding!("vortex.fixed_size_list", 24u16, FixedSizeList);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixedSizeListMetadata {
+    validity: ValidityMetadata,
+}
+
+impl FixedSizeListArray {
+    /// Create a new FixedSizeListArray of `len` lists, each covering `list_size` consecutive
+    /// values of the flat elements array.
+    pub fn try_new(
+        elements: Array,
+        list_size: u32,
+        len: usize,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        if elements.len() != len * list_size as usize {
+            vortex_bail!(
+                "FixedSizeListArray of {} lists of size {} requires {} elements, got {}",
+                len,
+                list_size,
+                len * list_size as usize,
+                elements.len()
+            );
+        }
+
+        let dtype = DType::FixedSizeList(
+            elements.dtype().clone().into(),
+            list_size,
+            validity.nullability(),
+        );
+        let metadata = FixedSizeListMetadata {
+            validity: validity.to_metadata(len)?,
+        };
+
+        let mut children = Vec::with_capacity(2);
+        children.push(elements);
+        if let Some(a) = validity.into_array() {
+            children.push(a)
+        }
+
+        Self::try_from_parts(dtype, len, metadata, children.into(), StatsSet::new())
+    }
+
+    #[inline]
+    pub fn elements(&self) -> Array {
+        self.array()
+            .child(
+                0,
+                &self.element_dtype(),
+                self.len() * self.list_size() as usize,
+            )
+            .expect("missing elements")
+    }
+
+    pub fn element_dtype(&self) -> DType {
+        let DType::FixedSizeList(element_dtype, ..) = self.dtype() else {
+            unreachable!("FixedSizeListArray must have a fixed size list dtype")
+        };
+        element_dtype.as_ref().clone()
+    }
+
+    pub fn list_size(&self) -> u32 {
+        let DType::FixedSizeList(_, list_size, _) = self.dtype() else {
+            unreachable!("FixedSizeListArray must have a fixed size list dtype")
+        };
+        *list_size
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(1, &Validity::DTYPE, self.len()))
+    }
+
+    /// Returns the elements of the list at the given index as an array.
+    pub fn elements_at(&self, index: usize) -> VortexResult<Array> {
+        if index >= self.len() {
+            return Err(vortex_err!(OutOfBounds: index, 0, self.len()));
+        }
+        let list_size = self.list_size() as usize;
+        slice(&self.elements(), index * list_size, (index + 1) * list_size)
+    }
+}
+
+impl ArrayTrait for FixedSizeListArray {}
+
+impl ArrayVariants for FixedSizeListArray {
+    fn as_fixed_size_list_array(&self) -> Option<&dyn FixedSizeListArrayTrait> {
+        Some(self)
+    }
+}
+
+impl FixedSizeListArrayTrait for FixedSizeListArray {}
+
+impl IntoCanonical for FixedSizeListArray {
+    /// FixedSizeListEncoding is the canonical form for a [DType::FixedSizeList] array, so return
+    /// self.
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        Ok(Canonical::FixedSizeList(self))
+    }
+}
+
+impl ArrayValidity for FixedSizeListArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for FixedSizeListArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("elements", &self.elements())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for FixedSizeListArray {}
+
+#[cfg(test)]
+mod test {
+    use crate::array::fixed_size_list::FixedSizeListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::validity::Validity;
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn test_elements_at() {
+        let elements = PrimitiveArray::from(vec![1f32, 2., 3., 4., 5., 6.]).into_array();
+        let list = FixedSizeListArray::try_new(elements, 3, 2, Validity::NonNullable).unwrap();
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(
+            list.elements_at(1)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<f32>(),
+            [4., 5., 6.]
+        );
+        assert!(list.elements_at(2).is_err());
+    }
+
+    #[test]
+    fn test_mismatched_elements() {
+        let elements = PrimitiveArray::from(vec![1f32, 2., 3., 4., 5.]).into_array();
+        assert!(FixedSizeListArray::try_new(elements, 3, 2, Validity::NonNullable).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use vortex_error::{vortex_err, VortexResult};
+
+use crate::array::list::ListArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::{FilterFn, TakeFn};
+use crate::{Array, IntoArray};
+
+impl FilterFn for ListArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        filter_by_take(self, predicate)
+    }
+}
+
+/// Filter an array by taking the indices that are set in the `predicate`.
+pub(crate) fn filter_by_take(array: &dyn TakeFn, predicate: &Array) -> VortexResult<Array> {
+    let indices = predicate.with_dyn(|p| {
+        p.as_bool_array()
+            .ok_or_else(|| {
+                vortex_err!(
+                    NotImplemented: "as_bool_array",
+                    predicate.encoding().id()
+                )
+            })
+            .map(|b| {
+                b.maybe_null_indices_iter()
+                    .map(|i| i as u64)
+                    .collect::<Vec<_>>()
+            })
+    })?;
+    array.take(&PrimitiveArray::from(indices).into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::list::ListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::BoolArray;
+    use crate::compute::filter;
+    use crate::compute::unary::scalar_at;
+    use crate::validity::Validity;
+    use crate::IntoArray;
+
+    #[test]
+    fn filter_lists() {
+        let list = ListArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 2, 5]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array(),
+            Validity::AllValid,
+        )
+        .unwrap()
+        .into_array();
+
+        let filtered = filter(
+            &list,
+            &BoolArray::from(vec![true, false, true]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(
+            Vec::<i32>::try_from(&scalar_at(&filtered, 1).unwrap()).unwrap(),
+            vec![3, 4, 5]
+        );
+    }
+}
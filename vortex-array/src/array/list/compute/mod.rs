@@ -0,0 +1,90 @@
+use vortex_error::VortexResult;
+use vortex_scalar::{Scalar, ScalarValue};
+
+use crate::array::list::ListArray;
+use crate::compute::unary::{scalar_at, ScalarAtFn};
+use crate::compute::{ArrayCompute, FilterFn, SliceFn, TakeFn};
+use crate::validity::ArrayValidity;
+use crate::ArrayDType;
+
+mod filter;
+mod slice;
+mod take;
+
+pub(crate) use filter::filter_by_take;
+pub(crate) use take::take_offsets;
+
+impl ArrayCompute for ListArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for ListArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+
+        let elements = self.elements_at(index)?;
+        let values = (0..elements.len())
+            .map(|i| scalar_at(&elements, i).map(|s| s.into_value()))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(Scalar::new(
+            self.dtype().clone(),
+            ScalarValue::List(values.into()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::{ListScalar, Scalar};
+
+    use crate::array::list::ListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::BoolArray;
+    use crate::compute::unary::scalar_at;
+    use crate::validity::Validity;
+    use crate::IntoArray;
+
+    #[test]
+    fn test_scalar_at() {
+        let list = ListArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 2, 5]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array(),
+            Validity::Array(BoolArray::from(vec![true, false, true]).into_array()),
+        )
+        .unwrap()
+        .into_array();
+
+        let first = scalar_at(&list, 0).unwrap();
+        let first = ListScalar::try_from(&first).unwrap();
+        assert_eq!(first.len(), 2);
+        assert_eq!(first.element(1).unwrap(), Scalar::from(2i32));
+
+        assert_eq!(
+            scalar_at(&list, 1).unwrap(),
+            Scalar::null(DType::List(
+                DType::Primitive(PType::I32, Nullability::NonNullable).into(),
+                Nullability::Nullable
+            ))
+        );
+
+        let last = scalar_at(&list, 2).unwrap();
+        assert_eq!(Vec::<i32>::try_from(&last).unwrap(), vec![3, 4, 5]);
+    }
+}
@@ -0,0 +1,16 @@
+use vortex_error::VortexResult;
+
+use crate::array::list::ListArray;
+use crate::compute::{slice, SliceFn};
+use crate::{Array, IntoArray};
+
+impl SliceFn for ListArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        Self::try_new(
+            slice(&self.offsets(), start, stop + 1)?,
+            self.elements(),
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
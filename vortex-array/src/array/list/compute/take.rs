@@ -0,0 +1,99 @@
+use num_traits::AsPrimitive;
+use vortex_dtype::{match_each_integer_ptype, NativePType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::list::ListArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::{take, TakeFn};
+use crate::{Array, IntoArray, IntoArrayVariant};
+
+impl TakeFn for ListArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let offsets = self.offsets().into_primitive()?;
+        let indices = indices.clone().into_primitive()?;
+        let (new_offsets, element_indices) = match_each_integer_ptype!(offsets.ptype(), |$O| {
+            match_each_integer_ptype!(indices.ptype(), |$I| {
+                take_offsets(
+                    offsets.maybe_null_slice::<$O>(),
+                    indices.maybe_null_slice::<$I>(),
+                )
+            })
+        })?;
+
+        Self::try_new(
+            PrimitiveArray::from(new_offsets).into_array(),
+            take(
+                &self.elements(),
+                &PrimitiveArray::from(element_indices).into_array(),
+            )?,
+            self.validity().take(&indices.into_array())?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+/// Compute the offsets of the taken lists along with the indices of the elements they cover.
+pub(crate) fn take_offsets<
+    O: NativePType + AsPrimitive<u64>,
+    I: NativePType + AsPrimitive<usize>,
+>(
+    offsets: &[O],
+    indices: &[I],
+) -> VortexResult<(Vec<u64>, Vec<u64>)> {
+    let len = offsets.len().saturating_sub(1);
+    let mut new_offsets = Vec::with_capacity(indices.len() + 1);
+    let mut element_indices = Vec::new();
+    new_offsets.push(0u64);
+    for idx in indices {
+        let idx: usize = idx.as_();
+        if idx >= len {
+            vortex_bail!(OutOfBounds: idx, 0, len);
+        }
+        let (start, stop): (u64, u64) = (offsets[idx].as_(), offsets[idx + 1].as_());
+        element_indices.extend(start..stop);
+        new_offsets.push(element_indices.len() as u64);
+    }
+    Ok((new_offsets, element_indices))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::list::ListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::take;
+    use crate::validity::Validity;
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn take_lists() {
+        let list = ListArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 2, 5]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let taken =
+            ListArray::try_from(take(&list, &vec![2u32, 0, 1].into_array()).unwrap()).unwrap();
+        assert_eq!(taken.len(), 3);
+        assert_eq!(
+            taken
+                .offsets()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            [0, 3, 5, 5]
+        );
+        assert_eq!(
+            taken
+                .elements()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            [3, 4, 5, 1, 2]
+        );
+
+        assert!(take(&list, &vec![3u32].into_array()).is_err());
+    }
+}
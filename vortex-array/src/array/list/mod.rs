@@ -0,0 +1,254 @@
+use num_traits::{AsPrimitive, ToPrimitive};
+use serde::{Deserialize, Serialize};
+use vortex_dtype::{match_each_integer_ptype, match_each_native_ptype, DType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::slice;
+use crate::compute::unary::scalar_at;
+use crate::stats::{ArrayStatisticsCompute, StatsSet};
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::variants::{ArrayVariants, ListArrayTrait};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{
+    impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoArrayVariant,
+    IntoCanonical,
+};
+
+mod compute;
+
+pub(crate) use compute::{filter_by_take, take_offsets};
+
+impl_encoding!("vortex.list", 6u16, List);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListMetadata {
+    validity: ValidityMetadata,
+    offsets_dtype: DType,
+    elements_len: usize,
+}
+
+impl ListArray {
+    /// Create a new ListArray from an array of `len + 1` monotonically increasing offsets into a
+    /// flat array of elements.
+    pub fn try_new(offsets: Array, elements: Array, validity: Validity) -> VortexResult<Self> {
+        if !offsets.dtype().is_int() || offsets.dtype().is_nullable() {
+            vortex_bail!(MismatchedTypes: "non nullable int", offsets.dtype());
+        }
+        if offsets.is_empty() {
+            vortex_bail!("ListArray offsets must have at least one element");
+        }
+        check_offsets(&offsets, elements.len())?;
+
+        let length = offsets.len() - 1;
+        let dtype = DType::List(elements.dtype().clone().into(), validity.nullability());
+
+        let metadata = ListMetadata {
+            validity: validity.to_metadata(length)?,
+            offsets_dtype: offsets.dtype().clone(),
+            elements_len: elements.len(),
+        };
+
+        let mut children = Vec::with_capacity(3);
+        children.push(offsets);
+        children.push(elements);
+        if let Some(a) = validity.into_array() {
+            children.push(a)
+        }
+
+        Self::try_from_parts(dtype, length, metadata, children.into(), StatsSet::new())
+    }
+
+    #[inline]
+    pub fn offsets(&self) -> Array {
+        self.array()
+            .child(0, &self.metadata().offsets_dtype, self.len() + 1)
+            .expect("missing offsets")
+    }
+
+    #[inline]
+    pub fn elements(&self) -> Array {
+        self.array()
+            .child(1, &self.element_dtype(), self.metadata().elements_len)
+            .expect("missing elements")
+    }
+
+    pub fn element_dtype(&self) -> DType {
+        let DType::List(element_dtype, _) = self.dtype() else {
+            unreachable!("ListArray must have a list dtype")
+        };
+        element_dtype.as_ref().clone()
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(2, &Validity::DTYPE, self.len()))
+    }
+
+    pub fn offset_at(&self, index: usize) -> usize {
+        offset_at(&self.offsets(), index)
+    }
+
+    /// Returns the elements of the list at the given index as an array.
+    pub fn elements_at(&self, index: usize) -> VortexResult<Array> {
+        if index >= self.len() {
+            return Err(vortex_err!(OutOfBounds: index, 0, self.len()));
+        }
+        let start = self.offset_at(index);
+        let end = self.offset_at(index + 1);
+        slice(&self.elements(), start, end)
+    }
+}
+
+/// Check that the offsets are non-negative, non-decreasing and within the elements.
+fn check_offsets(offsets: &Array, elements_len: usize) -> VortexResult<()> {
+    let offsets = offsets.clone().into_primitive()?;
+    let last = match_each_integer_ptype!(offsets.ptype(), |$O| {
+        let mut previous = 0usize;
+        for &offset in offsets.maybe_null_slice::<$O>() {
+            let Some(offset) = offset.to_usize() else {
+                vortex_bail!("ListArray offset {} is negative", offset);
+            };
+            if offset < previous {
+                vortex_bail!(
+                    "ListArray offsets must be non-decreasing, got {} after {}",
+                    offset,
+                    previous
+                );
+            }
+            previous = offset;
+        }
+        previous
+    });
+    if last > elements_len {
+        vortex_bail!(
+            "ListArray offset {} is out of bounds for {} elements",
+            last,
+            elements_len
+        );
+    }
+    Ok(())
+}
+
+/// Read the offset at `index`, shared by the offset-based nested arrays.
+pub(crate) fn offset_at(offsets: &Array, index: usize) -> usize {
+    PrimitiveArray::try_from(offsets)
+        .ok()
+        .map(|p| {
+            match_each_native_ptype!(p.ptype(), |$P| {
+                p.maybe_null_slice::<$P>()[index].as_()
+            })
+        })
+        .unwrap_or_else(|| {
+            scalar_at(offsets, index)
+                .unwrap_or_else(|err| panic!("Failed to get offset at index: {}: {}", index, err))
+                .as_ref()
+                .try_into()
+                .expect("Failed to convert offset to usize")
+        })
+}
+
+impl ArrayTrait for ListArray {}
+
+impl ArrayVariants for ListArray {
+    fn as_list_array(&self) -> Option<&dyn ListArrayTrait> {
+        Some(self)
+    }
+}
+
+impl ListArrayTrait for ListArray {}
+
+impl IntoCanonical for ListArray {
+    /// ListEncoding is the canonical form for a [DType::List] array, so return self.
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        Ok(Canonical::List(self))
+    }
+}
+
+impl ArrayValidity for ListArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for ListArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("offsets", &self.offsets())?;
+        visitor.visit_child("elements", &self.elements())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for ListArray {}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::list::ListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::BoolArray;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn test_elements_at() {
+        let elements = PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array();
+        let offsets = PrimitiveArray::from(vec![0u32, 2, 2, 5]).into_array();
+        let list = ListArray::try_new(offsets, elements, Validity::NonNullable).unwrap();
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            list.dtype(),
+            &DType::List(
+                DType::Primitive(PType::I32, Nullability::NonNullable).into(),
+                Nullability::NonNullable
+            )
+        );
+        assert_eq!(
+            list.elements_at(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            [1, 2]
+        );
+        assert!(list.elements_at(1).unwrap().is_empty());
+        assert_eq!(
+            list.elements_at(2)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            [3, 4, 5]
+        );
+        assert!(list.elements_at(3).is_err());
+    }
+
+    #[test]
+    fn test_malformed() {
+        let elements = || PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let offsets = |offsets: Vec<i32>| PrimitiveArray::from(offsets).into_array();
+
+        assert!(
+            ListArray::try_new(offsets(vec![0, 2, 1]), elements(), Validity::NonNullable).is_err()
+        );
+        assert!(
+            ListArray::try_new(offsets(vec![0, 4]), elements(), Validity::NonNullable).is_err()
+        );
+        assert!(
+            ListArray::try_new(offsets(vec![-1, 2]), elements(), Validity::NonNullable).is_err()
+        );
+        assert!(ListArray::try_new(
+            offsets(vec![0, 1, 3]),
+            elements(),
+            Validity::Array(BoolArray::from(vec![true, false, true]).into_array())
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,159 @@
+use num_traits::AsPrimitive;
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::VortexResult;
+use vortex_expr::Operator;
+use vortex_scalar::Scalar;
+
+use crate::array::chunked::ChunkedArray;
+use crate::array::constant::ConstantArray;
+use crate::array::map::MapArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::unary::try_cast;
+use crate::compute::{compare, search_sorted, slice, take, MapGetFn, SearchSortedSide};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+impl MapGetFn for MapArray {
+    fn map_get(&self, key: &Scalar) -> VortexResult<Array> {
+        let keys = self.keys();
+        let values = self.values();
+        let offsets = self.offsets().into_primitive()?;
+        let validity = self.validity();
+
+        // The index of the first matching entry of each map, or one past the last value if there
+        // is none, where a null is appended below.
+        let missing = values.len() as u64;
+        let indices: Vec<u64> = if self.map_dtype().keys_sorted() {
+            match_each_integer_ptype!(offsets.ptype(), |$O| {
+                offsets
+                    .maybe_null_slice::<$O>()
+                    .windows(2)
+                    .enumerate()
+                    .map(|(idx, window)| {
+                        if !validity.is_valid(idx) {
+                            return Ok(missing);
+                        }
+                        let (start, end): (usize, usize) = (window[0].as_(), window[1].as_());
+                        let found = search_sorted(
+                            &slice(&keys, start, end)?,
+                            key.clone(),
+                            SearchSortedSide::Left,
+                        )?
+                        .to_found();
+                        Ok(found.map_or(missing, |i| (start + i) as u64))
+                    })
+                    .collect::<VortexResult<_>>()?
+            })
+        } else {
+            let matches = compare(
+                &keys,
+                &ConstantArray::new(key.clone(), keys.len()).into_array(),
+                Operator::Eq,
+            )?
+            .into_bool()?
+            .boolean_buffer();
+            match_each_integer_ptype!(offsets.ptype(), |$O| {
+                offsets
+                    .maybe_null_slice::<$O>()
+                    .windows(2)
+                    .enumerate()
+                    .map(|(idx, window)| {
+                        if !validity.is_valid(idx) {
+                            return missing;
+                        }
+                        let (start, end): (usize, usize) = (window[0].as_(), window[1].as_());
+                        (start..end)
+                            .find(|&e| matches.value(e))
+                            .map_or(missing, |e| e as u64)
+                    })
+                    .collect()
+            })
+        };
+
+        let dtype = values.dtype().as_nullable();
+        let values = ChunkedArray::try_new(
+            vec![
+                try_cast(&values, &dtype)?,
+                ConstantArray::new(Scalar::null(dtype.clone()), 1).into_array(),
+            ],
+            dtype,
+        )?;
+        take(values.array(), &PrimitiveArray::from(indices).into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::accessor::ArrayAccessor;
+    use crate::array::map::MapArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::{BoolArray, VarBinViewArray};
+    use crate::compute::map_get;
+    use crate::validity::{ArrayValidity, Validity};
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn get_values() {
+        let map = MapArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 3, 3, 5]).into_array(),
+            VarBinViewArray::from_iter_str(["a", "b", "b", "a", "a"]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 3, 4, 5]).into_array(),
+            false,
+            Validity::Array(BoolArray::from(vec![true, true, false, true]).into_array()),
+        )
+        .unwrap()
+        .into_array();
+
+        let values = map_get(&map, &Scalar::from("a")).unwrap();
+        assert_eq!(
+            values.dtype(),
+            &DType::Primitive(PType::I32, Nullability::Nullable)
+        );
+        let values = values.into_primitive().unwrap();
+        assert_eq!(
+            (0..values.len())
+                .map(|i| values
+                    .is_valid(i)
+                    .then(|| values.maybe_null_slice::<i32>()[i]))
+                .collect::<Vec<_>>(),
+            vec![Some(1), None, None, Some(4)]
+        );
+    }
+
+    #[test]
+    fn get_sorted_keys() {
+        let map = MapArray::try_new(
+            PrimitiveArray::from(vec![0u32, 3, 5, 5]).into_array(),
+            PrimitiveArray::from(vec![1i64, 4, 7, 2, 4]).into_array(),
+            VarBinViewArray::from_iter_str(["a", "b", "c", "d", "e"]).into_array(),
+            true,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let values = map_get(&map, &Scalar::from(4i64)).unwrap();
+        assert_eq!(values.dtype(), &DType::Utf8(Nullability::Nullable));
+        assert_eq!(
+            values
+                .into_varbin_view()
+                .unwrap()
+                .with_iterator(|iter| iter
+                    .map(|v| v.map(|b| String::from_utf8(b.to_vec()).unwrap()))
+                    .collect::<Vec<_>>())
+                .unwrap(),
+            vec![Some("b".to_string()), Some("e".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn get_requires_map() {
+        assert!(map_get(
+            &PrimitiveArray::from(vec![1i32]).into_array(),
+            &Scalar::from(1i32)
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use vortex_error::VortexResult;
+use vortex_scalar::{Scalar, ScalarValue};
+
+use crate::array::map::MapArray;
+use crate::compute::unary::{scalar_at, ScalarAtFn};
+use crate::compute::{ArrayCompute, MapGetFn, SliceFn, TakeFn};
+use crate::validity::ArrayValidity;
+use crate::ArrayDType;
+
+mod map_get;
+mod slice;
+mod take;
+
+impl ArrayCompute for MapArray {
+    fn map_get(&self) -> Option<&dyn MapGetFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for MapArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+
+        let start = self.offset_at(index);
+        let end = self.offset_at(index + 1);
+        let (keys, values) = (self.keys(), self.values());
+        let entries = (start..end)
+            .map(|i| {
+                Ok(ScalarValue::List(
+                    vec![
+                        scalar_at(&keys, i)?.into_value(),
+                        scalar_at(&values, i)?.into_value(),
+                    ]
+                    .into(),
+                ))
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(Scalar::new(
+            self.dtype().clone(),
+            ScalarValue::List(entries.into()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::{MapScalar, Scalar};
+
+    use crate::array::map::MapArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::{BoolArray, VarBinViewArray};
+    use crate::compute::unary::scalar_at;
+    use crate::validity::Validity;
+    use crate::IntoArray;
+
+    #[test]
+    fn test_scalar_at() {
+        let map = MapArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 2, 3]).into_array(),
+            VarBinViewArray::from_iter_str(["a", "b", "c"]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+            false,
+            Validity::Array(BoolArray::from(vec![true, false, true]).into_array()),
+        )
+        .unwrap()
+        .into_array();
+
+        let first = scalar_at(&map, 0).unwrap();
+        let first = MapScalar::try_from(&first).unwrap();
+        assert_eq!(first.len(), 2);
+        assert_eq!(first.get(&Scalar::from("b")).unwrap(), Scalar::from(2i32));
+
+        assert!(scalar_at(&map, 1).unwrap().is_null());
+
+        let last = scalar_at(&map, 2).unwrap();
+        assert!(MapScalar::try_from(&last)
+            .unwrap()
+            .get(&Scalar::from("a"))
+            .is_none());
+    }
+}
@@ -0,0 +1,18 @@
+use vortex_error::VortexResult;
+
+use crate::array::map::MapArray;
+use crate::compute::{slice, SliceFn};
+use crate::{Array, IntoArray};
+
+impl SliceFn for MapArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        Self::try_new(
+            slice(&self.offsets(), start, stop + 1)?,
+            self.keys(),
+            self.values(),
+            self.map_dtype().keys_sorted(),
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
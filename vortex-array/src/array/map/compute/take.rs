@@ -0,0 +1,90 @@
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::VortexResult;
+
+use crate::array::list::take_offsets;
+use crate::array::map::MapArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::{take, TakeFn};
+use crate::{Array, IntoArray, IntoArrayVariant};
+
+impl TakeFn for MapArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let offsets = self.offsets().into_primitive()?;
+        let indices = indices.clone().into_primitive()?;
+        let (new_offsets, entry_indices) = match_each_integer_ptype!(offsets.ptype(), |$O| {
+            match_each_integer_ptype!(indices.ptype(), |$I| {
+                take_offsets(
+                    offsets.maybe_null_slice::<$O>(),
+                    indices.maybe_null_slice::<$I>(),
+                )
+            })
+        })?;
+        let entry_indices = PrimitiveArray::from(entry_indices).into_array();
+
+        Self::try_new(
+            PrimitiveArray::from(new_offsets).into_array(),
+            take(&self.keys(), &entry_indices)?,
+            take(&self.values(), &entry_indices)?,
+            self.map_dtype().keys_sorted(),
+            self.validity().take(&indices.into_array())?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::{MapScalar, Scalar};
+
+    use crate::array::map::MapArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::unary::scalar_at;
+    use crate::compute::{slice, take};
+    use crate::validity::Validity;
+    use crate::IntoArray;
+
+    fn map() -> MapArray {
+        MapArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 2, 3]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 1]).into_array(),
+            PrimitiveArray::from(vec![10i64, 20, 30]).into_array(),
+            true,
+            Validity::NonNullable,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn take_maps() {
+        let taken =
+            MapArray::try_from(take(map().array(), &vec![2u32, 1, 0].into_array()).unwrap())
+                .unwrap();
+        assert_eq!(taken.len(), 3);
+        assert!(taken.map_dtype().keys_sorted());
+
+        let first = scalar_at(taken.array(), 0).unwrap();
+        assert_eq!(
+            MapScalar::try_from(&first)
+                .unwrap()
+                .get(&Scalar::from(1i32))
+                .unwrap(),
+            Scalar::from(30i64)
+        );
+        let last = scalar_at(taken.array(), 2).unwrap();
+        assert_eq!(MapScalar::try_from(&last).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn slice_maps() {
+        let sliced = slice(map().array(), 1, 3).unwrap();
+        assert_eq!(sliced.len(), 2);
+        let last = scalar_at(&sliced, 1).unwrap();
+        assert_eq!(
+            MapScalar::try_from(&last)
+                .unwrap()
+                .get(&Scalar::from(1i32))
+                .unwrap(),
+            Scalar::from(30i64)
+        );
+    }
+}
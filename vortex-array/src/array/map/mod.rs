@@ -0,0 +1,238 @@
+use serde::{Deserialize, Serialize};
+use vortex_dtype::{DType, MapDType, MAP_KEY_FIELD, MAP_VALUE_FIELD};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::array::list::offset_at;
+use crate::array::struct_::StructArray;
+use crate::compute::slice;
+use crate::stats::{ArrayStatisticsCompute, StatsSet};
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::variants::{ArrayVariants, MapArrayTrait};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{
+    impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoArray, IntoCanonical,
+};
+
+mod compute;
+
+impl_encoding!("vortex.map", 26u16, Map);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapMetadata {
+    validity: ValidityMetadata,
+    offsets_dtype: DType,
+    entries_len: usize,
+}
+
+impl MapArray {
+    /// Create a new MapArray from an array of `len + 1` monotonically increasing offsets into
+    /// flat arrays of keys and values, which are stored together as `{key, value}` entries.
+    pub fn try_new(
+        offsets: Array,
+        keys: Array,
+        values: Array,
+        keys_sorted: bool,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        if !offsets.dtype().is_int() || offsets.dtype().is_nullable() {
+            vortex_bail!(MismatchedTypes: "non nullable int", offsets.dtype());
+        }
+        if offsets.is_empty() {
+            vortex_bail!("MapArray offsets must have at least one element");
+        }
+        if keys.len() != values.len() {
+            vortex_bail!(
+                "Map keys and values must have the same length, got {} and {}",
+                keys.len(),
+                values.len()
+            );
+        }
+
+        let length = offsets.len() - 1;
+        let nullability = validity.nullability();
+        let map_dtype =
+            MapDType::try_new(keys.dtype().clone(), values.dtype().clone(), keys_sorted)?;
+        let entries_len = keys.len();
+        let entries = StructArray::try_new(
+            [MAP_KEY_FIELD.into(), MAP_VALUE_FIELD.into()].into(),
+            vec![keys, values],
+            entries_len,
+            Validity::NonNullable,
+        )?;
+
+        let metadata = MapMetadata {
+            validity: validity.to_metadata(length)?,
+            offsets_dtype: offsets.dtype().clone(),
+            entries_len,
+        };
+
+        let mut children = Vec::with_capacity(3);
+        children.push(offsets);
+        children.push(entries.into_array());
+        if let Some(a) = validity.into_array() {
+            children.push(a)
+        }
+
+        Self::try_from_parts(
+            DType::Map(map_dtype, nullability),
+            length,
+            metadata,
+            children.into(),
+            StatsSet::new(),
+        )
+    }
+
+    pub fn map_dtype(&self) -> &MapDType {
+        let DType::Map(map_dtype, _) = self.dtype() else {
+            unreachable!("MapArray must have a map dtype")
+        };
+        map_dtype
+    }
+
+    #[inline]
+    pub fn offsets(&self) -> Array {
+        self.array()
+            .child(0, &self.metadata().offsets_dtype, self.len() + 1)
+            .expect("missing offsets")
+    }
+
+    /// The flat `{key, value}` entries of all maps in the array.
+    #[inline]
+    pub fn entries(&self) -> Array {
+        self.array()
+            .child(
+                1,
+                &self.map_dtype().entry_dtype(),
+                self.metadata().entries_len,
+            )
+            .expect("missing entries")
+    }
+
+    pub fn keys(&self) -> Array {
+        self.entries()
+            .with_dyn(|a| a.as_struct_array().and_then(|s| s.field(0)))
+            .expect("missing keys")
+    }
+
+    pub fn values(&self) -> Array {
+        self.entries()
+            .with_dyn(|a| a.as_struct_array().and_then(|s| s.field(1)))
+            .expect("missing values")
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(2, &Validity::DTYPE, self.len()))
+    }
+
+    pub fn offset_at(&self, index: usize) -> usize {
+        offset_at(&self.offsets(), index)
+    }
+
+    /// Returns the entries of the map at the given index as a struct array.
+    pub fn entries_at(&self, index: usize) -> VortexResult<Array> {
+        if index >= self.len() {
+            return Err(vortex_err!(OutOfBounds: index, 0, self.len()));
+        }
+        let start = self.offset_at(index);
+        let end = self.offset_at(index + 1);
+        slice(&self.entries(), start, end)
+    }
+}
+
+impl ArrayTrait for MapArray {}
+
+impl ArrayVariants for MapArray {
+    fn as_map_array(&self) -> Option<&dyn MapArrayTrait> {
+        Some(self)
+    }
+}
+
+impl MapArrayTrait for MapArray {}
+
+impl IntoCanonical for MapArray {
+    /// MapEncoding is the canonical form for a [DType::Map] array, so return self.
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        Ok(Canonical::Map(self))
+    }
+}
+
+impl ArrayValidity for MapArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for MapArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("offsets", &self.offsets())?;
+        visitor.visit_child("entries", &self.entries())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for MapArray {}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, MapDType, Nullability, PType};
+
+    use crate::array::map::MapArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::VarBinViewArray;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn test_entries_at() {
+        let map = MapArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 2, 3]).into_array(),
+            VarBinViewArray::from_iter_str(["a", "b", "c"]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+            false,
+            Validity::NonNullable,
+        )
+        .unwrap();
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(
+            map.dtype(),
+            &DType::Map(
+                MapDType::try_new(
+                    DType::Utf8(Nullability::NonNullable),
+                    DType::Primitive(PType::I32, Nullability::NonNullable),
+                    false
+                )
+                .unwrap(),
+                Nullability::NonNullable
+            )
+        );
+        assert_eq!(map.entries_at(0).unwrap().len(), 2);
+        assert!(map.entries_at(1).unwrap().is_empty());
+        assert_eq!(
+            map.values()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            [1, 2, 3]
+        );
+        assert!(map.entries_at(3).is_err());
+    }
+
+    #[test]
+    fn nullable_keys() {
+        assert!(MapArray::try_new(
+            PrimitiveArray::from(vec![0u32, 1]).into_array(),
+            VarBinViewArray::from_iter_nullable_str([Some("a")]).into_array(),
+            PrimitiveArray::from(vec![1i32]).into_array(),
+            false,
+            Validity::NonNullable,
+        )
+        .is_err());
+    }
+}
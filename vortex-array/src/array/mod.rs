@@ -2,11 +2,16 @@ mod bool;
 mod chunked;
 mod constant;
 mod datetime;
+mod decimal;
 mod extension;
+mod fixed_size_list;
+mod list;
+mod map;
 mod null;
 mod primitive;
 mod sparse;
 mod struct_;
+mod union;
 mod varbin;
 mod varbinview;
 
@@ -14,10 +19,15 @@ pub use self::bool::*;
 pub use self::chunked::*;
 pub use self::constant::*;
 pub use self::datetime::{temporal, *};
+pub use self::decimal::*;
 pub use self::extension::*;
+pub use self::fixed_size_list::*;
+pub use self::list::*;
+pub use self::map::*;
 pub use self::null::*;
 pub use self::primitive::*;
 pub use self::sparse::*;
 pub use self::struct_::*;
+pub use self::union::*;
 pub use self::varbin::*;
 pub use self::varbinview::*;
@@ -3,11 +3,17 @@ use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::array::null::NullArray;
+use crate::array::PrimitiveArray;
+use crate::compute::hash::null_hash;
 use crate::compute::unary::ScalarAtFn;
-use crate::compute::{ArrayCompute, SliceFn, TakeFn};
+use crate::compute::{ArrayCompute, HashFn, SliceFn, SortFn, SortOptions, TakeFn};
 use crate::{Array, IntoArray, IntoArrayVariant};
 
 impl ArrayCompute for NullArray {
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -16,6 +22,10 @@ impl ArrayCompute for NullArray {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
@@ -27,6 +37,20 @@ impl SliceFn for NullArray {
     }
 }
 
+impl HashFn for NullArray {
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray> {
+        Ok(PrimitiveArray::from(vec![null_hash(seed); self.len()]))
+    }
+}
+
+impl SortFn for NullArray {
+    fn sort_indices(&self, _options: SortOptions) -> VortexResult<PrimitiveArray> {
+        Ok(PrimitiveArray::from(
+            (0..self.len() as u64).collect::<Vec<_>>(),
+        ))
+    }
+}
+
 impl ScalarAtFn for NullArray {
     fn scalar_at(&self, _index: usize) -> VortexResult<Scalar> {
         Ok(Scalar::null(DType::Null))
@@ -0,0 +1,12 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::PrimitiveArray;
+use crate::compute::aggregate::sum_primitive;
+use crate::compute::SumFn;
+
+impl SumFn for PrimitiveArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        sum_primitive(self, None)
+    }
+}
@@ -0,0 +1,116 @@
+use arrow_buffer::NullBuffer;
+use vortex_dtype::{match_each_native_ptype, Nullability};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::array::{ConstantArray, PrimitiveArray};
+use crate::compute::arithmetic::{arithmetic_error, numeric_result_dtype, ArithmeticNative};
+use crate::compute::{BinaryNumericFn, BinaryOperator, NegateFn, OverflowMode, ScalarNumericFn};
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+impl BinaryNumericFn for PrimitiveArray {
+    fn binary_numeric(
+        &self,
+        rhs: &Array,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<Array> {
+        let rhs = rhs.clone().into_primitive()?;
+        let nulls = NullBuffer::union(
+            self.logical_validity().to_null_buffer()?.as_ref(),
+            rhs.logical_validity().to_null_buffer()?.as_ref(),
+        );
+        let nullability = numeric_result_dtype(self.dtype(), rhs.dtype()).nullability();
+
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let values = self
+                .maybe_null_slice::<$T>()
+                .iter()
+                .zip(rhs.maybe_null_slice::<$T>())
+                .enumerate()
+                .map(|(i, (&l, &r))| apply(l, r, operator, mode, i, nulls.as_ref()))
+                .collect::<VortexResult<Vec<_>>>()?;
+            Ok(PrimitiveArray::from_vec(values, result_validity(nulls, nullability)).into_array())
+        })
+    }
+}
+
+impl ScalarNumericFn for PrimitiveArray {
+    fn scalar_numeric(
+        &self,
+        rhs: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<Array> {
+        let dtype = numeric_result_dtype(self.dtype(), rhs.dtype());
+        if rhs.is_null() {
+            return Ok(ConstantArray::new(Scalar::null(dtype), self.len()).into_array());
+        }
+
+        let nulls = self.logical_validity().to_null_buffer()?;
+        let rhs = PrimitiveScalar::try_from(rhs)?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let r = rhs
+                .typed_value::<$T>()
+                .ok_or_else(|| vortex_err!("expected primitive"))?;
+            let values = self
+                .maybe_null_slice::<$T>()
+                .iter()
+                .enumerate()
+                .map(|(i, &l)| apply(l, r, operator, mode, i, nulls.as_ref()))
+                .collect::<VortexResult<Vec<_>>>()?;
+            Ok(PrimitiveArray::from_vec(values, result_validity(nulls, dtype.nullability()))
+                .into_array())
+        })
+    }
+}
+
+impl NegateFn for PrimitiveArray {
+    fn negate(&self, mode: OverflowMode) -> VortexResult<Array> {
+        let nulls = self.logical_validity().to_null_buffer()?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let values = self
+                .maybe_null_slice::<$T>()
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| match v.negate_op(mode) {
+                    Some(negated) => Ok(negated),
+                    None if is_null(nulls.as_ref(), i) => Ok(<$T>::default()),
+                    None => Err(vortex_err!("Negating {} overflows", v)),
+                })
+                .collect::<VortexResult<Vec<_>>>()?;
+            Ok(PrimitiveArray::from_vec(values, self.validity()).into_array())
+        })
+    }
+}
+
+/// Apply the operator to the element at `index`, ignoring failures for null elements.
+#[inline]
+fn apply<T: ArithmeticNative>(
+    lhs: T,
+    rhs: T,
+    operator: BinaryOperator,
+    mode: OverflowMode,
+    index: usize,
+    nulls: Option<&NullBuffer>,
+) -> VortexResult<T> {
+    match lhs.binary_op(rhs, operator, mode) {
+        Some(value) => Ok(value),
+        None if is_null(nulls, index) => Ok(T::default()),
+        None => Err(arithmetic_error(lhs, rhs, operator)),
+    }
+}
+
+#[inline]
+fn is_null(nulls: Option<&NullBuffer>, index: usize) -> bool {
+    nulls.map_or(false, |n| n.is_null(index))
+}
+
+fn result_validity(nulls: Option<NullBuffer>, nullability: Nullability) -> Validity {
+    match (nulls, nullability) {
+        (Some(nulls), _) => Validity::from(nulls),
+        (None, Nullability::Nullable) => Validity::AllValid,
+        (None, Nullability::NonNullable) => Validity::NonNullable,
+    }
+}
@@ -1,45 +1,93 @@
-use vortex_dtype::{match_each_native_ptype, DType, NativePType, PType};
-use vortex_error::{vortex_err, VortexResult};
+use num_traits::Zero;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType};
+use vortex_error::{vortex_bail, VortexResult};
 
-use crate::array::primitive::PrimitiveArray;
-use crate::compute::unary::CastFn;
-use crate::validity::Validity;
+use crate::array::decimal::number_to_decimal;
+use crate::array::{BoolArray, DecimalArray, PrimitiveArray, VarBinViewArray};
+use crate::compute::unary::{cast_number, cast_validity, CastFn, CastMode};
+use crate::validity::ArrayValidity;
 use crate::{Array, ArrayDType, IntoArray};
 
 impl CastFn for PrimitiveArray {
-    fn cast(&self, dtype: &DType) -> VortexResult<Array> {
-        let ptype = PType::try_from(dtype)?;
+    fn cast(&self, dtype: &DType, mode: CastMode) -> VortexResult<Array> {
+        let validity = cast_validity(self.validity(), dtype.nullability());
 
-        // Short-cut if we can just change the nullability
-        if self.ptype() == ptype && !self.dtype().is_nullable() && dtype.is_nullable() {
-            return Ok(PrimitiveArray::new(
-                self.buffer().clone(),
-                self.ptype(),
-                Validity::AllValid,
-            )
-            .into_array());
+        match dtype {
+            // Short-cut if we can just change the nullability
+            DType::Primitive(ptype, _) if *ptype == self.ptype() => {
+                Ok(PrimitiveArray::new(self.buffer().clone(), self.ptype(), validity).into_array())
+            }
+            DType::Primitive(ptype, _) => match_each_native_ptype!(ptype, |$T| {
+                Ok(PrimitiveArray::from_vec(cast::<$T>(self, mode)?, validity).into_array())
+            }),
+            DType::Bool(_) => match_each_native_ptype!(self.ptype(), |$E| {
+                Ok(BoolArray::try_new(
+                    self.maybe_null_slice::<$E>()
+                        .iter()
+                        .map(|v| !v.is_zero())
+                        .collect(),
+                    validity,
+                )?
+                .into_array())
+            }),
+            DType::Utf8(_) => {
+                let nulls = self.logical_validity().to_null_buffer()?;
+                match_each_native_ptype!(self.ptype(), |$E| {
+                    Ok(VarBinViewArray::from_iter(
+                        self.maybe_null_slice::<$E>()
+                            .iter()
+                            .enumerate()
+                            .map(|(idx, v)| {
+                                nulls
+                                    .as_ref()
+                                    .map_or(true, |n| n.is_valid(idx))
+                                    .then(|| v.to_string())
+                            }),
+                        dtype.clone(),
+                    )
+                    .into_array())
+                })
+            }
+            DType::Decimal(decimal, _) => {
+                let nulls = self.logical_validity().to_null_buffer()?;
+                match_each_native_ptype!(self.ptype(), |$E| {
+                    let values = self
+                        .maybe_null_slice::<$E>()
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, &v)| {
+                            if nulls.as_ref().map_or(true, |n| n.is_valid(idx)) {
+                                number_to_decimal(v, *decimal, mode)
+                            } else {
+                                Ok(0)
+                            }
+                        })
+                        .collect::<VortexResult<Vec<_>>>()?;
+                    Ok(DecimalArray::from_vec(values, *decimal, validity).into_array())
+                })
+            }
+            _ => vortex_bail!("Cannot cast {} to {}", self.dtype(), dtype),
         }
-
-        // FIXME(ngates): #260 - check validity and nullability
-        match_each_native_ptype!(ptype, |$T| {
-            Ok(PrimitiveArray::from_vec(
-                cast::<$T>(self)?,
-                self.validity().clone(),
-            ).into_array())
-        })
     }
 }
 
-fn cast<T: NativePType>(array: &PrimitiveArray) -> VortexResult<Vec<T>> {
+/// Cast the valid values, null slots are zeroed.
+fn cast<T: NativePType + num_traits::Bounded>(
+    array: &PrimitiveArray,
+    mode: CastMode,
+) -> VortexResult<Vec<T>> {
+    let nulls = array.logical_validity().to_null_buffer()?;
     match_each_native_ptype!(array.ptype(), |$E| {
         array
             .maybe_null_slice::<$E>()
             .iter()
-            // TODO(ngates): allow configurable checked/unchecked casting
-            .map(|&v| {
-                T::from(v).ok_or_else(|| {
-                    vortex_err!(ComputeError: "Failed to cast {} to {:?}", v, T::PTYPE)
-                })
+            .enumerate()
+            .map(|(idx, &v)| {
+                if nulls.as_ref().map_or(true, |n| n.is_valid(idx)) {
+                    cast_number::<$E, T>(v, mode)
+                } else {
+                    Ok(T::default())
+                }
             })
             .collect()
     })
@@ -47,10 +95,14 @@ fn cast<T: NativePType>(array: &PrimitiveArray) -> VortexResult<Vec<T>> {
 
 #[cfg(test)]
 mod test {
-    use vortex_dtype::PType;
+    use vortex_dtype::{DType, Nullability, PType};
     use vortex_error::VortexError;
 
-    use crate::{compute, IntoArray};
+    use crate::array::{PrimitiveArray, VarBinViewArray};
+    use crate::compute::slice;
+    use crate::compute::unary::CastMode;
+    use crate::validity::Validity;
+    use crate::{compute, ArrayDType, IntoArray, IntoArrayVariant};
 
     #[test]
     fn cast_u32_u8() {
@@ -81,4 +133,66 @@ mod test {
         };
         assert_eq!(s.to_string(), "Failed to cast -1 to U32");
     }
+    #[test]
+    fn cast_saturating() {
+        let arr = vec![-1i32, 300, 7].into_array();
+        let p = compute::unary::try_cast_with_mode(&arr, PType::U8.into(), CastMode::Saturating)
+            .unwrap()
+            .as_primitive();
+        assert_eq!(p.maybe_null_slice::<u8>(), vec![0u8, 255, 7]);
+
+        let arr = vec![f64::NAN, -1e20, 2.9].into_array();
+        let p = compute::unary::try_cast_with_mode(&arr, PType::I32.into(), CastMode::Saturating)
+            .unwrap()
+            .as_primitive();
+        assert_eq!(p.maybe_null_slice::<i32>(), vec![0, i32::MIN, 2]);
+    }
+
+    #[test]
+    fn cast_nullability() {
+        let arr = PrimitiveArray::from_nullable_vec(vec![Some(1i64), None]).into_array();
+        assert!(compute::unary::try_cast(
+            &arr,
+            &DType::Primitive(PType::I64, Nullability::NonNullable)
+        )
+        .is_err());
+
+        // Values behind nulls are never cast.
+        let arr = PrimitiveArray::from_vec(vec![1i64, -1], Validity::from(vec![true, false]))
+            .into_array();
+        let p =
+            compute::unary::try_cast(&arr, &DType::Primitive(PType::U64, Nullability::Nullable))
+                .unwrap();
+        assert!(!p.with_dyn(|a| a.is_valid(1)));
+
+        let sliced = slice(&arr, 0, 1).unwrap();
+        let p = compute::unary::try_cast(
+            &sliced,
+            &DType::Primitive(PType::U64, Nullability::NonNullable),
+        )
+        .unwrap();
+        assert_eq!(
+            p.dtype(),
+            &DType::Primitive(PType::U64, Nullability::NonNullable)
+        );
+    }
+
+    #[test]
+    fn cast_to_bool_and_utf8() {
+        let arr = vec![0u16, 3].into_array();
+        let bools = compute::unary::try_cast(&arr, &DType::Bool(Nullability::NonNullable))
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            bools.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true]
+        );
+
+        let strings = VarBinViewArray::try_from(
+            compute::unary::try_cast(&arr, &DType::Utf8(Nullability::NonNullable)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(strings.bytes_at(1).unwrap(), b"3");
+    }
 }
@@ -1,8 +1,9 @@
 use vortex_dtype::{match_each_native_ptype, Nullability};
 use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
 
 use crate::array::primitive::PrimitiveArray;
-use crate::compute::unary::FillForwardFn;
+use crate::compute::unary::{FillForwardFn, FillNullFn};
 use crate::validity::{ArrayValidity, Validity};
 use crate::{Array, ArrayDType, IntoArray};
 
@@ -45,6 +46,25 @@ impl FillForwardFn for PrimitiveArray {
     }
 }
 
+impl FillNullFn for PrimitiveArray {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array> {
+        let nulls = self.logical_validity().to_null_buffer()?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let fill = <$T>::try_from(fill_value)?;
+            let values = self.maybe_null_slice::<$T>();
+            let filled = match nulls {
+                None => values.to_vec(),
+                Some(nulls) => values
+                    .iter()
+                    .zip(nulls.iter())
+                    .map(|(&v, valid)| if valid { v } else { fill })
+                    .collect(),
+            };
+            Ok(PrimitiveArray::from_vec(filled, Validity::NonNullable).into_array())
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::array::primitive::PrimitiveArray;
@@ -0,0 +1,25 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+
+use crate::array::PrimitiveArray;
+use crate::compute::group::group_values;
+use crate::compute::sort::cmp_values;
+use crate::compute::{GroupIdsFn, HashFn};
+use crate::validity::ArrayValidity;
+use crate::{Array, AsArray};
+
+impl GroupIdsFn for PrimitiveArray {
+    fn group_ids(&self) -> VortexResult<(PrimitiveArray, Array)> {
+        let nulls = self.logical_validity().to_null_buffer()?;
+        let is_valid = |idx: usize| nulls.as_ref().map_or(true, |n| n.is_valid(idx));
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let values = self.maybe_null_slice::<$T>();
+            group_values(self.as_array_ref(), &self.hash(0)?, |a, b| {
+                match (is_valid(a), is_valid(b)) {
+                    (true, true) => cmp_values(&values[a], &values[b]).is_eq(),
+                    (a_valid, b_valid) => a_valid == b_valid,
+                }
+            })
+        })
+    }
+}
@@ -0,0 +1,21 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+
+use crate::array::PrimitiveArray;
+use crate::compute::hash::{hash_number, hash_values};
+use crate::compute::HashFn;
+use crate::validity::ArrayValidity;
+
+impl HashFn for PrimitiveArray {
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray> {
+        let nulls = self.logical_validity().to_null_buffer()?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            Ok(hash_values(
+                self.maybe_null_slice::<$T>().iter(),
+                nulls,
+                seed,
+                |&v| hash_number(seed, v),
+            ))
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use vortex_dtype::{match_each_native_ptype, NativePType};
+use vortex_error::VortexResult;
+
+use crate::array::PrimitiveArray;
+use crate::compute::hash::canonical_number;
+use crate::compute::is_in::is_in_keys;
+use crate::compute::IsInFn;
+use crate::{Array, IntoArrayVariant};
+
+impl IsInFn for PrimitiveArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        let values = values.clone().into_primitive()?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            is_in_keys(self, &values, |&v: &$T| number_key(v))
+        })
+    }
+}
+
+/// The bytes of a number, which are equal for all zeros and for all NaNs.
+fn number_key<T: NativePType>(value: T) -> [u8; 8] {
+    let mut key = [0u8; 8];
+    let value = canonical_number(value);
+    let bytes = value.to_le_bytes();
+    key[..bytes.len()].copy_from_slice(bytes);
+    key
+}
@@ -1,19 +1,31 @@
 use crate::array::primitive::PrimitiveArray;
-use crate::compute::unary::{CastFn, FillForwardFn, ScalarAtFn, SubtractScalarFn};
-use crate::compute::{ArrayCompute, CompareFn, FilterIndicesFn, SearchSortedFn, SliceFn, TakeFn};
+use crate::compute::unary::{CastFn, FillForwardFn, FillNullFn, ScalarAtFn};
+use crate::compute::{
+    ArrayCompute, BinaryNumericFn, CompareFn, FilterFn, FilterIndicesFn, GroupIdsFn, HashFn,
+    IsInFn, NegateFn, ScalarNumericFn, SearchSortedFn, SliceFn, SortFn, SumFn, TakeFn,
+};
 
+mod aggregate;
+mod arithmetic;
 mod cast;
 mod compare;
 mod fill;
 mod filter;
 mod filter_indices;
+mod group;
+mod hash;
+mod is_in;
 mod scalar_at;
 mod search_sorted;
 mod slice;
-mod subtract_scalar;
+mod sort;
 mod take;
 
 impl ArrayCompute for PrimitiveArray {
+    fn binary_numeric(&self) -> Option<&dyn BinaryNumericFn> {
+        Some(self)
+    }
+
     fn cast(&self) -> Option<&dyn CastFn> {
         Some(self)
     }
@@ -26,15 +38,39 @@ impl ArrayCompute for PrimitiveArray {
         Some(self)
     }
 
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn filter_indices(&self) -> Option<&dyn FilterIndicesFn> {
         Some(self)
     }
 
+    fn group_ids(&self) -> Option<&dyn GroupIdsFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
+    fn negate(&self) -> Option<&dyn NegateFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
-    fn subtract_scalar(&self) -> Option<&dyn SubtractScalarFn> {
+    fn scalar_numeric(&self) -> Option<&dyn ScalarNumericFn> {
         Some(self)
     }
 
@@ -46,6 +82,14 @@ impl ArrayCompute for PrimitiveArray {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
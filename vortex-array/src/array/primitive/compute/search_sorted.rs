@@ -12,12 +12,42 @@ impl SearchSortedFn for PrimitiveArray {
             Ok(self.maybe_null_slice::<$T>().search_sorted(&pvalue, side))
         })
     }
+
+    /// Sorted values, like the keys of a merge join, only search past the previous result.
+    fn search_sorted_many(
+        &self,
+        values: &[Scalar],
+        side: SearchSortedSide,
+    ) -> VortexResult<Vec<SearchResult>> {
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let slice = self.maybe_null_slice::<$T>();
+            let mut start = 0;
+            let mut previous: Option<$T> = None;
+            values
+                .iter()
+                .map(|value| {
+                    let pvalue: $T = value.try_into()?;
+                    if previous.map_or(true, |p| p > pvalue) {
+                        start = 0;
+                    }
+                    previous = Some(pvalue);
+                    let result = slice[start..].search_sorted(&pvalue, side).map(|i| i + start);
+                    // Values before the result are smaller than a repeated needle too, unless
+                    // they are equal to it at the right side.
+                    if matches!((side, result), (SearchSortedSide::Left, _) | (_, SearchResult::NotFound(_))) {
+                        start = result.to_index();
+                    }
+                    Ok(result)
+                })
+                .collect()
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::compute::search_sorted;
+    use crate::compute::{search_sorted, search_sorted_many};
     use crate::IntoArray;
 
     #[test]
@@ -41,4 +71,22 @@ mod test {
             SearchResult::NotFound(3)
         );
     }
+
+    #[test]
+    fn test_searchsorted_many() {
+        let values = vec![1u16, 2, 2, 3, 5].into_array();
+        let results =
+            search_sorted_many(&values, &[0u16, 2, 2, 4, 9, 1], SearchSortedSide::Right).unwrap();
+        assert_eq!(
+            results,
+            vec![
+                SearchResult::NotFound(0),
+                SearchResult::Found(3),
+                SearchResult::Found(3),
+                SearchResult::NotFound(4),
+                SearchResult::NotFound(5),
+                SearchResult::Found(1),
+            ]
+        );
+    }
 }
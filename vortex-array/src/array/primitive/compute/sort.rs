@@ -0,0 +1,19 @@
+use vortex_dtype::match_each_native_ptype;
+use vortex_error::VortexResult;
+
+use crate::array::PrimitiveArray;
+use crate::compute::sort::{cmp_values, sort_by};
+use crate::compute::{SortFn, SortOptions};
+use crate::validity::ArrayValidity;
+
+impl SortFn for PrimitiveArray {
+    fn sort_indices(&self, options: SortOptions) -> VortexResult<PrimitiveArray> {
+        let nulls = self.logical_validity().to_null_buffer()?;
+        match_each_native_ptype!(self.ptype(), |$T| {
+            let values = self.maybe_null_slice::<$T>();
+            Ok(sort_by(values.len(), nulls.as_ref(), options, |a, b| {
+                cmp_values(&values[a], &values[b])
+            }))
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::SparseArray;
+use crate::compute::{add_sums, min_max, sum, sum_repeated, MinMax, MinMaxFn, SumFn};
+
+impl SumFn for SparseArray {
+    fn sum(&self) -> VortexResult<Scalar> {
+        let fill_count = self.len() - self.values().len();
+        add_sums(
+            &sum(&self.values())?,
+            &sum_repeated(self.fill_value(), fill_count)?,
+        )
+    }
+}
+
+impl MinMaxFn for SparseArray {
+    fn min_max(&self) -> VortexResult<MinMax> {
+        let values = min_max(&self.values())?;
+        if self.len() > self.values().len() {
+            Ok(values.merge(MinMax::of(self.fill_value())?))
+        } else {
+            Ok(values)
+        }
+    }
+}
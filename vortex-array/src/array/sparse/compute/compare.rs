@@ -0,0 +1,40 @@
+use vortex_error::VortexResult;
+use vortex_expr::Operator;
+use vortex_scalar::{BoolScalar, Scalar};
+
+use crate::array::{ConstantArray, SparseArray};
+use crate::compute::{compare, scalar_cmp, CompareFn};
+use crate::{Array, ArrayDType, IntoArray, IntoCanonical};
+
+impl CompareFn for SparseArray {
+    /// Comparing against a literal compares the fill value once and the patches separately,
+    /// which keeps the result sparse.
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        let Ok(literal) = ConstantArray::try_from(other) else {
+            return compare(&self.clone().into_canonical()?.into(), other, operator);
+        };
+        let literal = literal.scalar().cast(self.dtype())?;
+
+        let values = compare(
+            &self.values(),
+            &ConstantArray::new(literal.clone(), self.values().len()).into_array(),
+            operator,
+        )?;
+        let fill_matches = scalar_cmp(self.fill_value(), &literal, operator);
+        let fill_value = Scalar::bool(
+            BoolScalar::try_from(&fill_matches)?
+                .value()
+                .unwrap_or(false),
+            values.dtype().nullability(),
+        );
+
+        Self::try_new_with_offset(
+            self.indices(),
+            values,
+            self.len(),
+            self.indices_offset(),
+            fill_value,
+        )
+        .map(|a| a.into_array())
+    }
+}
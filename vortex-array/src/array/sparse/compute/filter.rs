@@ -0,0 +1,35 @@
+use vortex_error::VortexResult;
+
+use crate::array::{BoolArray, PrimitiveArray, SparseArray};
+use crate::compute::{filter, FilterFn};
+use crate::{Array, IntoArray, IntoArrayVariant};
+
+impl FilterFn for SparseArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let predicate = predicate.clone().into_bool()?;
+        let buffer = predicate.boolean_buffer();
+
+        // Each kept patch moves to the number of selected positions that precede it.
+        let mut indices = Vec::new();
+        let mut keep = Vec::with_capacity(self.values().len());
+        let mut rank = 0;
+        let mut position = 0;
+        for index in self.resolved_indices() {
+            rank += buffer.slice(position, index - position).count_set_bits();
+            position = index;
+            let selected = buffer.value(index);
+            if selected {
+                indices.push(rank as u64);
+            }
+            keep.push(selected);
+        }
+
+        Self::try_new(
+            PrimitiveArray::from(indices).into_array(),
+            filter(&self.values(), &BoolArray::from(keep).into_array())?,
+            buffer.count_set_bits(),
+            self.fill_value().clone(),
+        )
+        .map(|a| a.into_array())
+    }
+}
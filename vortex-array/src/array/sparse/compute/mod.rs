@@ -2,16 +2,40 @@ use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::array::sparse::SparseArray;
-use crate::compute::unary::{scalar_at, ScalarAtFn};
+use crate::compute::unary::{fill_null, is_null, scalar_at, FillNullFn, IsNullFn, ScalarAtFn};
 use crate::compute::{
-    search_sorted, ArrayCompute, SearchResult, SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
+    search_sorted, ArrayCompute, CompareFn, FilterFn, MinMaxFn, SearchResult, SearchSortedFn,
+    SearchSortedSide, SliceFn, SumFn, TakeFn,
 };
-use crate::ArrayDType;
+use crate::{Array, ArrayDType, IntoArray};
 
+mod aggregate;
+mod compare;
+mod filter;
 mod slice;
 mod take;
 
 impl ArrayCompute for SparseArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn is_null(&self) -> Option<&dyn IsNullFn> {
+        Some(self)
+    }
+
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -24,11 +48,47 @@ impl ArrayCompute for SparseArray {
         Some(self)
     }
 
+    fn sum(&self) -> Option<&dyn SumFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 }
 
+impl IsNullFn for SparseArray {
+    /// A null fill value means the positions without patches are null.
+    fn is_null(&self) -> VortexResult<Array> {
+        Self::try_new_with_offset(
+            self.indices(),
+            is_null(&self.values())?,
+            self.len(),
+            self.indices_offset(),
+            self.fill_value().is_null().into(),
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl FillNullFn for SparseArray {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array> {
+        let sparse_fill = if self.fill_value().is_null() {
+            fill_value.clone()
+        } else {
+            self.fill_value().cast(fill_value.dtype())?
+        };
+        Self::try_new_with_offset(
+            self.indices(),
+            fill_null(&self.values(), fill_value)?,
+            self.len(),
+            self.indices_offset(),
+            sparse_fill,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
 impl ScalarAtFn for SparseArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         match self.find_index(index)? {
@@ -60,13 +120,16 @@ impl SearchSortedFn for SparseArray {
 #[cfg(test)]
 mod test {
     use vortex_dtype::{DType, Nullability, PType};
+    use vortex_expr::Operator;
     use vortex_scalar::Scalar;
 
     use crate::array::primitive::PrimitiveArray;
     use crate::array::sparse::SparseArray;
-    use crate::compute::{search_sorted, slice, SearchResult, SearchSortedSide};
+    use crate::array::{BoolArray, ConstantArray};
+    use crate::compute::unary::{fill_null, is_not_null, is_null, scalar_at};
+    use crate::compute::{compare, filter, search_sorted, slice, SearchResult, SearchSortedSide};
     use crate::validity::Validity;
-    use crate::{Array, IntoArray};
+    use crate::{Array, IntoArray, IntoArrayVariant};
 
     fn array() -> Array {
         SparseArray::try_new(
@@ -79,6 +142,29 @@ mod test {
         .into_array()
     }
 
+    #[test]
+    fn null_kernels() {
+        let nulls = is_null(&array()).unwrap();
+        assert!(SparseArray::try_from(&nulls).is_ok());
+        let nulls = nulls.into_bool().unwrap().boolean_buffer();
+        assert_eq!(nulls.count_set_bits(), 17);
+        assert!(!nulls.value(9));
+        assert_eq!(
+            is_not_null(&array())
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .count_set_bits(),
+            3
+        );
+
+        let filled = fill_null(&array(), &Scalar::from(-1)).unwrap();
+        assert!(SparseArray::try_from(&filled).is_ok());
+        assert_eq!(scalar_at(&filled, 0).unwrap(), Scalar::from(-1));
+        assert_eq!(scalar_at(&filled, 15).unwrap(), Scalar::from(55));
+    }
+
     #[test]
     pub fn search_larger_than() {
         let res = search_sorted(&array(), 66, SearchSortedSide::Left).unwrap();
@@ -105,4 +191,36 @@ mod test {
             SearchResult::NotFound(2)
         );
     }
+
+    #[test]
+    pub fn compare_literal() {
+        let sliced = slice(&array(), 7, 20).unwrap();
+        let result = compare(
+            &sliced,
+            &ConstantArray::new(44, sliced.len()).into_array(),
+            Operator::Gte,
+        )
+        .unwrap();
+        assert!(SparseArray::try_from(&result).is_ok());
+        let matching = result
+            .into_bool()
+            .unwrap()
+            .boolean_buffer()
+            .set_indices()
+            .collect::<Vec<_>>();
+        assert_eq!(matching, vec![2, 8]);
+    }
+
+    #[test]
+    pub fn filter_patches() {
+        let predicate = BoolArray::from((0..20).map(|i| i % 3 == 0).collect::<Vec<_>>());
+        let filtered = filter(&array(), predicate.array()).unwrap();
+        assert!(SparseArray::try_from(&filtered).is_ok());
+        assert_eq!(filtered.len(), 7);
+        // The patches at indices 9 and 15 are selected, index 2 is not.
+        assert!(scalar_at(&filtered, 0).unwrap().is_null());
+        assert_eq!(scalar_at(&filtered, 3).unwrap(), Scalar::from(Some(44)));
+        assert!(scalar_at(&filtered, 4).unwrap().is_null());
+        assert_eq!(scalar_at(&filtered, 5).unwrap(), Scalar::from(Some(55)));
+    }
 }
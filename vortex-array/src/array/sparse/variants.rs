@@ -3,8 +3,9 @@ use vortex_scalar::StructScalar;
 
 use crate::array::sparse::SparseArray;
 use crate::variants::{
-    ArrayVariants, BinaryArrayTrait, BoolArrayTrait, ExtensionArrayTrait, ListArrayTrait,
-    NullArrayTrait, PrimitiveArrayTrait, StructArrayTrait, Utf8ArrayTrait,
+    ArrayVariants, BinaryArrayTrait, BoolArrayTrait, DecimalArrayTrait, ExtensionArrayTrait,
+    FixedSizeListArrayTrait, ListArrayTrait, MapArrayTrait, NullArrayTrait, PrimitiveArrayTrait,
+    StructArrayTrait, UnionArrayTrait, Utf8ArrayTrait,
 };
 use crate::{Array, ArrayDType, IntoArray};
 
@@ -22,6 +23,10 @@ impl ArrayVariants for SparseArray {
         matches!(self.dtype(), DType::Primitive(..)).then_some(self)
     }
 
+    fn as_decimal_array(&self) -> Option<&dyn DecimalArrayTrait> {
+        matches!(self.dtype(), DType::Decimal(..)).then_some(self)
+    }
+
     fn as_utf8_array(&self) -> Option<&dyn Utf8ArrayTrait> {
         matches!(self.dtype(), DType::Utf8(_)).then_some(self)
     }
@@ -38,6 +43,18 @@ impl ArrayVariants for SparseArray {
         matches!(self.dtype(), DType::List(..)).then_some(self)
     }
 
+    fn as_fixed_size_list_array(&self) -> Option<&dyn FixedSizeListArrayTrait> {
+        matches!(self.dtype(), DType::FixedSizeList(..)).then_some(self)
+    }
+
+    fn as_union_array(&self) -> Option<&dyn UnionArrayTrait> {
+        matches!(self.dtype(), DType::Union(..)).then_some(self)
+    }
+
+    fn as_map_array(&self) -> Option<&dyn MapArrayTrait> {
+        matches!(self.dtype(), DType::Map(..)).then_some(self)
+    }
+
     fn as_extension_array(&self) -> Option<&dyn ExtensionArrayTrait> {
         matches!(self.dtype(), DType::Extension(..)).then_some(self)
     }
@@ -57,6 +74,8 @@ impl BoolArrayTrait for SparseArray {
 
 impl PrimitiveArrayTrait for SparseArray {}
 
+impl DecimalArrayTrait for SparseArray {}
+
 impl Utf8ArrayTrait for SparseArray {}
 
 impl BinaryArrayTrait for SparseArray {}
@@ -86,4 +105,10 @@ impl StructArrayTrait for SparseArray {
 
 impl ListArrayTrait for SparseArray {}
 
+impl FixedSizeListArrayTrait for SparseArray {}
+
+impl UnionArrayTrait for SparseArray {}
+
+impl MapArrayTrait for SparseArray {}
+
 impl ExtensionArrayTrait for SparseArray {}
@@ -3,12 +3,27 @@ use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::array::struct_::StructArray;
+use crate::array::PrimitiveArray;
+use crate::compute::hash::hash_values;
+use crate::compute::sort::validity_key;
 use crate::compute::unary::{scalar_at, ScalarAtFn};
-use crate::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
+use crate::compute::{
+    combine_hashes, filter, hash, lexsort_indices, slice, take, ArrayCompute, FilterFn, HashFn,
+    SliceFn, SortFn, SortOptions, TakeFn,
+};
+use crate::validity::{filter_validity, ArrayValidity};
 use crate::variants::StructArrayTrait;
-use crate::{Array, ArrayDType, IntoArray};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 
 impl ArrayCompute for StructArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -17,11 +32,53 @@ impl ArrayCompute for StructArray {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 }
 
+impl HashFn for StructArray {
+    /// Rows are hashed by combining the hashes of their fields in order.
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray> {
+        let mut hashes = PrimitiveArray::from(vec![seed; self.len()]);
+        for field in self.children() {
+            hashes = combine_hashes(&hashes, &hash(&field, seed)?)?;
+        }
+        match self.logical_validity().to_null_buffer()? {
+            None => Ok(hashes),
+            nulls => Ok(hash_values(
+                hashes.maybe_null_slice::<u64>().iter(),
+                nulls,
+                seed,
+                |&h| h,
+            )),
+        }
+    }
+}
+
+impl SortFn for StructArray {
+    /// Rows are sorted lexicographically by their fields, using the same options for each.
+    fn sort_indices(&self, options: SortOptions) -> VortexResult<PrimitiveArray> {
+        let mut keys = Vec::with_capacity(self.nfields() + 1);
+        if let Some(nulls) = self.logical_validity().to_null_buffer()? {
+            keys.push(validity_key(&nulls, options));
+        }
+        keys.extend(self.children().map(|field| (field, options)));
+        if keys.is_empty() {
+            return Ok(PrimitiveArray::from(
+                (0..self.len() as u64).collect::<Vec<_>>(),
+            ));
+        }
+
+        let (columns, options): (Vec<_>, Vec<_>) = keys.into_iter().unzip();
+        lexsort_indices(&columns, &options)
+    }
+}
+
 impl ScalarAtFn for StructArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         Ok(Scalar::r#struct(
@@ -47,6 +104,24 @@ impl TakeFn for StructArray {
     }
 }
 
+impl FilterFn for StructArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        Self::try_new(
+            self.names().clone(),
+            self.children()
+                .map(|field| filter(&field, predicate))
+                .try_collect()?,
+            predicate
+                .clone()
+                .into_bool()?
+                .boolean_buffer()
+                .count_set_bits(),
+            filter_validity(self.validity(), predicate)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
 impl SliceFn for StructArray {
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
         let fields = self
@@ -1,12 +1,16 @@
 use serde::{Deserialize, Serialize};
-use vortex_dtype::{DType, FieldName, FieldNames, Nullability, StructDType};
+use vortex_dtype::field::{FieldMask, FieldPath};
+use vortex_dtype::{DType, FieldName, FieldNames, StructDType};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
 use crate::stats::{ArrayStatisticsCompute, StatsSet};
 use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use crate::variants::{ArrayVariants, StructArrayTrait};
 use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
-use crate::{impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoCanonical};
+use crate::{
+    impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoArray, IntoArrayVariant,
+    IntoCanonical,
+};
 
 mod compute;
 
@@ -50,6 +54,7 @@ impl StructArray {
 
         let field_dtypes: Vec<_> = fields.iter().map(|d| d.dtype()).cloned().collect();
 
+        let nullability = validity.nullability();
         let validity_metadata = validity.to_metadata(length)?;
 
         let mut children = Vec::with_capacity(fields.len() + 1);
@@ -59,10 +64,7 @@ impl StructArray {
         }
 
         Self::try_from_parts(
-            DType::Struct(
-                StructDType::new(names, field_dtypes),
-                Nullability::NonNullable,
-            ),
+            DType::Struct(StructDType::new(names, field_dtypes), nullability),
             length,
             StructMetadata {
                 length,
@@ -85,7 +87,6 @@ impl StructArray {
             .expect("building StructArray with helper")
     }
 
-    // TODO(aduffy): Add equivalent function to support field masks for nested column access.
     /// Return a new StructArray with the given projection applied.
     ///
     /// Projection does not copy data arrays. Projection is defined by an ordinal array slice
@@ -115,6 +116,49 @@ impl StructArray {
             self.validity(),
         )
     }
+
+    /// Return a new StructArray keeping only the possibly nested fields referenced by `paths`.
+    ///
+    /// Selecting `a.b.c` keeps field `a`, holding a struct with field `b`, holding a struct with
+    /// only field `c`. The resulting DType is [StructDType::project_mask] of the mask resolved
+    /// from `paths`.
+    pub fn project_paths(&self, paths: &[FieldPath]) -> VortexResult<Self> {
+        let DType::Struct(struct_dtype, _) = self.dtype() else {
+            unreachable!("StructArray must have a struct dtype");
+        };
+        self.project_mask(&FieldMask::try_new(struct_dtype, paths)?)
+    }
+
+    /// Return a new StructArray with the fields selected by `mask`, which must have been resolved
+    /// against the DType of this array.
+    ///
+    /// Like [StructArray::project], this does not copy data arrays, but struct fields that are
+    /// projected further are first canonicalized.
+    pub fn project_mask(&self, mask: &FieldMask) -> VortexResult<Self> {
+        let FieldMask::Fields(fields) = mask else {
+            return Ok(self.clone());
+        };
+
+        let mut children = Vec::with_capacity(fields.len());
+        let mut names = Vec::with_capacity(fields.len());
+        for (column_idx, child_mask) in fields {
+            let child = self
+                .field(*column_idx)
+                .ok_or(vortex_err!(OutOfBounds: *column_idx, 0, self.dtypes().len()))?;
+            children.push(match child_mask {
+                FieldMask::All => child,
+                _ => child.into_struct()?.project_mask(child_mask)?.into_array(),
+            });
+            names.push(self.names()[*column_idx].clone());
+        }
+
+        StructArray::try_new(
+            FieldNames::from(names.as_slice()),
+            children,
+            self.len(),
+            self.validity(),
+        )
+    }
 }
 
 impl ArrayTrait for StructArray {}
@@ -166,15 +210,17 @@ impl ArrayStatisticsCompute for StructArray {}
 
 #[cfg(test)]
 mod test {
+    use vortex_dtype::field::{Field, FieldMask, FieldPath};
     use vortex_dtype::{DType, FieldName, FieldNames, Nullability};
 
     use crate::array::primitive::PrimitiveArray;
     use crate::array::struct_::StructArray;
     use crate::array::varbin::VarBinArray;
     use crate::array::BoolArray;
-    use crate::validity::Validity;
+    use crate::compute::filter;
+    use crate::validity::{ArrayValidity, Validity};
     use crate::variants::StructArrayTrait;
-    use crate::IntoArray;
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant};
 
     #[test]
     fn test_project() {
@@ -210,4 +256,80 @@ mod test {
         let prims = PrimitiveArray::try_from(struct_b.field(1).unwrap()).unwrap();
         assert_eq!(prims.maybe_null_slice::<i64>(), [0i64, 1, 2, 3, 4]);
     }
+
+    #[test]
+    fn test_project_paths() {
+        let inner = StructArray::try_new(
+            FieldNames::from(["c".into(), "d".into()]),
+            vec![
+                PrimitiveArray::from(vec![1u8, 2]).into_array(),
+                BoolArray::from(vec![true, false]).into_array(),
+            ],
+            2,
+            Validity::from(vec![true, false]),
+        )
+        .unwrap();
+        let outer = StructArray::try_new(
+            FieldNames::from(["a".into(), "b".into()]),
+            vec![
+                PrimitiveArray::from(vec![5i64, 6]).into_array(),
+                inner.into_array(),
+            ],
+            2,
+            Validity::NonNullable,
+        )
+        .unwrap();
+
+        let paths = [FieldPath::from(vec![Field::from("b"), Field::from("d")])];
+        let projected = outer.project_paths(&paths).unwrap();
+        let DType::Struct(outer_dtype, _) = outer.dtype() else {
+            unreachable!()
+        };
+        let expected_dtype = outer_dtype
+            .project_mask(&FieldMask::try_new(outer_dtype, &paths).unwrap())
+            .unwrap();
+        assert_eq!(
+            projected.dtype(),
+            &DType::Struct(expected_dtype, Nullability::NonNullable)
+        );
+
+        let b = StructArray::try_from(projected.field(0).unwrap()).unwrap();
+        assert_eq!(b.names().as_ref(), [FieldName::from("d")]);
+        assert!(!b.is_valid(1));
+        assert!(outer
+            .project_paths(&[FieldPath::from(vec![Field::from("a"), Field::from("c")])])
+            .is_err());
+    }
+
+    #[test]
+    fn test_filter() {
+        let xs = PrimitiveArray::from_vec(vec![0i64, 1, 2, 3], Validity::NonNullable);
+        let struct_a = StructArray::try_new(
+            FieldNames::from(["xs".into()]),
+            vec![xs.into_array()],
+            4,
+            Validity::from(vec![true, false, true, true]),
+        )
+        .unwrap();
+
+        let filtered = StructArray::try_from(
+            filter(
+                struct_a.array(),
+                BoolArray::from(vec![false, true, true, true]).array(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(filtered.len(), 3);
+        assert!(!filtered.is_valid(0));
+        assert_eq!(
+            filtered
+                .field(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i64>(),
+            &[1, 2, 3]
+        );
+    }
 }
@@ -0,0 +1,181 @@
+use itertools::Itertools;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::union::UnionArray;
+use crate::compute::unary::{scalar_at, ScalarAtFn};
+use crate::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
+use crate::{Array, ArrayDType, IntoArray};
+
+impl ArrayCompute for UnionArray {
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for UnionArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.validity().is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+        let (type_id, offset) = self.variant_index(index)?;
+        let variant = self
+            .variant(type_id as usize)
+            .ok_or_else(|| vortex_err!("Type id {} out of bounds", type_id))?;
+        let value = scalar_at(&variant, offset)?;
+        if value.is_null() && self.dtype().is_nullable() {
+            Ok(Scalar::null(self.dtype().clone()))
+        } else {
+            Scalar::union(self.dtype().clone(), type_id, value)
+        }
+    }
+}
+
+impl TakeFn for UnionArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let type_ids = take(&self.type_ids(), indices)?;
+        let validity = self.validity().take(indices)?;
+        let (offsets, variants) = match self.offsets() {
+            // Dense variants are addressed through the offsets, so they can be shared as-is.
+            Some(offsets) => (Some(take(&offsets, indices)?), self.variants().collect()),
+            None => (
+                None,
+                self.variants()
+                    .map(|variant| take(&variant, indices))
+                    .try_collect()?,
+            ),
+        };
+        Self::try_new(self.dtype().clone(), type_ids, offsets, variants, validity)
+            .map(|a| a.into_array())
+    }
+}
+
+impl SliceFn for UnionArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        let type_ids = slice(&self.type_ids(), start, stop)?;
+        let validity = self.validity().slice(start, stop)?;
+        let (offsets, variants) = match self.offsets() {
+            Some(offsets) => (
+                Some(slice(&offsets, start, stop)?),
+                self.variants().collect(),
+            ),
+            None => (
+                None,
+                self.variants()
+                    .map(|variant| slice(&variant, start, stop))
+                    .try_collect()?,
+            ),
+        };
+        Self::try_new(self.dtype().clone(), type_ids, offsets, variants, validity)
+            .map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType, UnionDType, UnionMode};
+    use vortex_scalar::Scalar;
+
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::union::UnionArray;
+    use crate::array::VarBinViewArray;
+    use crate::compute::unary::scalar_at;
+    use crate::compute::{slice, take};
+    use crate::validity::Validity;
+    use crate::{Array, IntoArray};
+
+    fn union_dtype(mode: UnionMode) -> DType {
+        DType::Union(
+            UnionDType::try_new(
+                ["int".into(), "str".into()].into(),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::Nullable),
+                    DType::Utf8(Nullability::Nullable),
+                ],
+                mode,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        )
+    }
+
+    fn int(dtype: &DType, v: i32) -> Scalar {
+        Scalar::union(dtype.clone(), 0, Scalar::from(v)).unwrap()
+    }
+
+    fn str(dtype: &DType, v: &str) -> Scalar {
+        Scalar::union(dtype.clone(), 1, Scalar::from(v)).unwrap()
+    }
+
+    fn dense() -> Array {
+        UnionArray::try_new(
+            union_dtype(UnionMode::Dense),
+            PrimitiveArray::from(vec![0u8, 1, 0, 1]).into_array(),
+            Some(PrimitiveArray::from(vec![0u32, 0, 1, 1]).into_array()),
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i32), None]).into_array(),
+                VarBinViewArray::from_iter_nullable_str([Some("a"), Some("b")]).into_array(),
+            ],
+            Validity::AllValid,
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    fn sparse() -> Array {
+        UnionArray::try_new(
+            union_dtype(UnionMode::Sparse),
+            PrimitiveArray::from(vec![0u8, 1, 1]).into_array(),
+            None,
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, None]).into_array(),
+                VarBinViewArray::from_iter_nullable_str([None, Some("a"), Some("b")]).into_array(),
+            ],
+            Validity::AllValid,
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    #[test]
+    fn scalar_at_dense() {
+        let array = dense();
+        let dtype = union_dtype(UnionMode::Dense);
+        assert_eq!(scalar_at(&array, 0).unwrap(), int(&dtype, 1));
+        assert_eq!(scalar_at(&array, 1).unwrap(), str(&dtype, "a"));
+        assert!(scalar_at(&array, 2).unwrap().is_null());
+        assert_eq!(scalar_at(&array, 3).unwrap(), str(&dtype, "b"));
+    }
+
+    #[test]
+    fn slice_and_take_dense() {
+        let dtype = union_dtype(UnionMode::Dense);
+        let sliced = slice(&dense(), 1, 4).unwrap();
+        assert_eq!(sliced.len(), 3);
+        assert_eq!(scalar_at(&sliced, 2).unwrap(), str(&dtype, "b"));
+
+        let taken = take(&dense(), PrimitiveArray::from(vec![3u32, 0]).array()).unwrap();
+        assert_eq!(scalar_at(&taken, 0).unwrap(), str(&dtype, "b"));
+        assert_eq!(scalar_at(&taken, 1).unwrap(), int(&dtype, 1));
+    }
+
+    #[test]
+    fn slice_and_take_sparse() {
+        let dtype = union_dtype(UnionMode::Sparse);
+        let sliced = slice(&sparse(), 1, 3).unwrap();
+        assert_eq!(scalar_at(&sliced, 0).unwrap(), str(&dtype, "a"));
+        assert_eq!(scalar_at(&sliced, 1).unwrap(), str(&dtype, "b"));
+
+        let taken = take(&sparse(), PrimitiveArray::from(vec![2u64, 0, 0]).array()).unwrap();
+        assert_eq!(scalar_at(&taken, 0).unwrap(), str(&dtype, "b"));
+        assert_eq!(scalar_at(&taken, 2).unwrap(), int(&dtype, 1));
+    }
+}
@@ -0,0 +1,446 @@
+use arrow_buffer::BooleanBuffer;
+use num_traits::AsPrimitive;
+use serde::{Deserialize, Serialize};
+use vortex_dtype::{match_each_integer_ptype, DType, Nullability, PType, UnionDType, UnionMode};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::array::primitive::PrimitiveArray;
+use crate::array::BoolArray;
+use crate::compute::unary::scalar_at;
+use crate::stats::{ArrayStatisticsCompute, StatsSet};
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::variants::{ArrayVariants, UnionArrayTrait};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{
+    impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoArray, IntoArrayVariant,
+    IntoCanonical,
+};
+
+mod compute;
+
+impl_encoding!("vortex.union", 25u16, Union);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnionMetadata {
+    validity: ValidityMetadata,
+    variant_lens: Vec<usize>,
+    offsets_dtype: Option<DType>,
+}
+
+impl UnionArray {
+    /// Create a new UnionArray from the type id of each element and one child array per variant.
+    ///
+    /// Sparse unions require every variant to have the same length as `type_ids`, while dense
+    /// unions must provide `offsets` locating each element within its variant.
+    ///
+    /// An element is null if either `validity` or the value it refers to marks it as null.
+    pub fn try_new(
+        dtype: DType,
+        type_ids: Array,
+        offsets: Option<Array>,
+        variants: Vec<Array>,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        let DType::Union(union_dtype, nullability) = &dtype else {
+            vortex_bail!(MismatchedTypes: "union", dtype);
+        };
+        if validity.nullability() != *nullability {
+            vortex_bail!(
+                "Union of nullability {} given validity of nullability {}",
+                nullability,
+                validity.nullability()
+            );
+        }
+        if type_ids.dtype() != &DType::Primitive(PType::U8, Nullability::NonNullable) {
+            vortex_bail!(MismatchedTypes: "non nullable u8", type_ids.dtype());
+        }
+        if variants.len() != union_dtype.dtypes().len() {
+            vortex_bail!(
+                "Union of {} variants given {} children",
+                union_dtype.dtypes().len(),
+                variants.len()
+            );
+        }
+        for (variant, variant_dtype) in variants.iter().zip(union_dtype.dtypes().iter()) {
+            if variant.dtype() != variant_dtype {
+                vortex_bail!(MismatchedTypes: variant_dtype, variant.dtype());
+            }
+        }
+
+        let len = type_ids.len();
+        match (union_dtype.mode(), &offsets) {
+            (UnionMode::Sparse, None) => {
+                if let Some(variant) = variants.iter().find(|v| v.len() != len) {
+                    vortex_bail!(
+                        "Sparse union variants must have length {}, got {}",
+                        len,
+                        variant.len()
+                    );
+                }
+            }
+            (UnionMode::Dense, Some(offsets)) => {
+                if !offsets.dtype().is_int() || offsets.dtype().is_nullable() {
+                    vortex_bail!(MismatchedTypes: "non nullable int", offsets.dtype());
+                }
+                if offsets.len() != len {
+                    vortex_bail!(
+                        "Dense union offsets must have length {}, got {}",
+                        len,
+                        offsets.len()
+                    );
+                }
+            }
+            (UnionMode::Sparse, Some(_)) => vortex_bail!("Sparse unions do not have offsets"),
+            (UnionMode::Dense, None) => vortex_bail!("Dense unions require offsets"),
+        }
+        validate_indices(&type_ids, offsets.as_ref(), &variants)?;
+
+        let metadata = UnionMetadata {
+            validity: validity.to_metadata(len)?,
+            variant_lens: variants.iter().map(|v| v.len()).collect(),
+            offsets_dtype: offsets.as_ref().map(|o| o.dtype().clone()),
+        };
+
+        let mut children = Vec::with_capacity(variants.len() + 3);
+        children.push(type_ids);
+        children.extend(variants);
+        children.extend(offsets);
+        children.extend(validity.into_array());
+
+        Self::try_from_parts(dtype, len, metadata, children.into(), StatsSet::new())
+    }
+
+    pub fn union_dtype(&self) -> &UnionDType {
+        let DType::Union(union_dtype, _) = self.dtype() else {
+            unreachable!("UnionArray must have a union dtype")
+        };
+        union_dtype
+    }
+
+    #[inline]
+    pub fn mode(&self) -> UnionMode {
+        self.union_dtype().mode()
+    }
+
+    /// The variant index of each element.
+    #[inline]
+    pub fn type_ids(&self) -> Array {
+        self.array()
+            .child(
+                0,
+                &DType::Primitive(PType::U8, Nullability::NonNullable),
+                self.len(),
+            )
+            .expect("missing type ids")
+    }
+
+    /// The position of each element within its variant, only present for dense unions.
+    pub fn offsets(&self) -> Option<Array> {
+        let dtype = self.metadata().offsets_dtype.as_ref()?;
+        self.array().child(self.nvariants() + 1, dtype, self.len())
+    }
+
+    pub fn validity(&self) -> Validity {
+        let idx = self.nvariants() + 1 + usize::from(self.metadata().offsets_dtype.is_some());
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(idx, &Validity::DTYPE, self.len()))
+    }
+
+    #[inline]
+    pub fn nvariants(&self) -> usize {
+        self.metadata().variant_lens.len()
+    }
+
+    pub fn variant(&self, idx: usize) -> Option<Array> {
+        let dtype = self.union_dtype().dtypes().get(idx)?;
+        self.array()
+            .child(idx + 1, dtype, self.metadata().variant_lens[idx])
+    }
+
+    pub fn variants(&self) -> impl Iterator<Item = Array> + '_ {
+        (0..self.nvariants()).map(move |idx| self.variant(idx).expect("missing variant"))
+    }
+
+    /// The type id and the index into its variant of the element at `index`.
+    pub fn variant_index(&self, index: usize) -> VortexResult<(u8, usize)> {
+        let type_id = index_at(&self.type_ids(), index)? as u8;
+        let offset = match self.offsets() {
+            None => index,
+            Some(offsets) => index_at(&offsets, index)?,
+        };
+        Ok((type_id, offset))
+    }
+
+    /// The type id and the index into its variant of every element.
+    pub(crate) fn variant_indices(&self) -> VortexResult<Vec<(u8, usize)>> {
+        let type_ids = self.type_ids().into_primitive()?;
+        let type_ids = type_ids.maybe_null_slice::<u8>();
+        match self.offsets() {
+            None => Ok(type_ids.iter().copied().zip(0..self.len()).collect()),
+            Some(offsets) => {
+                let offsets = offsets.into_primitive()?;
+                match_each_integer_ptype!(offsets.ptype(), |$O| {
+                    Ok(type_ids
+                        .iter()
+                        .copied()
+                        .zip(offsets.maybe_null_slice::<$O>().iter().map(|o| AsPrimitive::<usize>::as_(*o)))
+                        .collect())
+                })
+            }
+        }
+    }
+}
+
+/// Read a single integer, avoiding the construction of a scalar when the array is primitive.
+fn index_at(array: &Array, index: usize) -> VortexResult<usize> {
+    match PrimitiveArray::try_from(array) {
+        Ok(primitive) => match_each_integer_ptype!(primitive.ptype(), |$P| {
+            primitive
+                .maybe_null_slice::<$P>()
+                .get(index)
+                .map(|v| AsPrimitive::<usize>::as_(*v))
+                .ok_or_else(|| vortex_err!(OutOfBounds: index, 0, primitive.len()))
+        }),
+        Err(_) => usize::try_from(&scalar_at(array, index)?),
+    }
+}
+
+/// Check that every type id names a variant and every dense offset lies within its variant.
+fn validate_indices(
+    type_ids: &Array,
+    offsets: Option<&Array>,
+    variants: &[Array],
+) -> VortexResult<()> {
+    let type_ids = type_ids.clone().into_primitive()?;
+    let type_ids = type_ids.maybe_null_slice::<u8>();
+    if let Some(type_id) = type_ids.iter().find(|t| **t as usize >= variants.len()) {
+        vortex_bail!(
+            "Type id {} out of bounds for union of {} variants",
+            type_id,
+            variants.len()
+        );
+    }
+
+    let Some(offsets) = offsets else {
+        return Ok(());
+    };
+    let offsets = offsets.clone().into_primitive()?;
+    match_each_integer_ptype!(offsets.ptype(), |$O| {
+        for (type_id, offset) in type_ids.iter().zip(offsets.maybe_null_slice::<$O>()) {
+            let variant_len = variants[*type_id as usize].len();
+            if !usize::try_from(*offset).is_ok_and(|o| o < variant_len) {
+                vortex_bail!(
+                    "Dense union offset {} out of bounds for variant {} of length {}",
+                    offset,
+                    type_id,
+                    variant_len
+                );
+            }
+        }
+    });
+    Ok(())
+}
+
+impl ArrayTrait for UnionArray {}
+
+impl ArrayVariants for UnionArray {
+    fn as_union_array(&self) -> Option<&dyn UnionArrayTrait> {
+        Some(self)
+    }
+}
+
+impl UnionArrayTrait for UnionArray {}
+
+impl IntoCanonical for UnionArray {
+    /// UnionEncoding is the canonical form for a [DType::Union] array, so return self.
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        Ok(Canonical::Union(self))
+    }
+}
+
+impl ArrayValidity for UnionArray {
+    /// A union element is valid if it is not null itself and the value it refers to in its
+    /// variant is valid.
+    fn is_valid(&self, index: usize) -> bool {
+        if !self.dtype().is_nullable() {
+            return true;
+        }
+        if !self.validity().is_valid(index) {
+            return false;
+        }
+        let (type_id, offset) = self.variant_index(index).expect("variant index");
+        self.variant(type_id as usize)
+            .expect("missing variant")
+            .with_dyn(|a| a.is_valid(offset))
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        if !self.dtype().is_nullable() {
+            return LogicalValidity::AllValid(self.len());
+        }
+
+        let variant_validity = self
+            .variants()
+            .map(|v| {
+                v.with_dyn(|a| a.logical_validity())
+                    .to_null_buffer()
+                    .expect("null buffer")
+            })
+            .collect::<Vec<_>>();
+        let union_validity = self
+            .validity()
+            .to_logical(self.len())
+            .to_null_buffer()
+            .expect("null buffer");
+        let validity = self
+            .variant_indices()
+            .expect("variant indices")
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (type_id, offset))| {
+                union_validity
+                    .as_ref()
+                    .map_or(true, |nulls| nulls.is_valid(idx))
+                    && variant_validity[type_id as usize]
+                        .as_ref()
+                        .map_or(true, |nulls| nulls.is_valid(offset))
+            });
+        LogicalValidity::Array(BoolArray::from(BooleanBuffer::from_iter(validity)).into_array())
+    }
+}
+
+impl AcceptArrayVisitor for UnionArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("type_ids", &self.type_ids())?;
+        for (name, variant) in self.union_dtype().names().iter().zip(self.variants()) {
+            visitor.visit_child(name.as_ref(), &variant)?;
+        }
+        if let Some(offsets) = self.offsets() {
+            visitor.visit_child("offsets", &offsets)?;
+        }
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for UnionArray {}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType, UnionDType, UnionMode};
+
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::union::UnionArray;
+    use crate::array::VarBinViewArray;
+    use crate::validity::{ArrayValidity, Validity};
+    use crate::IntoArray;
+
+    fn union_dtype(mode: UnionMode) -> DType {
+        DType::Union(
+            UnionDType::try_new(
+                ["int".into(), "str".into()].into(),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::Nullable),
+                    DType::Utf8(Nullability::Nullable),
+                ],
+                mode,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        )
+    }
+
+    #[test]
+    fn dense_union() {
+        let union = UnionArray::try_new(
+            union_dtype(UnionMode::Dense),
+            PrimitiveArray::from(vec![0u8, 1, 0, 1]).into_array(),
+            Some(PrimitiveArray::from(vec![0u32, 0, 1, 1]).into_array()),
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i32), None]).into_array(),
+                VarBinViewArray::from_iter_nullable_str([Some("a"), Some("b")]).into_array(),
+            ],
+            Validity::AllValid,
+        )
+        .unwrap();
+
+        assert_eq!(union.len(), 4);
+        assert_eq!(union.nvariants(), 2);
+        assert!(union.is_valid(0));
+        assert!(!union.is_valid(2));
+        assert!(union.is_valid(3));
+    }
+
+    #[test]
+    fn union_validity() {
+        let union = UnionArray::try_new(
+            union_dtype(UnionMode::Sparse),
+            PrimitiveArray::from(vec![0u8, 1, 0]).into_array(),
+            None,
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i32), Some(0), None]).into_array(),
+                VarBinViewArray::from_iter_nullable_str([Some(""), Some("a"), Some("")])
+                    .into_array(),
+            ],
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap();
+
+        assert!(union.is_valid(0));
+        assert!(!union.is_valid(1));
+        assert!(!union.is_valid(2));
+        assert_eq!(
+            union.logical_validity().into_validity(),
+            Validity::from(vec![true, false, false])
+        );
+    }
+
+    #[test]
+    fn sparse_union_requires_equal_lengths() {
+        assert!(UnionArray::try_new(
+            union_dtype(UnionMode::Sparse),
+            PrimitiveArray::from(vec![0u8, 1]).into_array(),
+            None,
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i32), None]).into_array(),
+                VarBinViewArray::from_iter_nullable_str([Some("a")]).into_array(),
+            ],
+            Validity::AllValid,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn invalid_indices() {
+        let variants = || {
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i32), None]).into_array(),
+                VarBinViewArray::from_iter_nullable_str([Some("a"), Some("b")]).into_array(),
+            ]
+        };
+        assert!(UnionArray::try_new(
+            union_dtype(UnionMode::Sparse),
+            PrimitiveArray::from(vec![0u8, 2]).into_array(),
+            None,
+            variants(),
+            Validity::AllValid,
+        )
+        .is_err());
+        assert!(UnionArray::try_new(
+            union_dtype(UnionMode::Dense),
+            PrimitiveArray::from(vec![0u8, 1]).into_array(),
+            Some(PrimitiveArray::from(vec![0u32, 2]).into_array()),
+            variants(),
+            Validity::AllValid,
+        )
+        .is_err());
+        assert!(UnionArray::try_new(
+            union_dtype(UnionMode::Dense),
+            PrimitiveArray::from(vec![0u8, 1]).into_array(),
+            Some(PrimitiveArray::from(vec![0i32, -1]).into_array()),
+            variants(),
+            Validity::AllValid,
+        )
+        .is_err());
+    }
+}
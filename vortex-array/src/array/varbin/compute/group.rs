@@ -0,0 +1,17 @@
+use vortex_error::VortexResult;
+
+use crate::accessor::ArrayAccessor;
+use crate::array::{PrimitiveArray, VarBinArray};
+use crate::compute::group::group_values;
+use crate::compute::{GroupIdsFn, HashFn};
+use crate::{Array, AsArray};
+
+impl GroupIdsFn for VarBinArray {
+    fn group_ids(&self) -> VortexResult<(PrimitiveArray, Array)> {
+        let hashes = self.hash(0)?;
+        self.with_iterator(|iter| {
+            let values = iter.collect::<Vec<_>>();
+            group_values(self.as_array_ref(), &hashes, |a, b| values[a] == values[b])
+        })?
+    }
+}
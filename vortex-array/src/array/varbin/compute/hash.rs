@@ -0,0 +1,16 @@
+use vortex_error::VortexResult;
+
+use crate::accessor::ArrayAccessor;
+use crate::array::{PrimitiveArray, VarBinArray};
+use crate::compute::hash::{hash_bytes, null_hash};
+use crate::compute::HashFn;
+
+impl HashFn for VarBinArray {
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray> {
+        let hashes = self.with_iterator(|iter| {
+            iter.map(|value| value.map_or_else(|| null_hash(seed), |v| hash_bytes(seed, v)))
+                .collect::<Vec<_>>()
+        })?;
+        Ok(PrimitiveArray::from(hashes))
+    }
+}
@@ -2,16 +2,48 @@ use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::array::varbin::{varbin_scalar, VarBinArray};
-use crate::compute::unary::ScalarAtFn;
-use crate::compute::{ArrayCompute, SliceFn, TakeFn};
+use crate::compute::is_in::is_in_bytes;
+use crate::compute::unary::{fill_null_bytes, FillNullFn, ScalarAtFn};
+use crate::compute::{
+    ArrayCompute, FilterFn, FoldCaseFn, GroupIdsFn, HashFn, IsInFn, SliceFn, SortFn,
+    StringLengthFn, StringMatchFn, TakeFn,
+};
 use crate::validity::ArrayValidity;
-use crate::ArrayDType;
+use crate::{Array, ArrayDType};
 
 mod filter;
+mod group;
+mod hash;
 mod slice;
+mod sort;
+mod string;
 mod take;
 
 impl ArrayCompute for VarBinArray {
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn fold_case(&self) -> Option<&dyn FoldCaseFn> {
+        Some(self)
+    }
+
+    fn group_ids(&self) -> Option<&dyn GroupIdsFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -20,11 +52,35 @@ impl ArrayCompute for VarBinArray {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
+    fn string_length(&self) -> Option<&dyn StringLengthFn> {
+        Some(self)
+    }
+
+    fn string_match(&self) -> Option<&dyn StringMatchFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 }
 
+impl FillNullFn for VarBinArray {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array> {
+        fill_null_bytes(self, fill_value)
+    }
+}
+
+impl IsInFn for VarBinArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        is_in_bytes(self, values)
+    }
+}
+
 impl ScalarAtFn for VarBinArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         if self.is_valid(index) {
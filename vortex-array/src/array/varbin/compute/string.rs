@@ -0,0 +1,26 @@
+use vortex_error::VortexResult;
+
+use crate::array::VarBinArray;
+use crate::compute::string::{fold_values, match_values, value_lengths};
+use crate::compute::{
+    Case, FoldCaseFn, StringLength, StringLengthFn, StringMatchFn, StringMatcher,
+};
+use crate::{Array, ArrayDType};
+
+impl StringMatchFn for VarBinArray {
+    fn string_match(&self, matcher: &StringMatcher) -> VortexResult<Array> {
+        match_values(self, matcher)
+    }
+}
+
+impl StringLengthFn for VarBinArray {
+    fn string_length(&self, unit: StringLength) -> VortexResult<Array> {
+        value_lengths(self, self.dtype().nullability(), unit)
+    }
+}
+
+impl FoldCaseFn for VarBinArray {
+    fn fold_case(&self, case: Case) -> VortexResult<Array> {
+        fold_values(self, self.dtype(), case)
+    }
+}
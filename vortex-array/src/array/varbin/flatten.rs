@@ -1,10 +1,40 @@
-use vortex_error::VortexResult;
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::varbin::VarBinArray;
-use crate::{Canonical, IntoCanonical};
+use crate::array::varbinview::BinaryView;
+use crate::array::VarBinViewArray;
+use crate::{ArrayDType, Canonical, IntoArray, IntoArrayVariant, IntoCanonical};
 
 impl IntoCanonical for VarBinArray {
+    /// The views point into the bytes of this array, which are shared rather than copied.
     fn into_canonical(self) -> VortexResult<Canonical> {
-        Ok(Canonical::VarBin(self))
+        let bytes = self.bytes().into_primitive()?;
+        if u32::try_from(bytes.len()).is_err() {
+            vortex_bail!(
+                "VarBin data of {} bytes does not fit into a single view buffer",
+                bytes.len()
+            );
+        }
+        let data = bytes.maybe_null_slice::<u8>();
+        let offsets = self.offsets().into_primitive()?;
+        let views = match_each_integer_ptype!(offsets.ptype(), |$O| {
+            offsets
+                .maybe_null_slice::<$O>()
+                .windows(2)
+                .map(|w| {
+                    let (start, end) = (w[0] as usize, w[1] as usize);
+                    BinaryView::new(&data[start..end], 0, start as u32)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        VarBinViewArray::from_views(
+            &views,
+            vec![bytes.into_array()],
+            self.dtype().clone(),
+            self.validity(),
+        )
+        .map(Canonical::VarBinView)
     }
 }
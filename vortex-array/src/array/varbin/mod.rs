@@ -234,7 +234,7 @@ mod test {
     use crate::compute::slice;
     use crate::compute::unary::scalar_at;
     use crate::validity::Validity;
-    use crate::{Array, IntoArray};
+    use crate::{Array, IntoArray, IntoArrayVariant};
 
     fn binary_array() -> Array {
         let values = PrimitiveArray::from(
@@ -273,4 +273,29 @@ mod test {
             "hello world this is a long string".into()
         );
     }
+
+    #[test]
+    pub fn canonical_views() {
+        let binary_arr = slice(&binary_array(), 1, 2).unwrap();
+        let views = binary_arr.into_varbin_view().unwrap();
+        assert_eq!(views.len(), 1);
+        assert_eq!(
+            scalar_at(&views.into_array(), 0).unwrap(),
+            "hello world this is a long string".into()
+        );
+
+        let nullable = VarBinArray::from_iter(
+            [Some("a"), None, Some("a long string value")],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array();
+        #[allow(deprecated)]
+        let roundtrip = nullable.clone().into_varbin().unwrap().into_array();
+        for i in 0..3 {
+            assert_eq!(
+                scalar_at(&roundtrip, i).unwrap(),
+                scalar_at(&nullable, i).unwrap()
+            );
+        }
+    }
 }
@@ -0,0 +1,168 @@
+use vortex_dtype::{match_each_native_ptype, DType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::decimal::parse_decimal;
+use crate::array::varbinview::VarBinViewArray;
+use crate::array::{BoolArray, DecimalArray, PrimitiveArray};
+use crate::compute::unary::{cast_number, cast_validity, CastFn, CastMode};
+use crate::validity::Validity;
+use crate::{Array, ArrayDType, IntoArray};
+
+/// Strings are parsed into numbers, booleans and decimals.
+impl CastFn for VarBinViewArray {
+    fn cast(&self, dtype: &DType, mode: CastMode) -> VortexResult<Array> {
+        match (self.dtype(), dtype) {
+            (DType::Utf8(_), DType::Utf8(n))
+            | (DType::Binary(_), DType::Binary(n))
+            | (DType::Utf8(_), DType::Binary(n)) => Ok(Self::try_new(
+                self.views(),
+                self.buffers(),
+                dtype.clone(),
+                cast_validity(self.validity(), *n),
+            )?
+            .into_array()),
+            (DType::Binary(_), DType::Utf8(_)) => {
+                let values = self.parse_values(dtype, mode, |s| Some(Ok(s.to_string())))?;
+                cast_nullability(Self::from_iter_nullable_str(values).into_array(), dtype)
+            }
+            (DType::Utf8(_), DType::Primitive(ptype, n)) => match_each_native_ptype!(ptype, |$T| {
+                let values = self.parse_values(dtype, mode, |s| match s.trim().parse::<$T>() {
+                    Ok(v) => Some(Ok(v)),
+                    Err(_) if mode == CastMode::Saturating => s
+                        .trim()
+                        .parse::<f64>()
+                        .ok()
+                        .map(|v| cast_number::<f64, $T>(v, mode)),
+                    Err(_) => None,
+                })?;
+                Ok(match n {
+                    Nullability::Nullable => PrimitiveArray::from_nullable_vec(values),
+                    Nullability::NonNullable => PrimitiveArray::from_vec(
+                        values.into_iter().flatten().collect(),
+                        Validity::NonNullable,
+                    ),
+                }
+                .into_array())
+            }),
+            (DType::Utf8(_), DType::Bool(n)) => {
+                let values = self.parse_values(dtype, mode, |s| {
+                    match s.trim().to_ascii_lowercase().as_str() {
+                        "true" | "t" | "1" => Some(Ok(true)),
+                        "false" | "f" | "0" => Some(Ok(false)),
+                        _ => None,
+                    }
+                })?;
+                Ok(match n {
+                    Nullability::Nullable => BoolArray::from_iter(values),
+                    Nullability::NonNullable => {
+                        BoolArray::from(values.into_iter().flatten().collect::<Vec<_>>())
+                    }
+                }
+                .into_array())
+            }
+            (DType::Utf8(_), DType::Decimal(decimal, n)) => {
+                let values =
+                    self.parse_values(dtype, mode, |s| parse_decimal(s, *decimal, mode))?;
+                Ok(match n {
+                    Nullability::Nullable => DecimalArray::from_nullable_vec(values, *decimal),
+                    Nullability::NonNullable => DecimalArray::from_vec(
+                        values.into_iter().flatten().collect(),
+                        *decimal,
+                        Validity::NonNullable,
+                    ),
+                }
+                .into_array())
+            }
+            _ => vortex_bail!("Cannot cast {} to {}", self.dtype(), dtype),
+        }
+    }
+}
+
+impl VarBinViewArray {
+    /// Parse each valid value, where `parse` returns `None` for values it can't understand.
+    ///
+    /// Those become null in [CastMode::Saturating] if the target is nullable, and fail otherwise.
+    fn parse_values<T>(
+        &self,
+        dtype: &DType,
+        mode: CastMode,
+        parse: impl Fn(&str) -> Option<VortexResult<T>>,
+    ) -> VortexResult<Vec<Option<T>>> {
+        self.with_iterator(|iter| {
+            iter.map(|value| {
+                let Some(bytes) = value else {
+                    return Ok(None);
+                };
+                let text = std::str::from_utf8(bytes).ok();
+                match text.and_then(&parse) {
+                    Some(parsed) => parsed.map(Some),
+                    None if mode == CastMode::Saturating && dtype.is_nullable() => Ok(None),
+                    None => Err(vortex_err!(
+                        ComputeError: "Failed to parse {:?} as {}",
+                        String::from_utf8_lossy(bytes),
+                        dtype
+                    )),
+                }
+            })
+            .collect()
+        })?
+    }
+}
+
+fn cast_nullability(array: Array, dtype: &DType) -> VortexResult<Array> {
+    if dtype.is_nullable() {
+        return Ok(array);
+    }
+    let array = VarBinViewArray::try_from(array)?;
+    Ok(VarBinViewArray::try_new(
+        array.views(),
+        array.buffers(),
+        dtype.clone(),
+        Validity::NonNullable,
+    )?
+    .into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, DecimalDType, Nullability, PType};
+
+    use crate::array::{DecimalArray, VarBinViewArray};
+    use crate::compute::unary::{scalar_at, try_cast, try_cast_with_mode, CastMode};
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn parse_numbers() {
+        let strings =
+            VarBinViewArray::from_iter_nullable_str([Some(" 42"), Some("300"), Some("x"), None])
+                .into_array();
+        let u8s = DType::Primitive(PType::U8, Nullability::Nullable);
+        assert!(try_cast(&strings, &u8s).is_err());
+
+        let parsed = try_cast_with_mode(&strings, &u8s, CastMode::Saturating).unwrap();
+        assert_eq!(scalar_at(&parsed, 0).unwrap(), Some(42u8).into());
+        assert_eq!(scalar_at(&parsed, 1).unwrap(), Some(255u8).into());
+        assert!(scalar_at(&parsed, 2).unwrap().is_null());
+        assert!(scalar_at(&parsed, 3).unwrap().is_null());
+
+        let decimal = DecimalDType::try_new(6, 2).unwrap();
+        let strings = VarBinViewArray::from_iter_str(["-1.5", "20"]).into_array();
+        let parsed =
+            try_cast(&strings, &DType::Decimal(decimal, Nullability::NonNullable)).unwrap();
+        assert_eq!(
+            DecimalArray::try_from(parsed).unwrap().maybe_null_slice(),
+            &[-150, 2000]
+        );
+
+        let bools = VarBinViewArray::from_iter_str(["TRUE", "0"]).into_array();
+        let parsed = try_cast(&bools, &DType::Bool(Nullability::NonNullable))
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            parsed.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use arrow_buffer::BooleanBuffer;
+use vortex_error::VortexResult;
+use vortex_expr::Operator;
+
+use crate::accessor::ArrayAccessor;
+use crate::array::varbinview::VarBinViewArray;
+use crate::array::BoolArray;
+use crate::compute::CompareFn;
+use crate::{Array, IntoArray, IntoArrayVariant};
+
+impl CompareFn for VarBinViewArray {
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        let other = other.clone().into_varbin_view()?;
+        let predicate_fn = bytes_predicate(operator);
+
+        // Comparisons involving a null value evaluate to false.
+        let matches = self.with_iterator(|lhs| {
+            other.with_iterator(|rhs| {
+                BooleanBuffer::from_iter(lhs.zip(rhs).map(|(l, r)| match (l, r) {
+                    (Some(l), Some(r)) => predicate_fn(l, r),
+                    _ => false,
+                }))
+            })
+        })??;
+
+        Ok(BoolArray::from(matches).into_array())
+    }
+}
+
+/// Lexicographic byte-wise comparison for the given operator.
+pub(super) fn bytes_predicate(operator: Operator) -> fn(&[u8], &[u8]) -> bool {
+    match operator {
+        Operator::Eq => |l, r| l == r,
+        Operator::NotEq => |l, r| l != r,
+        Operator::Gt => |l, r| l > r,
+        Operator::Gte => |l, r| l >= r,
+        Operator::Lt => |l, r| l < r,
+        Operator::Lte => |l, r| l <= r,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_expr::Operator;
+
+    use crate::array::{VarBinArray, VarBinViewArray};
+    use crate::compute::compare;
+    use crate::IntoArrayVariant;
+
+    fn to_vec(array: crate::Array) -> Vec<bool> {
+        array.into_bool().unwrap().boolean_buffer().iter().collect()
+    }
+
+    #[test]
+    fn compare_views() {
+        let lhs = VarBinViewArray::from_iter_nullable_str([
+            Some("apple"),
+            Some("a string that does not fit inline"),
+            None,
+            Some("pear"),
+        ]);
+        let rhs = VarBinViewArray::from_iter_nullable_str([
+            Some("apple"),
+            Some("a string that does not fit inline!"),
+            Some("fig"),
+            Some("banana"),
+        ]);
+
+        assert_eq!(
+            to_vec(compare(lhs.array(), rhs.array(), Operator::Eq).unwrap()),
+            vec![true, false, false, false]
+        );
+        assert_eq!(
+            to_vec(compare(lhs.array(), rhs.array(), Operator::Lt).unwrap()),
+            vec![false, true, false, false]
+        );
+        assert_eq!(
+            to_vec(compare(lhs.array(), rhs.array(), Operator::Gte).unwrap()),
+            vec![true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn compare_with_varbin() {
+        let lhs = VarBinViewArray::from_iter_str(["a", "b", "c"]);
+        let rhs = VarBinArray::from(vec!["a", "c", "b"]);
+
+        assert_eq!(
+            to_vec(compare(rhs.array(), lhs.array(), Operator::Gt).unwrap()),
+            vec![false, true, false]
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use vortex_error::{vortex_err, VortexError, VortexResult};
+
+use crate::array::varbinview::VarBinViewArray;
+use crate::compute::FilterFn;
+use crate::validity::filter_validity;
+use crate::{Array, IntoArray};
+
+impl FilterFn for VarBinViewArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let validity = filter_validity(self.validity(), predicate)?;
+        let views = self.view_slice();
+        let filtered = predicate.with_dyn(|p| {
+            let predicate = p.as_bool_array().ok_or_else(|| {
+                vortex_err!(
+                    NotImplemented: "as_bool_array",
+                    predicate.encoding().id()
+                )
+            })?;
+            let mut filtered = Vec::with_capacity(predicate.true_count());
+            predicate.maybe_null_slices_iter().for_each(|(start, end)| {
+                filtered.extend_from_slice(&views[start..end]);
+            });
+            Ok::<_, VortexError>(filtered)
+        })?;
+
+        self.with_views(&filtered, validity).map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::array::{BoolArray, VarBinViewArray};
+    use crate::compute::filter;
+    use crate::compute::unary::scalar_at;
+
+    #[test]
+    fn filter_views() {
+        let arr = VarBinViewArray::from_iter_str([
+            "hello",
+            "world",
+            "a string long enough to live in a data buffer",
+            "!",
+        ]);
+        let predicate = BoolArray::from(vec![false, true, true, false]);
+
+        let filtered = filter(arr.array(), predicate.array()).unwrap();
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(scalar_at(&filtered, 0).unwrap(), Scalar::from("world"));
+        assert_eq!(
+            scalar_at(&filtered, 1).unwrap(),
+            Scalar::from("a string long enough to live in a data buffer")
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use std::ops::{BitAnd, BitOr};
+
+use arrow_buffer::BooleanBuffer;
+use vortex_buffer::Buffer;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::{Disjunction, Predicate, Value};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::varbinview::compute::compare::bytes_predicate;
+use crate::array::varbinview::VarBinViewArray;
+use crate::array::BoolArray;
+use crate::compute::FilterIndicesFn;
+use crate::{Array, ArrayDType, IntoArray};
+
+impl FilterIndicesFn for VarBinViewArray {
+    fn filter_indices(&self, disjunction: &Disjunction) -> VortexResult<Array> {
+        let conjunction_indices = disjunction.iter().map(|conj| {
+            conj.iter()
+                .map(|pred| indices_matching_predicate(self, pred))
+                .reduce(|a, b| Ok(a?.bitand(&b?)))
+                .ok_or_else(|| vortex_err!("Empty conjunction"))?
+        });
+
+        let bitset: VortexResult<BooleanBuffer> = conjunction_indices
+            .reduce(|a, b| Ok(a?.bitor(&b?)))
+            .unwrap_or_else(|| Ok(BooleanBuffer::new_set(self.len())));
+
+        Ok(BoolArray::from(bitset?).into_array())
+    }
+}
+
+fn indices_matching_predicate(
+    arr: &VarBinViewArray,
+    predicate: &Predicate,
+) -> VortexResult<BooleanBuffer> {
+    if !predicate.lhs.path().is_empty() {
+        vortex_bail!("Invalid path for varbinview array")
+    }
+
+    let rhs = match &predicate.rhs {
+        Value::Field(_) => {
+            vortex_bail!("Cannot apply field reference to varbinview array")
+        }
+        Value::Literal(scalar) => scalar,
+    };
+
+    let rhs: Option<Buffer> = match arr.dtype() {
+        DType::Utf8(_) => rhs.value().as_buffer_string()?.map(Buffer::from),
+        _ => rhs.value().as_buffer()?,
+    };
+    let Some(rhs) = rhs else {
+        // Nothing compares to a null literal.
+        return Ok(BooleanBuffer::new_unset(arr.len()));
+    };
+
+    let predicate_fn = bytes_predicate(predicate.op);
+    arr.with_iterator(|iter| {
+        BooleanBuffer::from_iter(iter.map(|v| v.map_or(false, |v| predicate_fn(v, rhs.as_ref()))))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::field::FieldPath;
+    use vortex_expr::{lit, Conjunction, FieldPathOperations};
+
+    use super::*;
+    use crate::IntoArrayVariant;
+
+    fn apply(arr: &VarBinViewArray, conjunctions: Vec<Conjunction>) -> Vec<bool> {
+        arr.filter_indices(&Disjunction::from_iter(conjunctions))
+            .unwrap()
+            .into_bool()
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn filter_literal() {
+        let arr = VarBinViewArray::from_iter_nullable_str([
+            Some("alpha"),
+            None,
+            Some("a string that is much too long to inline"),
+            Some("zeta"),
+        ]);
+        let field = FieldPath::root();
+
+        assert_eq!(
+            apply(&arr, vec![Conjunction::from(field.equal(lit("zeta")))]),
+            vec![false, false, false, true]
+        );
+        assert_eq!(
+            apply(&arr, vec![Conjunction::from(field.lt(lit("b")))]),
+            vec![true, false, true, false]
+        );
+        assert_eq!(
+            apply(
+                &arr,
+                vec![
+                    Conjunction::from(field.gt(lit("a"))),
+                    Conjunction::from(field.equal(lit("alpha")))
+                ]
+            ),
+            vec![true, false, true, true]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use vortex_buffer::Buffer;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::varbin::varbin_scalar;
+use crate::array::varbinview::{VarBinViewArray, VIEW_SIZE};
+use crate::compute::is_in::is_in_bytes;
+use crate::compute::unary::{fill_null_bytes, CastFn, FillNullFn, ScalarAtFn};
+use crate::compute::{
+    slice, ArrayCompute, CompareFn, FilterFn, FilterIndicesFn, FoldCaseFn, GroupIdsFn, HashFn,
+    IsInFn, SliceFn, SortFn, StringLengthFn, StringMatchFn, TakeFn,
+};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray};
+
+mod cast;
+mod compare;
+mod filter;
+mod filter_indices;
+mod group;
+mod hash;
+mod sort;
+mod string;
+mod take;
+
+impl ArrayCompute for VarBinViewArray {
+    fn cast(&self) -> Option<&dyn CastFn> {
+        Some(self)
+    }
+
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn filter_indices(&self) -> Option<&dyn FilterIndicesFn> {
+        Some(self)
+    }
+
+    fn fold_case(&self) -> Option<&dyn FoldCaseFn> {
+        Some(self)
+    }
+
+    fn group_ids(&self) -> Option<&dyn GroupIdsFn> {
+        Some(self)
+    }
+
+    fn hash(&self) -> Option<&dyn HashFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
+    fn string_length(&self) -> Option<&dyn StringLengthFn> {
+        Some(self)
+    }
+
+    fn string_match(&self) -> Option<&dyn StringMatchFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl FillNullFn for VarBinViewArray {
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array> {
+        fill_null_bytes(self, fill_value)
+    }
+}
+
+impl IsInFn for VarBinViewArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        is_in_bytes(self, values)
+    }
+}
+
+impl ScalarAtFn for VarBinViewArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if self.is_valid(index) {
+            self.bytes_at(index)
+                .map(|bytes| varbin_scalar(Buffer::from(bytes), self.dtype()))
+        } else {
+            Ok(Scalar::null(self.dtype().clone()))
+        }
+    }
+}
+
+impl SliceFn for VarBinViewArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        Ok(Self::try_new(
+            slice(&self.views(), start * VIEW_SIZE, stop * VIEW_SIZE)?,
+            self.buffers(),
+            self.dtype().clone(),
+            self.validity().slice(start, stop)?,
+        )?
+        .into_array())
+    }
+}
@@ -0,0 +1,11 @@
+use vortex_error::VortexResult;
+
+use crate::array::{PrimitiveArray, VarBinViewArray};
+use crate::compute::sort::sort_bytes;
+use crate::compute::{SortFn, SortOptions};
+
+impl SortFn for VarBinViewArray {
+    fn sort_indices(&self, options: SortOptions) -> VortexResult<PrimitiveArray> {
+        sort_bytes(self, options)
+    }
+}
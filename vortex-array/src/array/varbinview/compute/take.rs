@@ -0,0 +1,66 @@
+use num_traits::AsPrimitive;
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::VortexResult;
+
+use crate::array::varbinview::VarBinViewArray;
+use crate::compute::TakeFn;
+use crate::{Array, IntoArray, IntoArrayVariant};
+
+impl TakeFn for VarBinViewArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let validity = self.validity().take(indices)?;
+        let indices = indices.clone().into_primitive()?;
+        let views = self.view_slice();
+        let taken = match_each_integer_ptype!(indices.ptype(), |$I| {
+            indices
+                .maybe_null_slice::<$I>()
+                .iter()
+                .map(|idx| views[AsPrimitive::<usize>::as_(*idx)])
+                .collect::<Vec<_>>()
+        });
+
+        self.with_views(&taken, validity).map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::accessor::ArrayAccessor;
+    use crate::array::{PrimitiveArray, VarBinViewArray};
+    use crate::compute::take;
+    use crate::IntoArrayVariant;
+
+    #[test]
+    fn take_inlined_and_referenced() {
+        let arr = VarBinViewArray::from_iter_nullable_str([
+            Some("short"),
+            None,
+            Some("a much longer string that is not inlined"),
+            Some("tiny"),
+        ]);
+
+        let taken = take(
+            arr.array(),
+            PrimitiveArray::from(vec![2u32, 1, 0, 2]).array(),
+        )
+        .unwrap()
+        .into_varbin_view()
+        .unwrap();
+
+        let values = taken
+            .with_iterator(|iter| {
+                iter.map(|v| v.map(|b| String::from_utf8(b.to_vec()).unwrap()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(
+            values,
+            vec![
+                Some("a much longer string that is not inlined".to_string()),
+                None,
+                Some("short".to_string()),
+                Some("a much longer string that is not inlined".to_string()),
+            ]
+        );
+    }
+}
@@ -1,26 +1,22 @@
 use std::fmt::{Debug, Formatter};
-use std::ops::Deref;
-use std::sync::Arc;
 use std::{mem, slice};
 
 use ::serde::{Deserialize, Serialize};
 use arrow_array::builder::{BinaryViewBuilder, StringViewBuilder};
-use arrow_array::{ArrayRef, BinaryViewArray, StringViewArray};
-use arrow_buffer::ScalarBuffer;
-use arrow_schema::DataType;
+use arrow_buffer::{Buffer as ArrowBuffer, MutableBuffer};
 use itertools::Itertools;
-use vortex_dtype::{DType, PType};
-use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_buffer::Buffer;
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::primitive::PrimitiveArray;
-use crate::array::varbin::VarBinArray;
 use crate::arrow::FromArrowArray;
 use crate::compute::slice;
 use crate::stats::StatsSet;
 use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use crate::{
-    impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoArrayVariant,
+    impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoArray, IntoArrayVariant,
     IntoCanonical,
 };
 
@@ -87,9 +83,32 @@ impl BinaryView {
         unsafe { self.inlined.size as usize }
     }
 
+    /// A view of `value`, inlined if it is short enough and otherwise pointing at `offset` in the
+    /// data buffer at `buffer_index`.
+    pub fn new(value: &[u8], buffer_index: u32, offset: u32) -> Self {
+        if value.len() <= Self::MAX_INLINED_SIZE {
+            return Self {
+                inlined: Inlined::new(value),
+            };
+        }
+        let mut prefix = [0u8; 4];
+        prefix.copy_from_slice(&value[..4]);
+        Self {
+            _ref: Ref::new(value.len() as u32, prefix, buffer_index, offset),
+        }
+    }
+
     pub fn is_inlined(&self) -> bool {
         unsafe { self.inlined.size <= Self::MAX_INLINED_SIZE as u32 }
     }
+
+    /// Shift the data buffer index of a non-inlined view by `offset`.
+    pub(crate) fn rebase_buffer(mut self, offset: u32) -> Self {
+        if !self.is_inlined() {
+            unsafe { self._ref.buffer_index += offset };
+        }
+        self
+    }
 }
 
 impl Debug for BinaryView {
@@ -156,7 +175,7 @@ impl VarBinViewArray {
         Self::try_from_parts(dtype, num_views, metadata, children.into(), StatsSet::new())
     }
 
-    fn view_slice(&self) -> &[BinaryView] {
+    pub(crate) fn view_slice(&self) -> &[BinaryView] {
         unsafe {
             slice::from_raw_parts(
                 PrimitiveArray::try_from(self.views())
@@ -172,6 +191,37 @@ impl VarBinViewArray {
         self.view_slice()[index]
     }
 
+    /// Create a new array from a slice of views over the given data buffers.
+    pub(crate) fn from_views(
+        views: &[BinaryView],
+        buffers: Vec<Array>,
+        dtype: DType,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        let view_bytes =
+            unsafe { slice::from_raw_parts(views.as_ptr() as *const u8, views.len() * VIEW_SIZE) };
+        // Copy into an aligned buffer so the views can be reinterpreted as `BinaryView`s.
+        let mut buffer = MutableBuffer::with_capacity(view_bytes.len());
+        buffer.extend_from_slice(view_bytes);
+
+        Self::try_new(
+            PrimitiveArray::new(
+                Buffer::from(ArrowBuffer::from(buffer)),
+                PType::U8,
+                Validity::NonNullable,
+            )
+            .into_array(),
+            buffers,
+            dtype,
+            validity,
+        )
+    }
+
+    /// Create a new array holding the given views, sharing the data buffers of this array.
+    fn with_views(&self, views: &[BinaryView], validity: Validity) -> VortexResult<Self> {
+        Self::from_views(views, self.buffers(), self.dtype().clone(), validity)
+    }
+
     #[inline]
     pub fn views(&self) -> Array {
         self.array()
@@ -186,6 +236,13 @@ impl VarBinViewArray {
             .expect("Missing data buffer")
     }
 
+    /// The data buffers referenced by non-inlined views.
+    pub fn buffers(&self) -> Vec<Array> {
+        (0..self.metadata().data_lens.len())
+            .map(|i| self.bytes(i))
+            .collect()
+    }
+
     pub fn validity(&self) -> Validity {
         self.metadata().validity.to_validity(self.array().child(
             self.metadata().data_lens.len() + 1,
@@ -235,6 +292,20 @@ impl VarBinViewArray {
         VarBinViewArray::try_from(array).expect("should be var bin view array")
     }
 
+    /// Create an array of the UTF-8 or binary `dtype`, where `None` values are null.
+    #[allow(clippy::same_name_method)]
+    pub fn from_iter<T: AsRef<[u8]>, I: IntoIterator<Item = Option<T>>>(
+        iter: I,
+        dtype: DType,
+    ) -> Self {
+        let array = Self::from_iter_nullable_bin(iter);
+        let validity = match dtype.nullability() {
+            Nullability::NonNullable => Validity::NonNullable,
+            Nullability::Nullable => array.validity(),
+        };
+        Self::try_new(array.views(), array.buffers(), dtype, validity).expect("should be valid")
+    }
+
     pub fn bytes_at(&self, index: usize) -> VortexResult<Vec<u8>> {
         let view = self.view_at(index);
         unsafe {
@@ -257,55 +328,7 @@ impl ArrayTrait for VarBinViewArray {}
 
 impl IntoCanonical for VarBinViewArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
-        let nullable = self.dtype().is_nullable();
-        let arrow_self = as_arrow(self);
-        let arrow_varbin = arrow_cast::cast(arrow_self.deref(), &DataType::Utf8)
-            .map_err(VortexError::ArrowError)?;
-        let vortex_array = Array::from_arrow(arrow_varbin, nullable);
-
-        Ok(Canonical::VarBin(VarBinArray::try_from(&vortex_array)?))
-    }
-}
-
-fn as_arrow(var_bin_view: VarBinViewArray) -> ArrayRef {
-    // Views should be buffer of u8
-    let views = var_bin_view
-        .views()
-        .into_primitive()
-        .expect("views must be primitive");
-    assert_eq!(views.ptype(), PType::U8);
-    let nulls = var_bin_view
-        .logical_validity()
-        .to_null_buffer()
-        .expect("null buffer");
-
-    let data = (0..var_bin_view.metadata().data_lens.len())
-        .map(|i| var_bin_view.bytes(i).into_primitive())
-        .collect::<VortexResult<Vec<_>>>()
-        .expect("bytes arrays must be primitive");
-    if !data.is_empty() {
-        assert_eq!(data[0].ptype(), PType::U8);
-        assert!(data.iter().map(|d| d.ptype()).all_equal());
-    }
-
-    let data = data
-        .iter()
-        .map(|p| p.buffer().clone().into_arrow())
-        .collect::<Vec<_>>();
-
-    // Switch on Arrow DType.
-    match var_bin_view.dtype() {
-        DType::Binary(_) => Arc::new(BinaryViewArray::new(
-            ScalarBuffer::<u128>::from(views.buffer().clone().into_arrow()),
-            data,
-            nulls,
-        )),
-        DType::Utf8(_) => Arc::new(StringViewArray::new(
-            ScalarBuffer::<u128>::from(views.buffer().clone().into_arrow()),
-            data,
-            nulls,
-        )),
-        _ => panic!("expected utf8 or binary, got {}", var_bin_view.dtype()),
+        Ok(Canonical::VarBinView(self))
     }
 }
 
@@ -397,7 +420,7 @@ mod test {
         let binary_arr = VarBinViewArray::from_iter_str(["string1", "string2"]);
 
         let flattened = binary_arr.into_canonical().unwrap();
-        assert!(matches!(flattened, Canonical::VarBin(_)));
+        assert!(matches!(flattened, Canonical::VarBinView(_)));
 
         let var_bin = flattened.into();
         assert_eq!(scalar_at(&var_bin, 0).unwrap(), Scalar::from("string1"));
@@ -1,29 +1,35 @@
 use arrow_array::array::{
     Array as ArrowArray, ArrayRef as ArrowArrayRef, ArrowPrimitiveType,
-    BooleanArray as ArrowBooleanArray, GenericByteArray, NullArray as ArrowNullArray,
+    BooleanArray as ArrowBooleanArray, FixedSizeListArray as ArrowFixedSizeListArray,
+    GenericByteArray, GenericListArray, MapArray as ArrowMapArray, NullArray as ArrowNullArray,
     OffsetSizeTrait, PrimitiveArray as ArrowPrimitiveArray, StructArray as ArrowStructArray,
+    UnionArray as ArrowUnionArray,
 };
-use arrow_array::cast::{as_null_array, AsArray};
+use arrow_array::cast::{as_null_array, as_union_array, AsArray};
 use arrow_array::types::{
-    ByteArrayType, ByteViewType, Date32Type, Date64Type, DurationMicrosecondType,
-    DurationMillisecondType, DurationNanosecondType, DurationSecondType, Float16Type, Float32Type,
-    Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, Time32MillisecondType,
+    ByteArrayType, ByteViewType, Date32Type, Date64Type, Decimal128Type, Decimal256Type,
+    DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType, DurationSecondType,
+    Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+    IntervalDayTimeType, IntervalMonthDayNanoType, IntervalYearMonthType, Time32MillisecondType,
     Time32SecondType, Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType,
     TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type,
     UInt64Type, UInt8Type,
 };
-use arrow_array::{BinaryViewArray, GenericByteViewArray, StringViewArray};
+use arrow_array::{Decimal128Array, Decimal256Array, GenericByteViewArray};
 use arrow_buffer::buffer::{NullBuffer, OffsetBuffer};
 use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
-use arrow_schema::{DataType, TimeUnit as ArrowTimeUnit};
+use arrow_schema::{DataType, Field, IntervalUnit as ArrowIntervalUnit, TimeUnit as ArrowTimeUnit};
 use itertools::Itertools;
-use vortex_dtype::{DType, NativePType, PType};
+use vortex_dtype::{DType, DecimalDType, NativePType, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
 use crate::array::temporal::TemporalArray;
 use crate::array::{
-    BoolArray, NullArray, PrimitiveArray, StructArray, TimeUnit, VarBinArray, VarBinViewArray,
+    BoolArray, DecimalArray, ExtensionArray, FixedSizeListArray, IntervalUnit, ListArray, MapArray,
+    NullArray, PrimitiveArray, StructArray, TimeUnit, UnionArray, VarBinArray, VarBinViewArray,
+    ARROW_EXTENSION_NAME_KEY,
 };
-use crate::arrow::FromArrowArray;
+use crate::arrow::{FromArrowArray, FromArrowType, TryFromArrowArray, TryFromArrowType};
 use crate::stats::{Stat, Statistics};
 use crate::validity::Validity;
 use crate::{Array, ArrayData};
@@ -96,8 +102,12 @@ where
             }
             DataType::Date32 => TemporalArray::new_date(arr.into(), TimeUnit::D).into(),
             DataType::Date64 => TemporalArray::new_date(arr.into(), TimeUnit::Ms).into(),
-            DataType::Duration(_) => unimplemented!(),
-            DataType::Interval(_) => unimplemented!(),
+            DataType::Duration(time_unit) => {
+                TemporalArray::new_duration(arr.into(), from_arrow_time_unit(time_unit)).into()
+            }
+            DataType::Interval(ArrowIntervalUnit::YearMonth) => {
+                TemporalArray::new_interval(arr.into(), IntervalUnit::YearMonth).into()
+            }
             _ => panic!("Invalid data type for PrimitiveArray"),
         }
     }
@@ -169,8 +179,9 @@ impl FromArrowArray<&ArrowStructArray> for Array {
                 .columns()
                 .iter()
                 .zip(value.fields())
-                .map(|(c, field)| Self::from_arrow(c.clone(), field.is_nullable()))
-                .collect(),
+                .map(|(c, field)| from_arrow_field(c.clone(), field))
+                .collect::<VortexResult<Vec<_>>>()
+                .unwrap_or_else(|err| panic!("{}", err)),
             value.len(),
             nulls(value.nulls(), nullable),
         )
@@ -179,6 +190,194 @@ impl FromArrowArray<&ArrowStructArray> for Array {
     }
 }
 
+impl<O> FromArrowArray<&GenericListArray<O>> for Array
+where
+    O: NativePType + OffsetSizeTrait,
+{
+    fn from_arrow(value: &GenericListArray<O>, nullable: bool) -> Self {
+        let element_nullable = match value.data_type() {
+            DataType::List(field) | DataType::LargeList(field) => field.is_nullable(),
+            _ => panic!("Invalid data type for ListArray"),
+        };
+        ListArray::try_new(
+            ArrayData::from(value.offsets().clone()).into(),
+            Self::from_arrow(value.values().clone(), element_nullable),
+            nulls(value.nulls(), nullable),
+        )
+        .unwrap()
+        .into()
+    }
+}
+
+impl FromArrowArray<&ArrowMapArray> for Array {
+    fn from_arrow(value: &ArrowMapArray, nullable: bool) -> Self {
+        let (entry_fields, keys_sorted) = match value.data_type() {
+            DataType::Map(field, keys_sorted) => match field.data_type() {
+                DataType::Struct(fields) => (fields.clone(), *keys_sorted),
+                _ => panic!("Invalid entries type for MapArray"),
+            },
+            _ => panic!("Invalid data type for MapArray"),
+        };
+        MapArray::try_new(
+            ArrayData::from(value.offsets().clone()).into(),
+            Self::from_arrow(value.keys().clone(), entry_fields[0].is_nullable()),
+            Self::from_arrow(value.values().clone(), entry_fields[1].is_nullable()),
+            keys_sorted,
+            nulls(value.nulls(), nullable),
+        )
+        .unwrap()
+        .into()
+    }
+}
+
+impl FromArrowArray<&ArrowFixedSizeListArray> for Array {
+    fn from_arrow(value: &ArrowFixedSizeListArray, nullable: bool) -> Self {
+        let element_nullable = match value.data_type() {
+            DataType::FixedSizeList(field, _) => field.is_nullable(),
+            _ => panic!("Invalid data type for FixedSizeListArray"),
+        };
+        FixedSizeListArray::try_new(
+            Self::from_arrow(value.values().clone(), element_nullable),
+            value.value_length() as u32,
+            value.len(),
+            nulls(value.nulls(), nullable),
+        )
+        .unwrap()
+        .into()
+    }
+}
+
+impl FromArrowArray<&ArrowUnionArray> for Array {
+    fn from_arrow(value: &ArrowUnionArray, nullable: bool) -> Self {
+        let DataType::Union(fields, _) = value.data_type() else {
+            panic!("Invalid data type for UnionArray")
+        };
+        // Arrow type ids are arbitrary, while Vortex type ids are the position of the variant.
+        let mut positions = [0u8; 128];
+        for (position, (type_id, _)) in fields.iter().enumerate() {
+            positions[type_id as usize] = position as u8;
+        }
+        let type_ids = value
+            .type_ids()
+            .iter()
+            .map(|type_id| positions[*type_id as usize])
+            .collect_vec();
+        let offsets = value.offsets().map(|offsets| {
+            PrimitiveArray::from_vec(offsets.to_vec(), Validity::NonNullable).into()
+        });
+        let variants = fields
+            .iter()
+            .map(|(type_id, field)| {
+                Self::from_arrow(value.child(type_id).clone(), field.is_nullable())
+            })
+            .collect_vec();
+
+        UnionArray::try_new(
+            DType::from_arrow(&Field::new("", value.data_type().clone(), nullable)),
+            PrimitiveArray::from(type_ids).into(),
+            offsets,
+            variants,
+            // Arrow unions have no validity of their own, their nulls live in the variants.
+            nulls(None, nullable),
+        )
+        .unwrap()
+        .into()
+    }
+}
+
+fn decimal_dtype(data_type: &DataType) -> VortexResult<DecimalDType> {
+    match DType::try_from_arrow(&Field::new("", data_type.clone(), true))? {
+        DType::Decimal(decimal_dtype, _) => Ok(decimal_dtype),
+        _ => unreachable!("{} is not a decimal type", data_type),
+    }
+}
+
+fn from_arrow_decimal128(value: &Decimal128Array, nullable: bool) -> VortexResult<Array> {
+    Ok(DecimalArray::try_new(
+        value.values().clone().into_inner().into(),
+        decimal_dtype(value.data_type())?,
+        nulls(value.nulls(), nullable),
+    )?
+    .into())
+}
+
+/// Convert an Arrow column, wrapping it in an [ExtensionArray] if its field is tagged with a
+/// registered extension type.
+pub(crate) fn from_arrow_field(array: ArrowArrayRef, field: &Field) -> VortexResult<Array> {
+    let storage = Array::try_from_arrow(array, field.is_nullable())?;
+    match DType::try_from_arrow(field)? {
+        DType::Extension(ext_dtype, _)
+            if field.metadata().contains_key(ARROW_EXTENSION_NAME_KEY) =>
+        {
+            ExtensionArray::try_new(ext_dtype, storage).map(Array::from)
+        }
+        _ => Ok(storage),
+    }
+}
+
+/// Arrow stores `DayTime` intervals as packed structs, Vortex splits them into one child per part.
+fn from_arrow_interval_day_time(
+    value: &ArrowPrimitiveArray<IntervalDayTimeType>,
+    nullable: bool,
+) -> VortexResult<Array> {
+    let (days, milliseconds): (Vec<i32>, Vec<i32>) = value
+        .values()
+        .iter()
+        .map(|v| (v.days, v.milliseconds))
+        .unzip();
+    let storage = StructArray::try_new(
+        ["days".into(), "milliseconds".into()].into(),
+        vec![
+            PrimitiveArray::from(days).into(),
+            PrimitiveArray::from(milliseconds).into(),
+        ],
+        value.len(),
+        nulls(value.nulls(), nullable),
+    )?;
+    Ok(TemporalArray::new_interval(storage.into(), IntervalUnit::DayTime).into())
+}
+
+/// Arrow stores `MonthDayNano` intervals as packed structs, Vortex splits them into one child per
+/// part.
+fn from_arrow_interval_month_day_nano(
+    value: &ArrowPrimitiveArray<IntervalMonthDayNanoType>,
+    nullable: bool,
+) -> VortexResult<Array> {
+    let mut months = Vec::with_capacity(value.len());
+    let mut days = Vec::with_capacity(value.len());
+    let mut nanoseconds = Vec::with_capacity(value.len());
+    for v in value.values().iter() {
+        months.push(v.months);
+        days.push(v.days);
+        nanoseconds.push(v.nanoseconds);
+    }
+    let storage = StructArray::try_new(
+        ["months".into(), "days".into(), "nanoseconds".into()].into(),
+        vec![
+            PrimitiveArray::from(months).into(),
+            PrimitiveArray::from(days).into(),
+            PrimitiveArray::from(nanoseconds).into(),
+        ],
+        value.len(),
+        nulls(value.nulls(), nullable),
+    )?;
+    Ok(TemporalArray::new_interval(storage.into(), IntervalUnit::MonthDayNano).into())
+}
+
+/// Vortex decimals are stored in 128 bits, so each value must be narrowed.
+fn from_arrow_decimal256(value: &Decimal256Array, nullable: bool) -> VortexResult<Array> {
+    let decimal_dtype = decimal_dtype(value.data_type())?;
+    let values = value
+        .values()
+        .iter()
+        .map(|v| {
+            v.to_i128()
+                .ok_or_else(|| vortex_err!("Decimal256 value {} does not fit in 128 bits", v))
+        })
+        .collect::<VortexResult<Vec<_>>>()?;
+    Ok(DecimalArray::from_vec(values, decimal_dtype, nulls(value.nulls(), nullable)).into())
+}
+
 impl FromArrowArray<&ArrowNullArray> for Array {
     fn from_arrow(value: &ArrowNullArray, nullable: bool) -> Self {
         assert!(nullable);
@@ -205,7 +404,13 @@ fn nulls(nulls: Option<&NullBuffer>, nullable: bool) -> Validity {
 
 impl FromArrowArray<ArrowArrayRef> for Array {
     fn from_arrow(array: ArrowArrayRef, nullable: bool) -> Self {
-        match array.data_type() {
+        Self::try_from_arrow(array, nullable).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl TryFromArrowArray<ArrowArrayRef> for Array {
+    fn try_from_arrow(array: ArrowArrayRef, nullable: bool) -> VortexResult<Self> {
+        Ok(match array.data_type() {
             DataType::Boolean => Self::from_arrow(array.as_boolean(), nullable),
             DataType::UInt8 => Self::from_arrow(array.as_primitive::<UInt8Type>(), nullable),
             DataType::UInt16 => Self::from_arrow(array.as_primitive::<UInt16Type>(), nullable),
@@ -222,15 +427,20 @@ impl FromArrowArray<ArrowArrayRef> for Array {
             DataType::LargeUtf8 => Self::from_arrow(array.as_string::<i64>(), nullable),
             DataType::Binary => Self::from_arrow(array.as_binary::<i32>(), nullable),
             DataType::LargeBinary => Self::from_arrow(array.as_binary::<i64>(), nullable),
-            DataType::BinaryView => Self::from_arrow(
-                array.as_any().downcast_ref::<BinaryViewArray>().unwrap(),
-                nullable,
-            ),
-            DataType::Utf8View => Self::from_arrow(
-                array.as_any().downcast_ref::<StringViewArray>().unwrap(),
-                nullable,
-            ),
+            DataType::BinaryView => Self::from_arrow(array.as_binary_view(), nullable),
+            DataType::Utf8View => Self::from_arrow(array.as_string_view(), nullable),
+            DataType::Decimal128(..) => {
+                from_arrow_decimal128(array.as_primitive::<Decimal128Type>(), nullable)?
+            }
+            DataType::Decimal256(..) => {
+                from_arrow_decimal256(array.as_primitive::<Decimal256Type>(), nullable)?
+            }
             DataType::Struct(_) => Self::from_arrow(array.as_struct(), nullable),
+            DataType::List(_) => Self::from_arrow(array.as_list::<i32>(), nullable),
+            DataType::LargeList(_) => Self::from_arrow(array.as_list::<i64>(), nullable),
+            DataType::FixedSizeList(..) => Self::from_arrow(array.as_fixed_size_list(), nullable),
+            DataType::Union(..) => Self::from_arrow(as_union_array(&array), nullable),
+            DataType::Map(..) => Self::from_arrow(array.as_map(), nullable),
             DataType::Null => Self::from_arrow(as_null_array(&array), nullable),
             DataType::Timestamp(u, _) => match u {
                 ArrowTimeUnit::Second => {
@@ -280,11 +490,24 @@ impl FromArrowArray<ArrowArrayRef> for Array {
                     Self::from_arrow(array.as_primitive::<DurationNanosecondType>(), nullable)
                 }
             },
-            _ => panic!(
+            DataType::Interval(u) => match u {
+                ArrowIntervalUnit::YearMonth => {
+                    Self::from_arrow(array.as_primitive::<IntervalYearMonthType>(), nullable)
+                }
+                ArrowIntervalUnit::DayTime => from_arrow_interval_day_time(
+                    array.as_primitive::<IntervalDayTimeType>(),
+                    nullable,
+                )?,
+                ArrowIntervalUnit::MonthDayNano => from_arrow_interval_month_day_nano(
+                    array.as_primitive::<IntervalMonthDayNanoType>(),
+                    nullable,
+                )?,
+            },
+            _ => vortex_bail!(
                 "TODO(robert): Missing array encoding for dtype {}",
                 array.data_type().clone()
             ),
-        }
+        })
     }
 }
 
@@ -1,11 +1,19 @@
 use std::sync::Arc;
 
-use arrow_schema::{DataType, Field, SchemaRef, TimeUnit as ArrowTimeUnit};
+use arrow_schema::{
+    DataType, Field, IntervalUnit as ArrowIntervalUnit, SchemaRef, TimeUnit as ArrowTimeUnit,
+    UnionMode as ArrowUnionMode,
+};
 use itertools::Itertools;
-use vortex_dtype::{DType, Nullability, PType, StructDType};
-use vortex_error::{vortex_err, VortexResult};
+use vortex_dtype::{
+    DType, DecimalDType, ExtID, MapDType, Nullability, PType, StructDType, UnionDType, UnionMode,
+    MAX_DECIMAL_PRECISION,
+};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::array::{make_temporal_ext_dtype, TimeUnit};
+use crate::array::{
+    find_extension_type, make_temporal_ext_dtype, IntervalUnit, TimeUnit, ARROW_EXTENSION_NAME_KEY,
+};
 use crate::arrow::{FromArrowType, TryFromArrowType};
 
 impl TryFromArrowType<&DataType> for PType {
@@ -53,15 +61,32 @@ impl FromArrowType<SchemaRef> for DType {
 
 impl FromArrowType<&Field> for DType {
     fn from_arrow(field: &Field) -> Self {
+        Self::try_from_arrow(field).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl TryFromArrowType<&Field> for DType {
+    fn try_from_arrow(field: &Field) -> VortexResult<Self> {
         use vortex_dtype::DType::*;
 
         let nullability: Nullability = field.is_nullable().into();
 
+        if let Some(ext_type) = field
+            .metadata()
+            .get(ARROW_EXTENSION_NAME_KEY)
+            .and_then(|name| find_extension_type(&ExtID::from(name.as_str())))
+        {
+            return Ok(Extension(
+                ext_type.ext_dtype_from_arrow(field.metadata())?,
+                nullability,
+            ));
+        }
+
         if let Ok(ptype) = PType::try_from_arrow(field.data_type()) {
-            return Primitive(ptype, nullability);
+            return Ok(Primitive(ptype, nullability));
         }
 
-        match field.data_type() {
+        Ok(match field.data_type() {
             DataType::Null => Null,
             DataType::Boolean => Bool(nullability),
             DataType::Utf8 | DataType::LargeUtf8 => Utf8(nullability),
@@ -70,25 +95,90 @@ impl FromArrowType<&Field> for DType {
             | DataType::Date64
             | DataType::Time32(_)
             | DataType::Time64(_)
-            | DataType::Timestamp(..) => Extension(
+            | DataType::Timestamp(..)
+            | DataType::Duration(_)
+            | DataType::Interval(_) => Extension(
                 make_temporal_ext_dtype(field.data_type()),
                 field.is_nullable().into(),
             ),
+            // Decimal256 columns are narrowed to 128-bit storage, which is only lossless up to the
+            // maximum precision of a Vortex decimal.
+            DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+                if *precision > MAX_DECIMAL_PRECISION {
+                    vortex_bail!(
+                        "Decimal precision {} exceeds the maximum of {}",
+                        precision,
+                        MAX_DECIMAL_PRECISION
+                    );
+                }
+                let scale = u8::try_from(*scale).map_err(|_| {
+                    vortex_err!("Negative decimal scale {} is not supported", scale)
+                })?;
+                Decimal(DecimalDType::try_new(*precision, scale)?, nullability)
+            }
             DataType::List(e) | DataType::LargeList(e) => {
-                List(Arc::new(Self::from_arrow(e.as_ref())), nullability)
+                List(Arc::new(Self::try_from_arrow(e.as_ref())?), nullability)
             }
+            DataType::FixedSizeList(e, size) => FixedSizeList(
+                Arc::new(Self::try_from_arrow(e.as_ref())?),
+                u32::try_from(*size)
+                    .map_err(|_| vortex_err!("Negative fixed size list size {}", size))?,
+                nullability,
+            ),
             DataType::Struct(f) => Struct(
                 StructDType::new(
                     f.iter()
                         .map(|f| f.name().as_str().into())
                         .collect_vec()
                         .into(),
-                    f.iter().map(|f| Self::from_arrow(f.as_ref())).collect_vec(),
+                    f.iter()
+                        .map(|f| Self::try_from_arrow(f.as_ref()))
+                        .try_collect()?,
                 ),
                 nullability,
             ),
-            _ => unimplemented!("Arrow data type not yet supported: {:?}", field.data_type()),
-        }
+            DataType::Map(entries, keys_sorted) => {
+                let DataType::Struct(fields) = entries.data_type() else {
+                    vortex_bail!(
+                        "Arrow map entries must be a struct, got {}",
+                        entries.data_type()
+                    )
+                };
+                if fields.len() != 2 {
+                    vortex_bail!(
+                        "Arrow map entries must have a key and a value field, got {}",
+                        fields.len()
+                    );
+                }
+                Map(
+                    MapDType::try_new(
+                        Self::try_from_arrow(fields[0].as_ref())?,
+                        Self::try_from_arrow(fields[1].as_ref())?,
+                        *keys_sorted,
+                    )?,
+                    nullability,
+                )
+            }
+            DataType::Union(fields, mode) => Union(
+                UnionDType::try_new(
+                    fields
+                        .iter()
+                        .map(|(_, f)| f.name().as_str().into())
+                        .collect_vec()
+                        .into(),
+                    fields
+                        .iter()
+                        .map(|(_, f)| Self::try_from_arrow(f.as_ref()))
+                        .try_collect()?,
+                    match mode {
+                        ArrowUnionMode::Sparse => UnionMode::Sparse,
+                        ArrowUnionMode::Dense => UnionMode::Dense,
+                    },
+                )?,
+                nullability,
+            ),
+            _ => vortex_bail!("Arrow data type not yet supported: {:?}", field.data_type()),
+        })
     }
 }
 
@@ -114,3 +204,23 @@ impl From<TimeUnit> for ArrowTimeUnit {
         }
     }
 }
+
+impl From<&ArrowIntervalUnit> for IntervalUnit {
+    fn from(value: &ArrowIntervalUnit) -> Self {
+        match value {
+            ArrowIntervalUnit::YearMonth => Self::YearMonth,
+            ArrowIntervalUnit::DayTime => Self::DayTime,
+            ArrowIntervalUnit::MonthDayNano => Self::MonthDayNano,
+        }
+    }
+}
+
+impl From<IntervalUnit> for ArrowIntervalUnit {
+    fn from(value: IntervalUnit) -> Self {
+        match value {
+            IntervalUnit::YearMonth => Self::YearMonth,
+            IntervalUnit::DayTime => Self::DayTime,
+            IntervalUnit::MonthDayNano => Self::MonthDayNano,
+        }
+    }
+}
@@ -9,6 +9,10 @@ pub trait FromArrowArray<A> {
     fn from_arrow(array: A, nullable: bool) -> Self;
 }
 
+pub trait TryFromArrowArray<A>: Sized {
+    fn try_from_arrow(array: A, nullable: bool) -> VortexResult<Self>;
+}
+
 pub trait FromArrowType<T>: Sized {
     fn from_arrow(value: T) -> Self;
 }
@@ -1,9 +1,10 @@
 use arrow_array::cast::as_struct_array;
 use arrow_array::RecordBatch;
 use itertools::Itertools;
+use vortex_error::VortexResult;
 
 use crate::array::StructArray;
-use crate::arrow::FromArrowArray;
+use crate::arrow::array::from_arrow_field;
 use crate::validity::Validity;
 use crate::{Array, IntoArray, IntoCanonical};
 
@@ -21,10 +22,11 @@ impl From<RecordBatch> for Array {
                 .columns()
                 .iter()
                 .zip(value.schema().fields())
-                .map(|(array, field)| Array::from_arrow(array.clone(), field.is_nullable()))
-                .collect(),
+                .map(|(array, field)| from_arrow_field(array.clone(), field))
+                .collect::<VortexResult<Vec<_>>>()
+                .unwrap_or_else(|err| panic!("{}", err)),
             value.num_rows(),
-            Validity::AllValid,
+            Validity::NonNullable,
         )
         .unwrap()
         .into()
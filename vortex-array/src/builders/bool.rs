@@ -0,0 +1,82 @@
+use arrow_buffer::BooleanBufferBuilder;
+use vortex_dtype::{DType, Nullability};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::BoolArray;
+use crate::builders::{check_dtype, ArrayBuilder, ValidityBuilder};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+pub struct BoolBuilder {
+    dtype: DType,
+    values: BooleanBufferBuilder,
+    validity: ValidityBuilder,
+}
+
+impl BoolBuilder {
+    pub fn with_capacity(nullability: Nullability, capacity: usize) -> Self {
+        Self {
+            dtype: DType::Bool(nullability),
+            values: BooleanBufferBuilder::new(capacity),
+            validity: ValidityBuilder::with_capacity(nullability, capacity),
+        }
+    }
+
+    pub fn append_value(&mut self, value: bool) {
+        self.values.append(value);
+        self.validity.append_non_nulls(1);
+    }
+
+    pub fn append_option(&mut self, value: Option<bool>) {
+        match value {
+            Some(v) => self.append_value(v),
+            None => self.append_null(),
+        }
+    }
+}
+
+impl ArrayBuilder for BoolBuilder {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if scalar.value().is_null() {
+            self.append_null();
+            return Ok(());
+        }
+        check_dtype(&self.dtype, scalar.dtype())?;
+        let value = scalar
+            .value()
+            .as_bool()?
+            .ok_or_else(|| vortex_err!("expected bool scalar value"))?;
+        self.append_value(value);
+        Ok(())
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.values.append_n(n, false);
+        self.validity.append_nulls(n);
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.values.append_n(n, false);
+        self.validity.append_non_nulls(n);
+    }
+
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(&self.dtype, array.dtype())?;
+        let bools = array.clone().into_bool()?;
+        self.values.append_buffer(&bools.boolean_buffer());
+        self.validity.append_validity(bools.logical_validity())
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        Ok(BoolArray::try_new(self.values.finish(), self.validity.finish()?)?.into_array())
+    }
+}
@@ -0,0 +1,86 @@
+use vortex_dtype::{DType, DecimalDType, Nullability};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::DecimalArray;
+use crate::builders::{check_dtype, ArrayBuilder, ValidityBuilder};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+pub struct DecimalBuilder {
+    dtype: DType,
+    decimal_dtype: DecimalDType,
+    values: Vec<i128>,
+    validity: ValidityBuilder,
+}
+
+impl DecimalBuilder {
+    pub fn with_capacity(
+        decimal_dtype: DecimalDType,
+        nullability: Nullability,
+        capacity: usize,
+    ) -> Self {
+        Self {
+            dtype: DType::Decimal(decimal_dtype, nullability),
+            decimal_dtype,
+            values: Vec::with_capacity(capacity),
+            validity: ValidityBuilder::with_capacity(nullability, capacity),
+        }
+    }
+
+    /// Append an unscaled decimal value.
+    pub fn append_value(&mut self, value: i128) {
+        self.values.push(value);
+        self.validity.append_non_nulls(1);
+    }
+}
+
+impl ArrayBuilder for DecimalBuilder {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if scalar.value().is_null() {
+            self.append_null();
+            return Ok(());
+        }
+        check_dtype(&self.dtype, scalar.dtype())?;
+        let value = scalar
+            .value()
+            .as_decimal()?
+            .ok_or_else(|| vortex_err!("expected decimal scalar value"))?;
+        self.append_value(value);
+        Ok(())
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.values.resize(self.values.len() + n, 0);
+        self.validity.append_nulls(n);
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.values.resize(self.values.len() + n, 0);
+        self.validity.append_non_nulls(n);
+    }
+
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(&self.dtype, array.dtype())?;
+        let decimals = array.clone().into_decimal()?;
+        self.values.extend_from_slice(decimals.maybe_null_slice());
+        self.validity.append_validity(decimals.logical_validity())
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        Ok(DecimalArray::from_vec(
+            std::mem::take(&mut self.values),
+            self.decimal_dtype,
+            self.validity.finish()?,
+        )
+        .into_array())
+    }
+}
@@ -0,0 +1,134 @@
+use vortex_dtype::{DType, ExtDType};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::{find_extension_type, ExtensionArray};
+use crate::builders::{builder_with_capacity, check_dtype, ArrayBuilder};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+/// Builds extension arrays by building their storage.
+///
+/// An [ExtDType] does not describe its storage, so the storage DType is taken from the registered
+/// extension type, or from the first array the builder is extended with. Values appended before
+/// the storage DType is known are buffered as placeholders.
+pub struct ExtensionBuilder {
+    dtype: DType,
+    storage: Option<Box<dyn ArrayBuilder>>,
+    // Whether each value appended before the storage DType was known is null.
+    pending: Vec<bool>,
+    capacity: usize,
+}
+
+impl ExtensionBuilder {
+    /// # Panics
+    ///
+    /// If `dtype` is not an extension type.
+    pub fn with_capacity(dtype: DType, capacity: usize) -> Self {
+        let DType::Extension(ext_dtype, nullability) = &dtype else {
+            panic!("ExtensionBuilder requires an extension dtype, got {dtype}");
+        };
+        let storage = find_extension_type(ext_dtype.id())
+            .and_then(|ext_type| ext_type.storage_dtype(ext_dtype, *nullability))
+            .map(|storage_dtype| builder_with_capacity(&storage_dtype, capacity));
+        Self {
+            dtype,
+            storage,
+            pending: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// Create a builder for extension arrays with the given storage DType.
+    pub fn with_storage(ext_dtype: ExtDType, storage_dtype: &DType, capacity: usize) -> Self {
+        Self {
+            dtype: DType::Extension(ext_dtype, storage_dtype.nullability()),
+            storage: Some(builder_with_capacity(storage_dtype, capacity)),
+            pending: Vec::new(),
+            capacity,
+        }
+    }
+
+    fn ext_dtype(&self) -> &ExtDType {
+        let DType::Extension(ext_dtype, _) = &self.dtype else {
+            unreachable!("ExtensionBuilder dtype is an extension type");
+        };
+        ext_dtype
+    }
+
+    fn storage_builder(&mut self, storage_dtype: &DType) -> &mut Box<dyn ArrayBuilder> {
+        let storage_dtype = storage_dtype.with_nullability(self.dtype.nullability());
+        let capacity = self.capacity;
+        let pending = &mut self.pending;
+        self.storage.get_or_insert_with(|| {
+            let mut builder = builder_with_capacity(&storage_dtype, capacity);
+            for null in pending.drain(..) {
+                if null {
+                    builder.append_null();
+                } else {
+                    builder.append_zeros(1);
+                }
+            }
+            builder
+        })
+    }
+}
+
+impl ArrayBuilder for ExtensionBuilder {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.storage
+            .as_ref()
+            .map_or(self.pending.len(), |storage| storage.len())
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if scalar.value().is_null() {
+            self.append_null();
+            return Ok(());
+        }
+        check_dtype(&self.dtype, scalar.dtype())?;
+        let Some(storage) = self.storage.as_mut() else {
+            vortex_bail!(
+                "storage type of extension {} is unknown, extend from an array first",
+                self.ext_dtype().id()
+            );
+        };
+        let storage_scalar = Scalar::new(storage.dtype().clone(), scalar.value().clone());
+        storage.append_scalar(&storage_scalar)
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        match self.storage.as_mut() {
+            Some(storage) => storage.append_nulls(n),
+            None => self.pending.extend(std::iter::repeat(true).take(n)),
+        }
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        match self.storage.as_mut() {
+            Some(storage) => storage.append_zeros(n),
+            None => self.pending.extend(std::iter::repeat(false).take(n)),
+        }
+    }
+
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(&self.dtype, array.dtype())?;
+        let storage = array.clone().into_extension()?.storage();
+        self.storage_builder(storage.dtype())
+            .extend_from_array(&storage)
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        let Some(storage) = self.storage.as_mut() else {
+            vortex_bail!(
+                "storage type of extension {} is unknown, extend from an array first",
+                self.ext_dtype().id()
+            );
+        };
+        let storage = storage.finish()?;
+        Ok(ExtensionArray::try_new(self.ext_dtype().clone(), storage)?.into_array())
+    }
+}
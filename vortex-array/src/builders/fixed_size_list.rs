@@ -0,0 +1,108 @@
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::FixedSizeListArray;
+use crate::builders::{
+    build_scalars, builder_with_capacity, check_dtype, ArrayBuilder, ValidityBuilder,
+};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+pub struct FixedSizeListBuilder {
+    dtype: DType,
+    elements: Box<dyn ArrayBuilder>,
+    list_size: u32,
+    validity: ValidityBuilder,
+    len: usize,
+}
+
+impl FixedSizeListBuilder {
+    /// # Panics
+    ///
+    /// If `dtype` is not a fixed size list.
+    pub fn with_capacity(dtype: DType, capacity: usize) -> Self {
+        let DType::FixedSizeList(element_dtype, list_size, nullability) = &dtype else {
+            panic!("FixedSizeListBuilder requires a fixed size list dtype, got {dtype}");
+        };
+        let elements = builder_with_capacity(element_dtype, capacity * *list_size as usize);
+        let validity = ValidityBuilder::with_capacity(*nullability, capacity);
+        let list_size = *list_size;
+        Self {
+            dtype,
+            elements,
+            list_size,
+            validity,
+            len: 0,
+        }
+    }
+}
+
+impl ArrayBuilder for FixedSizeListBuilder {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if scalar.value().is_null() {
+            self.append_null();
+            return Ok(());
+        }
+        check_dtype(&self.dtype, scalar.dtype())?;
+        let values = scalar
+            .value()
+            .as_list()?
+            .ok_or_else(|| vortex_err!("expected list scalar value"))?;
+        if values.len() != self.list_size as usize {
+            vortex_bail!(
+                "expected list of {} elements, got {}",
+                self.list_size,
+                values.len()
+            );
+        }
+        let elements = values
+            .iter()
+            .map(|value| Scalar::new(self.elements.dtype().clone(), value.clone()))
+            .collect::<Vec<_>>();
+        let elements = build_scalars(self.elements.dtype(), elements.iter())?;
+        self.elements.extend_from_array(&elements)?;
+        self.validity.append_non_nulls(1);
+        self.len += 1;
+        Ok(())
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.elements.append_zeros(n * self.list_size as usize);
+        self.validity.append_nulls(n);
+        self.len += n;
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.elements.append_zeros(n * self.list_size as usize);
+        self.validity.append_non_nulls(n);
+        self.len += n;
+    }
+
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(&self.dtype, array.dtype())?;
+        let list = array.clone().into_fixed_size_list()?;
+        self.elements.extend_from_array(&list.elements())?;
+        self.validity.append_validity(list.logical_validity())?;
+        self.len += list.len();
+        Ok(())
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        Ok(FixedSizeListArray::try_new(
+            self.elements.finish()?,
+            self.list_size,
+            std::mem::take(&mut self.len),
+            self.validity.finish()?,
+        )?
+        .into_array())
+    }
+}
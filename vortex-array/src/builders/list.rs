@@ -0,0 +1,123 @@
+use vortex_dtype::DType;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::{ListArray, PrimitiveArray};
+use crate::builders::{
+    build_scalars, builder_with_capacity, check_dtype, ArrayBuilder, ValidityBuilder,
+};
+use crate::compute::slice;
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+pub struct ListBuilder {
+    dtype: DType,
+    elements: Box<dyn ArrayBuilder>,
+    offsets: Vec<u64>,
+    validity: ValidityBuilder,
+}
+
+impl ListBuilder {
+    /// # Panics
+    ///
+    /// If `dtype` is not a list.
+    pub fn with_capacity(dtype: DType, capacity: usize) -> Self {
+        let DType::List(element_dtype, nullability) = &dtype else {
+            panic!("ListBuilder requires a list dtype, got {dtype}");
+        };
+        let elements = builder_with_capacity(element_dtype, capacity);
+        let validity = ValidityBuilder::with_capacity(*nullability, capacity);
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+        Self {
+            dtype,
+            elements,
+            offsets,
+            validity,
+        }
+    }
+
+    /// The builder of the list elements.
+    ///
+    /// Elements appended directly must be followed by [ListBuilder::append_valid] to close the
+    /// list holding them.
+    pub fn elements_builder(&mut self) -> &mut Box<dyn ArrayBuilder> {
+        &mut self.elements
+    }
+
+    /// Close a valid list holding every element appended since the previous list.
+    pub fn append_valid(&mut self) {
+        self.offsets.push(self.elements.len() as u64);
+        self.validity.append_non_nulls(1);
+    }
+}
+
+impl ArrayBuilder for ListBuilder {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if scalar.value().is_null() {
+            self.append_null();
+            return Ok(());
+        }
+        check_dtype(&self.dtype, scalar.dtype())?;
+        let values = scalar
+            .value()
+            .as_list()?
+            .ok_or_else(|| vortex_err!("expected list scalar value"))?;
+        let elements = values
+            .iter()
+            .map(|value| Scalar::new(self.elements.dtype().clone(), value.clone()))
+            .collect::<Vec<_>>();
+        let elements = build_scalars(self.elements.dtype(), elements.iter())?;
+        self.elements.extend_from_array(&elements)?;
+        self.append_valid();
+        Ok(())
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        let end = self.elements.len() as u64;
+        self.offsets.extend(std::iter::repeat(end).take(n));
+        self.validity.append_nulls(n);
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        let end = self.elements.len() as u64;
+        self.offsets.extend(std::iter::repeat(end).take(n));
+        self.validity.append_non_nulls(n);
+    }
+
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(&self.dtype, array.dtype())?;
+        let list = array.clone().into_list()?;
+        if list.is_empty() {
+            return Ok(());
+        }
+        let start = list.offset_at(0);
+        let base = self.elements.len() as u64;
+        self.elements.extend_from_array(&slice(
+            &list.elements(),
+            start,
+            list.offset_at(list.len()),
+        )?)?;
+        self.offsets
+            .extend((1..=list.len()).map(|i| base + (list.offset_at(i) - start) as u64));
+        self.validity.append_validity(list.logical_validity())
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        let offsets = std::mem::replace(&mut self.offsets, vec![0]);
+        Ok(ListArray::try_new(
+            PrimitiveArray::from(offsets).into_array(),
+            self.elements.finish()?,
+            self.validity.finish()?,
+        )?
+        .into_array())
+    }
+}
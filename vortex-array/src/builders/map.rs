@@ -0,0 +1,116 @@
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+use vortex_scalar::{MapScalar, Scalar};
+
+use crate::array::{MapArray, PrimitiveArray};
+use crate::builders::{
+    build_scalars, builder_with_capacity, check_dtype, ArrayBuilder, ValidityBuilder,
+};
+use crate::compute::slice;
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+pub struct MapBuilder {
+    dtype: DType,
+    keys: Box<dyn ArrayBuilder>,
+    values: Box<dyn ArrayBuilder>,
+    keys_sorted: bool,
+    offsets: Vec<u64>,
+    validity: ValidityBuilder,
+}
+
+impl MapBuilder {
+    /// # Panics
+    ///
+    /// If `dtype` is not a map.
+    pub fn with_capacity(dtype: DType, capacity: usize) -> Self {
+        let DType::Map(map_dtype, nullability) = &dtype else {
+            panic!("MapBuilder requires a map dtype, got {dtype}");
+        };
+        let keys = builder_with_capacity(map_dtype.key_dtype(), capacity);
+        let values = builder_with_capacity(map_dtype.value_dtype(), capacity);
+        let keys_sorted = map_dtype.keys_sorted();
+        let validity = ValidityBuilder::with_capacity(*nullability, capacity);
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+        Self {
+            dtype,
+            keys,
+            values,
+            keys_sorted,
+            offsets,
+            validity,
+        }
+    }
+
+    fn append_empty(&mut self, n: usize) {
+        let end = self.keys.len() as u64;
+        self.offsets.extend(std::iter::repeat(end).take(n));
+    }
+}
+
+impl ArrayBuilder for MapBuilder {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if scalar.value().is_null() {
+            self.append_null();
+            return Ok(());
+        }
+        check_dtype(&self.dtype, scalar.dtype())?;
+        let (keys, values): (Vec<_>, Vec<_>) = MapScalar::try_from(scalar)?.entries().unzip();
+        let keys = build_scalars(self.keys.dtype(), keys.iter())?;
+        let values = build_scalars(self.values.dtype(), values.iter())?;
+        self.keys.extend_from_array(&keys)?;
+        self.values.extend_from_array(&values)?;
+        self.offsets.push(self.keys.len() as u64);
+        self.validity.append_non_nulls(1);
+        Ok(())
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.append_empty(n);
+        self.validity.append_nulls(n);
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.append_empty(n);
+        self.validity.append_non_nulls(n);
+    }
+
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(&self.dtype, array.dtype())?;
+        let map = array.clone().into_map()?;
+        if map.is_empty() {
+            return Ok(());
+        }
+        let start = map.offset_at(0);
+        let end = map.offset_at(map.len());
+        let base = self.keys.len() as u64;
+        self.keys
+            .extend_from_array(&slice(&map.keys(), start, end)?)?;
+        self.values
+            .extend_from_array(&slice(&map.values(), start, end)?)?;
+        self.offsets
+            .extend((1..=map.len()).map(|i| base + (map.offset_at(i) - start) as u64));
+        self.validity.append_validity(map.logical_validity())
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        let offsets = std::mem::replace(&mut self.offsets, vec![0]);
+        Ok(MapArray::try_new(
+            PrimitiveArray::from(offsets).into_array(),
+            self.keys.finish()?,
+            self.values.finish()?,
+            self.keys_sorted,
+            self.validity.finish()?,
+        )?
+        .into_array())
+    }
+}
@@ -0,0 +1,411 @@
+//! Builders that construct canonical arrays of any [DType] from row-oriented data.
+//!
+//! [builder_for] returns a dynamically typed [ArrayBuilder] for a DType. Values can be appended as
+//! [Scalar]s or copied from existing arrays, and the builder finishes into an array of the DType's
+//! [canonical](crate::Canonical) encoding.
+
+use arrow_buffer::NullBufferBuilder;
+use vortex_dtype::{match_each_native_ptype, DType, Nullability};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+pub use self::bool::*;
+pub use self::decimal::*;
+pub use self::extension::*;
+pub use self::fixed_size_list::*;
+pub use self::list::*;
+pub use self::map::*;
+pub use self::null::*;
+pub use self::primitive::*;
+pub use self::struct_::*;
+pub use self::union::*;
+pub use self::varbinview::*;
+use crate::compute::unary::scalar_at;
+use crate::validity::{LogicalValidity, Validity};
+use crate::{Array, ArrayDType};
+
+mod bool;
+mod decimal;
+mod extension;
+mod fixed_size_list;
+mod list;
+mod map;
+mod null;
+mod primitive;
+mod struct_;
+mod union;
+mod varbinview;
+
+/// An incrementally built array of a single [DType].
+pub trait ArrayBuilder: Send {
+    fn dtype(&self) -> &DType;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append a scalar of the builder's DType, ignoring nullability. Null scalars append a null.
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()>;
+
+    /// Append `n` nulls.
+    ///
+    /// Builders of non-nullable DTypes accept nulls, but fail to finish if any were appended.
+    fn append_nulls(&mut self, n: usize);
+
+    fn append_null(&mut self) {
+        self.append_nulls(1)
+    }
+
+    /// Append `n` valid placeholder values, used to fill the children of null nested values.
+    fn append_zeros(&mut self, n: usize);
+
+    /// Append every value of `array`, which must have the builder's DType, ignoring nullability.
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(self.dtype(), array.dtype())?;
+        for index in 0..array.len() {
+            self.append_scalar(&scalar_at(array, index)?)?;
+        }
+        Ok(())
+    }
+
+    /// Finish into an array of the canonical encoding, leaving the builder empty.
+    fn finish(&mut self) -> VortexResult<Array>;
+}
+
+/// Create an [ArrayBuilder] for arrays of `dtype`.
+pub fn builder_for(dtype: &DType) -> Box<dyn ArrayBuilder> {
+    builder_with_capacity(dtype, 0)
+}
+
+/// Create an [ArrayBuilder] for arrays of `dtype`, with room for `capacity` values.
+pub fn builder_with_capacity(dtype: &DType, capacity: usize) -> Box<dyn ArrayBuilder> {
+    match dtype {
+        DType::Null => Box::new(NullBuilder::new()),
+        DType::Bool(n) => Box::new(BoolBuilder::with_capacity(*n, capacity)),
+        DType::Primitive(ptype, n) => match_each_native_ptype!(ptype, |$T| {
+            Box::new(PrimitiveBuilder::<$T>::with_capacity(*n, capacity))
+        }),
+        DType::Decimal(decimal_dtype, n) => {
+            Box::new(DecimalBuilder::with_capacity(*decimal_dtype, *n, capacity))
+        }
+        DType::Utf8(_) | DType::Binary(_) => {
+            Box::new(VarBinViewBuilder::with_capacity(dtype.clone(), capacity))
+        }
+        DType::Struct(..) => Box::new(StructBuilder::with_capacity(dtype.clone(), capacity)),
+        DType::List(..) => Box::new(ListBuilder::with_capacity(dtype.clone(), capacity)),
+        DType::FixedSizeList(..) => {
+            Box::new(FixedSizeListBuilder::with_capacity(dtype.clone(), capacity))
+        }
+        DType::Union(..) => Box::new(UnionBuilder::with_capacity(dtype.clone(), capacity)),
+        DType::Map(..) => Box::new(MapBuilder::with_capacity(dtype.clone(), capacity)),
+        DType::Extension(..) => Box::new(ExtensionBuilder::with_capacity(dtype.clone(), capacity)),
+    }
+}
+
+fn check_dtype(expected: &DType, actual: &DType) -> VortexResult<()> {
+    if !expected.eq_ignore_nullability(actual) {
+        vortex_bail!(MismatchedTypes: expected, actual);
+    }
+    Ok(())
+}
+
+/// Build the nested `scalars` into a standalone array, so that a failing element leaves the
+/// parent builder untouched.
+fn build_scalars<'a>(
+    dtype: &DType,
+    scalars: impl ExactSizeIterator<Item = &'a Scalar>,
+) -> VortexResult<Array> {
+    let mut builder = builder_with_capacity(dtype, scalars.len());
+    for scalar in scalars {
+        builder.append_scalar(scalar)?;
+    }
+    builder.finish()
+}
+
+/// Tracks the validity of the values appended to a builder.
+struct ValidityBuilder {
+    nulls: NullBufferBuilder,
+    nullability: Nullability,
+}
+
+impl ValidityBuilder {
+    fn with_capacity(nullability: Nullability, capacity: usize) -> Self {
+        Self {
+            nulls: NullBufferBuilder::new(capacity),
+            nullability,
+        }
+    }
+
+    fn append_non_nulls(&mut self, n: usize) {
+        self.nulls.append_n_non_nulls(n);
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.nulls.append_n_nulls(n);
+    }
+
+    fn append_validity(&mut self, validity: LogicalValidity) -> VortexResult<()> {
+        match validity.to_null_buffer()? {
+            None => self.append_non_nulls(validity.len()),
+            Some(nulls) => nulls
+                .inner()
+                .iter()
+                .for_each(|valid| self.nulls.append(valid)),
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> VortexResult<Validity> {
+        let nulls = self.nulls.finish();
+        Ok(match self.nullability {
+            Nullability::Nullable => nulls.map(Validity::from).unwrap_or(Validity::AllValid),
+            Nullability::NonNullable => {
+                if nulls.is_some() {
+                    vortex_bail!("nulls appended to a builder of non-nullable values");
+                }
+                Validity::NonNullable
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use vortex_dtype::{DType, Nullability, PType, StructDType, UnionDType, UnionMode};
+    use vortex_scalar::{Scalar, ScalarValue};
+
+    use crate::array::{PrimitiveArray, TemporalArray, TimeUnit, VarBinViewArray};
+    use crate::builders::builder_for;
+    use crate::compute::unary::scalar_at;
+    use crate::validity::Validity;
+    use crate::variants::StructArrayTrait;
+    use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn primitive() {
+        let mut builder = builder_for(&DType::Primitive(PType::I32, Nullability::Nullable));
+        builder.append_scalar(&Scalar::from(1i32)).unwrap();
+        builder.append_null();
+        builder
+            .extend_from_array(&PrimitiveArray::from(vec![2i32, 3]).into_array())
+            .unwrap();
+        let array = builder.finish().unwrap();
+
+        assert!(builder.is_empty());
+        assert_eq!(
+            array.dtype(),
+            &DType::Primitive(PType::I32, Nullability::Nullable)
+        );
+        assert_eq!(
+            array.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 0, 2, 3]
+        );
+    }
+
+    #[test]
+    fn non_nullable_rejects_nulls() {
+        let mut builder = builder_for(&DType::Utf8(Nullability::NonNullable));
+        builder.append_scalar(&Scalar::from("a")).unwrap();
+        builder.append_null();
+        assert!(builder.finish().is_err());
+        assert!(builder.append_scalar(&Scalar::from(1i32)).is_err());
+    }
+
+    #[test]
+    fn struct_of_strings() {
+        let dtype = DType::Struct(
+            StructDType::new(
+                ["a".into(), "b".into()].into(),
+                vec![
+                    DType::Primitive(PType::I64, Nullability::NonNullable),
+                    DType::Utf8(Nullability::Nullable),
+                ],
+            ),
+            Nullability::Nullable,
+        );
+        let mut builder = builder_for(&dtype);
+        builder
+            .append_scalar(&Scalar::new(
+                dtype.clone(),
+                ScalarValue::List(
+                    vec![
+                        Scalar::from(5i64).value().clone(),
+                        Scalar::from("five").value().clone(),
+                    ]
+                    .into(),
+                ),
+            ))
+            .unwrap();
+        builder.append_null();
+        let array = builder.finish().unwrap();
+
+        assert_eq!(array.dtype(), &dtype);
+        assert_eq!(array.len(), 2);
+        assert!(!array.with_dyn(|a| a.is_valid(1)));
+        let field = array.into_struct().unwrap().field(1).unwrap();
+        assert_eq!(
+            scalar_at(&field, 0).unwrap(),
+            Scalar::utf8("five".to_string(), Nullability::Nullable)
+        );
+    }
+
+    #[test]
+    fn list_extend() {
+        let element_dtype = DType::Primitive(PType::U8, Nullability::NonNullable);
+        let dtype = DType::List(Arc::new(element_dtype.clone()), Nullability::NonNullable);
+        let mut source = builder_for(&dtype);
+        for values in [vec![1u8, 2], vec![], vec![3]] {
+            source
+                .append_scalar(&Scalar::list(
+                    element_dtype.clone(),
+                    values
+                        .into_iter()
+                        .map(|v| Scalar::from(v).value().clone())
+                        .collect(),
+                ))
+                .unwrap();
+        }
+        let source = source.finish().unwrap();
+
+        let mut builder = builder_for(&dtype);
+        builder.extend_from_array(&source).unwrap();
+        builder.append_zeros(1);
+        builder.extend_from_array(&source).unwrap();
+        let list = builder.finish().unwrap().into_list().unwrap();
+
+        assert_eq!(list.len(), 7);
+        assert_eq!(
+            (0..=7).map(|i| list.offset_at(i)).collect::<Vec<_>>(),
+            vec![0, 2, 2, 3, 3, 5, 5, 6]
+        );
+        assert_eq!(list.elements().len(), 6);
+    }
+
+    #[test]
+    fn dense_union() {
+        let dtype = DType::Union(
+            UnionDType::try_new(
+                ["int".into(), "str".into()].into(),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::Nullable),
+                    DType::Utf8(Nullability::Nullable),
+                ],
+                UnionMode::Dense,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        );
+        let mut builder = builder_for(&dtype);
+        builder
+            .append_scalar(&Scalar::union(dtype.clone(), 1, Scalar::from("x")).unwrap())
+            .unwrap();
+        builder.append_null();
+        builder
+            .append_scalar(&Scalar::union(dtype.clone(), 0, Scalar::from(7i32)).unwrap())
+            .unwrap();
+        let array = builder.finish().unwrap();
+
+        assert_eq!(array.len(), 3);
+        assert_eq!(
+            scalar_at(&array, 0).unwrap(),
+            Scalar::union(dtype.clone(), 1, Scalar::from("x")).unwrap()
+        );
+        assert!(scalar_at(&array, 1).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&array, 2).unwrap(),
+            Scalar::union(dtype, 0, Scalar::from(7i32)).unwrap()
+        );
+    }
+
+    #[test]
+    fn union_nulls_with_non_nullable_variants() {
+        let dtype = DType::Union(
+            UnionDType::try_new(
+                ["int".into(), "str".into()].into(),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::NonNullable),
+                    DType::Utf8(Nullability::NonNullable),
+                ],
+                UnionMode::Dense,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        );
+        let mut builder = builder_for(&dtype);
+        builder.append_null();
+        builder
+            .append_scalar(&Scalar::union(dtype.clone(), 0, Scalar::from(7i32)).unwrap())
+            .unwrap();
+        let array = builder.finish().unwrap();
+
+        assert!(scalar_at(&array, 0).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&array, 1).unwrap(),
+            Scalar::union(dtype, 0, Scalar::from(7i32)).unwrap()
+        );
+    }
+
+    #[test]
+    fn list_rejected_scalar_leaves_builder_intact() {
+        let element_dtype = DType::Primitive(PType::U8, Nullability::NonNullable);
+        let dtype = DType::List(Arc::new(element_dtype.clone()), Nullability::NonNullable);
+        let mut builder = builder_for(&dtype);
+        builder
+            .append_scalar(&Scalar::list(
+                element_dtype.clone(),
+                vec![Scalar::from(1u8).value().clone(), ScalarValue::Null],
+            ))
+            .unwrap_err();
+        builder
+            .append_scalar(&Scalar::list(
+                element_dtype,
+                vec![Scalar::from(2u8).value().clone()],
+            ))
+            .unwrap();
+        let list = builder.finish().unwrap().into_list().unwrap();
+
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.elements().len(), 1);
+    }
+
+    #[test]
+    fn temporal_extension() {
+        let durations = Array::from(TemporalArray::new_duration(
+            PrimitiveArray::from_vec(vec![10i64, 20], Validity::AllValid).into_array(),
+            TimeUnit::Ms,
+        ));
+        let mut builder = builder_for(durations.dtype());
+        builder.append_null();
+        builder.extend_from_array(&durations).unwrap();
+        builder
+            .append_scalar(&scalar_at(&durations, 1).unwrap())
+            .unwrap();
+        let array = builder.finish().unwrap();
+
+        assert_eq!(array.dtype(), durations.dtype());
+        assert_eq!(array.len(), 4);
+        assert!(scalar_at(&array, 0).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&array, 3).unwrap(),
+            scalar_at(&durations, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn varbinview_extend() {
+        let source =
+            VarBinViewArray::from_iter_nullable_str([Some("a"), None, Some("long string value")]);
+        let mut builder = builder_for(source.dtype());
+        builder.extend_from_array(source.array()).unwrap();
+        let array = builder.finish().unwrap();
+        assert_eq!(
+            scalar_at(&array, 2).unwrap(),
+            scalar_at(source.array(), 2).unwrap()
+        );
+        assert!(scalar_at(&array, 1).unwrap().is_null());
+    }
+}
@@ -0,0 +1,57 @@
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::NullArray;
+use crate::builders::{check_dtype, ArrayBuilder};
+use crate::{Array, ArrayDType, IntoArray};
+
+pub struct NullBuilder {
+    len: usize,
+}
+
+impl NullBuilder {
+    pub fn new() -> Self {
+        Self { len: 0 }
+    }
+}
+
+impl Default for NullBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrayBuilder for NullBuilder {
+    fn dtype(&self) -> &DType {
+        &DType::Null
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        check_dtype(&DType::Null, scalar.dtype())?;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.len += n;
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.len += n;
+    }
+
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(&DType::Null, array.dtype())?;
+        self.len += array.len();
+        Ok(())
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        Ok(NullArray::new(std::mem::take(&mut self.len)).into_array())
+    }
+}
@@ -0,0 +1,89 @@
+use vortex_dtype::{DType, NativePType, Nullability};
+use vortex_error::{vortex_err, VortexError, VortexResult};
+use vortex_scalar::{PValue, Scalar};
+
+use crate::array::PrimitiveArray;
+use crate::builders::{check_dtype, ArrayBuilder, ValidityBuilder};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+pub struct PrimitiveBuilder<T: NativePType> {
+    dtype: DType,
+    values: Vec<T>,
+    validity: ValidityBuilder,
+}
+
+impl<T: NativePType> PrimitiveBuilder<T> {
+    pub fn with_capacity(nullability: Nullability, capacity: usize) -> Self {
+        Self {
+            dtype: DType::Primitive(T::PTYPE, nullability),
+            values: Vec::with_capacity(capacity),
+            validity: ValidityBuilder::with_capacity(nullability, capacity),
+        }
+    }
+
+    pub fn append_value(&mut self, value: T) {
+        self.values.push(value);
+        self.validity.append_non_nulls(1);
+    }
+
+    pub fn append_option(&mut self, value: Option<T>) {
+        match value {
+            Some(v) => self.append_value(v),
+            None => self.push_nulls(1),
+        }
+    }
+
+    fn push_nulls(&mut self, n: usize) {
+        self.values.resize(self.values.len() + n, T::default());
+        self.validity.append_nulls(n);
+    }
+}
+
+impl<T: NativePType + TryFrom<PValue, Error = VortexError>> ArrayBuilder for PrimitiveBuilder<T> {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if scalar.value().is_null() {
+            self.push_nulls(1);
+            return Ok(());
+        }
+        check_dtype(&self.dtype, scalar.dtype())?;
+        let value = scalar
+            .value()
+            .as_pvalue()?
+            .ok_or_else(|| vortex_err!("expected primitive scalar value"))?;
+        self.append_value(T::try_from(value)?);
+        Ok(())
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.push_nulls(n)
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.values.resize(self.values.len() + n, T::default());
+        self.validity.append_non_nulls(n);
+    }
+
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(&self.dtype, array.dtype())?;
+        let primitive = array.clone().into_primitive()?;
+        self.values
+            .extend_from_slice(primitive.maybe_null_slice::<T>());
+        self.validity.append_validity(primitive.logical_validity())
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        Ok(
+            PrimitiveArray::from_vec(std::mem::take(&mut self.values), self.validity.finish()?)
+                .into_array(),
+        )
+    }
+}
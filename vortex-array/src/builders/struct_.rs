@@ -0,0 +1,135 @@
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::StructArray;
+use crate::builders::{builder_with_capacity, check_dtype, ArrayBuilder, ValidityBuilder};
+use crate::validity::ArrayValidity;
+use crate::variants::StructArrayTrait;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+pub struct StructBuilder {
+    dtype: DType,
+    fields: Vec<Box<dyn ArrayBuilder>>,
+    validity: ValidityBuilder,
+    len: usize,
+}
+
+impl StructBuilder {
+    /// # Panics
+    ///
+    /// If `dtype` is not a struct.
+    pub fn with_capacity(dtype: DType, capacity: usize) -> Self {
+        let DType::Struct(struct_dtype, nullability) = &dtype else {
+            panic!("StructBuilder requires a struct dtype, got {dtype}");
+        };
+        let fields = struct_dtype
+            .dtypes()
+            .iter()
+            .map(|field_dtype| builder_with_capacity(field_dtype, capacity))
+            .collect();
+        let validity = ValidityBuilder::with_capacity(*nullability, capacity);
+        Self {
+            dtype,
+            fields,
+            validity,
+            len: 0,
+        }
+    }
+
+    /// The builder of the field at `idx`.
+    ///
+    /// Values appended directly to a field must be matched by a call to
+    /// [StructBuilder::append_valid] once every field has been appended to.
+    pub fn field_builder(&mut self, idx: usize) -> Option<&mut Box<dyn ArrayBuilder>> {
+        self.fields.get_mut(idx)
+    }
+
+    /// Record a valid struct for values that were appended to each field builder.
+    pub fn append_valid(&mut self) -> VortexResult<()> {
+        if let Some(field) = self.fields.iter().find(|f| f.len() != self.len + 1) {
+            vortex_bail!(
+                "expected every field to have {} values, found {}",
+                self.len + 1,
+                field.len()
+            );
+        }
+        self.validity.append_non_nulls(1);
+        self.len += 1;
+        Ok(())
+    }
+}
+
+impl ArrayBuilder for StructBuilder {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if scalar.value().is_null() {
+            self.append_null();
+            return Ok(());
+        }
+        check_dtype(&self.dtype, scalar.dtype())?;
+        let values = scalar
+            .value()
+            .as_list()?
+            .ok_or_else(|| vortex_err!("expected struct scalar value"))?;
+        for (field, value) in self.fields.iter_mut().zip(values.iter()) {
+            let field_scalar = Scalar::new(field.dtype().clone(), value.clone());
+            field.append_scalar(&field_scalar)?;
+        }
+        self.validity.append_non_nulls(1);
+        self.len += 1;
+        Ok(())
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.fields.iter_mut().for_each(|f| f.append_zeros(n));
+        self.validity.append_nulls(n);
+        self.len += n;
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.fields.iter_mut().for_each(|f| f.append_zeros(n));
+        self.validity.append_non_nulls(n);
+        self.len += n;
+    }
+
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(&self.dtype, array.dtype())?;
+        let struct_array = array.clone().into_struct()?;
+        for (idx, field) in self.fields.iter_mut().enumerate() {
+            let child = struct_array
+                .field(idx)
+                .ok_or_else(|| vortex_err!("missing struct field {idx}"))?;
+            field.extend_from_array(&child)?;
+        }
+        self.validity
+            .append_validity(struct_array.logical_validity())?;
+        self.len += struct_array.len();
+        Ok(())
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        let DType::Struct(struct_dtype, _) = &self.dtype else {
+            unreachable!("StructBuilder dtype is a struct");
+        };
+        let fields = self
+            .fields
+            .iter_mut()
+            .map(|f| f.finish())
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(StructArray::try_new(
+            struct_dtype.names().clone(),
+            fields,
+            std::mem::take(&mut self.len),
+            self.validity.finish()?,
+        )?
+        .into_array())
+    }
+}
@@ -0,0 +1,157 @@
+use vortex_dtype::{DType, UnionMode};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::{Scalar, UnionScalar};
+
+use crate::array::{PrimitiveArray, UnionArray};
+use crate::builders::{builder_with_capacity, check_dtype, ArrayBuilder, ValidityBuilder};
+use crate::validity::Validity;
+use crate::{Array, IntoArray};
+
+/// Builds sparse and dense unions.
+///
+/// Nulls are recorded in the validity of the union and point at a zero of the first variant.
+pub struct UnionBuilder {
+    dtype: DType,
+    mode: UnionMode,
+    variants: Vec<Box<dyn ArrayBuilder>>,
+    type_ids: Vec<u8>,
+    offsets: Vec<i32>,
+    validity: ValidityBuilder,
+    // Elements appended to a union without variants to refer to.
+    unrepresentable: usize,
+}
+
+impl UnionBuilder {
+    /// # Panics
+    ///
+    /// If `dtype` is not a union.
+    pub fn with_capacity(dtype: DType, capacity: usize) -> Self {
+        let DType::Union(union_dtype, nullability) = &dtype else {
+            panic!("UnionBuilder requires a union dtype, got {dtype}");
+        };
+        let mode = union_dtype.mode();
+        let variants = union_dtype
+            .dtypes()
+            .iter()
+            .map(|variant_dtype| builder_with_capacity(variant_dtype, capacity))
+            .collect();
+        let offsets = match mode {
+            UnionMode::Sparse => Vec::new(),
+            UnionMode::Dense => Vec::with_capacity(capacity),
+        };
+        let validity = ValidityBuilder::with_capacity(*nullability, capacity);
+        Self {
+            dtype,
+            mode,
+            variants,
+            type_ids: Vec::with_capacity(capacity),
+            offsets,
+            validity,
+            unrepresentable: 0,
+        }
+    }
+
+    /// Record an element of variant `type_id`, appended by `append` to the variant builder.
+    fn append_variant<F>(&mut self, type_id: u8, append: F) -> VortexResult<()>
+    where
+        F: FnOnce(&mut dyn ArrayBuilder) -> VortexResult<()>,
+    {
+        let variant = self
+            .variants
+            .get_mut(type_id as usize)
+            .ok_or_else(|| vortex_err!("Type id {} out of bounds", type_id))?;
+        let offset = variant.len();
+        append(variant.as_mut())?;
+        self.type_ids.push(type_id);
+        match self.mode {
+            UnionMode::Sparse => self
+                .variants
+                .iter_mut()
+                .enumerate()
+                .filter(|(idx, _)| *idx != type_id as usize)
+                .for_each(|(_, v)| v.append_zeros(1)),
+            UnionMode::Dense => self.offsets.push(offset as i32),
+        }
+        Ok(())
+    }
+
+    /// Append `n` zeros of the first variant, or record them as unrepresentable if the union
+    /// has no variants.
+    fn append_placeholders(&mut self, n: usize) {
+        if self.variants.is_empty() {
+            self.unrepresentable += n;
+            return;
+        }
+        for _ in 0..n {
+            self.append_variant(0, |variant| {
+                variant.append_zeros(1);
+                Ok(())
+            })
+            .expect("variant exists");
+        }
+    }
+}
+
+impl ArrayBuilder for UnionBuilder {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        self.type_ids.len() + self.unrepresentable
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if scalar.value().is_null() {
+            self.append_null();
+            return Ok(());
+        }
+        check_dtype(&self.dtype, scalar.dtype())?;
+        let union_scalar = UnionScalar::try_from(scalar)?;
+        let type_id = union_scalar
+            .type_id()
+            .ok_or_else(|| vortex_err!("union scalar without a type id"))?;
+        let value = union_scalar
+            .value()
+            .ok_or_else(|| vortex_err!("union scalar without a value"))?;
+        self.append_variant(type_id, |variant| variant.append_scalar(&value))?;
+        self.validity.append_non_nulls(1);
+        Ok(())
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.append_placeholders(n);
+        self.validity.append_nulls(n);
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        self.append_placeholders(n);
+        self.validity.append_non_nulls(n);
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        if std::mem::take(&mut self.unrepresentable) > 0 {
+            vortex_bail!("union without variants cannot hold elements");
+        }
+        let variants = self
+            .variants
+            .iter_mut()
+            .map(|v| v.finish())
+            .collect::<VortexResult<Vec<_>>>()?;
+        let offsets = match self.mode {
+            UnionMode::Sparse => None,
+            UnionMode::Dense => Some(
+                PrimitiveArray::from_vec(std::mem::take(&mut self.offsets), Validity::NonNullable)
+                    .into_array(),
+            ),
+        };
+        Ok(UnionArray::try_new(
+            self.dtype.clone(),
+            PrimitiveArray::from(std::mem::take(&mut self.type_ids)).into_array(),
+            offsets,
+            variants,
+            self.validity.finish()?,
+        )?
+        .into_array())
+    }
+}
@@ -0,0 +1,131 @@
+use arrow_array::builder::{ArrayBuilder as _, BinaryViewBuilder, StringViewBuilder};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::arrow::FromArrowArray;
+use crate::builders::{check_dtype, ArrayBuilder};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArrayVariant};
+
+enum ViewBuilder {
+    Utf8(StringViewBuilder),
+    Binary(BinaryViewBuilder),
+}
+
+/// Builds [VarBinViewArray](crate::array::VarBinViewArray)s of either `Utf8` or `Binary` values.
+pub struct VarBinViewBuilder {
+    dtype: DType,
+    builder: ViewBuilder,
+    null_count: usize,
+}
+
+impl VarBinViewBuilder {
+    /// # Panics
+    ///
+    /// If `dtype` is not `Utf8` or `Binary`.
+    pub fn with_capacity(dtype: DType, capacity: usize) -> Self {
+        let builder = match dtype {
+            DType::Utf8(_) => ViewBuilder::Utf8(StringViewBuilder::with_capacity(capacity)),
+            DType::Binary(_) => ViewBuilder::Binary(BinaryViewBuilder::with_capacity(capacity)),
+            _ => panic!("VarBinViewBuilder requires Utf8 or Binary dtype, got {dtype}"),
+        };
+        Self {
+            dtype,
+            builder,
+            null_count: 0,
+        }
+    }
+
+    /// Append a value, which must be valid UTF-8 for `Utf8` builders.
+    pub fn append_value(&mut self, value: &[u8]) -> VortexResult<()> {
+        match &mut self.builder {
+            ViewBuilder::Utf8(b) => b.append_value(
+                std::str::from_utf8(value).map_err(|e| vortex_err!("invalid utf8 value: {e}"))?,
+            ),
+            ViewBuilder::Binary(b) => b.append_value(value),
+        }
+        Ok(())
+    }
+}
+
+impl ArrayBuilder for VarBinViewBuilder {
+    fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    fn len(&self) -> usize {
+        match &self.builder {
+            ViewBuilder::Utf8(b) => b.len(),
+            ViewBuilder::Binary(b) => b.len(),
+        }
+    }
+
+    fn append_scalar(&mut self, scalar: &Scalar) -> VortexResult<()> {
+        if scalar.value().is_null() {
+            self.append_null();
+            return Ok(());
+        }
+        check_dtype(&self.dtype, scalar.dtype())?;
+        match &self.dtype {
+            DType::Utf8(_) => {
+                let value = scalar
+                    .value()
+                    .as_buffer_string()?
+                    .ok_or_else(|| vortex_err!("expected utf8 scalar value"))?;
+                self.append_value(value.as_bytes())
+            }
+            _ => {
+                let value = scalar
+                    .value()
+                    .as_buffer()?
+                    .ok_or_else(|| vortex_err!("expected binary scalar value"))?;
+                self.append_value(value.as_slice())
+            }
+        }
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        for _ in 0..n {
+            match &mut self.builder {
+                ViewBuilder::Utf8(b) => b.append_null(),
+                ViewBuilder::Binary(b) => b.append_null(),
+            }
+        }
+        self.null_count += n;
+    }
+
+    fn append_zeros(&mut self, n: usize) {
+        for _ in 0..n {
+            match &mut self.builder {
+                ViewBuilder::Utf8(b) => b.append_value(""),
+                ViewBuilder::Binary(b) => b.append_value(b""),
+            }
+        }
+    }
+
+    fn extend_from_array(&mut self, array: &Array) -> VortexResult<()> {
+        check_dtype(&self.dtype, array.dtype())?;
+        let views = array.clone().into_varbin_view()?;
+        for index in 0..views.len() {
+            if views.is_valid(index) {
+                self.append_value(&views.bytes_at(index)?)?;
+            } else {
+                self.append_null();
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> VortexResult<Array> {
+        let nullable = self.dtype.is_nullable();
+        if !nullable && self.null_count > 0 {
+            vortex_bail!("nulls appended to a builder of non-nullable values");
+        }
+        self.null_count = 0;
+        Ok(match &mut self.builder {
+            ViewBuilder::Utf8(b) => Array::from_arrow(&b.finish(), nullable),
+            ViewBuilder::Binary(b) => Array::from_arrow(&b.finish(), nullable),
+        })
+    }
+}
@@ -1,28 +1,36 @@
 use std::sync::Arc;
 
+use arrow_array::cast::AsArray;
 use arrow_array::types::{
     Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
     UInt32Type, UInt64Type, UInt8Type,
 };
 use arrow_array::{
-    ArrayRef, ArrowPrimitiveType, BinaryArray, BooleanArray as ArrowBoolArray, Date32Array,
-    Date64Array, LargeBinaryArray, LargeStringArray, NullArray as ArrowNullArray,
-    PrimitiveArray as ArrowPrimitiveArray, StringArray, StructArray as ArrowStructArray,
-    Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray,
-    TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
-    TimestampSecondArray,
+    make_array, Array as _, ArrayRef, ArrowPrimitiveType, BinaryViewArray,
+    BooleanArray as ArrowBoolArray, Date32Array, Date64Array, Decimal128Array,
+    DurationMicrosecondArray, DurationMillisecondArray, DurationNanosecondArray,
+    DurationSecondArray, FixedSizeListArray as ArrowFixedSizeListArray, IntervalDayTimeArray,
+    IntervalMonthDayNanoArray, IntervalYearMonthArray, LargeListArray, ListArray as ArrowListArray,
+    MapArray as ArrowMapArray, NullArray as ArrowNullArray, PrimitiveArray as ArrowPrimitiveArray,
+    StringViewArray, StructArray as ArrowStructArray, Time32MillisecondArray, Time32SecondArray,
+    Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
+    TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray,
+    UnionArray as ArrowUnionArray,
 };
-use arrow_buffer::ScalarBuffer;
-use arrow_schema::{Field, Fields};
+use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano, NullBuffer, ScalarBuffer};
+use arrow_schema::{DataType, Field, Fields, UnionFields};
 use vortex_dtype::{DType, NativePType, PType};
 use vortex_error::{vortex_bail, VortexResult};
 
-use crate::array::temporal::{is_temporal_ext_type, TemporalMetadata};
+use crate::accessor::ArrayAccessor;
+use crate::array::temporal::TemporalMetadata;
 use crate::array::{
-    BoolArray, ExtensionArray, NullArray, PrimitiveArray, StructArray, TemporalArray, TimeUnit,
-    VarBinArray,
+    find_extension_type, BoolArray, DecimalArray, ExtensionArray, FixedSizeListArray, IntervalUnit,
+    ListArray, MapArray, NullArray, PrimitiveArray, StructArray, TemporalArray, TimeUnit,
+    UnionArray, VarBinArray, VarBinViewArray,
 };
 use crate::arrow::wrappers::as_offset_buffer;
+use crate::compute::take;
 use crate::compute::unary::try_cast;
 use crate::encoding::ArrayEncoding;
 use crate::validity::ArrayValidity;
@@ -47,21 +55,21 @@ use crate::{Array, ArrayDType, IntoArray, ToArray};
 ///
 /// # Views support
 ///
-/// Binary and String views are a new, better encoding format for nearly all use-cases. For now,
-/// because DataFusion does not include pervasive support for compute over StringView, we opt to use
-/// the [`VarBinArray`] as the canonical encoding (which corresponds to the Arrow `BinaryViewArray`).
-///
-/// We expect to change this soon once DataFusion is able to finish up some initial support, which
-/// is tracked in <https://github.com/apache/datafusion/issues/10918>.
+/// Binary and String views are a new, better encoding format for nearly all use-cases. Utf8 and
+/// Binary arrays canonicalize to [`VarBinViewArray`], which converts with zero copies into the
+/// Arrow `StringViewArray` and `BinaryViewArray` respectively.
 #[derive(Debug, Clone)]
 pub enum Canonical {
     Null(NullArray),
     Bool(BoolArray),
     Primitive(PrimitiveArray),
+    Decimal(DecimalArray),
     Struct(StructArray),
-    VarBin(VarBinArray),
-    // TODO(aduffy): switch to useing VarBinView instead of VarBin
-    // VarBinView(VarBinViewArray),
+    VarBinView(VarBinViewArray),
+    List(ListArray),
+    FixedSizeList(FixedSizeListArray),
+    Union(UnionArray),
+    Map(MapArray),
     Extension(ExtensionArray),
 }
 
@@ -76,18 +84,19 @@ impl Canonical {
             Canonical::Null(a) => null_to_arrow(a),
             Canonical::Bool(a) => bool_to_arrow(a),
             Canonical::Primitive(a) => primitive_to_arrow(a),
+            Canonical::Decimal(a) => decimal_to_arrow(a),
             Canonical::Struct(a) => struct_to_arrow(a),
-            Canonical::VarBin(a) => varbin_to_arrow(a),
-            Canonical::Extension(a) => {
-                if !is_temporal_ext_type(a.id()) {
+            Canonical::VarBinView(a) => varbinview_to_arrow(a),
+            Canonical::List(a) => list_to_arrow(a),
+            Canonical::FixedSizeList(a) => fixed_size_list_to_arrow(a),
+            Canonical::Union(a) => union_to_arrow(a),
+            Canonical::Map(a) => map_to_arrow(a),
+            Canonical::Extension(a) => find_extension_type(a.id())
+                .unwrap_or_else(|| {
                     panic!("unsupported extension dtype with ID {}", a.id().as_ref())
-                }
-
-                temporal_to_arrow(
-                    TemporalArray::try_from(&a.into_array())
-                        .expect("array must be known temporal array ext type"),
-                )
-            }
+                })
+                .to_arrow(&a)
+                .expect("extension array must convert to Arrow"),
         }
     }
 }
@@ -115,6 +124,13 @@ impl Canonical {
         }
     }
 
+    pub fn into_decimal(self) -> VortexResult<DecimalArray> {
+        match self {
+            Canonical::Decimal(a) => Ok(a),
+            _ => vortex_bail!(InvalidArgument: "cannot unwrap DecimalArray from {:?}", &self),
+        }
+    }
+
     pub fn into_struct(self) -> VortexResult<StructArray> {
         match self {
             Canonical::Struct(a) => Ok(a),
@@ -122,10 +138,44 @@ impl Canonical {
         }
     }
 
+    pub fn into_varbin_view(self) -> VortexResult<VarBinViewArray> {
+        match self {
+            Canonical::VarBinView(a) => Ok(a),
+            _ => vortex_bail!(InvalidArgument: "cannot unwrap VarBinViewArray from {:?}", &self),
+        }
+    }
+
+    #[deprecated(note = "strings and binary canonicalize to VarBinViewArray, use into_varbin_view")]
     pub fn into_varbin(self) -> VortexResult<VarBinArray> {
+        let array = self.into_varbin_view()?;
+        array.with_iterator(|iter| VarBinArray::from_iter(iter, array.dtype().clone()))
+    }
+
+    pub fn into_list(self) -> VortexResult<ListArray> {
+        match self {
+            Canonical::List(a) => Ok(a),
+            _ => vortex_bail!(InvalidArgument: "cannot unwrap ListArray from {:?}", &self),
+        }
+    }
+
+    pub fn into_fixed_size_list(self) -> VortexResult<FixedSizeListArray> {
+        match self {
+            Canonical::FixedSizeList(a) => Ok(a),
+            _ => vortex_bail!(InvalidArgument: "cannot unwrap FixedSizeListArray from {:?}", &self),
+        }
+    }
+
+    pub fn into_union(self) -> VortexResult<UnionArray> {
+        match self {
+            Canonical::Union(a) => Ok(a),
+            _ => vortex_bail!(InvalidArgument: "cannot unwrap UnionArray from {:?}", &self),
+        }
+    }
+
+    pub fn into_map(self) -> VortexResult<MapArray> {
         match self {
-            Canonical::VarBin(a) => Ok(a),
-            _ => vortex_bail!(InvalidArgument: "cannot unwrap VarBinArray from {:?}", &self),
+            Canonical::Map(a) => Ok(a),
+            _ => vortex_bail!(InvalidArgument: "cannot unwrap MapArray from {:?}", &self),
         }
     }
 
@@ -151,6 +201,25 @@ fn bool_to_arrow(bool_array: BoolArray) -> ArrayRef {
     ))
 }
 
+fn decimal_to_arrow(decimal_array: DecimalArray) -> ArrayRef {
+    let decimal_dtype = decimal_array.decimal_dtype();
+    Arc::new(
+        Decimal128Array::new(
+            ScalarBuffer::<i128>::new(
+                decimal_array.buffer().clone().into_arrow(),
+                0,
+                decimal_array.len(),
+            ),
+            decimal_array
+                .logical_validity()
+                .to_null_buffer()
+                .expect("null buffer"),
+        )
+        .with_precision_and_scale(decimal_dtype.precision(), decimal_dtype.scale() as i8)
+        .expect("decimal precision and scale must be valid for arrow"),
+    )
+}
+
 fn primitive_to_arrow(primitive_array: PrimitiveArray) -> ArrayRef {
     fn as_arrow_array_primitive<T: ArrowPrimitiveType>(
         array: &PrimitiveArray,
@@ -198,11 +267,18 @@ fn struct_to_arrow(struct_array: StructArray) -> ArrayRef {
         .zip(field_arrays.iter())
         .zip(struct_array.dtypes().iter())
         .map(|((name, arrow_field), vortex_field)| {
-            Field::new(
+            let field = Field::new(
                 &**name,
                 arrow_field.data_type().clone(),
                 vortex_field.is_nullable(),
-            )
+            );
+            match vortex_field {
+                DType::Extension(ext_dtype, _) => match find_extension_type(ext_dtype.id()) {
+                    Some(ext_type) => field.with_metadata(ext_type.arrow_metadata(ext_dtype)),
+                    None => field,
+                },
+                _ => field,
+            }
         })
         .map(Arc::new)
         .collect();
@@ -210,89 +286,270 @@ fn struct_to_arrow(struct_array: StructArray) -> ArrayRef {
     Arc::new(ArrowStructArray::new(arrow_fields, field_arrays, None))
 }
 
-fn varbin_to_arrow(varbin_array: VarBinArray) -> ArrayRef {
-    let offsets = varbin_array
+fn varbinview_to_arrow(var_bin_view: VarBinViewArray) -> ArrayRef {
+    let views = var_bin_view
+        .views()
+        .into_primitive()
+        .expect("views must be primitive");
+    assert_eq!(views.ptype(), PType::U8);
+    let nulls = var_bin_view
+        .logical_validity()
+        .to_null_buffer()
+        .expect("null buffer");
+
+    let data = var_bin_view
+        .buffers()
+        .into_iter()
+        .map(|b| {
+            let b = b.into_primitive().expect("bytes arrays must be primitive");
+            assert_eq!(b.ptype(), PType::U8);
+            b.buffer().clone().into_arrow()
+        })
+        .collect::<Vec<_>>();
+    let views = ScalarBuffer::<u128>::from(views.buffer().clone().into_arrow());
+
+    // Switch on Arrow DType.
+    match var_bin_view.dtype() {
+        DType::Binary(_) => Arc::new(BinaryViewArray::new(views, data, nulls)),
+        DType::Utf8(_) => Arc::new(StringViewArray::new(views, data, nulls)),
+        _ => panic!("expected utf8 or binary, got {}", var_bin_view.dtype()),
+    }
+}
+
+fn list_to_arrow(list_array: ListArray) -> ArrayRef {
+    let offsets = list_array
         .offsets()
         .into_primitive()
         .expect("flatten_primitive");
     let offsets = match offsets.ptype() {
         PType::I32 | PType::I64 => offsets,
-        // Unless it's u64, everything else can be converted into an i32.
-        // FIXME(ngates): do not copy offsets again
-        PType::U64 => offsets.reinterpret_cast(PType::I64),
-        PType::U32 => offsets.reinterpret_cast(PType::I32),
-        _ => try_cast(&offsets.to_array(), PType::I32.into())
-            .expect("cast to i32")
-            .into_primitive()
-            .expect("flatten_primitive"),
+        PType::U64 => try_cast(
+            &offsets.to_array(),
+            &DType::Primitive(PType::I64, offsets.dtype().nullability()),
+        )
+        .expect("cast to i64")
+        .into_primitive()
+        .expect("flatten_primitive"),
+        _ => try_cast(
+            &offsets.to_array(),
+            &DType::Primitive(PType::I32, offsets.dtype().nullability()),
+        )
+        .expect("cast to i32")
+        .into_primitive()
+        .expect("flatten_primitive"),
     };
-    let nulls = varbin_array
+    let nulls = list_array
         .logical_validity()
         .to_null_buffer()
         .expect("null buffer");
 
-    let data = varbin_array
-        .bytes()
-        .into_primitive()
-        .expect("flatten_primitive");
-    assert_eq!(data.ptype(), PType::U8);
-    let data = data.buffer();
+    let elements = list_array
+        .elements()
+        .into_canonical()
+        .expect("canonical elements")
+        .into_arrow();
+    let field = Arc::new(Field::new(
+        "item",
+        elements.data_type().clone(),
+        list_array.element_dtype().is_nullable(),
+    ));
 
-    // Switch on Arrow DType.
-    match varbin_array.dtype() {
-        DType::Binary(_) => match offsets.ptype() {
-            PType::I32 => Arc::new(unsafe {
-                BinaryArray::new_unchecked(
-                    as_offset_buffer::<i32>(offsets),
-                    data.clone().into_arrow(),
-                    nulls,
-                )
-            }),
-            PType::I64 => Arc::new(unsafe {
-                LargeBinaryArray::new_unchecked(
-                    as_offset_buffer::<i64>(offsets),
-                    data.clone().into_arrow(),
-                    nulls,
-                )
-            }),
-            _ => panic!("Invalid offsets type"),
-        },
-        DType::Utf8(_) => match offsets.ptype() {
-            PType::I32 => Arc::new(unsafe {
-                StringArray::new_unchecked(
-                    as_offset_buffer::<i32>(offsets),
-                    data.clone().into_arrow(),
-                    nulls,
-                )
-            }),
-            PType::I64 => Arc::new(unsafe {
-                LargeStringArray::new_unchecked(
-                    as_offset_buffer::<i64>(offsets),
-                    data.clone().into_arrow(),
-                    nulls,
-                )
-            }),
-            _ => panic!("Invalid offsets type"),
-        },
-        _ => panic!(
-            "expected utf8 or binary instead of {}",
-            varbin_array.dtype()
-        ),
+    match offsets.ptype() {
+        PType::I32 => Arc::new(ArrowListArray::new(
+            field,
+            as_offset_buffer::<i32>(offsets),
+            elements,
+            nulls,
+        )),
+        PType::I64 => Arc::new(LargeListArray::new(
+            field,
+            as_offset_buffer::<i64>(offsets),
+            elements,
+            nulls,
+        )),
+        _ => panic!("Invalid offsets type"),
     }
 }
 
-fn temporal_to_arrow(temporal_array: TemporalArray) -> ArrayRef {
+fn fixed_size_list_to_arrow(list_array: FixedSizeListArray) -> ArrayRef {
+    let nulls = list_array
+        .logical_validity()
+        .to_null_buffer()
+        .expect("null buffer");
+    let elements = list_array
+        .elements()
+        .into_canonical()
+        .expect("canonical elements")
+        .into_arrow();
+    let field = Arc::new(Field::new(
+        "item",
+        elements.data_type().clone(),
+        list_array.element_dtype().is_nullable(),
+    ));
+
+    Arc::new(ArrowFixedSizeListArray::new(
+        field,
+        list_array.list_size() as i32,
+        elements,
+        nulls,
+    ))
+}
+
+fn map_to_arrow(map_array: MapArray) -> ArrayRef {
+    // Arrow maps only support 32-bit offsets.
+    let offsets = map_array.offsets();
+    let offsets = try_cast(
+        &offsets,
+        &DType::Primitive(PType::I32, offsets.dtype().nullability()),
+    )
+    .and_then(|offsets| offsets.into_primitive())
+    .expect("map offsets must cast to i32");
+    let nulls = map_array
+        .logical_validity()
+        .to_null_buffer()
+        .expect("null buffer");
+    let entries = map_array
+        .entries()
+        .into_canonical()
+        .expect("canonical entries")
+        .into_arrow();
+    let field = Arc::new(Field::new("entries", entries.data_type().clone(), false));
+
+    Arc::new(
+        ArrowMapArray::try_new(
+            field,
+            as_offset_buffer::<i32>(offsets),
+            entries.as_struct().clone(),
+            nulls,
+            map_array.map_dtype().keys_sorted(),
+        )
+        .expect("valid map array"),
+    )
+}
+
+fn union_to_arrow(union_array: UnionArray) -> ArrayRef {
+    let type_ids = union_array.type_ids();
+    let type_ids = try_cast(
+        &type_ids,
+        &DType::Primitive(PType::I8, type_ids.dtype().nullability()),
+    )
+    .and_then(|ids| ids.into_primitive())
+    .expect("type ids must cast to i8");
+    // Arrow unions have no validity of their own, so the union nulls are pushed into the variants.
+    let nulls = union_array
+        .validity()
+        .to_logical(union_array.len())
+        .to_null_buffer()
+        .expect("union validity");
+    let (offsets, variants) = match (&nulls, union_array.offsets()) {
+        (Some(_), Some(_)) => unshare_dense_variants(&union_array).expect("unshared variants"),
+        (_, offsets) => (offsets, union_array.variants().collect()),
+    };
+    let dense = offsets.is_some();
+
+    let children: Vec<ArrayRef> = variants
+        .into_iter()
+        .enumerate()
+        .map(|(type_id, variant)| {
+            let child = variant
+                .into_canonical()
+                .expect("canonical variant")
+                .into_arrow();
+            match &nulls {
+                None => child,
+                Some(nulls) if !dense => with_nulls(child, nulls.clone()),
+                Some(nulls) => {
+                    let variant_nulls = type_ids
+                        .maybe_null_slice::<i8>()
+                        .iter()
+                        .zip(nulls.iter())
+                        .filter(|(id, _)| **id as usize == type_id)
+                        .map(|(_, valid)| valid);
+                    with_nulls(child, NullBuffer::from_iter(variant_nulls))
+                }
+            }
+        })
+        .collect();
+    let fields = union_array
+        .union_dtype()
+        .names()
+        .iter()
+        .zip(union_array.union_dtype().dtypes().iter())
+        .zip(children.iter())
+        .enumerate()
+        .map(|(type_id, ((name, dtype), child))| {
+            (
+                type_id as i8,
+                Arc::new(Field::new(
+                    name.as_ref(),
+                    child.data_type().clone(),
+                    dtype.is_nullable() || nulls.is_some(),
+                )),
+            )
+        })
+        .collect::<UnionFields>();
+    let offsets = offsets.map(|offsets| {
+        let offsets = try_cast(
+            &offsets,
+            &DType::Primitive(PType::I32, offsets.dtype().nullability()),
+        )
+        .and_then(|offsets| offsets.into_primitive())
+        .expect("union offsets must cast to i32");
+        ScalarBuffer::<i32>::new(offsets.into_buffer().into_arrow(), 0, union_array.len())
+    });
+
+    Arc::new(
+        ArrowUnionArray::try_new(
+            fields,
+            ScalarBuffer::<i8>::new(type_ids.into_buffer().into_arrow(), 0, union_array.len()),
+            offsets,
+            children,
+        )
+        .expect("valid union array"),
+    )
+}
+
+/// Give every element of a dense union its own value, so that masking the value of a null element
+/// cannot affect another element sharing it.
+fn unshare_dense_variants(union_array: &UnionArray) -> VortexResult<(Option<Array>, Vec<Array>)> {
+    let mut indices = vec![Vec::new(); union_array.nvariants()];
+    let mut offsets = Vec::with_capacity(union_array.len());
+    for (type_id, offset) in union_array.variant_indices()? {
+        let variant_indices = &mut indices[type_id as usize];
+        offsets.push(variant_indices.len() as i32);
+        variant_indices.push(offset as u64);
+    }
+    let variants = union_array
+        .variants()
+        .zip(indices)
+        .map(|(variant, indices)| take(&variant, &PrimitiveArray::from(indices).into_array()))
+        .collect::<VortexResult<Vec<_>>>()?;
+    Ok((Some(PrimitiveArray::from(offsets).into_array()), variants))
+}
+
+/// Mask the values of `array` with `nulls` in addition to its own nulls.
+fn with_nulls(array: ArrayRef, nulls: NullBuffer) -> ArrayRef {
+    if array.data_type() == &DataType::Null {
+        return array;
+    }
+    let data = array.into_data();
+    let nulls = NullBuffer::union(data.nulls(), Some(&nulls));
+    make_array(
+        data.into_builder()
+            .nulls(nulls)
+            .build()
+            .expect("valid array data"),
+    )
+}
+
+pub(crate) fn temporal_to_arrow(temporal_array: TemporalArray) -> VortexResult<ArrayRef> {
     macro_rules! extract_temporal_values {
         ($values:expr, $prim:ty) => {{
-            let temporal_values = try_cast($values, <$prim as NativePType>::PTYPE.into())
-                .expect("values must cast to primitive type")
-                .into_primitive()
-                .expect("must be primitive array");
+            let dtype =
+                DType::Primitive(<$prim as NativePType>::PTYPE, $values.dtype().nullability());
+            let temporal_values = try_cast($values, &dtype)?.into_primitive()?;
             let len = temporal_values.len();
-            let nulls = temporal_values
-                .logical_validity()
-                .to_null_buffer()
-                .expect("null buffer");
+            let nulls = temporal_values.logical_validity().to_null_buffer()?;
             let scalars =
                 ScalarBuffer::<$prim>::new(temporal_values.into_buffer().into_arrow(), 0, len);
 
@@ -300,7 +557,7 @@ fn temporal_to_arrow(temporal_array: TemporalArray) -> ArrayRef {
         }};
     }
 
-    match temporal_array.temporal_metadata() {
+    Ok(match temporal_array.temporal_metadata() {
         TemporalMetadata::Date(time_unit) => match time_unit {
             TimeUnit::D => {
                 let (scalars, nulls) =
@@ -312,7 +569,7 @@ fn temporal_to_arrow(temporal_array: TemporalArray) -> ArrayRef {
                     extract_temporal_values!(&temporal_array.temporal_values(), i64);
                 Arc::new(Date64Array::new(scalars, nulls))
             }
-            _ => panic!("invalid time_unit {time_unit} for vortex.date"),
+            _ => vortex_bail!("invalid time_unit {time_unit} for vortex.date"),
         },
         TemporalMetadata::Time(time_unit) => match time_unit {
             TimeUnit::S => {
@@ -335,7 +592,7 @@ fn temporal_to_arrow(temporal_array: TemporalArray) -> ArrayRef {
                     extract_temporal_values!(&temporal_array.temporal_values(), i64);
                 Arc::new(Time64NanosecondArray::new(scalars, nulls))
             }
-            _ => panic!("invalid TimeUnit for Time32 array {time_unit}"),
+            _ => vortex_bail!("invalid TimeUnit for Time32 array {time_unit}"),
         },
         TemporalMetadata::Timestamp(time_unit, _) => {
             let (scalars, nulls) = extract_temporal_values!(&temporal_array.temporal_values(), i64);
@@ -344,10 +601,76 @@ fn temporal_to_arrow(temporal_array: TemporalArray) -> ArrayRef {
                 TimeUnit::Us => Arc::new(TimestampMicrosecondArray::new(scalars, nulls)),
                 TimeUnit::Ms => Arc::new(TimestampMillisecondArray::new(scalars, nulls)),
                 TimeUnit::S => Arc::new(TimestampSecondArray::new(scalars, nulls)),
-                _ => panic!("invalid TimeUnit for Time32 array {time_unit}"),
+                _ => vortex_bail!("invalid TimeUnit for Time32 array {time_unit}"),
             }
         }
-    }
+        TemporalMetadata::Duration(time_unit) => {
+            let (scalars, nulls) = extract_temporal_values!(&temporal_array.temporal_values(), i64);
+            match time_unit {
+                TimeUnit::Ns => Arc::new(DurationNanosecondArray::new(scalars, nulls)),
+                TimeUnit::Us => Arc::new(DurationMicrosecondArray::new(scalars, nulls)),
+                TimeUnit::Ms => Arc::new(DurationMillisecondArray::new(scalars, nulls)),
+                TimeUnit::S => Arc::new(DurationSecondArray::new(scalars, nulls)),
+                _ => vortex_bail!("invalid TimeUnit for Duration array {time_unit}"),
+            }
+        }
+        TemporalMetadata::Interval(interval_unit) => match interval_unit {
+            IntervalUnit::YearMonth => {
+                let (scalars, nulls) =
+                    extract_temporal_values!(&temporal_array.temporal_values(), i32);
+                Arc::new(IntervalYearMonthArray::new(scalars, nulls))
+            }
+            IntervalUnit::DayTime => {
+                let (parts, nulls) = interval_parts(&temporal_array)?;
+                let [days, milliseconds] = parts.as_slice() else {
+                    vortex_bail!("DayTime interval must have 2 parts, found {}", parts.len())
+                };
+                let values = days
+                    .maybe_null_slice::<i32>()
+                    .iter()
+                    .zip(milliseconds.maybe_null_slice::<i32>())
+                    .map(|(&days, &milliseconds)| IntervalDayTime { days, milliseconds })
+                    .collect::<ScalarBuffer<_>>();
+                Arc::new(IntervalDayTimeArray::new(values, nulls))
+            }
+            IntervalUnit::MonthDayNano => {
+                let (parts, nulls) = interval_parts(&temporal_array)?;
+                let [months, days, nanoseconds] = parts.as_slice() else {
+                    vortex_bail!(
+                        "MonthDayNano interval must have 3 parts, found {}",
+                        parts.len()
+                    )
+                };
+                let values = months
+                    .maybe_null_slice::<i32>()
+                    .iter()
+                    .zip(days.maybe_null_slice::<i32>())
+                    .zip(nanoseconds.maybe_null_slice::<i64>())
+                    .map(|((&months, &days), &nanoseconds)| IntervalMonthDayNano {
+                        months,
+                        days,
+                        nanoseconds,
+                    })
+                    .collect::<ScalarBuffer<_>>();
+                Arc::new(IntervalMonthDayNanoArray::new(values, nulls))
+            }
+        },
+    })
+}
+
+/// Canonicalize the struct storage of a `DayTime` or `MonthDayNano` interval array into its
+/// primitive parts, in field order, alongside the interval validity.
+fn interval_parts(
+    temporal_array: &TemporalArray,
+) -> VortexResult<(Vec<PrimitiveArray>, Option<NullBuffer>)> {
+    let storage = temporal_array.temporal_values().into_struct()?;
+    let nulls = storage.logical_validity().to_null_buffer()?;
+    let parts = storage
+        .children()
+        .map(|part| part.into_primitive())
+        .collect::<VortexResult<_>>()?;
+
+    Ok((parts, nulls))
 }
 
 /// Support trait for transmuting an array into its [vortex_dtype::DType]'s canonical encoding.
@@ -374,10 +697,23 @@ pub trait IntoArrayVariant {
 
     fn into_primitive(self) -> VortexResult<PrimitiveArray>;
 
+    fn into_decimal(self) -> VortexResult<DecimalArray>;
+
     fn into_struct(self) -> VortexResult<StructArray>;
 
+    fn into_varbin_view(self) -> VortexResult<VarBinViewArray>;
+
+    #[deprecated(note = "strings and binary canonicalize to VarBinViewArray, use into_varbin_view")]
     fn into_varbin(self) -> VortexResult<VarBinArray>;
 
+    fn into_list(self) -> VortexResult<ListArray>;
+
+    fn into_fixed_size_list(self) -> VortexResult<FixedSizeListArray>;
+
+    fn into_union(self) -> VortexResult<UnionArray>;
+
+    fn into_map(self) -> VortexResult<MapArray>;
+
     fn into_extension(self) -> VortexResult<ExtensionArray>;
 }
 
@@ -397,14 +733,39 @@ where
         self.into_canonical()?.into_primitive()
     }
 
+    fn into_decimal(self) -> VortexResult<DecimalArray> {
+        self.into_canonical()?.into_decimal()
+    }
+
     fn into_struct(self) -> VortexResult<StructArray> {
         self.into_canonical()?.into_struct()
     }
 
+    fn into_varbin_view(self) -> VortexResult<VarBinViewArray> {
+        self.into_canonical()?.into_varbin_view()
+    }
+
+    #[allow(deprecated)]
     fn into_varbin(self) -> VortexResult<VarBinArray> {
         self.into_canonical()?.into_varbin()
     }
 
+    fn into_list(self) -> VortexResult<ListArray> {
+        self.into_canonical()?.into_list()
+    }
+
+    fn into_fixed_size_list(self) -> VortexResult<FixedSizeListArray> {
+        self.into_canonical()?.into_fixed_size_list()
+    }
+
+    fn into_union(self) -> VortexResult<UnionArray> {
+        self.into_canonical()?.into_union()
+    }
+
+    fn into_map(self) -> VortexResult<MapArray> {
+        self.into_canonical()?.into_map()
+    }
+
     fn into_extension(self) -> VortexResult<ExtensionArray> {
         self.into_canonical()?.into_extension()
     }
@@ -431,8 +792,13 @@ impl From<Canonical> for Array {
             Canonical::Null(a) => a.into(),
             Canonical::Bool(a) => a.into(),
             Canonical::Primitive(a) => a.into(),
+            Canonical::Decimal(a) => a.into(),
             Canonical::Struct(a) => a.into(),
-            Canonical::VarBin(a) => a.into(),
+            Canonical::VarBinView(a) => a.into(),
+            Canonical::List(a) => a.into(),
+            Canonical::FixedSizeList(a) => a.into(),
+            Canonical::Union(a) => a.into(),
+            Canonical::Map(a) => a.into(),
             Canonical::Extension(a) => a.into(),
         }
     }
@@ -440,16 +806,27 @@ impl From<Canonical> for Array {
 
 #[cfg(test)]
 mod test {
-    use arrow_array::types::{Int64Type, UInt64Type};
+    use std::sync::Arc;
+
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float32Type, Float64Type, Int32Type, Int64Type, UInt64Type};
     use arrow_array::{
-        Array, PrimitiveArray as ArrowPrimitiveArray, StructArray as ArrowStructArray,
+        Array, ArrayRef, Decimal128Array, Decimal256Array, DurationMillisecondArray,
+        FixedSizeListArray as ArrowFixedSizeListArray, IntervalDayTimeArray,
+        IntervalMonthDayNanoArray, IntervalYearMonthArray, ListArray as ArrowListArray,
+        MapArray as ArrowMapArray, PrimitiveArray as ArrowPrimitiveArray,
+        StructArray as ArrowStructArray, UnionArray as ArrowUnionArray,
     };
-    use vortex_dtype::Nullability;
+    use arrow_buffer::{i256, IntervalDayTime, IntervalMonthDayNano, ScalarBuffer};
+    use arrow_schema::{DataType, Field, UnionFields};
+    use vortex_dtype::{DType, Nullability, PType, UnionDType, UnionMode};
     use vortex_scalar::Scalar;
 
-    use crate::array::{PrimitiveArray, SparseArray, StructArray};
+    use crate::array::{PrimitiveArray, SparseArray, StructArray, UnionArray};
+    use crate::arrow::{FromArrowArray, TryFromArrowArray};
     use crate::validity::Validity;
-    use crate::{IntoArray, IntoCanonical};
+    use crate::{ArrayDType, Canonical, IntoArray, IntoArrayVariant, IntoCanonical};
 
     #[test]
     fn test_canonicalize_nested_struct() {
@@ -515,4 +892,243 @@ mod test {
             ArrowPrimitiveArray::from(vec![100i64]),
         );
     }
+
+    #[test]
+    fn test_list_arrow_roundtrip() {
+        let arrow_list = ArrowListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), None]),
+        ]);
+
+        let vortex_list =
+            crate::Array::from_arrow(Arc::new(arrow_list.clone()) as arrow_array::ArrayRef, true)
+                .into_list()
+                .unwrap();
+        assert_eq!(vortex_list.len(), 4);
+
+        let roundtrip = Canonical::List(vortex_list).into_arrow();
+        assert_eq!(
+            roundtrip.as_any().downcast_ref::<ArrowListArray>().unwrap(),
+            &arrow_list
+        );
+    }
+
+    #[test]
+    fn test_decimal_arrow_roundtrip() {
+        let arrow_decimal = Decimal128Array::from(vec![Some(12345), None, Some(-7)])
+            .with_precision_and_scale(10, 3)
+            .unwrap();
+
+        let vortex_decimal = crate::Array::from_arrow(
+            Arc::new(arrow_decimal.clone()) as arrow_array::ArrayRef,
+            true,
+        )
+        .into_decimal()
+        .unwrap();
+        assert_eq!(vortex_decimal.decimal_dtype().precision(), 10);
+        assert_eq!(vortex_decimal.decimal_dtype().scale(), 3);
+
+        let roundtrip = Canonical::Decimal(vortex_decimal).into_arrow();
+        assert_eq!(
+            roundtrip
+                .as_any()
+                .downcast_ref::<Decimal128Array>()
+                .unwrap(),
+            &arrow_decimal
+        );
+    }
+
+    #[test]
+    fn test_decimal_arrow_unsupported() {
+        let negative_scale = Decimal128Array::from(vec![1])
+            .with_precision_and_scale(10, -2)
+            .unwrap();
+        assert!(crate::Array::try_from_arrow(Arc::new(negative_scale) as ArrayRef, false).is_err());
+
+        let too_precise = Decimal256Array::from(vec![i256::ONE])
+            .with_precision_and_scale(40, 0)
+            .unwrap();
+        assert!(crate::Array::try_from_arrow(Arc::new(too_precise) as ArrayRef, false).is_err());
+
+        let too_large = Decimal256Array::from(vec![i256::MAX])
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+        assert!(crate::Array::try_from_arrow(Arc::new(too_large) as ArrayRef, false).is_err());
+    }
+
+    #[test]
+    fn test_fixed_size_list_arrow_roundtrip() {
+        let arrow_list = ArrowFixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(
+            vec![
+                Some(vec![Some(1.0), Some(2.0)]),
+                None,
+                Some(vec![Some(3.0), None]),
+            ],
+            2,
+        );
+
+        let vortex_list =
+            crate::Array::from_arrow(Arc::new(arrow_list.clone()) as arrow_array::ArrayRef, true)
+                .into_fixed_size_list()
+                .unwrap();
+        assert_eq!(vortex_list.len(), 3);
+        assert_eq!(vortex_list.list_size(), 2);
+
+        let roundtrip = Canonical::FixedSizeList(vortex_list).into_arrow();
+        assert_eq!(
+            roundtrip
+                .as_any()
+                .downcast_ref::<ArrowFixedSizeListArray>()
+                .unwrap(),
+            &arrow_list
+        );
+    }
+
+    #[test]
+    fn test_union_arrow_roundtrip() {
+        let fields = UnionFields::new(
+            vec![3, 7],
+            vec![
+                Field::new("int", DataType::Int32, true),
+                Field::new("float", DataType::Float64, true),
+            ],
+        );
+        let arrow_union = ArrowUnionArray::try_new(
+            fields.clone(),
+            ScalarBuffer::from(vec![3i8, 7, 3]),
+            Some(ScalarBuffer::from(vec![0i32, 0, 1])),
+            vec![
+                Arc::new(ArrowPrimitiveArray::<Int32Type>::from(vec![Some(1), None])),
+                Arc::new(ArrowPrimitiveArray::<Float64Type>::from(vec![2.5])),
+            ],
+        )
+        .unwrap();
+
+        let vortex_union =
+            crate::Array::from_arrow(Arc::new(arrow_union) as arrow_array::ArrayRef, true)
+                .into_union()
+                .unwrap();
+        assert_eq!(vortex_union.len(), 3);
+        assert_eq!(
+            vortex_union
+                .type_ids()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u8>(),
+            &[0, 1, 0]
+        );
+
+        let roundtrip = Canonical::Union(vortex_union).into_arrow();
+        let roundtrip = roundtrip
+            .as_any()
+            .downcast_ref::<ArrowUnionArray>()
+            .unwrap();
+        assert_eq!(roundtrip.type_ids(), &[0, 1, 0]);
+        assert_eq!(roundtrip.value_offset(2), 1);
+        assert!(roundtrip.value(2).is_null(0));
+        assert_eq!(
+            roundtrip
+                .value(1)
+                .as_any()
+                .downcast_ref::<ArrowPrimitiveArray<Float64Type>>()
+                .unwrap()
+                .value(0),
+            2.5
+        );
+    }
+
+    #[test]
+    fn test_union_nulls_to_arrow() {
+        let dtype = DType::Union(
+            UnionDType::try_new(
+                ["int".into()].into(),
+                vec![DType::Primitive(PType::I32, Nullability::NonNullable)],
+                UnionMode::Dense,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        );
+        // The second and third element share a value, of which only the third is null.
+        let union = UnionArray::try_new(
+            dtype,
+            PrimitiveArray::from(vec![0u8, 0, 0]).into_array(),
+            Some(PrimitiveArray::from(vec![0u32, 1, 1]).into_array()),
+            vec![PrimitiveArray::from(vec![5i32, 6]).into_array()],
+            Validity::from(vec![true, true, false]),
+        )
+        .unwrap();
+
+        let arrow = Canonical::Union(union).into_arrow();
+        let arrow = arrow.as_any().downcast_ref::<ArrowUnionArray>().unwrap();
+        let values = (0..arrow.len())
+            .map(|i| {
+                let value = arrow.value(i);
+                let value = value.as_primitive::<Int32Type>();
+                value.is_valid(0).then(|| value.value(0))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![Some(5), Some(6), None]);
+    }
+
+    #[test]
+    fn test_map_arrow_roundtrip() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("c");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        let arrow_map = builder.finish();
+
+        let vortex_map =
+            crate::Array::from_arrow(Arc::new(arrow_map.clone()) as arrow_array::ArrayRef, true)
+                .into_map()
+                .unwrap();
+        assert_eq!(vortex_map.len(), 3);
+
+        let roundtrip = Canonical::Map(vortex_map).into_arrow();
+        let roundtrip = roundtrip.as_any().downcast_ref::<ArrowMapArray>().unwrap();
+        assert_eq!(roundtrip.value_offsets(), arrow_map.value_offsets());
+        assert_eq!(roundtrip.nulls(), arrow_map.nulls());
+        assert_eq!(
+            roundtrip.values().as_primitive::<Int32Type>(),
+            arrow_map.values().as_primitive::<Int32Type>()
+        );
+    }
+
+    #[test]
+    fn test_duration_interval_arrow_roundtrip() {
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(DurationMillisecondArray::from(vec![
+                Some(-1),
+                None,
+                Some(90_061_001),
+            ])),
+            Arc::new(IntervalYearMonthArray::from(vec![Some(14), None])),
+            Arc::new(IntervalDayTimeArray::from(vec![
+                Some(IntervalDayTime::new(1, 500)),
+                None,
+                Some(IntervalDayTime::new(-2, 0)),
+            ])),
+            Arc::new(IntervalMonthDayNanoArray::from(vec![
+                None,
+                Some(IntervalMonthDayNano::new(1, 2, 3_000_000_000)),
+            ])),
+        ];
+
+        for arrow_array in arrays {
+            let vortex_array = crate::Array::from_arrow(arrow_array.clone(), true);
+            assert!(vortex_array.dtype().is_nullable());
+
+            let roundtrip =
+                Canonical::Extension(vortex_array.into_extension().unwrap()).into_arrow();
+            assert_eq!(&roundtrip, &arrow_array);
+        }
+    }
 }
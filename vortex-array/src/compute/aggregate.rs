@@ -0,0 +1,345 @@
+use arrow_buffer::NullBuffer;
+use num_traits::AsPrimitive;
+use vortex_dtype::half::f16;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::PrimitiveArray;
+use crate::compute::arithmetic::ArithmeticNative;
+use crate::compute::{scalar_binary_numeric, BinaryOperator, OverflowMode};
+use crate::stats::{ArrayStatistics, Stat};
+use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::{Array, ArrayDType, IntoArrayVariant, IntoCanonical};
+
+/// Sum of the valid values of an array.
+pub trait SumFn {
+    /// Returns a scalar of [sum_dtype], which is null if the array has no valid values.
+    fn sum(&self) -> VortexResult<Scalar>;
+}
+
+/// Minimum and maximum of the valid values of an array.
+pub trait MinMaxFn {
+    fn min_max(&self) -> VortexResult<MinMax>;
+}
+
+/// The minimum and maximum valid values of an array, both null if there are none.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinMax {
+    pub min: Scalar,
+    pub max: Scalar,
+}
+
+impl MinMax {
+    /// The min and max of values of `dtype` that are all null.
+    pub fn null(dtype: &DType) -> Self {
+        Self {
+            min: Scalar::null(dtype.as_nullable()),
+            max: Scalar::null(dtype.as_nullable()),
+        }
+    }
+
+    /// The min and max of a single value.
+    pub fn of(value: &Scalar) -> VortexResult<Self> {
+        let value = value.cast(&value.dtype().as_nullable())?;
+        Ok(Self {
+            min: value.clone(),
+            max: value,
+        })
+    }
+
+    /// The min and max over the values of both `self` and `other`.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            min: merge_by(self.min, other.min, |a, b| a < b),
+            max: merge_by(self.max, other.max, |a, b| a > b),
+        }
+    }
+}
+
+fn merge_by(a: Scalar, b: Scalar, prefer_a: impl Fn(&Scalar, &Scalar) -> bool) -> Scalar {
+    match (a.is_null(), b.is_null()) {
+        (true, _) => b,
+        (_, true) => a,
+        _ if prefer_a(&a, &b) => a,
+        _ => b,
+    }
+}
+
+/// Sum the valid values of a primitive array.
+///
+/// Integers are summed as 64-bit integers of the same signedness, failing on overflow, and floats
+/// as `f64`. The sum is null if there are no valid values.
+pub fn sum(array: &Array) -> VortexResult<Scalar> {
+    sum_dtype(array.dtype())?;
+
+    if let Some(result) = array.with_dyn(|a| a.sum().map(|a| a.sum())) {
+        return result;
+    }
+
+    sum_primitive(&array.clone().into_primitive()?, None)
+}
+
+/// The minimum and maximum valid values of an array.
+///
+/// Cached [Stat::Min] and [Stat::Max] statistics are used when present.
+pub fn min_max(array: &Array) -> VortexResult<MinMax> {
+    let stats = array.statistics();
+    if let (Some(min), Some(max)) = (stats.get(Stat::Min), stats.get(Stat::Max)) {
+        let dtype = array.dtype().as_nullable();
+        return Ok(MinMax {
+            min: min.cast(&dtype)?,
+            max: max.cast(&dtype)?,
+        });
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.min_max().map(|a| a.min_max())) {
+        return result;
+    }
+
+    let canonical = Array::from(array.clone().into_canonical()?);
+    let stats = canonical.statistics();
+    match (stats.compute(Stat::Min), stats.compute(Stat::Max)) {
+        (Some(min), Some(max)) => {
+            let dtype = array.dtype().as_nullable();
+            Ok(MinMax {
+                min: min.cast(&dtype)?,
+                max: max.cast(&dtype)?,
+            })
+        }
+        _ if count(array)? == 0 => Ok(MinMax::null(array.dtype())),
+        _ => Err(vortex_err!(NotImplemented: "min_max", array.encoding().id())),
+    }
+}
+
+pub fn min(array: &Array) -> VortexResult<Scalar> {
+    Ok(min_max(array)?.min)
+}
+
+pub fn max(array: &Array) -> VortexResult<Scalar> {
+    Ok(min_max(array)?.max)
+}
+
+/// The number of valid values in an array.
+pub fn count(array: &Array) -> VortexResult<usize> {
+    if let Some(null_count) = array.statistics().get_as::<usize>(Stat::NullCount) {
+        return Ok(array.len() - null_count);
+    }
+
+    Ok(match array.with_dyn(|a| a.logical_validity()) {
+        LogicalValidity::AllValid(len) => len,
+        LogicalValidity::AllInvalid(_) => 0,
+        LogicalValidity::Array(validity) => validity.into_bool()?.boolean_buffer().count_set_bits(),
+    })
+}
+
+/// The mean of the valid values of a primitive array as an `f64`, null if there are none.
+pub fn mean(array: &Array) -> VortexResult<Scalar> {
+    let dtype = DType::Primitive(PType::F64, Nullability::Nullable);
+    let total = sum(array)?;
+    let count = count(array)?;
+    if total.is_null() || count == 0 {
+        return Ok(Scalar::null(dtype));
+    }
+    let total = f64::try_from(&total.cast(&dtype)?)?;
+    Ok(Scalar::primitive(
+        total / count as f64,
+        Nullability::Nullable,
+    ))
+}
+
+/// The DType of the sum of values of `dtype`.
+pub fn sum_dtype(dtype: &DType) -> VortexResult<DType> {
+    let DType::Primitive(ptype, _) = dtype else {
+        vortex_bail!("Sum is only supported on primitive types, got {}", dtype);
+    };
+    let sum_ptype = if ptype.is_float() {
+        PType::F64
+    } else if ptype.is_signed_int() {
+        PType::I64
+    } else {
+        PType::U64
+    };
+    Ok(DType::Primitive(sum_ptype, Nullability::Nullable))
+}
+
+/// Add two sums, where a null sum is the sum of no values.
+pub fn add_sums(lhs: &Scalar, rhs: &Scalar) -> VortexResult<Scalar> {
+    match (lhs.is_null(), rhs.is_null()) {
+        (true, _) => Ok(rhs.clone()),
+        (_, true) => Ok(lhs.clone()),
+        _ => scalar_binary_numeric(lhs, rhs, BinaryOperator::Add, OverflowMode::Checked),
+    }
+}
+
+/// The sum of `count` repetitions of the primitive `value`.
+pub fn sum_repeated(value: &Scalar, count: usize) -> VortexResult<Scalar> {
+    let dtype = sum_dtype(value.dtype())?;
+    if value.is_null() || count == 0 {
+        return Ok(Scalar::null(dtype));
+    }
+    scalar_binary_numeric(
+        &value.cast(&dtype)?,
+        &Scalar::from(count as u64).cast(&dtype)?,
+        BinaryOperator::Mul,
+        OverflowMode::Checked,
+    )
+}
+
+/// The sum of each valid value of a primitive array multiplied by its count.
+///
+/// This sums encodings that reference values several times, such as runs or dictionaries.
+pub fn weighted_sum(values: &Array, counts: &[u64]) -> VortexResult<Scalar> {
+    if values.len() != counts.len() {
+        vortex_bail!(
+            "Expected a count for each of {} values, got {}",
+            values.len(),
+            counts.len()
+        );
+    }
+    sum_primitive(&values.clone().into_primitive()?, Some(counts))
+}
+
+/// Sum the valid values of a primitive array, each multiplied by its count if `counts` is given.
+pub(crate) fn sum_primitive(
+    array: &PrimitiveArray,
+    counts: Option<&[u64]>,
+) -> VortexResult<Scalar> {
+    let nulls = array.logical_validity().to_null_buffer()?;
+    match_each_native_ptype!(array.ptype(), |$T| {
+        <$T as Summable>::sum_values(array.maybe_null_slice::<$T>(), counts, nulls.as_ref())
+    })
+}
+
+/// Native types that can be summed into a wider accumulator.
+trait Summable: NativePType {
+    fn sum_values(
+        values: &[Self],
+        counts: Option<&[u64]>,
+        nulls: Option<&NullBuffer>,
+    ) -> VortexResult<Scalar>;
+}
+
+macro_rules! summable {
+    ($acc:ty => $($T:ty),*) => {
+        $(
+            impl Summable for $T {
+                fn sum_values(
+                    values: &[Self],
+                    counts: Option<&[u64]>,
+                    nulls: Option<&NullBuffer>,
+                ) -> VortexResult<Scalar> {
+                    let mut total: Option<$acc> = None;
+                    for (i, value) in values.iter().enumerate() {
+                        if nulls.map_or(false, |n| n.is_null(i)) {
+                            continue;
+                        }
+                        let count = counts.map_or(1, |c| c[i]);
+                        if count == 0 {
+                            continue;
+                        }
+                        let value: $acc = value.as_();
+                        let count: $acc = count.as_();
+                        let term = if counts.is_some() {
+                            value.binary_op(count, BinaryOperator::Mul, OverflowMode::Checked)
+                        } else {
+                            Some(value)
+                        };
+                        total = Some(
+                            term.and_then(|term| match total {
+                                None => Some(term),
+                                Some(total) => {
+                                    total.binary_op(term, BinaryOperator::Add, OverflowMode::Checked)
+                                }
+                            })
+                            .ok_or_else(|| vortex_err!("Sum overflows {}", <$acc>::PTYPE))?,
+                        );
+                    }
+                    Ok(match total {
+                        Some(total) => Scalar::primitive(total, Nullability::Nullable),
+                        None => Scalar::null(DType::Primitive(<$acc>::PTYPE, Nullability::Nullable)),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+summable!(i64 => i8, i16, i32, i64);
+summable!(u64 => u8, u16, u32, u64);
+summable!(f64 => f16, f32, f64);
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ChunkedArray, ConstantArray, PrimitiveArray, SparseArray};
+    use crate::compute::{count, max, mean, min, min_max, sum, MinMax};
+    use crate::stats::{ArrayStatistics, Stat};
+    use crate::IntoArray;
+
+    #[test]
+    fn primitive() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(-7), Some(10)])
+            .into_array();
+        assert_eq!(sum(&array).unwrap(), Scalar::from(Some(6i64)));
+        assert_eq!(count(&array).unwrap(), 3);
+        assert_eq!(mean(&array).unwrap(), Scalar::from(Some(2.0f64)));
+        assert_eq!(min(&array).unwrap(), Scalar::from(Some(-7i32)));
+        assert_eq!(max(&array).unwrap(), Scalar::from(Some(10i32)));
+    }
+
+    #[test]
+    fn empty_and_overflow() {
+        let empty = PrimitiveArray::from_nullable_vec(vec![None::<u8>]).into_array();
+        assert!(sum(&empty).unwrap().is_null());
+        assert!(mean(&empty).unwrap().is_null());
+        assert_eq!(
+            min_max(&empty).unwrap(),
+            MinMax::null(&DType::Primitive(PType::U8, Nullability::Nullable))
+        );
+
+        let large = PrimitiveArray::from(vec![u64::MAX, 1]).into_array();
+        assert!(sum(&large).is_err());
+    }
+
+    #[test]
+    fn cached_stats() {
+        let array = PrimitiveArray::from(vec![1u16, 2, 3]).into_array();
+        array.statistics().set(Stat::Min, Scalar::from(0u16));
+        array.statistics().set(Stat::Max, Scalar::from(9u16));
+        assert_eq!(min(&array).unwrap(), Scalar::from(Some(0u16)));
+        assert_eq!(max(&array).unwrap(), Scalar::from(Some(9u16)));
+    }
+
+    #[test]
+    fn constant_and_sparse() {
+        let constant = ConstantArray::new(5u32, 10).into_array();
+        assert_eq!(sum(&constant).unwrap(), Scalar::from(Some(50u64)));
+        assert_eq!(max(&constant).unwrap(), Scalar::from(Some(5u32)));
+
+        let sparse = SparseArray::try_new(
+            PrimitiveArray::from(vec![1u64, 4]).into_array(),
+            PrimitiveArray::from(vec![-10i64, 20]).into_array(),
+            6,
+            Scalar::from(1i64),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(sum(&sparse).unwrap(), Scalar::from(Some(14i64)));
+        assert_eq!(min(&sparse).unwrap(), Scalar::from(Some(-10i64)));
+        assert_eq!(max(&sparse).unwrap(), Scalar::from(Some(20i64)));
+    }
+
+    #[test]
+    fn chunked() {
+        let chunked = ChunkedArray::from_iter([
+            PrimitiveArray::from(vec![1.5f32, 2.5]).into_array(),
+            PrimitiveArray::from(vec![-1f32]).into_array(),
+        ])
+        .into_array();
+        assert_eq!(sum(&chunked).unwrap(), Scalar::from(Some(3.0f64)));
+        assert_eq!(min(&chunked).unwrap(), Scalar::from(Some(-1f32)));
+    }
+}
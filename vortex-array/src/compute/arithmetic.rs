@@ -0,0 +1,433 @@
+use std::fmt::{Display, Formatter};
+
+use vortex_dtype::half::f16;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::array::ConstantArray;
+use crate::{Array, ArrayDType, IntoArrayVariant};
+
+/// A binary arithmetic operator on numeric values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let display = match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Sub => "-",
+            BinaryOperator::Mul => "*",
+            BinaryOperator::Div => "/",
+            BinaryOperator::Rem => "%",
+        };
+        Display::fmt(display, f)
+    }
+}
+
+/// How integer arithmetic handles results that do not fit the integer type.
+///
+/// Integer division by zero is an error in both modes, floating point arithmetic follows IEEE 754.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverflowMode {
+    /// Fail if any valid element overflows.
+    #[default]
+    Checked,
+    /// Wrap around at the boundary of the type.
+    Wrapping,
+}
+
+/// Element-wise arithmetic between two arrays of the same numeric type.
+pub trait BinaryNumericFn {
+    fn binary_numeric(
+        &self,
+        rhs: &Array,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<Array>;
+}
+
+/// Arithmetic between every element of an array and a scalar, with the array on the left-hand
+/// side of the operator.
+pub trait ScalarNumericFn {
+    fn scalar_numeric(
+        &self,
+        rhs: &Scalar,
+        operator: BinaryOperator,
+        mode: OverflowMode,
+    ) -> VortexResult<Array>;
+}
+
+pub trait NegateFn {
+    fn negate(&self, mode: OverflowMode) -> VortexResult<Array>;
+}
+
+/// Apply `operator` to each pair of elements of `lhs` and `rhs`.
+///
+/// Both arrays must have the same primitive type, ignoring nullability. An element of the result
+/// is null if either input element is null.
+pub fn binary_numeric(
+    lhs: &Array,
+    rhs: &Array,
+    operator: BinaryOperator,
+    mode: OverflowMode,
+) -> VortexResult<Array> {
+    if lhs.len() != rhs.len() {
+        vortex_bail!("Arithmetic operations only support arrays of the same length");
+    }
+    check_numeric_dtypes(lhs.dtype(), rhs.dtype())?;
+
+    if let Ok(constant) = ConstantArray::try_from(rhs) {
+        return scalar_numeric(lhs, constant.scalar(), operator, mode);
+    }
+
+    if let Some(result) = lhs.with_dyn(|a| {
+        a.binary_numeric()
+            .map(|a| a.binary_numeric(rhs, operator, mode))
+    }) {
+        return result;
+    }
+
+    lhs.clone()
+        .into_primitive()?
+        .binary_numeric(rhs, operator, mode)
+}
+
+/// Apply `operator` to each element of `array` and `rhs`.
+///
+/// The result is entirely null if `rhs` is null.
+pub fn scalar_numeric(
+    array: &Array,
+    rhs: &Scalar,
+    operator: BinaryOperator,
+    mode: OverflowMode,
+) -> VortexResult<Array> {
+    check_numeric_dtypes(array.dtype(), rhs.dtype())?;
+
+    if let Some(result) = array.with_dyn(|a| {
+        a.scalar_numeric()
+            .map(|a| a.scalar_numeric(rhs, operator, mode))
+    }) {
+        return result;
+    }
+
+    array
+        .clone()
+        .into_primitive()?
+        .scalar_numeric(rhs, operator, mode)
+}
+
+/// Negate each element of a numeric array.
+pub fn negate(array: &Array, mode: OverflowMode) -> VortexResult<Array> {
+    if !matches!(array.dtype(), DType::Primitive(..)) {
+        vortex_bail!("Arithmetic operations are only supported on primitive arrays");
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.negate().map(|a| a.negate(mode))) {
+        return result;
+    }
+
+    array.clone().into_primitive()?.negate(mode)
+}
+
+pub fn add(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_numeric(lhs, rhs, BinaryOperator::Add, OverflowMode::Checked)
+}
+
+pub fn sub(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_numeric(lhs, rhs, BinaryOperator::Sub, OverflowMode::Checked)
+}
+
+pub fn mul(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_numeric(lhs, rhs, BinaryOperator::Mul, OverflowMode::Checked)
+}
+
+pub fn div(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_numeric(lhs, rhs, BinaryOperator::Div, OverflowMode::Checked)
+}
+
+pub fn rem(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_numeric(lhs, rhs, BinaryOperator::Rem, OverflowMode::Checked)
+}
+
+/// Apply `operator` to two primitive scalars, returning a null scalar if either is null.
+pub fn scalar_binary_numeric(
+    lhs: &Scalar,
+    rhs: &Scalar,
+    operator: BinaryOperator,
+    mode: OverflowMode,
+) -> VortexResult<Scalar> {
+    check_numeric_dtypes(lhs.dtype(), rhs.dtype())?;
+    let dtype = numeric_result_dtype(lhs.dtype(), rhs.dtype());
+    let lhs = PrimitiveScalar::try_from(lhs)?;
+    let rhs = PrimitiveScalar::try_from(rhs)?;
+
+    match_each_native_ptype!(lhs.ptype(), |$T| {
+        match (lhs.typed_value::<$T>(), rhs.typed_value::<$T>()) {
+            (Some(l), Some(r)) => {
+                let value = l
+                    .binary_op(r, operator, mode)
+                    .ok_or_else(|| arithmetic_error(l, r, operator))?;
+                Ok(Scalar::primitive(value, dtype.nullability()))
+            }
+            _ => Ok(Scalar::null(dtype)),
+        }
+    })
+}
+
+/// Negate a primitive scalar, returning a null scalar if it is null.
+pub fn scalar_negate(scalar: &Scalar, mode: OverflowMode) -> VortexResult<Scalar> {
+    let primitive = PrimitiveScalar::try_from(scalar)?;
+    match_each_native_ptype!(primitive.ptype(), |$T| {
+        match primitive.typed_value::<$T>() {
+            Some(v) => {
+                let value = v
+                    .negate_op(mode)
+                    .ok_or_else(|| vortex_err!("Negating {} overflows", v))?;
+                Ok(Scalar::primitive(value, scalar.dtype().nullability()))
+            }
+            None => Ok(scalar.clone()),
+        }
+    })
+}
+
+/// The DType of the result of arithmetic between values of `lhs` and `rhs`.
+pub(crate) fn numeric_result_dtype(lhs: &DType, rhs: &DType) -> DType {
+    lhs.with_nullability(Nullability::from(lhs.is_nullable() || rhs.is_nullable()))
+}
+
+fn check_numeric_dtypes(lhs: &DType, rhs: &DType) -> VortexResult<()> {
+    if !matches!(lhs, DType::Primitive(..)) {
+        vortex_bail!("Arithmetic operations are only supported on primitive types, got {lhs}");
+    }
+    // Like compare, operands must have the same type until there is type coercion.
+    if !lhs.eq_ignore_nullability(rhs) {
+        vortex_bail!(MismatchedTypes: lhs, rhs);
+    }
+    Ok(())
+}
+
+/// The error for a failed arithmetic operation between two valid values.
+pub(crate) fn arithmetic_error<T: ArithmeticNative>(
+    lhs: T,
+    rhs: T,
+    operator: BinaryOperator,
+) -> VortexError {
+    if matches!(operator, BinaryOperator::Div | BinaryOperator::Rem) && rhs == T::zero() {
+        vortex_err!("Division by zero evaluating {} {} {}", lhs, operator, rhs)
+    } else {
+        vortex_err!(
+            "Arithmetic overflow evaluating {} {} {}",
+            lhs,
+            operator,
+            rhs
+        )
+    }
+}
+
+/// Arithmetic on native values, returning `None` if the result is not representable.
+pub(crate) trait ArithmeticNative: NativePType {
+    fn binary_op(self, rhs: Self, operator: BinaryOperator, mode: OverflowMode) -> Option<Self>;
+
+    fn negate_op(self, mode: OverflowMode) -> Option<Self>;
+}
+
+macro_rules! integer_arithmetic {
+    ($($T:ty),*) => {
+        $(
+            impl ArithmeticNative for $T {
+                #[inline]
+                fn binary_op(
+                    self,
+                    rhs: Self,
+                    operator: BinaryOperator,
+                    mode: OverflowMode,
+                ) -> Option<Self> {
+                    match (mode, operator) {
+                        (OverflowMode::Checked, BinaryOperator::Add) => self.checked_add(rhs),
+                        (OverflowMode::Checked, BinaryOperator::Sub) => self.checked_sub(rhs),
+                        (OverflowMode::Checked, BinaryOperator::Mul) => self.checked_mul(rhs),
+                        (OverflowMode::Checked, BinaryOperator::Div) => self.checked_div(rhs),
+                        (OverflowMode::Checked, BinaryOperator::Rem) => self.checked_rem(rhs),
+                        (OverflowMode::Wrapping, BinaryOperator::Add) => Some(self.wrapping_add(rhs)),
+                        (OverflowMode::Wrapping, BinaryOperator::Sub) => Some(self.wrapping_sub(rhs)),
+                        (OverflowMode::Wrapping, BinaryOperator::Mul) => Some(self.wrapping_mul(rhs)),
+                        (OverflowMode::Wrapping, BinaryOperator::Div) => {
+                            (rhs != 0).then(|| self.wrapping_div(rhs))
+                        }
+                        (OverflowMode::Wrapping, BinaryOperator::Rem) => {
+                            (rhs != 0).then(|| self.wrapping_rem(rhs))
+                        }
+                    }
+                }
+
+                #[inline]
+                fn negate_op(self, mode: OverflowMode) -> Option<Self> {
+                    match mode {
+                        OverflowMode::Checked => self.checked_neg(),
+                        OverflowMode::Wrapping => Some(self.wrapping_neg()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer_arithmetic!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+macro_rules! float_arithmetic {
+    ($($T:ty),*) => {
+        $(
+            impl ArithmeticNative for $T {
+                #[inline]
+                fn binary_op(self, rhs: Self, operator: BinaryOperator, _mode: OverflowMode) -> Option<Self> {
+                    Some(match operator {
+                        BinaryOperator::Add => self + rhs,
+                        BinaryOperator::Sub => self - rhs,
+                        BinaryOperator::Mul => self * rhs,
+                        BinaryOperator::Div => self / rhs,
+                        BinaryOperator::Rem => self % rhs,
+                    })
+                }
+
+                #[inline]
+                fn negate_op(self, _mode: OverflowMode) -> Option<Self> {
+                    Some(-self)
+                }
+            }
+        )*
+    };
+}
+
+float_arithmetic!(f16, f32, f64);
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ChunkedArray, ConstantArray, PrimitiveArray};
+    use crate::compute::unary::scalar_at;
+    use crate::compute::{
+        add, binary_numeric, div, negate, scalar_numeric, sub, BinaryOperator, OverflowMode,
+    };
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn array_array() {
+        let lhs = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(10)]).into_array();
+        let rhs = PrimitiveArray::from(vec![2i32, 3, 4]).into_array();
+
+        let sum = add(&lhs, &rhs).unwrap();
+        assert!(sum.dtype().is_nullable());
+        assert_eq!(scalar_at(&sum, 0).unwrap(), Scalar::from(Some(3i32)));
+        assert!(scalar_at(&sum, 1).unwrap().is_null());
+
+        let difference = sub(&rhs, &lhs).unwrap().into_primitive().unwrap();
+        assert_eq!(difference.maybe_null_slice::<i32>()[2], -6);
+    }
+
+    #[test]
+    fn overflow_modes() {
+        let lhs = PrimitiveArray::from(vec![u8::MAX, 1]).into_array();
+        let rhs = PrimitiveArray::from(vec![1u8, 1]).into_array();
+        assert!(add(&lhs, &rhs).is_err());
+
+        let wrapped = binary_numeric(&lhs, &rhs, BinaryOperator::Add, OverflowMode::Wrapping)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(wrapped.maybe_null_slice::<u8>(), &[0, 2]);
+
+        assert!(negate(&lhs, OverflowMode::Checked).is_err());
+        assert_eq!(
+            negate(&lhs, OverflowMode::Wrapping)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u8>(),
+            &[1, 255]
+        );
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let lhs = PrimitiveArray::from_nullable_vec(vec![Some(4i64), Some(6)]).into_array();
+        let rhs = PrimitiveArray::from_nullable_vec(vec![Some(2i64), Some(0)]).into_array();
+        assert!(div(&lhs, &rhs).is_err());
+        assert!(binary_numeric(&lhs, &rhs, BinaryOperator::Div, OverflowMode::Wrapping).is_err());
+
+        // Division by zero is only an error for valid elements.
+        let rhs = PrimitiveArray::from_nullable_vec(vec![Some(2i64), None]).into_array();
+        let quotient = div(&lhs, &rhs).unwrap();
+        assert_eq!(scalar_at(&quotient, 0).unwrap(), Scalar::from(Some(2i64)));
+        assert!(scalar_at(&quotient, 1).unwrap().is_null());
+
+        let floats = PrimitiveArray::from(vec![1.0f64]).into_array();
+        let zero = PrimitiveArray::from(vec![0.0f64]).into_array();
+        assert_eq!(
+            div(&floats, &zero)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<f64>(),
+            &[f64::INFINITY]
+        );
+    }
+
+    #[test]
+    fn scalar_rhs() {
+        let array = PrimitiveArray::from(vec![10u32, 20, 30]).into_array();
+        let remainder = scalar_numeric(
+            &array,
+            &Scalar::from(7u32),
+            BinaryOperator::Rem,
+            OverflowMode::Checked,
+        )
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+        assert_eq!(remainder.maybe_null_slice::<u32>(), &[3, 6, 2]);
+
+        let null = scalar_numeric(
+            &array,
+            &Scalar::from(None::<u32>),
+            BinaryOperator::Mul,
+            OverflowMode::Checked,
+        )
+        .unwrap();
+        assert!(null.dtype().is_nullable());
+        assert!(scalar_at(&null, 2).unwrap().is_null());
+    }
+
+    #[test]
+    fn constant_stays_constant() {
+        let lhs = ConstantArray::new(5i16, 4).into_array();
+        let rhs = ConstantArray::new(3i16, 4).into_array();
+        let product =
+            binary_numeric(&lhs, &rhs, BinaryOperator::Mul, OverflowMode::Checked).unwrap();
+        assert!(ConstantArray::try_from(&product).is_ok());
+        assert_eq!(scalar_at(&product, 3).unwrap(), Scalar::from(15i16));
+    }
+
+    #[test]
+    fn chunked() {
+        let lhs = ChunkedArray::from_iter([
+            PrimitiveArray::from(vec![1i64, 2]).into_array(),
+            PrimitiveArray::from(vec![3i64]).into_array(),
+        ])
+        .into_array();
+        let rhs = PrimitiveArray::from(vec![10i64, 20, 30]).into_array();
+        let sum = add(&lhs, &rhs).unwrap();
+        assert!(ChunkedArray::try_from(&sum).is_ok());
+        assert_eq!(
+            sum.into_primitive().unwrap().maybe_null_slice::<i64>(),
+            &[11, 22, 33]
+        );
+    }
+}
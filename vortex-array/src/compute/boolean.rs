@@ -1,5 +1,6 @@
 use vortex_error::{vortex_bail, VortexResult};
 
+use crate::array::BoolArray;
 use crate::{Array, ArrayDType, IntoArrayVariant};
 
 pub trait AndFn {
@@ -10,30 +11,93 @@ pub trait OrFn {
     fn or(&self, array: &Array) -> VortexResult<Array>;
 }
 
+pub trait XorFn {
+    fn xor(&self, array: &Array) -> VortexResult<Array>;
+}
+
+pub trait AndKleeneFn {
+    fn and_kleene(&self, array: &Array) -> VortexResult<Array>;
+}
+
+pub trait OrKleeneFn {
+    fn or_kleene(&self, array: &Array) -> VortexResult<Array>;
+}
+
+pub trait NotFn {
+    fn not(&self) -> VortexResult<Array>;
+}
+
+/// Logical conjunction, null wherever either side is null.
 pub fn and(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
-    if lhs.len() != rhs.len() {
-        vortex_bail!("Boolean operations aren't supported on arrays of different lengths")
-    }
+    binary_boolean(
+        lhs,
+        rhs,
+        |lhs, rhs| lhs.with_dyn(|lhs| lhs.and().map(|lhs| lhs.and(rhs))),
+        |lhs, rhs| lhs.and(rhs),
+    )
+}
 
-    if !lhs.dtype().is_boolean() || !rhs.dtype().is_boolean() {
-        vortex_bail!("Boolean operations are only supported on boolean arrays")
-    }
+/// Logical disjunction, null wherever either side is null.
+pub fn or(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_boolean(
+        lhs,
+        rhs,
+        |lhs, rhs| lhs.with_dyn(|lhs| lhs.or().map(|lhs| lhs.or(rhs))),
+        |lhs, rhs| lhs.or(rhs),
+    )
+}
 
-    if let Some(selection) = lhs.with_dyn(|lhs| lhs.and().map(|lhs| lhs.and(rhs))) {
-        return selection;
-    }
+/// Logical exclusive or, null wherever either side is null.
+pub fn xor(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_boolean(
+        lhs,
+        rhs,
+        |lhs, rhs| lhs.with_dyn(|lhs| lhs.xor().map(|lhs| lhs.xor(rhs))),
+        |lhs, rhs| lhs.xor(rhs),
+    )
+}
+
+/// SQL `AND`: false if either side is false, otherwise null if either side is null.
+pub fn and_kleene(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_boolean(
+        lhs,
+        rhs,
+        |lhs, rhs| lhs.with_dyn(|lhs| lhs.and_kleene().map(|lhs| lhs.and_kleene(rhs))),
+        |lhs, rhs| lhs.and_kleene(rhs),
+    )
+}
 
-    if let Some(selection) = rhs.with_dyn(|rhs| rhs.and().map(|rhs| rhs.and(lhs))) {
-        return selection;
+/// SQL `OR`: true if either side is true, otherwise null if either side is null.
+pub fn or_kleene(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_boolean(
+        lhs,
+        rhs,
+        |lhs, rhs| lhs.with_dyn(|lhs| lhs.or_kleene().map(|lhs| lhs.or_kleene(rhs))),
+        |lhs, rhs| lhs.or_kleene(rhs),
+    )
+}
+
+/// Logical negation, null values stay null.
+pub fn not(array: &Array) -> VortexResult<Array> {
+    if !array.dtype().is_boolean() {
+        vortex_bail!("Boolean operations are only supported on boolean arrays")
     }
 
-    // If neither side implements `AndFn`, we try to expand the left-hand side into a `BoolArray`, which we know does implement it, and call into that implementation.
-    let lhs = lhs.clone().into_bool()?;
+    if let Some(result) = array.with_dyn(|a| a.not().map(|a| a.not())) {
+        return result;
+    }
 
-    lhs.and(rhs)
+    array.clone().into_bool()?.not()
 }
 
-pub fn or(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+/// All binary boolean operations are commutative, so the right-hand side's kernel is tried
+/// with the operands swapped before falling back to [BoolArray].
+fn binary_boolean(
+    lhs: &Array,
+    rhs: &Array,
+    kernel: impl Fn(&Array, &Array) -> Option<VortexResult<Array>>,
+    canonical: impl FnOnce(&BoolArray, &Array) -> VortexResult<Array>,
+) -> VortexResult<Array> {
     if lhs.len() != rhs.len() {
         vortex_bail!("Boolean operations aren't supported on arrays of different lengths")
     }
@@ -42,18 +106,17 @@ pub fn or(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
         vortex_bail!("Boolean operations are only supported on boolean arrays")
     }
 
-    if let Some(selection) = lhs.with_dyn(|lhs| lhs.or().map(|lhs| lhs.or(rhs))) {
-        return selection;
+    if let Some(result) = kernel(lhs, rhs) {
+        return result;
     }
 
-    if let Some(selection) = rhs.with_dyn(|rhs| rhs.or().map(|rhs| rhs.or(lhs))) {
-        return selection;
+    if let Some(result) = kernel(rhs, lhs) {
+        return result;
     }
 
-    // If neither side implements `OrFn`, we try to expand the left-hand side into a `BoolArray`, which we know does implement it, and call into that implementation.
-    let lhs = lhs.clone().into_bool()?;
-
-    lhs.or(rhs)
+    // If neither side implements the operation, we expand the left-hand side into a `BoolArray`,
+    // which we know does implement it, and call into that implementation.
+    canonical(&lhs.clone().into_bool()?, rhs)
 }
 
 #[cfg(test)]
@@ -106,4 +169,50 @@ mod tests {
         assert!(!v2.unwrap());
         assert!(!v3.unwrap());
     }
+
+    fn nullable_bools() -> (Array, Array) {
+        let values = [Some(true), Some(false), None];
+        let lhs = values.iter().flat_map(|l| values.iter().map(move |_| *l));
+        let rhs = values.iter().flat_map(|_| values.iter().copied());
+        (
+            BoolArray::from_iter(lhs).into_array(),
+            BoolArray::from_iter(rhs).into_array(),
+        )
+    }
+
+    fn to_vec(array: Array) -> Vec<Option<bool>> {
+        let array = array.into_bool().unwrap();
+        (0..array.len())
+            .map(|i| {
+                scalar_at(array.array(), i)
+                    .unwrap()
+                    .value()
+                    .as_bool()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_null_semantics() {
+        let (lhs, rhs) = nullable_bools();
+        let (t, f, n) = (Some(true), Some(false), None);
+        assert_eq!(
+            to_vec(and_kleene(&lhs, &rhs).unwrap()),
+            vec![t, f, n, f, f, f, n, f, n]
+        );
+        assert_eq!(
+            to_vec(or_kleene(&lhs, &rhs).unwrap()),
+            vec![t, t, t, t, f, n, t, n, n]
+        );
+        assert_eq!(
+            to_vec(xor(&lhs, &rhs).unwrap()),
+            vec![f, t, n, t, f, n, n, n, n]
+        );
+        assert_eq!(
+            to_vec(and(&lhs, &rhs).unwrap()),
+            vec![t, f, n, f, f, n, n, n, n]
+        );
+        assert_eq!(to_vec(not(&lhs).unwrap()), vec![f, f, f, t, t, t, n, n, n]);
+    }
 }
@@ -27,14 +27,22 @@ pub fn compare(left: &Array, right: &Array, operator: Operator) -> VortexResult<
         return selection;
     }
 
-    if let Some(selection) = right.with_dyn(|rhs| {
-        rhs.compare()
-            .map(|rhs| rhs.compare(left, operator.inverse()))
-    }) {
+    if let Some(selection) =
+        right.with_dyn(|rhs| rhs.compare().map(|rhs| rhs.compare(left, operator.swap())))
+    {
         return selection;
     }
 
     // Fallback to arrow on canonical types
+    arrow_compare(left, right, operator)
+}
+
+/// Compare the canonical Arrow forms of two arrays.
+pub(crate) fn arrow_compare(
+    left: &Array,
+    right: &Array,
+    operator: Operator,
+) -> VortexResult<Array> {
     let lhs = left.clone().into_canonical()?.into_arrow();
     let rhs = right.clone().into_canonical()?.into_arrow();
 
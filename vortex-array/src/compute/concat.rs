@@ -0,0 +1,240 @@
+use std::mem;
+
+use vortex_dtype::{DType, Nullability, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::array::{ChunkedArray, StructArray};
+use crate::compute::slice;
+use crate::compute::unary::{cast_validity, try_cast};
+use crate::variants::StructArrayTrait;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
+
+/// The size of the chunks produced by [rechunk].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChunkSize {
+    /// Chunks of this many rows.
+    Rows(usize),
+    /// Chunks of about this many bytes, estimated from the average row size of the input chunks.
+    Bytes(usize),
+}
+
+/// Concatenate arrays into a single array in canonical form.
+///
+/// The arrays must have the same dtype up to nullability, also of struct fields. The result is
+/// nullable wherever any of the inputs is.
+pub fn concat(arrays: &[Array]) -> VortexResult<Array> {
+    let Some(first) = arrays.first() else {
+        vortex_bail!("Cannot concatenate zero arrays");
+    };
+    let dtype = arrays
+        .iter()
+        .skip(1)
+        .try_fold(first.dtype().clone(), |dtype, array| {
+            common_dtype(&dtype, array.dtype())
+                .ok_or_else(|| vortex_err!(MismatchedTypes: dtype, array.dtype()))
+        })?;
+
+    let chunks = arrays
+        .iter()
+        .map(|array| coerce(array, &dtype))
+        .collect::<VortexResult<Vec<_>>>()?;
+    Ok(ChunkedArray::try_new(chunks, dtype)?
+        .into_canonical()?
+        .into())
+}
+
+/// Split and merge the chunks of a chunked array into chunks of the `target` size.
+///
+/// Chunks that are too large are sliced, and runs of small chunks are [concatenated](concat).
+/// Only the last chunk may be smaller than the target.
+pub fn rechunk(array: &ChunkedArray, target: ChunkSize) -> VortexResult<ChunkedArray> {
+    let target_size = match target {
+        ChunkSize::Rows(size) | ChunkSize::Bytes(size) => size.max(1),
+    };
+
+    let mut chunks = Vec::new();
+    let mut pending = Vec::new();
+    let mut pending_size = 0;
+    for chunk in array.chunks() {
+        let row_size = match target {
+            ChunkSize::Rows(_) => 1,
+            ChunkSize::Bytes(_) => chunk.nbytes().div_ceil(chunk.len().max(1)).max(1),
+        };
+
+        let mut offset = 0;
+        while offset < chunk.len() {
+            let rows = (target_size - pending_size)
+                .div_ceil(row_size)
+                .min(chunk.len() - offset);
+            pending.push(if rows == chunk.len() {
+                chunk.clone()
+            } else {
+                slice(&chunk, offset, offset + rows)?
+            });
+            pending_size += rows * row_size;
+            offset += rows;
+
+            if pending_size >= target_size {
+                chunks.push(merge(mem::take(&mut pending))?);
+                pending_size = 0;
+            }
+        }
+    }
+    if !pending.is_empty() {
+        chunks.push(merge(pending)?);
+    }
+
+    ChunkedArray::try_new(chunks, array.dtype().clone())
+}
+
+/// A single chunk from pieces of the same dtype, keeping the encoding of a lone piece.
+fn merge(mut pieces: Vec<Array>) -> VortexResult<Array> {
+    if pieces.len() == 1 {
+        return Ok(pieces.remove(0));
+    }
+    concat(&pieces)
+}
+
+/// The dtype both dtypes can be cast to, if they only differ in nullability.
+fn common_dtype(a: &DType, b: &DType) -> Option<DType> {
+    let nullability = if a.is_nullable() || b.is_nullable() {
+        Nullability::Nullable
+    } else {
+        Nullability::NonNullable
+    };
+    match (a, b) {
+        (DType::Struct(a_fields, _), DType::Struct(b_fields, _)) => {
+            if a_fields.names() != b_fields.names() {
+                return None;
+            }
+            let dtypes = a_fields
+                .dtypes()
+                .iter()
+                .zip(b_fields.dtypes().iter())
+                .map(|(a, b)| common_dtype(a, b))
+                .collect::<Option<Vec<_>>>()?;
+            Some(DType::Struct(
+                StructDType::new(a_fields.names().clone(), dtypes),
+                nullability,
+            ))
+        }
+        _ if a.eq_ignore_nullability(b) => Some(a.with_nullability(nullability)),
+        _ => None,
+    }
+}
+
+/// Cast an array to the [common_dtype] of it and another array.
+fn coerce(array: &Array, dtype: &DType) -> VortexResult<Array> {
+    if array.dtype() == dtype {
+        return Ok(array.clone());
+    }
+
+    match dtype {
+        DType::Struct(struct_dtype, nullability) => {
+            let array = array.clone().into_struct()?;
+            let fields = struct_dtype
+                .dtypes()
+                .iter()
+                .enumerate()
+                .map(|(idx, dtype)| {
+                    let field = array
+                        .field(idx)
+                        .ok_or_else(|| vortex_err!("Missing field {} of struct", idx))?;
+                    coerce(&field, dtype)
+                })
+                .collect::<VortexResult<Vec<_>>>()?;
+            Ok(StructArray::try_new(
+                struct_dtype.names().clone(),
+                fields,
+                array.len(),
+                cast_validity(array.validity(), *nullability),
+            )?
+            .into_array())
+        }
+        _ => try_cast(array, dtype),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{PrimitiveArray, VarBinViewArray};
+    use crate::compute::unary::scalar_at;
+    use crate::validity::{ArrayValidity, Validity};
+
+    #[test]
+    fn concat_coerces_nullability() {
+        let array = concat(&[
+            PrimitiveArray::from(vec![1i32, 2]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![None, Some(4)]).into_array(),
+        ])
+        .unwrap();
+        assert_eq!(
+            array.dtype(),
+            &DType::Primitive(vortex_dtype::PType::I32, Nullability::Nullable)
+        );
+        let array = array.into_primitive().unwrap();
+        assert_eq!(array.maybe_null_slice::<i32>()[..2], [1, 2]);
+        assert!(!array.is_valid(2));
+
+        let strings = VarBinViewArray::from_iter_str(["a"]).into_array();
+        assert!(concat(&[strings, PrimitiveArray::from(vec![1i32]).into_array()]).is_err());
+        assert!(concat(&[]).is_err());
+    }
+
+    #[test]
+    fn concat_structs() {
+        let first =
+            StructArray::from_fields(&[("a", PrimitiveArray::from(vec![1u8, 2]).into_array())])
+                .into_array();
+        let second = StructArray::try_new(
+            ["a".into()].into(),
+            vec![PrimitiveArray::from_nullable_vec(vec![Some(3u8)]).into_array()],
+            1,
+            Validity::AllValid,
+        )
+        .unwrap()
+        .into_array();
+
+        let array = concat(&[first, second]).unwrap();
+        assert_eq!(array.len(), 3);
+        assert!(array.dtype().is_nullable());
+        let field = array.into_struct().unwrap().field(0).unwrap();
+        assert!(field.dtype().is_nullable());
+        assert_eq!(scalar_at(&field, 2).unwrap(), Some(3u8).into());
+    }
+
+    #[test]
+    fn rechunk_rows_and_bytes() {
+        let chunks = (0..7)
+            .map(|i| PrimitiveArray::from(vec![i as u64; i % 3 + 1]).into_array())
+            .collect::<Vec<_>>();
+        let dtype = chunks[0].dtype().clone();
+        let chunked = ChunkedArray::try_new(chunks, dtype).unwrap();
+        assert_eq!(chunked.len(), 13);
+
+        let rechunked = rechunk(&chunked, ChunkSize::Rows(4)).unwrap();
+        assert_eq!(
+            rechunked.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            &[4, 4, 4, 1]
+        );
+        assert_eq!(
+            Array::from(rechunked.into_canonical().unwrap())
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            chunked
+                .clone()
+                .into_array()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>()
+        );
+
+        let rechunked = rechunk(&chunked, ChunkSize::Bytes(40)).unwrap();
+        assert_eq!(
+            rechunked.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            &[5, 5, 3]
+        );
+    }
+}
@@ -3,7 +3,7 @@ use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, VortexResult};
 
 use crate::arrow::FromArrowArray;
-use crate::{Array, ArrayDType, IntoCanonical};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
 
 pub trait FilterFn {
     /// Filter an array by the provided predicate.
@@ -35,6 +35,8 @@ pub fn filter(array: &Array, predicate: &Array) -> VortexResult<Array> {
         );
     }
 
+    // Kernels read the predicate as a bool array, which not every boolean encoding supports.
+    let predicate = &predicate.clone().into_bool()?.into_array();
     array.with_dyn(|a| {
         if let Some(filter_fn) = a.filter() {
             filter_fn.filter(predicate)
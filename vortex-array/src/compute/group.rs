@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+use vortex_scalar::{
+    ListScalar, MapScalar, PValue, Scalar, ScalarValue, StructScalar, UnionScalar,
+};
+
+use crate::array::{ConstantArray, PrimitiveArray};
+use crate::compute::hash::{canonical_number, combine, null_hash};
+use crate::compute::unary::scalar_at;
+use crate::compute::{hash, take};
+use crate::{Array, IntoArray, IntoCanonical};
+
+pub trait GroupIdsFn {
+    /// See [group_ids].
+    fn group_ids(&self) -> VortexResult<(PrimitiveArray, Array)>;
+}
+
+/// Assign every value a dense `u64` group id, numbered in order of first occurrence.
+///
+/// Returns the ids together with the distinct values, where the value at position `i` is the
+/// value of group `i`. All nulls belong to a single group, and floating point values are grouped
+/// like [hash] treats them: negative and positive zero are equal, and so are all NaNs.
+pub fn group_ids(array: &Array) -> VortexResult<(PrimitiveArray, Array)> {
+    if let Some(result) = array.with_dyn(|a| a.group_ids().map(|a| a.group_ids())) {
+        return result;
+    }
+
+    let canonical = Array::from(array.clone().into_canonical()?);
+    if canonical.encoding().id() != array.encoding().id() {
+        return group_ids(&canonical);
+    }
+
+    // Canonical encodings without their own kernel, such as lists, compare values as scalars.
+    let scalars = (0..array.len())
+        .map(|idx| scalar_at(array, idx))
+        .collect::<VortexResult<Vec<_>>>()?;
+    let hashes = scalars
+        .iter()
+        .map(hash_scalar)
+        .collect::<VortexResult<Vec<_>>>()?;
+    group_values(array, &PrimitiveArray::from(hashes), |a, b| {
+        values_eq(scalars[a].value(), scalars[b].value())
+    })
+}
+
+/// Hash a scalar like [hash] hashes the values of an array. Nested values combine the hashes of
+/// their children, all other values are hashed by their canonical encoding.
+fn hash_scalar(scalar: &Scalar) -> VortexResult<u64> {
+    if scalar.is_null() {
+        return Ok(null_hash(0));
+    }
+
+    let children: Vec<Scalar> = match scalar.dtype() {
+        DType::List(..) | DType::FixedSizeList(..) => {
+            ListScalar::try_from(scalar)?.elements().collect()
+        }
+        DType::Struct(st, _) => {
+            let fields = StructScalar::try_from(scalar)?;
+            (0..st.dtypes().len())
+                .filter_map(|idx| fields.field_by_idx(idx))
+                .collect()
+        }
+        DType::Map(..) => MapScalar::try_from(scalar)?
+            .entries()
+            .flat_map(|(key, value)| [key, value])
+            .collect(),
+        DType::Union(..) => {
+            let union = UnionScalar::try_from(scalar)?;
+            union
+                .type_id()
+                .map(Scalar::from)
+                .into_iter()
+                .chain(union.value())
+                .collect()
+        }
+        _ => {
+            let single = ConstantArray::new(scalar.clone(), 1).into_array();
+            return Ok(hash(&single, 0)?.maybe_null_slice::<u64>()[0]);
+        }
+    };
+    children
+        .iter()
+        .try_fold(children.len() as u64, |acc, child| {
+            Ok(combine(acc, hash_scalar(child)?))
+        })
+}
+
+/// Whether two values of the same dtype belong to the same group, where all zeros and all NaNs
+/// are equal.
+fn values_eq(lhs: &ScalarValue, rhs: &ScalarValue) -> bool {
+    match (lhs, rhs) {
+        (ScalarValue::List(lhs), ScalarValue::List(rhs)) => {
+            lhs.len() == rhs.len() && lhs.iter().zip(rhs.iter()).all(|(l, r)| values_eq(l, r))
+        }
+        (ScalarValue::Primitive(PValue::F16(l)), ScalarValue::Primitive(PValue::F16(r))) => {
+            canonical_number(*l).to_bits() == canonical_number(*r).to_bits()
+        }
+        (ScalarValue::Primitive(PValue::F32(l)), ScalarValue::Primitive(PValue::F32(r))) => {
+            canonical_number(*l).to_bits() == canonical_number(*r).to_bits()
+        }
+        (ScalarValue::Primitive(PValue::F64(l)), ScalarValue::Primitive(PValue::F64(r))) => {
+            canonical_number(*l).to_bits() == canonical_number(*r).to_bits()
+        }
+        _ => lhs == rhs,
+    }
+}
+
+/// The distinct values of an array, in order of first occurrence.
+pub fn unique(array: &Array) -> VortexResult<Array> {
+    group_ids(array).map(|(_, uniques)| uniques)
+}
+
+/// The distinct values of an array, in order of first occurrence, with the number of times each
+/// of them occurs as `u64`.
+pub fn value_counts(array: &Array) -> VortexResult<(Array, PrimitiveArray)> {
+    let (codes, uniques) = group_ids(array)?;
+    let mut counts = vec![0u64; uniques.len()];
+    for &code in codes.maybe_null_slice::<u64>() {
+        counts[code as usize] += 1;
+    }
+    Ok((uniques, PrimitiveArray::from(counts)))
+}
+
+/// [GroupIdsFn] for an array whose values at two positions can be compared with `eq`, given the
+/// [hash] of every value.
+pub(crate) fn group_values(
+    array: &Array,
+    hashes: &PrimitiveArray,
+    eq: impl Fn(usize, usize) -> bool,
+) -> VortexResult<(PrimitiveArray, Array)> {
+    let mut groups: HashMap<u64, Vec<u64>> = HashMap::new();
+    let mut firsts: Vec<u64> = Vec::new();
+    let codes = hashes
+        .maybe_null_slice::<u64>()
+        .iter()
+        .enumerate()
+        .map(|(idx, &hash)| {
+            let candidates = groups.entry(hash).or_default();
+            if let Some(&group) = candidates
+                .iter()
+                .find(|&&group| eq(firsts[group as usize] as usize, idx))
+            {
+                return group;
+            }
+            let group = firsts.len() as u64;
+            firsts.push(idx as u64);
+            candidates.push(group);
+            group
+        })
+        .collect::<Vec<_>>();
+
+    let uniques = take(array, &PrimitiveArray::from(firsts).into_array())?;
+    Ok((PrimitiveArray::from(codes), uniques))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{ConstantArray, ListArray, VarBinViewArray};
+    use crate::validity::{ArrayValidity, Validity};
+    use crate::IntoArrayVariant;
+
+    #[test]
+    fn group_primitives() {
+        let array = PrimitiveArray::from_nullable_vec(vec![
+            Some(0.0f32),
+            None,
+            Some(f32::NAN),
+            Some(-0.0),
+            None,
+            Some(f32::NAN),
+            Some(2.5),
+        ])
+        .into_array();
+        let (codes, uniques) = group_ids(&array).unwrap();
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[0, 1, 2, 0, 1, 2, 3]);
+        assert_eq!(uniques.len(), 4);
+
+        let (uniques, counts) = value_counts(&array).unwrap();
+        assert_eq!(counts.maybe_null_slice::<u64>(), &[2, 2, 2, 1]);
+        let uniques = uniques.into_primitive().unwrap();
+        assert_eq!(uniques.maybe_null_slice::<f32>()[3], 2.5);
+        assert!(!uniques.is_valid(1));
+    }
+
+    #[test]
+    fn group_strings_and_constants() {
+        let strings =
+            VarBinViewArray::from_iter_nullable_str([Some("b"), Some("a"), None, Some("b")])
+                .into_array();
+        let (codes, uniques) = group_ids(&strings).unwrap();
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[0, 1, 2, 0]);
+        assert_eq!(
+            uniques.into_varbin_view().unwrap().bytes_at(1).unwrap(),
+            b"a"
+        );
+
+        let constant = ConstantArray::new(7u8, 3).into_array();
+        let (codes, uniques) = group_ids(&constant).unwrap();
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[0, 0, 0]);
+        assert_eq!(uniques.len(), 1);
+    }
+
+    #[test]
+    fn group_scalars() {
+        let lists = ListArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 4, 5]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 1, 2, 3]).into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let (codes, uniques) = group_ids(&lists).unwrap();
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[0, 0, 1]);
+        assert_eq!(uniques.len(), 2);
+    }
+
+    #[test]
+    fn group_lists_of_floats() {
+        let lists = ListArray::try_new(
+            PrimitiveArray::from(vec![0u32, 2, 4, 5, 6]).into_array(),
+            PrimitiveArray::from(vec![1.0f64, f64::NAN, 1.0, -f64::NAN, 0.0, -0.0]).into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let (codes, uniques) = group_ids(&lists).unwrap();
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[0, 0, 1, 1]);
+        assert_eq!(uniques.len(), 2);
+    }
+
+    #[test]
+    fn group_lists_of_binary() {
+        let lists = ListArray::try_new(
+            PrimitiveArray::from(vec![0u32, 1, 2, 3]).into_array(),
+            VarBinViewArray::from_iter_bin([b"a".as_slice(), b"b", b"a"]).into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let (codes, _) = group_ids(&lists).unwrap();
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[0, 1, 0]);
+    }
+}
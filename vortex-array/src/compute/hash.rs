@@ -0,0 +1,176 @@
+use arrow_buffer::NullBuffer;
+use vortex_dtype::NativePType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::PrimitiveArray;
+use crate::{Array, IntoCanonical};
+
+pub trait HashFn {
+    fn hash(&self, seed: u64) -> VortexResult<PrimitiveArray>;
+}
+
+/// Hash every value of an array into a non-nullable `u64` array.
+///
+/// The hash of a value only depends on its logical value, its [DType](vortex_dtype::DType) and
+/// the seed, so it is the same for every encoding and stable across processes and platforms.
+/// Nulls hash to a fixed value. Use [combine_hashes] to hash a row across several columns.
+pub fn hash(array: &Array, seed: u64) -> VortexResult<PrimitiveArray> {
+    if let Some(result) = array.with_dyn(|a| a.hash().map(|a| a.hash(seed))) {
+        return result;
+    }
+
+    let canonical = Array::from(array.clone().into_canonical()?);
+    if canonical.encoding().id() == array.encoding().id() {
+        vortex_bail!(NotImplemented: "hash", array.encoding().id());
+    }
+    hash(&canonical, seed)
+}
+
+/// Combine two arrays of hashes row by row, for example the hashes of two join key columns.
+///
+/// The combination is order dependent, `combine_hashes(a, b)` differs from `combine_hashes(b, a)`.
+pub fn combine_hashes(lhs: &PrimitiveArray, rhs: &PrimitiveArray) -> VortexResult<PrimitiveArray> {
+    if lhs.len() != rhs.len() {
+        vortex_bail!(
+            "Cannot combine hashes of different lengths {} and {}",
+            lhs.len(),
+            rhs.len()
+        );
+    }
+
+    Ok(PrimitiveArray::from(
+        lhs.maybe_null_slice::<u64>()
+            .iter()
+            .zip(rhs.maybe_null_slice::<u64>())
+            .map(|(&lhs, &rhs)| combine(lhs, rhs))
+            .collect::<Vec<_>>(),
+    ))
+}
+
+const MULTIPLIER: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The murmur3 64-bit finalizer.
+fn mix(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xC4CE_B9FE_1A85_EC53);
+    h ^ (h >> 33)
+}
+
+pub(crate) fn combine(acc: u64, hash: u64) -> u64 {
+    mix(acc.rotate_left(29).wrapping_mul(MULTIPLIER) ^ hash)
+}
+
+/// Hash a byte string, in little-endian words of 8 bytes.
+pub(crate) fn hash_bytes(seed: u64, bytes: &[u8]) -> u64 {
+    let mut h = seed ^ (bytes.len() as u64).wrapping_mul(MULTIPLIER);
+    if bytes.is_empty() {
+        return mix(h);
+    }
+    for chunk in bytes.chunks(8) {
+        let mut word = [0u8; 8];
+        word[..chunk.len()].copy_from_slice(chunk);
+        h = mix(h ^ u64::from_le_bytes(word));
+    }
+    h
+}
+
+/// Hash the little-endian bytes of a number, where all zeros and all NaNs hash the same.
+pub(crate) fn hash_number<T: NativePType>(seed: u64, value: T) -> u64 {
+    hash_bytes(seed, canonical_number(value).to_le_bytes())
+}
+
+/// The number with the same representation for all zeros and for all NaNs.
+pub(crate) fn canonical_number<T: NativePType>(value: T) -> T {
+    if value == T::zero() {
+        T::zero()
+    } else if value.partial_cmp(&value).is_none() {
+        T::from(f64::NAN).unwrap_or(value)
+    } else {
+        value
+    }
+}
+
+pub(crate) fn null_hash(seed: u64) -> u64 {
+    mix(seed ^ MULTIPLIER.rotate_left(32))
+}
+
+/// Hash each value with `f`, replacing the hashes of null values by [null_hash].
+pub(crate) fn hash_values<T>(
+    values: impl Iterator<Item = T>,
+    nulls: Option<NullBuffer>,
+    seed: u64,
+    f: impl Fn(T) -> u64,
+) -> PrimitiveArray {
+    let hashes: Vec<u64> = match nulls {
+        None => values.map(f).collect(),
+        Some(nulls) => values
+            .zip(nulls.iter())
+            .map(|(v, valid)| if valid { f(v) } else { null_hash(seed) })
+            .collect(),
+    };
+    PrimitiveArray::from(hashes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{BoolArray, ChunkedArray, StructArray, VarBinArray, VarBinViewArray};
+    use crate::compute::slice;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn stable_across_encodings() {
+        let strings = ["a", "hello world, hashes", "", "a"];
+        let varbin = VarBinArray::from(strings.to_vec()).into_array();
+        let view = VarBinViewArray::from_iter_str(strings).into_array();
+        let chunked = ChunkedArray::try_new(
+            vec![slice(&view, 0, 1).unwrap(), slice(&view, 1, 4).unwrap()],
+            view.dtype().clone(),
+        )
+        .unwrap()
+        .into_array();
+
+        let expected = hash(&view, 7).unwrap();
+        let expected = expected.maybe_null_slice::<u64>();
+        assert_eq!(expected[0], expected[3]);
+        assert_ne!(expected[0], expected[2]);
+        assert_eq!(
+            hash(&varbin, 7).unwrap().maybe_null_slice::<u64>(),
+            expected
+        );
+        assert_eq!(
+            hash(&chunked, 7).unwrap().maybe_null_slice::<u64>(),
+            expected
+        );
+        assert_ne!(hash(&view, 8).unwrap().maybe_null_slice::<u64>(), expected);
+
+        assert_eq!(hash_bytes(0, b"a"), hash_number(0, b'a'));
+        assert_eq!(hash_number(0, -0.0f64), hash_number(0, 0.0f64));
+    }
+
+    #[test]
+    fn struct_rows() {
+        let a = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(1)]).into_array();
+        let b = BoolArray::from(vec![true, true, true]).into_array();
+        let rows = StructArray::try_new(
+            ["a".into(), "b".into()].into(),
+            vec![a.clone(), b],
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let hashes = hash(&rows, 0).unwrap();
+        let hashes = hashes.maybe_null_slice::<u64>();
+        assert_eq!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+        assert_eq!(
+            hash(&a, 0).unwrap().maybe_null_slice::<u64>()[1],
+            null_hash(0)
+        );
+    }
+}
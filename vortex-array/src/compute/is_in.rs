@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::accessor::ArrayAccessor;
+use crate::array::BoolArray;
+use crate::builders::builder_with_capacity;
+use crate::compute::unary::{scalar_at, try_cast};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
+
+pub trait IsInFn {
+    /// See [is_in], `values` have the dtype of the array up to nullability.
+    fn is_in(&self, values: &Array) -> VortexResult<Array>;
+}
+
+/// Whether each value of `array` is one of `values`, like an SQL `IN (...)` list.
+///
+/// The result is a non-nullable boolean mask that can be passed to
+/// [filter](crate::compute::filter). Nulls are never members, neither of the array nor of
+/// `values`. The `values` are cast to the dtype of the array first, dropping numbers that it
+/// cannot represent, and floating point values compare like [hash](crate::compute::hash) treats
+/// them.
+pub fn is_in(array: &Array, values: &Array) -> VortexResult<Array> {
+    let values = if values.dtype().eq_ignore_nullability(array.dtype()) {
+        values.clone()
+    } else {
+        cast_values(values, &array.dtype().as_nullable())?
+    };
+
+    if let Some(result) = array.with_dyn(|a| a.is_in().map(|a| a.is_in(&values))) {
+        return result;
+    }
+
+    let canonical = Array::from(array.clone().into_canonical()?);
+    if canonical.encoding().id() == array.encoding().id() {
+        vortex_bail!(NotImplemented: "is_in", array.encoding().id());
+    }
+    is_in(&canonical, &values)
+}
+
+/// Cast `values` to `dtype`. Numbers that a primitive `dtype` cannot represent exactly, like
+/// 300 for `u8` or 2.5 for `i32`, can never be members, so they are dropped rather than
+/// truncated or failing the cast.
+fn cast_values(values: &Array, dtype: &DType) -> VortexResult<Array> {
+    if !matches!(
+        (values.dtype(), dtype),
+        (DType::Primitive(..), DType::Primitive(..))
+    ) {
+        return try_cast(values, dtype);
+    }
+
+    let mut builder = builder_with_capacity(dtype, values.len());
+    for idx in 0..values.len() {
+        let value = scalar_at(values, idx)?;
+        if !value.is_valid() {
+            continue;
+        }
+        let Ok(cast) = value.cast(dtype) else {
+            continue;
+        };
+        // NaN never equals itself, but it is a member of floating point arrays containing NaN.
+        let exact = cast.cast(value.dtype()).map_or(false, |back| {
+            back == value || (is_nan(&back) && is_nan(&value))
+        });
+        if exact {
+            builder.append_scalar(&cast)?;
+        }
+    }
+    builder.finish()
+}
+
+fn is_nan(scalar: &Scalar) -> bool {
+    scalar.partial_cmp(scalar).is_none()
+}
+
+/// [IsInFn] through a hash set of the `key` of every valid value.
+pub(crate) fn is_in_keys<T: ?Sized, K: Eq + Hash>(
+    array: &impl ArrayAccessor<T>,
+    values: &impl ArrayAccessor<T>,
+    key: impl Fn(&T) -> K,
+) -> VortexResult<Array> {
+    let set = values.with_iterator(|iter| iter.flatten().map(&key).collect::<HashSet<_>>())?;
+    let mask = array.with_iterator(|iter| {
+        iter.map(|v| v.map_or(false, |v| set.contains(&key(v))))
+            .collect::<Vec<_>>()
+    })?;
+    Ok(BoolArray::from(mask).into_array())
+}
+
+/// [IsInFn] for any array that can iterate its bytes.
+pub(crate) fn is_in_bytes(array: &impl ArrayAccessor<[u8]>, values: &Array) -> VortexResult<Array> {
+    let set = values
+        .clone()
+        .into_varbin_view()?
+        .with_iterator(|iter| iter.flatten().map(<[u8]>::to_vec).collect::<HashSet<_>>())?;
+    let mask = array.with_iterator(|iter| {
+        iter.map(|v| v.map_or(false, |v| set.contains(v)))
+            .collect::<Vec<_>>()
+    })?;
+    Ok(BoolArray::from(mask).into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{
+        ChunkedArray, ConstantArray, PrimitiveArray, TemporalArray, TimeUnit, VarBinArray,
+        VarBinViewArray,
+    };
+    use crate::compute::filter;
+
+    fn mask(array: Array) -> Vec<bool> {
+        array.into_bool().unwrap().boolean_buffer().iter().collect()
+    }
+
+    #[test]
+    fn membership() {
+        let array = PrimitiveArray::from_nullable_vec(vec![
+            Some(-0.0f64),
+            None,
+            Some(f64::NAN),
+            Some(2.5),
+            Some(3.0),
+        ])
+        .into_array();
+        let values =
+            PrimitiveArray::from_nullable_vec(vec![Some(0.0f64), Some(f64::NAN), None, Some(3.0)])
+                .into_array();
+        let members = is_in(&array, &values).unwrap();
+        assert_eq!(members.dtype(), &DType::Bool(false.into()));
+        assert_eq!(mask(members.clone()), &[true, false, true, false, true]);
+        assert_eq!(filter(&array, &members).unwrap().len(), 3);
+
+        // Values are cast to the dtype of the array.
+        let ints = PrimitiveArray::from(vec![1u8, 2, 3]).into_array();
+        let values = PrimitiveArray::from(vec![3i64, 1]).into_array();
+        assert_eq!(mask(is_in(&ints, &values).unwrap()), &[true, false, true]);
+    }
+
+    #[test]
+    fn unrepresentable_values() {
+        let bytes = PrimitiveArray::from(vec![1u8, 2, 3]).into_array();
+        let values = PrimitiveArray::from(vec![300i64, 1]).into_array();
+        assert_eq!(mask(is_in(&bytes, &values).unwrap()), &[true, false, false]);
+
+        let ints = PrimitiveArray::from(vec![1u32, 2, 3]).into_array();
+        let values =
+            PrimitiveArray::from_nullable_vec(vec![Some(-1i64), None, Some(2)]).into_array();
+        assert_eq!(mask(is_in(&ints, &values).unwrap()), &[false, true, false]);
+
+        let ints = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let values = PrimitiveArray::from(vec![2.5f64, f64::NAN, 3.0]).into_array();
+        assert_eq!(mask(is_in(&ints, &values).unwrap()), &[false, false, true]);
+
+        let floats = PrimitiveArray::from(vec![0.1f32, f32::NAN, 0.5]).into_array();
+        let values = PrimitiveArray::from(vec![0.1f64, f64::NAN, 0.5]).into_array();
+        assert_eq!(mask(is_in(&floats, &values).unwrap()), &[false, true, true]);
+    }
+
+    #[test]
+    fn extension_values() {
+        let dates: Array = TemporalArray::new_date(
+            PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+            TimeUnit::D,
+        )
+        .into();
+        let values = ConstantArray::new(scalar_at(&dates, 1).unwrap(), 2).into_array();
+        assert_eq!(mask(is_in(&dates, &values).unwrap()), &[false, true, false]);
+    }
+
+    #[test]
+    fn strings_across_encodings() {
+        let strings = ["a", "bb", "", "a"];
+        let values = VarBinViewArray::from_iter_str(["a", ""]).into_array();
+        let view = VarBinViewArray::from_iter_str(strings).into_array();
+        let varbin = VarBinArray::from(strings.to_vec()).into_array();
+        let chunked =
+            ChunkedArray::try_new(vec![view.clone(), varbin.clone()], view.dtype().clone())
+                .unwrap()
+                .into_array();
+
+        assert_eq!(
+            mask(is_in(&view, &values).unwrap()),
+            &[true, false, true, true]
+        );
+        assert_eq!(
+            mask(is_in(&varbin, &values).unwrap()),
+            &[true, false, true, true]
+        );
+        assert_eq!(mask(is_in(&chunked, &values).unwrap()).len(), 8);
+
+        let constant = ConstantArray::new("bb", 2).into_array();
+        assert_eq!(mask(is_in(&constant, &values).unwrap()), &[false, false]);
+    }
+}
@@ -0,0 +1,33 @@
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::{Array, ArrayDType, IntoArrayVariant};
+
+pub trait MapGetFn {
+    /// Look up the value of `key` within every map of the array.
+    fn map_get(&self, key: &Scalar) -> VortexResult<Array>;
+}
+
+/// Return the value of `key` within each map of a [DType::Map] array.
+///
+/// The result has the nullable value type of the map, and is null wherever the map is null or
+/// does not contain the key. If a map holds the key more than once, the first entry wins.
+pub fn map_get(array: &Array, key: &Scalar) -> VortexResult<Array> {
+    let DType::Map(map_dtype, _) = array.dtype() else {
+        vortex_bail!(MismatchedTypes: "map", array.dtype());
+    };
+    if key.is_null() {
+        vortex_bail!("Map keys cannot be null");
+    }
+    let key = key.cast(map_dtype.key_dtype())?;
+
+    array.with_dyn(|a| {
+        if let Some(map_get_fn) = a.map_get() {
+            map_get_fn.map_get(&key)
+        } else {
+            // Fallback: look the key up in the canonical MapArray.
+            MapGetFn::map_get(&array.clone().into_map()?, &key)
+        }
+    })
+}
@@ -7,27 +7,65 @@
 //! implementations of these operators, else we will decode, and perform the equivalent operator
 //! from Arrow.
 
-pub use boolean::{and, or, AndFn, OrFn};
+pub use aggregate::{
+    add_sums, count, max, mean, min, min_max, sum, sum_dtype, sum_repeated, weighted_sum, MinMax,
+    MinMaxFn, SumFn,
+};
+pub use arithmetic::{
+    add, binary_numeric, div, mul, negate, rem, scalar_binary_numeric, scalar_negate,
+    scalar_numeric, sub, BinaryNumericFn, BinaryOperator, NegateFn, OverflowMode, ScalarNumericFn,
+};
+pub use boolean::{
+    and, and_kleene, not, or, or_kleene, xor, AndFn, AndKleeneFn, NotFn, OrFn, OrKleeneFn, XorFn,
+};
+pub(crate) use compare::arrow_compare;
 pub use compare::{compare, scalar_cmp, CompareFn};
+pub use concat::{concat, rechunk, ChunkSize};
 pub use filter::{filter, FilterFn};
 pub use filter_indices::{filter_indices, FilterIndicesFn};
+pub use group::{group_ids, unique, value_counts, GroupIdsFn};
+pub use hash::{combine_hashes, hash, HashFn};
+pub use is_in::{is_in, IsInFn};
+pub use map_get::{map_get, MapGetFn};
 pub use search_sorted::*;
 pub use slice::{slice, SliceFn};
+pub use sort::{lexsort_indices, sort, sort_indices, SortFn, SortOptions};
+pub use string::{
+    byte_length, char_length, contains, ends_with, fold_case, like, lower, starts_with,
+    string_length, string_match, upper, Case, FoldCaseFn, LikeOptions, StringLength,
+    StringLengthFn, StringMatchFn, StringMatcher,
+};
 pub use take::{take, TakeFn};
-use unary::{CastFn, FillForwardFn, ScalarAtFn, SubtractScalarFn};
+use unary::{CastFn, FillForwardFn, FillNullFn, IsNullFn, ScalarAtFn};
 
+pub(crate) mod aggregate;
+pub(crate) mod arithmetic;
 mod boolean;
 mod compare;
+mod concat;
 mod filter;
 mod filter_indices;
+pub(crate) mod group;
+pub(crate) mod hash;
+pub(crate) mod is_in;
+mod map_get;
 mod search_sorted;
 mod slice;
+pub(crate) mod sort;
+pub(crate) mod string;
 mod take;
 
 pub mod unary;
 
 /// Trait providing compute functions on top of Vortex arrays.
 pub trait ArrayCompute {
+    /// Element-wise arithmetic between two arrays.
+    ///
+    /// See: [BinaryNumericFn].
+    fn binary_numeric(&self) -> Option<&dyn BinaryNumericFn> {
+        None
+    }
+
     /// Implemented for arrays that can be casted to different types.
     ///
     /// See: [CastFn].
@@ -42,6 +80,13 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Lower or upper casing of a UTF-8 array.
+    ///
+    /// See: [FoldCaseFn].
+    fn fold_case(&self) -> Option<&dyn FoldCaseFn> {
+        None
+    }
+
     /// Array function that returns new arrays a non-null value is repeated across runs of nulls.
     ///
     /// See: [FillForwardFn].
@@ -49,6 +94,13 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Replace the nulls of an array with a scalar.
+    ///
+    /// See: [FillNullFn].
+    fn fill_null(&self) -> Option<&dyn FillNullFn> {
+        None
+    }
+
     /// Filtering function on arrays of predicates.
     ///
     /// See: [FilterFn].
@@ -62,6 +114,55 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Dense group ids and distinct values of an array.
+    ///
+    /// See: [GroupIdsFn].
+    fn group_ids(&self) -> Option<&dyn GroupIdsFn> {
+        None
+    }
+
+    /// Hash every value of an array.
+    ///
+    /// See: [HashFn].
+    fn hash(&self) -> Option<&dyn HashFn> {
+        None
+    }
+
+    /// Membership of the values of an array in a set of values.
+    ///
+    /// See: [IsInFn].
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        None
+    }
+
+    /// Null mask of an array.
+    ///
+    /// See: [IsNullFn].
+    fn is_null(&self) -> Option<&dyn IsNullFn> {
+        None
+    }
+
+    /// Key lookup within the maps of a map array.
+    ///
+    /// See: [MapGetFn].
+    fn map_get(&self) -> Option<&dyn MapGetFn> {
+        None
+    }
+
+    /// Minimum and maximum of the valid values of an array.
+    ///
+    /// See: [MinMaxFn].
+    fn min_max(&self) -> Option<&dyn MinMaxFn> {
+        None
+    }
+
+    /// Negation of every element of a numeric array.
+    ///
+    /// See: [NegateFn].
+    fn negate(&self) -> Option<&dyn NegateFn> {
+        None
+    }
+
     /// Single item indexing on Vortex arrays.
     ///
     /// See: [ScalarAtFn].
@@ -69,10 +170,10 @@ pub trait ArrayCompute {
         None
     }
 
-    /// Broadcast subtraction of scalar from Vortex array.
+    /// Broadcast arithmetic between an array and a scalar.
     ///
-    /// See: [SubtractScalarFn].
-    fn subtract_scalar(&self) -> Option<&dyn SubtractScalarFn> {
+    /// See: [ScalarNumericFn].
+    fn scalar_numeric(&self) -> Option<&dyn ScalarNumericFn> {
         None
     }
 
@@ -83,6 +184,13 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Sum of the valid values of a numeric array.
+    ///
+    /// See: [SumFn].
+    fn sum(&self) -> Option<&dyn SumFn> {
+        None
+    }
+
     /// Perform zero-copy slicing of an array.
     ///
     /// See: [SliceFn].
@@ -90,6 +198,27 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Stable sorting of the values of an array.
+    ///
+    /// See: [SortFn].
+    fn sort(&self) -> Option<&dyn SortFn> {
+        None
+    }
+
+    /// Length of each value of a string or binary array.
+    ///
+    /// See: [StringLengthFn].
+    fn string_length(&self) -> Option<&dyn StringLengthFn> {
+        None
+    }
+
+    /// Pattern matching over a string or binary array.
+    ///
+    /// See: [StringMatchFn].
+    fn string_match(&self) -> Option<&dyn StringMatchFn> {
+        None
+    }
+
     /// Take a set of indices from an array. This often forces allocations and decoding of
     /// the receiver.
     ///
@@ -111,4 +240,32 @@ pub trait ArrayCompute {
     fn or(&self) -> Option<&dyn OrFn> {
         None
     }
+
+    /// Perform a boolean XOR operation over two arrays
+    ///
+    /// See: [XorFn].
+    fn xor(&self) -> Option<&dyn XorFn> {
+        None
+    }
+
+    /// Perform a three-valued boolean AND operation over two arrays
+    ///
+    /// See: [AndKleeneFn].
+    fn and_kleene(&self) -> Option<&dyn AndKleeneFn> {
+        None
+    }
+
+    /// Perform a three-valued boolean OR operation over two arrays
+    ///
+    /// See: [OrKleeneFn].
+    fn or_kleene(&self) -> Option<&dyn OrKleeneFn> {
+        None
+    }
+
+    /// Negate a boolean array
+    ///
+    /// See: [NotFn].
+    fn not(&self) -> Option<&dyn NotFn> {
+        None
+    }
 }
@@ -44,6 +44,18 @@ impl SearchResult {
 
 pub trait SearchSortedFn {
     fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult>;
+
+    /// Search for every value, see [search_sorted_many].
+    fn search_sorted_many(
+        &self,
+        values: &[Scalar],
+        side: SearchSortedSide,
+    ) -> VortexResult<Vec<SearchResult>> {
+        values
+            .iter()
+            .map(|value| self.search_sorted(value, side))
+            .collect()
+    }
 }
 
 pub fn search_sorted<T: Into<Scalar>>(
@@ -68,6 +80,37 @@ pub fn search_sorted<T: Into<Scalar>>(
     })
 }
 
+/// Search for many targets at once, for example the keys of a sorted-merge join.
+///
+/// Returns the result of [search_sorted] for every target, in order.
+pub fn search_sorted_many<T: Into<Scalar> + Clone>(
+    array: &Array,
+    targets: &[T],
+    side: SearchSortedSide,
+) -> VortexResult<Vec<SearchResult>> {
+    let scalars = targets
+        .iter()
+        .map(|target| target.clone().into().cast(array.dtype()))
+        .collect::<VortexResult<Vec<_>>>()?;
+    array.with_dyn(|a| {
+        if let Some(search_sorted) = a.search_sorted() {
+            return search_sorted.search_sorted_many(&scalars, side);
+        }
+
+        if a.scalar_at().is_some() {
+            return Ok(scalars
+                .iter()
+                .map(|scalar| SearchSorted::search_sorted(array, scalar, side))
+                .collect());
+        }
+
+        vortex_bail!(
+            NotImplemented: "search_sorted_many",
+            array.encoding().id()
+        )
+    })
+}
+
 pub trait IndexOrd<V> {
     fn index_cmp(&self, idx: usize, elem: &V) -> Option<Ordering>;
 
@@ -0,0 +1,239 @@
+use std::cmp::Ordering;
+
+use arrow_buffer::NullBuffer;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::{BoolArray, PrimitiveArray};
+use crate::compute::take;
+use crate::stats::{ArrayStatistics, Stat};
+use crate::{Array, ArrayDType, IntoArray, IntoCanonical};
+
+/// How [sort_indices] orders values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SortOptions {
+    /// Largest values first.
+    pub descending: bool,
+    /// Nulls before all valid values, regardless of `descending`.
+    pub nulls_first: bool,
+}
+
+pub trait SortFn {
+    /// The indices that stably sort the array, see [sort_indices].
+    fn sort_indices(&self, options: SortOptions) -> VortexResult<PrimitiveArray>;
+}
+
+/// The `u64` indices that sort an array.
+///
+/// The sort is stable, equal values keep their original order. Floating point NaNs are larger
+/// than every other value.
+pub fn sort_indices(array: &Array, options: SortOptions) -> VortexResult<PrimitiveArray> {
+    if is_sorted(array, options) {
+        return Ok(PrimitiveArray::from(
+            (0..array.len() as u64).collect::<Vec<_>>(),
+        ));
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.sort().map(|a| a.sort_indices(options))) {
+        return result;
+    }
+
+    let canonical = Array::from(array.clone().into_canonical()?);
+    if canonical.encoding().id() == array.encoding().id() {
+        vortex_bail!(NotImplemented: "sort", array.encoding().id());
+    }
+    sort_indices(&canonical, options)
+}
+
+/// Sort the values of an array, see [sort_indices].
+///
+/// Arrays already known to be sorted are returned unchanged.
+pub fn sort(array: &Array, options: SortOptions) -> VortexResult<Array> {
+    if is_sorted(array, options) {
+        return Ok(array.clone());
+    }
+    take(array, &sort_indices(array, options)?.into_array())
+}
+
+/// The indices that sort the rows formed by several columns of the same length, comparing the
+/// columns in order with their respective options.
+pub fn lexsort_indices(columns: &[Array], options: &[SortOptions]) -> VortexResult<PrimitiveArray> {
+    if columns.len() != options.len() {
+        vortex_bail!(
+            "Expected sort options for each of the {} columns, got {}",
+            columns.len(),
+            options.len()
+        );
+    }
+    let Some(len) = columns.first().map(|c| c.len()) else {
+        vortex_bail!("Cannot sort without any columns");
+    };
+    if let Some(column) = columns.iter().find(|c| c.len() != len) {
+        vortex_bail!(
+            "Cannot sort columns of different lengths {} and {}",
+            len,
+            column.len()
+        );
+    }
+
+    // Stable sorts from the least to the most significant column.
+    let mut indices = PrimitiveArray::from((0..len as u64).collect::<Vec<_>>()).into_array();
+    for (column, options) in columns.iter().zip(options).rev() {
+        let order = sort_indices(&take(column, &indices)?, *options)?;
+        indices = take(&indices, &order.into_array())?;
+    }
+    PrimitiveArray::try_from(indices)
+}
+
+/// Whether the cached statistics show the array is already in the requested order.
+fn is_sorted(array: &Array, options: SortOptions) -> bool {
+    let no_nulls = !array.dtype().is_nullable()
+        || array.statistics().get_as::<u64>(Stat::NullCount) == Some(0);
+    !options.descending
+        && no_nulls
+        && array.statistics().get_as::<bool>(Stat::IsSorted) == Some(true)
+}
+
+/// Compare two values, ordering NaN after every other value.
+pub(crate) fn cmp_values<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b)
+        .unwrap_or_else(|| is_nan(a).cmp(&is_nan(b)))
+}
+
+fn is_nan<T: PartialOrd>(value: &T) -> bool {
+    value.partial_cmp(value).is_none()
+}
+
+/// Stably sort the indices `0..len` with `cmp` applied to the valid positions.
+pub(crate) fn sort_by(
+    len: usize,
+    nulls: Option<&NullBuffer>,
+    options: SortOptions,
+    cmp: impl Fn(usize, usize) -> Ordering,
+) -> PrimitiveArray {
+    let (mut valid, mut invalid): (Vec<u64>, Vec<u64>) =
+        (0..len as u64).partition(|&idx| nulls.map_or(true, |n| n.is_valid(idx as usize)));
+    if options.descending {
+        valid.sort_by(|&a, &b| cmp(b as usize, a as usize));
+    } else {
+        valid.sort_by(|&a, &b| cmp(a as usize, b as usize));
+    }
+
+    PrimitiveArray::from(if options.nulls_first {
+        invalid.extend(valid);
+        invalid
+    } else {
+        valid.extend(invalid);
+        valid
+    })
+}
+
+/// [SortFn] over any array that can iterate its bytes.
+pub(crate) fn sort_bytes<A: ArrayAccessor<[u8]>>(
+    array: &A,
+    options: SortOptions,
+) -> VortexResult<PrimitiveArray> {
+    array.with_iterator(|iter| {
+        let values = iter.collect::<Vec<_>>();
+        let nulls = NullBuffer::from_iter(values.iter().map(Option::is_some));
+        sort_by(values.len(), Some(&nulls), options, |a, b| {
+            values[a].cmp(&values[b])
+        })
+    })
+}
+
+/// The validity of rows as a sort key that orders nulls according to `options`.
+pub(crate) fn validity_key(nulls: &NullBuffer, options: SortOptions) -> (Array, SortOptions) {
+    let key = BoolArray::from(nulls.inner().clone()).into_array();
+    let key_options = SortOptions {
+        descending: !options.nulls_first,
+        nulls_first: false,
+    };
+    (key, key_options)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{ChunkedArray, StructArray, VarBinViewArray};
+    use crate::validity::Validity;
+    use crate::IntoArrayVariant;
+
+    fn indices(array: PrimitiveArray) -> Vec<u64> {
+        array.maybe_null_slice::<u64>().to_vec()
+    }
+
+    #[test]
+    fn null_ordering() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(3.0f64), None, Some(f64::NAN), Some(-1.0)])
+                .into_array();
+        let ascending = SortOptions::default();
+        assert_eq!(
+            indices(sort_indices(&array, ascending).unwrap()),
+            &[3, 0, 2, 1]
+        );
+
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        assert_eq!(
+            indices(sort_indices(&array, descending).unwrap()),
+            &[1, 2, 0, 3]
+        );
+
+        let strings = VarBinViewArray::from_iter_nullable_str([Some("b"), None, Some("a")]);
+        let dtype = strings.dtype().clone();
+        let chunked = ChunkedArray::try_new(vec![strings.into_array()], dtype)
+            .unwrap()
+            .into_array();
+        assert_eq!(
+            indices(sort_indices(&chunked, ascending).unwrap()),
+            &[2, 0, 1]
+        );
+    }
+
+    #[test]
+    fn sorted_is_unchanged() {
+        let array = PrimitiveArray::from(vec![1u32, 2, 2, 5]).into_array();
+        array.statistics().compute_is_sorted();
+        let sorted = sort(&array, SortOptions::default()).unwrap();
+        assert_eq!(sorted.encoding().id(), array.encoding().id());
+        assert_eq!(
+            sorted.into_primitive().unwrap().maybe_null_slice::<u32>(),
+            &[1, 2, 2, 5]
+        );
+    }
+
+    #[test]
+    fn lexicographic_struct() {
+        let a = PrimitiveArray::from(vec![2i32, 1, 2, 1]).into_array();
+        let b = VarBinViewArray::from_iter_str(["x", "z", "a", "y"]).into_array();
+        let rows = StructArray::try_new(
+            ["a".into(), "b".into()].into(),
+            vec![a.clone(), b.clone()],
+            4,
+            Validity::from(vec![true, true, true, false]),
+        )
+        .unwrap()
+        .into_array();
+
+        assert_eq!(
+            indices(sort_indices(&rows, SortOptions::default()).unwrap()),
+            &[1, 2, 0, 3]
+        );
+
+        let options = [
+            SortOptions::default(),
+            SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+        ];
+        assert_eq!(
+            indices(lexsort_indices(&[a, b], &options).unwrap()),
+            &[1, 3, 0, 2]
+        );
+    }
+}
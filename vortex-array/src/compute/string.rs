@@ -0,0 +1,444 @@
+use std::borrow::Cow;
+
+use vortex_dtype::{DType, Nullability};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::accessor::ArrayAccessor;
+use crate::array::{BoolArray, PrimitiveArray, VarBinViewArray};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+/// Evaluates a [StringMatcher] against every value of a string or binary array.
+pub trait StringMatchFn {
+    /// Returns a non-nullable boolean array where null values never match.
+    fn string_match(&self, matcher: &StringMatcher) -> VortexResult<Array>;
+}
+
+/// Length of every value of a string or binary array.
+pub trait StringLengthFn {
+    fn string_length(&self, unit: StringLength) -> VortexResult<Array>;
+}
+
+/// Case folding of every value of a UTF-8 array.
+pub trait FoldCaseFn {
+    fn fold_case(&self, case: Case) -> VortexResult<Array>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringLength {
+    /// The number of bytes of each value.
+    Bytes,
+    /// The number of unicode scalar values of each value.
+    Chars,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    Lower,
+    Upper,
+}
+
+/// Options of a SQL `LIKE` match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LikeOptions {
+    /// Match as `NOT LIKE`, null values still never match.
+    pub negated: bool,
+    /// Match as `ILIKE`.
+    pub case_insensitive: bool,
+    /// The character that makes the following `%`, `_` or escape character match literally.
+    pub escape_char: char,
+}
+
+impl Default for LikeOptions {
+    fn default() -> Self {
+        Self {
+            negated: false,
+            case_insensitive: false,
+            escape_char: '\\',
+        }
+    }
+}
+
+/// A compiled predicate over the bytes of string values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringMatcher {
+    pattern: Pattern,
+    case_insensitive: bool,
+    negated: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Pattern {
+    Equals(String),
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+    Wildcard(Vec<Token>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Char(char),
+    AnyChar,
+    AnyString,
+}
+
+impl StringMatcher {
+    /// Compile a SQL `LIKE` pattern, where `%` matches any string and `_` any single character.
+    pub fn like(pattern: &str, options: LikeOptions) -> VortexResult<Self> {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            let literal = match c {
+                c if c == options.escape_char => match chars.next() {
+                    Some(escaped) => escaped,
+                    None => vortex_bail!("LIKE pattern {} ends with its escape character", pattern),
+                },
+                '%' => {
+                    tokens.push(Token::AnyString);
+                    continue;
+                }
+                '_' => {
+                    tokens.push(Token::AnyChar);
+                    continue;
+                }
+                c => c,
+            };
+            // The escape character is recognized before folding, so that it keeps its case.
+            if options.case_insensitive {
+                tokens.extend(literal.to_lowercase().map(Token::Char));
+            } else {
+                tokens.push(Token::Char(literal));
+            }
+        }
+
+        Ok(Self {
+            pattern: Pattern::compile(tokens),
+            case_insensitive: options.case_insensitive,
+            negated: options.negated,
+        })
+    }
+
+    pub fn starts_with(prefix: &str) -> Self {
+        Self::literal(Pattern::StartsWith(prefix.to_string()))
+    }
+
+    pub fn ends_with(suffix: &str) -> Self {
+        Self::literal(Pattern::EndsWith(suffix.to_string()))
+    }
+
+    pub fn contains(needle: &str) -> Self {
+        Self::literal(Pattern::Contains(needle.to_string()))
+    }
+
+    fn literal(pattern: Pattern) -> Self {
+        Self {
+            pattern,
+            case_insensitive: false,
+            negated: false,
+        }
+    }
+
+    /// Whether a non-null value matches.
+    pub fn matches(&self, value: &[u8]) -> bool {
+        let value = if self.case_insensitive {
+            Cow::Owned(String::from_utf8_lossy(value).to_lowercase().into_bytes())
+        } else {
+            Cow::Borrowed(value)
+        };
+        self.pattern.matches(&value) != self.negated
+    }
+}
+
+impl Pattern {
+    /// Use substring searches for patterns whose only wildcards are `%` at either end.
+    fn compile(tokens: Vec<Token>) -> Self {
+        let starts_any = tokens.first() == Some(&Token::AnyString);
+        let ends_any =
+            tokens.len() > usize::from(starts_any) && tokens.last() == Some(&Token::AnyString);
+        let inner = &tokens[usize::from(starts_any)..tokens.len() - usize::from(ends_any)];
+        let literal = inner
+            .iter()
+            .map(|token| match token {
+                Token::Char(c) => Some(*c),
+                _ => None,
+            })
+            .collect::<Option<String>>();
+
+        match (literal, starts_any, ends_any) {
+            (Some(literal), false, false) => Self::Equals(literal),
+            (Some(literal), false, true) => Self::StartsWith(literal),
+            (Some(literal), true, false) => Self::EndsWith(literal),
+            (Some(literal), true, true) => Self::Contains(literal),
+            (None, ..) => Self::Wildcard(tokens),
+        }
+    }
+
+    fn matches(&self, value: &[u8]) -> bool {
+        match self {
+            Self::Equals(literal) => value == literal.as_bytes(),
+            Self::StartsWith(prefix) => value.starts_with(prefix.as_bytes()),
+            Self::EndsWith(suffix) => value.ends_with(suffix.as_bytes()),
+            Self::Contains(needle) => {
+                needle.is_empty()
+                    || value
+                        .windows(needle.len())
+                        .any(|window| window == needle.as_bytes())
+            }
+            Self::Wildcard(tokens) => {
+                let value = String::from_utf8_lossy(value).chars().collect::<Vec<_>>();
+                wildcard_match(tokens, &value)
+            }
+        }
+    }
+}
+
+/// Greedy matching that backtracks to the most recent `%` on a mismatch.
+fn wildcard_match(tokens: &[Token], value: &[char]) -> bool {
+    let (mut t, mut v) = (0, 0);
+    let mut backtrack = None;
+    while v < value.len() {
+        match tokens.get(t) {
+            Some(Token::AnyString) => {
+                backtrack = Some((t, v));
+                t += 1;
+            }
+            Some(Token::AnyChar) => {
+                t += 1;
+                v += 1;
+            }
+            Some(Token::Char(c)) if *c == value[v] => {
+                t += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                Some((star, consumed)) => {
+                    backtrack = Some((star, consumed + 1));
+                    t = star + 1;
+                    v = consumed + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    tokens[t..].iter().all(|token| *token == Token::AnyString)
+}
+
+/// Evaluate a [StringMatcher] against a string or binary array.
+pub fn string_match(array: &Array, matcher: &StringMatcher) -> VortexResult<Array> {
+    if !matches!(array.dtype(), DType::Utf8(_) | DType::Binary(_)) {
+        vortex_bail!("String matching is not supported on {}", array.dtype());
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.string_match().map(|a| a.string_match(matcher))) {
+        return result;
+    }
+
+    array.clone().into_varbin_view()?.string_match(matcher)
+}
+
+/// SQL `LIKE` and `ILIKE`, see [StringMatcher::like].
+pub fn like(array: &Array, pattern: &str, options: LikeOptions) -> VortexResult<Array> {
+    string_match(array, &StringMatcher::like(pattern, options)?)
+}
+
+pub fn starts_with(array: &Array, prefix: &str) -> VortexResult<Array> {
+    string_match(array, &StringMatcher::starts_with(prefix))
+}
+
+pub fn ends_with(array: &Array, suffix: &str) -> VortexResult<Array> {
+    string_match(array, &StringMatcher::ends_with(suffix))
+}
+
+pub fn contains(array: &Array, needle: &str) -> VortexResult<Array> {
+    string_match(array, &StringMatcher::contains(needle))
+}
+
+/// The length of each value of a string or binary array as `u64`, null for null values.
+pub fn string_length(array: &Array, unit: StringLength) -> VortexResult<Array> {
+    match (array.dtype(), unit) {
+        (DType::Utf8(_), _) | (DType::Binary(_), StringLength::Bytes) => {}
+        (dtype, _) => vortex_bail!("Cannot compute the {:?} length of {}", unit, dtype),
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.string_length().map(|a| a.string_length(unit))) {
+        return result;
+    }
+
+    array.clone().into_varbin_view()?.string_length(unit)
+}
+
+pub fn byte_length(array: &Array) -> VortexResult<Array> {
+    string_length(array, StringLength::Bytes)
+}
+
+pub fn char_length(array: &Array) -> VortexResult<Array> {
+    string_length(array, StringLength::Chars)
+}
+
+/// Convert every value of a UTF-8 array to lower or upper case.
+pub fn fold_case(array: &Array, case: Case) -> VortexResult<Array> {
+    if !matches!(array.dtype(), DType::Utf8(_)) {
+        vortex_bail!(
+            "Case folding is only supported on UTF-8, got {}",
+            array.dtype()
+        );
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.fold_case().map(|a| a.fold_case(case))) {
+        return result;
+    }
+
+    array.clone().into_varbin_view()?.fold_case(case)
+}
+
+pub fn lower(array: &Array) -> VortexResult<Array> {
+    fold_case(array, Case::Lower)
+}
+
+pub fn upper(array: &Array) -> VortexResult<Array> {
+    fold_case(array, Case::Upper)
+}
+
+/// [StringMatchFn] over any array that can iterate its bytes.
+pub(crate) fn match_values<A: ArrayAccessor<[u8]>>(
+    array: &A,
+    matcher: &StringMatcher,
+) -> VortexResult<Array> {
+    array.with_iterator(|iter| {
+        BoolArray::from(
+            iter.map(|value| value.map_or(false, |value| matcher.matches(value)))
+                .collect::<Vec<_>>(),
+        )
+        .into_array()
+    })
+}
+
+/// [StringLengthFn] over any array that can iterate its bytes.
+pub(crate) fn value_lengths<A: ArrayAccessor<[u8]>>(
+    array: &A,
+    nullability: Nullability,
+    unit: StringLength,
+) -> VortexResult<Array> {
+    let lengths = array.with_iterator(|iter| {
+        iter.map(|value| {
+            value.map(|value| match unit {
+                StringLength::Bytes => value.len() as u64,
+                StringLength::Chars => String::from_utf8_lossy(value).chars().count() as u64,
+            })
+        })
+        .collect::<Vec<_>>()
+    })?;
+    Ok(match nullability {
+        Nullability::Nullable => PrimitiveArray::from_nullable_vec(lengths),
+        Nullability::NonNullable => {
+            PrimitiveArray::from(lengths.into_iter().flatten().collect::<Vec<_>>())
+        }
+    }
+    .into_array())
+}
+
+/// [FoldCaseFn] over any array that can iterate its bytes.
+pub(crate) fn fold_values<A: ArrayAccessor<[u8]>>(
+    array: &A,
+    dtype: &DType,
+    case: Case,
+) -> VortexResult<Array> {
+    let folded = array.with_iterator(|iter| {
+        iter.map(|value| {
+            value.map(|value| {
+                let value = String::from_utf8_lossy(value);
+                match case {
+                    Case::Lower => value.to_lowercase(),
+                    Case::Upper => value.to_uppercase(),
+                }
+            })
+        })
+        .collect::<Vec<_>>()
+    })?;
+    Ok(VarBinViewArray::from_iter(folded, dtype.clone()).into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::accessor::ArrayAccessor;
+    use crate::array::{VarBinArray, VarBinViewArray};
+    use crate::compute::{char_length, contains, like, upper, LikeOptions, StringMatcher};
+    use crate::{Array, IntoArray, IntoArrayVariant};
+
+    fn strings() -> Array {
+        VarBinArray::from_iter(
+            vec![Some("Hello"), None, Some("héllo world"), Some("50%_off")],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array()
+    }
+
+    fn bools(array: Array) -> Vec<bool> {
+        array.into_bool().unwrap().boolean_buffer().iter().collect()
+    }
+
+    #[test]
+    fn like_patterns() {
+        let matches = |pattern: &str, options| bools(like(&strings(), pattern, options).unwrap());
+        let default = LikeOptions::default();
+        assert_eq!(matches("H%", default), vec![true, false, false, false]);
+        assert_eq!(matches("%o w%", default), vec![false, false, true, false]);
+        assert_eq!(matches("h_llo%", default), vec![false, false, true, false]);
+        assert_eq!(
+            matches("%\\%\\_%", default),
+            vec![false, false, false, true]
+        );
+        let insensitive = LikeOptions {
+            case_insensitive: true,
+            ..default
+        };
+        assert_eq!(matches("h%o", insensitive), vec![true, false, false, false]);
+        let upper_escape = LikeOptions {
+            escape_char: 'X',
+            ..insensitive
+        };
+        assert_eq!(
+            matches("50X%X_OFF", upper_escape),
+            vec![false, false, false, true]
+        );
+        let negated = LikeOptions {
+            negated: true,
+            ..default
+        };
+        assert_eq!(matches("H%", negated), vec![false, false, true, true]);
+    }
+
+    #[test]
+    fn wildcard_backtracking() {
+        let matcher = StringMatcher::like("a%b_c%d", LikeOptions::default()).unwrap();
+        assert!(matcher.matches(b"axxbxcbyczd"));
+        assert!(!matcher.matches(b"axxbxcbycz"));
+        assert!(StringMatcher::like("%", LikeOptions::default())
+            .unwrap()
+            .matches(b""));
+        assert!(StringMatcher::like("ab\\", LikeOptions::default()).is_err());
+    }
+
+    #[test]
+    fn substring_lengths_and_case() {
+        assert_eq!(
+            bools(contains(&strings(), "llo").unwrap()),
+            vec![true, false, true, false]
+        );
+
+        let lengths = char_length(&strings()).unwrap().into_primitive().unwrap();
+        assert_eq!(lengths.maybe_null_slice::<u64>()[2], 11);
+        assert!(!lengths.validity().is_valid(1));
+
+        let upper = VarBinViewArray::try_from(upper(&strings()).unwrap())
+            .unwrap()
+            .with_iterator(|iter| {
+                iter.map(|v| v.map(|v| String::from_utf8(v.to_vec()).unwrap()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(upper[2].as_deref(), Some("HÉLLO WORLD"));
+    }
+}
@@ -1,24 +1,97 @@
-use vortex_dtype::DType;
-use vortex_error::{vortex_err, VortexResult};
+use std::cmp::Ordering;
 
-use crate::{Array, ArrayDType};
+use num_traits::Bounded;
+use vortex_dtype::{DType, NativePType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::stats::ArrayStatistics;
+use crate::validity::Validity;
+use crate::{Array, ArrayDType, IntoCanonical};
 
 pub trait CastFn {
-    fn cast(&self, dtype: &DType) -> VortexResult<Array>;
+    fn cast(&self, dtype: &DType, mode: CastMode) -> VortexResult<Array>;
+}
+
+/// How a cast treats values that cannot be represented in the target type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CastMode {
+    /// Fail the cast.
+    #[default]
+    Safe,
+    /// Clamp out of range values to the closest representable value. NaN becomes zero, and
+    /// strings that don't parse become null if the target type is nullable.
+    Saturating,
 }
 
-/// Attempt to cast an array to a desired DType.
+/// Attempt to cast an array to a desired DType, failing on values that don't fit.
 ///
 /// Some array support the ability to narrow or upcast.
 pub fn try_cast(array: &Array, dtype: &DType) -> VortexResult<Array> {
+    try_cast_with_mode(array, dtype, CastMode::Safe)
+}
+
+/// Attempt to cast an array to a desired DType with the given [CastMode].
+///
+/// Casting to a non-nullable type fails if the array contains nulls, regardless of the mode.
+pub fn try_cast_with_mode(array: &Array, dtype: &DType, mode: CastMode) -> VortexResult<Array> {
     if array.dtype() == dtype {
         return Ok(array.clone());
     }
 
-    // TODO(ngates): check for null_count if dtype is non-nullable
-    array.with_dyn(|a| {
-        a.cast()
-            .map(|f| f.cast(dtype))
-            .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "cast", array.encoding().id())))
-    })
+    if array.dtype().is_nullable() && !dtype.is_nullable() {
+        let null_count = match array.statistics().compute_null_count() {
+            Some(null_count) => null_count,
+            None => array
+                .with_dyn(|a| a.logical_validity())
+                .to_null_buffer()?
+                .map_or(0, |nulls| nulls.null_count()),
+        };
+        if null_count > 0 {
+            vortex_bail!(
+                "Cannot cast array with {} nulls to non-nullable {}",
+                null_count,
+                dtype
+            );
+        }
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.cast().map(|f| f.cast(dtype, mode))) {
+        return result;
+    }
+
+    let canonical = Array::from(array.clone().into_canonical()?);
+    if canonical.encoding().id() == array.encoding().id() {
+        vortex_bail!(NotImplemented: "cast", array.encoding().id());
+    }
+    try_cast_with_mode(&canonical, dtype, mode)
+}
+
+/// The validity of a cast result, which has already been checked for nulls if it is
+/// non-nullable.
+pub(crate) fn cast_validity(validity: Validity, nullability: Nullability) -> Validity {
+    match (validity, nullability) {
+        (_, Nullability::NonNullable) => Validity::NonNullable,
+        (Validity::NonNullable, Nullability::Nullable) => Validity::AllValid,
+        (validity, Nullability::Nullable) => validity,
+    }
+}
+
+/// Cast a single number, see [CastMode] for the handling of values that don't fit.
+pub(crate) fn cast_number<E: NativePType, T: NativePType + Bounded>(
+    value: E,
+    mode: CastMode,
+) -> VortexResult<T> {
+    match (T::from(value), mode) {
+        (Some(cast), _) => Ok(cast),
+        (None, CastMode::Safe) => Err(vortex_err!(
+            ComputeError: "Failed to cast {} to {:?}",
+            value,
+            T::PTYPE
+        )),
+        (None, CastMode::Saturating) => Ok(match value.partial_cmp(&E::zero()) {
+            None => T::zero(),
+            Some(Ordering::Less) => T::min_value(),
+            Some(_) => T::max_value(),
+        }),
+    }
 }
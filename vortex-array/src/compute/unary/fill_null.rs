@@ -0,0 +1,109 @@
+use vortex_buffer::{Buffer, BufferString};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::accessor::ArrayAccessor;
+use crate::array::VarBinViewArray;
+use crate::compute::unary::try_cast;
+use crate::validity::Validity;
+use crate::{Array, ArrayDType, IntoArray, IntoCanonical};
+
+pub trait FillNullFn {
+    /// Replace nulls with `fill_value`, which is non-null and of the non-nullable array type.
+    fn fill_null(&self, fill_value: &Scalar) -> VortexResult<Array>;
+}
+
+/// Replace the nulls of an array with `fill_value`, which is cast to the type of the array.
+///
+/// The result is non-nullable, unless `fill_value` is null in which case the array is returned
+/// as is.
+pub fn fill_null(array: &Array, fill_value: &Scalar) -> VortexResult<Array> {
+    if !array.dtype().is_nullable() || fill_value.is_null() {
+        return Ok(array.clone());
+    }
+
+    let fill_value = fill_value.cast(&array.dtype().as_nonnullable())?;
+    if let Some(result) = array.with_dyn(|a| a.fill_null().map(|a| a.fill_null(&fill_value))) {
+        return result;
+    }
+
+    if array.with_dyn(|a| a.logical_validity().all_valid()) {
+        return try_cast(array, fill_value.dtype());
+    }
+
+    let canonical = Array::from(array.clone().into_canonical()?);
+    if canonical.encoding().id() == array.encoding().id() {
+        vortex_bail!(NotImplemented: "fill_null", array.encoding().id());
+    }
+    fill_null(&canonical, &fill_value)
+}
+
+/// [FillNullFn] over any string or binary array that can iterate its bytes.
+pub(crate) fn fill_null_bytes<A: ArrayAccessor<[u8]> + ArrayDType>(
+    array: &A,
+    fill_value: &Scalar,
+) -> VortexResult<Array> {
+    let fill = match fill_value.dtype() {
+        DType::Utf8(_) => BufferString::try_from(fill_value)?
+            .as_str()
+            .as_bytes()
+            .to_vec(),
+        _ => Buffer::try_from(fill_value)?.as_ref().to_vec(),
+    };
+    let filled = array.with_iterator(|iter| {
+        VarBinViewArray::from_iter_bin(iter.map(|v| v.unwrap_or(fill.as_slice())))
+    })?;
+    Ok(VarBinViewArray::try_new(
+        filled.views(),
+        filled.buffers(),
+        fill_value.dtype().clone(),
+        Validity::NonNullable,
+    )?
+    .into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use super::*;
+    use crate::array::{BoolArray, ConstantArray, PrimitiveArray};
+    use crate::IntoArrayVariant;
+
+    #[test]
+    fn fill_nulls() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i64), None, Some(3)]).into_array();
+        let filled = fill_null(&array, &Scalar::from(-1i64)).unwrap();
+        assert!(!filled.dtype().is_nullable());
+        assert_eq!(
+            filled.into_primitive().unwrap().maybe_null_slice::<i64>(),
+            &[1, -1, 3]
+        );
+
+        let bools = BoolArray::from_iter([Some(false), None, Some(true)]).into_array();
+        let filled = fill_null(&bools, &true.into())
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            filled.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, true]
+        );
+
+        let strings = VarBinViewArray::from_iter_nullable_str([None, Some("a")]).into_array();
+        let filled = fill_null(&strings, &"-".into()).unwrap();
+        assert_eq!(filled.dtype(), &DType::Utf8(false.into()));
+        assert_eq!(
+            filled.into_varbin_view().unwrap().bytes_at(0).unwrap(),
+            b"-"
+        );
+
+        let constant = ConstantArray::new(Scalar::null(array.dtype().clone()), 2).into_array();
+        let filled = fill_null(&constant, &Scalar::from(0i64)).unwrap();
+        assert_eq!(
+            ConstantArray::try_from(filled).unwrap().scalar(),
+            &Scalar::from(0i64)
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use vortex_error::VortexResult;
+
+use crate::array::{BoolArray, ConstantArray};
+use crate::compute::not;
+use crate::validity::LogicalValidity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+pub trait IsNullFn {
+    fn is_null(&self) -> VortexResult<Array>;
+}
+
+/// A non-nullable boolean array that is true where the array is null.
+pub fn is_null(array: &Array) -> VortexResult<Array> {
+    if !array.dtype().is_nullable() {
+        return Ok(ConstantArray::new(false, array.len()).into_array());
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.is_null().map(|a| a.is_null())) {
+        return result;
+    }
+
+    validity_mask(array, true)
+}
+
+/// A non-nullable boolean array that is true where the array is not null.
+pub fn is_not_null(array: &Array) -> VortexResult<Array> {
+    if array.dtype().is_nullable() && array.with_dyn(|a| a.is_null().is_some()) {
+        return not(&is_null(array)?);
+    }
+
+    validity_mask(array, false)
+}
+
+fn validity_mask(array: &Array, null: bool) -> VortexResult<Array> {
+    Ok(match array.with_dyn(|a| a.logical_validity()) {
+        LogicalValidity::AllValid(len) => ConstantArray::new(!null, len).into_array(),
+        LogicalValidity::AllInvalid(len) => ConstantArray::new(null, len).into_array(),
+        LogicalValidity::Array(validity) if null => {
+            BoolArray::from(!&validity.into_bool()?.boolean_buffer()).into_array()
+        }
+        LogicalValidity::Array(validity) => {
+            BoolArray::from(validity.into_bool()?.boolean_buffer()).into_array()
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PrimitiveArray;
+
+    fn to_vec(array: Array) -> Vec<bool> {
+        array.into_bool().unwrap().boolean_buffer().iter().collect()
+    }
+
+    #[test]
+    fn nulls() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1), None, Some(3)]).into_array();
+        assert_eq!(to_vec(is_null(&array).unwrap()), vec![false, true, false]);
+        assert_eq!(
+            to_vec(is_not_null(&array).unwrap()),
+            vec![true, false, true]
+        );
+
+        let array = PrimitiveArray::from(vec![1, 2]).into_array();
+        assert_eq!(to_vec(is_null(&array).unwrap()), vec![false, false]);
+        assert!(!is_null(&array).unwrap().dtype().is_nullable());
+    }
+}
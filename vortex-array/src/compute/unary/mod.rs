@@ -1,9 +1,15 @@
-pub use cast::{try_cast, CastFn};
+pub(crate) use cast::{cast_number, cast_validity};
+pub use cast::{try_cast, try_cast_with_mode, CastFn, CastMode};
 pub use fill_forward::{fill_forward, FillForwardFn};
+pub(crate) use fill_null::fill_null_bytes;
+pub use fill_null::{fill_null, FillNullFn};
+pub use is_null::{is_not_null, is_null, IsNullFn};
 pub use scalar_at::{scalar_at, ScalarAtFn};
-pub use scalar_subtract::{subtract_scalar, SubtractScalarFn};
+pub use scalar_subtract::subtract_scalar;
 
 mod cast;
 mod fill_forward;
+mod fill_null;
+mod is_null;
 mod scalar_at;
 mod scalar_subtract;
@@ -1,31 +1,106 @@
-use vortex_dtype::DType;
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
-use crate::{Array, ArrayDType, IntoArrayVariant};
+use crate::compute::{scalar_numeric, BinaryOperator, OverflowMode};
+use crate::Array;
 
-pub trait SubtractScalarFn {
-    fn subtract_scalar(&self, to_subtract: &Scalar) -> VortexResult<Array>;
+/// Subtract a scalar from every element of `array`, failing if a valid element overflows.
+///
+/// This is [scalar_numeric] with [BinaryOperator::Sub] in [OverflowMode::Checked].
+pub fn subtract_scalar(array: &Array, to_subtract: &Scalar) -> VortexResult<Array> {
+    scalar_numeric(
+        array,
+        to_subtract,
+        BinaryOperator::Sub,
+        OverflowMode::Checked,
+    )
 }
 
-pub fn subtract_scalar(array: &Array, to_subtract: &Scalar) -> VortexResult<Array> {
-    if let Some(subtraction_result) =
-        array.with_dyn(|c| c.subtract_scalar().map(|t| t.subtract_scalar(to_subtract)))
-    {
-        return subtraction_result;
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use crate::array::PrimitiveArray;
+    use crate::compute::unary::subtract_scalar;
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn test_scalar_subtract_unsigned() {
+        let values = vec![1u16, 2, 3].into_array();
+        let results = subtract_scalar(&values, &1u16.into())
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<u16>()
+            .to_vec();
+        assert_eq!(results, &[0u16, 1, 2]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_signed() {
+        let values = vec![1i64, 2, 3].into_array();
+        let results = subtract_scalar(&values, &(-1i64).into())
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<i64>()
+            .to_vec();
+        assert_eq!(results, &[2i64, 3, 4]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_nullable() {
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(1u16), Some(2), None, Some(3)])
+            .into_array();
+        let flattened = subtract_scalar(&values, &Some(1u16).into())
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+
+        let results = flattened.maybe_null_slice::<u16>().to_vec();
+        assert_eq!(results, &[0u16, 1, 0, 2]);
+        let valid_indices = flattened
+            .validity()
+            .to_logical(flattened.len())
+            .to_null_buffer()
+            .unwrap()
+            .unwrap()
+            .valid_indices()
+            .collect_vec();
+        assert_eq!(valid_indices, &[0, 1, 3]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_float() {
+        let values = vec![1.0f64, 2.0, 3.0].into_array();
+        let to_subtract = -1f64;
+        let results = subtract_scalar(&values, &to_subtract.into())
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<f64>()
+            .to_vec();
+        assert_eq!(results, &[2.0f64, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_scalar_subtract_float_underflow_is_ok() {
+        let values = vec![f32::MIN, 2.0, 3.0].into_array();
+        let _results = subtract_scalar(&values, &1.0f32.into()).unwrap();
+        let _results = subtract_scalar(&values, &f32::MAX.into()).unwrap();
+    }
+
+    #[test]
+    fn test_scalar_subtract_overflow_fails() {
+        let values = vec![0u8, 1].into_array();
+        subtract_scalar(&values, &1u8.into()).expect_err("Expected overflow error");
     }
-    // if subtraction is not implemented for the given array type, but the array has a numeric
-    // DType, we can flatten the array and apply subtraction to the flattened primitive array
-    match array.dtype() {
-        DType::Primitive(..) => {
-            // TODO(@jcasale): pass array instead of ref to get rid of clone?
-            // downside is that subtract_scalar then consumes the array, which is not great
-            let flat = array.clone().into_primitive()?;
-            flat.subtract_scalar(to_subtract)
-        }
-        _ => Err(vortex_err!(
-            NotImplemented: "scalar_subtract",
-            array.encoding().id()
-        )),
+
+    #[test]
+    fn test_scalar_subtract_type_mismatch_fails() {
+        let values = vec![1u64, 2, 3].into_array();
+        // Subtracting incompatible dtypes should fail
+        let _results =
+            subtract_scalar(&values, &1.5f64.into()).expect_err("Expected type mismatch error");
     }
 }
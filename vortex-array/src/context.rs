@@ -1,14 +1,20 @@
 use std::collections::HashMap;
 
+use vortex_dtype::ExtID;
+
+use crate::array::temporal::temporal_extension_types;
 use crate::array::{
-    BoolEncoding, ChunkedEncoding, ConstantEncoding, ExtensionEncoding, PrimitiveEncoding,
-    SparseEncoding, StructEncoding, VarBinEncoding, VarBinViewEncoding,
+    register_extension_types, BoolEncoding, ChunkedEncoding, ConstantEncoding, DecimalEncoding,
+    ExtensionEncoding, ExtensionTypeRef, FixedSizeListEncoding, ListEncoding, MapEncoding,
+    PrimitiveEncoding, SparseEncoding, StructEncoding, UnionEncoding, VarBinEncoding,
+    VarBinViewEncoding,
 };
 use crate::encoding::EncodingRef;
 
 #[derive(Debug, Clone)]
 pub struct Context {
     encodings: HashMap<u16, EncodingRef>,
+    extension_types: HashMap<ExtID, ExtensionTypeRef>,
 }
 
 impl Context {
@@ -30,6 +36,36 @@ impl Context {
     pub fn lookup_encoding(&self, encoding_code: u16) -> Option<EncodingRef> {
         self.encodings.get(&encoding_code).cloned()
     }
+
+    pub fn with_extension_type(mut self, ext_type: ExtensionTypeRef) -> Self {
+        self.extension_types.insert(ext_type.id().clone(), ext_type);
+        self
+    }
+
+    pub fn with_extension_types<E: IntoIterator<Item = ExtensionTypeRef>>(
+        mut self,
+        ext_types: E,
+    ) -> Self {
+        self.extension_types
+            .extend(ext_types.into_iter().map(|e| (e.id().clone(), e)));
+        self
+    }
+
+    pub fn extension_types(&self) -> impl Iterator<Item = ExtensionTypeRef> + '_ {
+        self.extension_types.values().cloned()
+    }
+
+    pub fn lookup_extension_type(&self, id: &ExtID) -> Option<ExtensionTypeRef> {
+        self.extension_types.get(id).cloned()
+    }
+
+    /// Make the extension types of this context available to conversions that run without a
+    /// context, such as [crate::Canonical::into_arrow] or validating an [ExtensionArray].
+    ///
+    /// [ExtensionArray]: crate::array::ExtensionArray
+    pub fn register_extension_types(&self) {
+        register_extension_types(self.extension_types());
+    }
 }
 
 impl Default for Context {
@@ -39,16 +75,25 @@ impl Default for Context {
                 &BoolEncoding as EncodingRef,
                 &ChunkedEncoding,
                 &ConstantEncoding,
+                &DecimalEncoding,
                 &ExtensionEncoding,
+                &FixedSizeListEncoding,
+                &ListEncoding,
+                &MapEncoding,
                 &PrimitiveEncoding,
                 &SparseEncoding,
                 &StructEncoding,
+                &UnionEncoding,
                 &VarBinEncoding,
                 &VarBinViewEncoding,
             ]
             .into_iter()
             .map(|e| (e.id().code(), e))
             .collect(),
+            extension_types: temporal_extension_types()
+                .into_iter()
+                .map(|e| (e.id().clone(), e))
+                .collect(),
         }
     }
 }
@@ -36,6 +36,7 @@ use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
 pub mod accessor;
 pub mod array;
 pub mod arrow;
+pub mod builders;
 mod canonical;
 pub mod compress;
 pub mod compute;
@@ -177,10 +178,14 @@ impl Array {
                         DType::Null => array.as_null_array().is_some(),
                         DType::Bool(_) => array.as_bool_array().is_some(),
                         DType::Primitive(..) => array.as_primitive_array().is_some(),
+                        DType::Decimal(..) => array.as_decimal_array().is_some(),
                         DType::Utf8(_) => array.as_utf8_array().is_some(),
                         DType::Binary(_) => array.as_binary_array().is_some(),
                         DType::Struct(..) => array.as_struct_array().is_some(),
                         DType::List(..) => array.as_list_array().is_some(),
+                        DType::FixedSizeList(..) => array.as_fixed_size_list_array().is_some(),
+                        DType::Union(..) => array.as_union_array().is_some(),
+                        DType::Map(..) => array.as_map_array().is_some(),
                         DType::Extension(..) => array.as_extension_array().is_some(),
                     },
                     "Encoding {} does not implement the variant trait for {}",
@@ -31,6 +31,14 @@ pub trait ArrayVariants {
         self.as_primitive_array().expect("Expected PrimitiveArray")
     }
 
+    fn as_decimal_array(&self) -> Option<&dyn DecimalArrayTrait> {
+        None
+    }
+
+    fn as_decimal_array_unchecked(&self) -> &dyn DecimalArrayTrait {
+        self.as_decimal_array().expect("Expected DecimalArray")
+    }
+
     fn as_utf8_array(&self) -> Option<&dyn Utf8ArrayTrait> {
         None
     }
@@ -63,6 +71,31 @@ pub trait ArrayVariants {
         self.as_list_array().expect("Expected ListArray")
     }
 
+    fn as_fixed_size_list_array(&self) -> Option<&dyn FixedSizeListArrayTrait> {
+        None
+    }
+
+    fn as_fixed_size_list_array_unchecked(&self) -> &dyn FixedSizeListArrayTrait {
+        self.as_fixed_size_list_array()
+            .expect("Expected FixedSizeListArray")
+    }
+
+    fn as_map_array(&self) -> Option<&dyn MapArrayTrait> {
+        None
+    }
+
+    fn as_map_array_unchecked(&self) -> &dyn MapArrayTrait {
+        self.as_map_array().expect("Expected MapArray")
+    }
+
+    fn as_union_array(&self) -> Option<&dyn UnionArrayTrait> {
+        None
+    }
+
+    fn as_union_array_unchecked(&self) -> &dyn UnionArrayTrait {
+        self.as_union_array().expect("Expected UnionArray")
+    }
+
     fn as_extension_array(&self) -> Option<&dyn ExtensionArrayTrait> {
         None
     }
@@ -101,6 +134,8 @@ pub trait BoolArrayTrait: ArrayTrait {
 
 pub trait PrimitiveArrayTrait: ArrayTrait {}
 
+pub trait DecimalArrayTrait: ArrayTrait {}
+
 pub trait Utf8ArrayTrait: ArrayTrait {}
 
 pub trait BinaryArrayTrait: ArrayTrait {}
@@ -138,4 +173,10 @@ pub trait StructArrayTrait: ArrayTrait {
 
 pub trait ListArrayTrait: ArrayTrait {}
 
+pub trait FixedSizeListArrayTrait: ArrayTrait {}
+
+pub trait UnionArrayTrait: ArrayTrait {}
+
+pub trait MapArrayTrait: ArrayTrait {}
+
 pub trait ExtensionArrayTrait: ArrayTrait {}
@@ -10,14 +10,11 @@
 //! For this reason, it's recommended to do as much computation as possible within Vortex, and then
 //! materialize an Arrow ArrayRef at the very end of the processing chain.
 
-use std::sync::Arc;
-
 use arrow_schema::{
-    DataType, Field, FieldRef, Fields, Schema, SchemaBuilder, TimeUnit as ArrowTimeUnit,
+    DataType, Field, FieldRef, Fields, Schema, SchemaBuilder, UnionMode as ArrowUnionMode,
 };
-use vortex::array::temporal::TemporalMetadata;
-use vortex::array::TimeUnit;
-use vortex_dtype::{DType, Nullability, PType};
+use vortex::array::find_extension_type;
+use vortex_dtype::{DType, Nullability, PType, UnionMode};
 
 /// Convert a Vortex [struct DType][DType] to an Arrow [Schema].
 ///
@@ -40,11 +37,7 @@ pub(crate) fn infer_schema(dtype: &DType) -> Schema {
         .iter()
         .zip(struct_dtype.dtypes().iter())
     {
-        builder.push(FieldRef::from(Field::new(
-            field_name.to_string(),
-            infer_data_type(field_dtype),
-            field_dtype.is_nullable(),
-        )));
+        builder.push(FieldRef::from(infer_field(field_name, field_dtype)));
     }
 
     builder.finish()
@@ -67,8 +60,11 @@ pub(crate) fn infer_data_type(dtype: &DType) -> DataType {
             PType::F32 => DataType::Float32,
             PType::F64 => DataType::Float64,
         },
-        DType::Utf8(_) => DataType::Utf8,
-        DType::Binary(_) => DataType::Binary,
+        DType::Decimal(decimal_dtype, _) => {
+            DataType::Decimal128(decimal_dtype.precision(), decimal_dtype.scale() as i8)
+        }
+        DType::Utf8(_) => DataType::Utf8View,
+        DType::Binary(_) => DataType::BinaryView,
         DType::Struct(struct_dtype, _) => {
             let mut fields = Vec::with_capacity(struct_dtype.names().len());
             for (field_name, field_dt) in struct_dtype
@@ -76,11 +72,7 @@ pub(crate) fn infer_data_type(dtype: &DType) -> DataType {
                 .iter()
                 .zip(struct_dtype.dtypes().iter())
             {
-                fields.push(FieldRef::from(Field::new(
-                    field_name.to_string(),
-                    infer_data_type(field_dt),
-                    field_dt.is_nullable(),
-                )));
+                fields.push(FieldRef::from(infer_field(field_name, field_dt)));
             }
 
             DataType::Struct(Fields::from(fields))
@@ -93,37 +85,65 @@ pub(crate) fn infer_data_type(dtype: &DType) -> DataType {
                 dtype.is_nullable(),
             )))
         }
-        DType::Extension(ext_dtype, _) => {
-            // Try and match against the known extension DTypes.
-            if let Ok(temporal_metadata) = TemporalMetadata::try_from(ext_dtype) {
-                match temporal_metadata {
-                    TemporalMetadata::Time(time_unit) => match time_unit {
-                        TimeUnit::S => DataType::Time32(ArrowTimeUnit::Second),
-                        TimeUnit::Ms => DataType::Time32(ArrowTimeUnit::Millisecond),
-                        TimeUnit::Us => DataType::Time64(ArrowTimeUnit::Microsecond),
-                        TimeUnit::Ns => DataType::Time64(ArrowTimeUnit::Nanosecond),
-                        _ => panic!("invalid time_unit for Time: {time_unit}"),
-                    },
-                    TemporalMetadata::Date(time_unit) => match time_unit {
-                        TimeUnit::D => DataType::Date32,
-                        TimeUnit::Ms => DataType::Date64,
-                        _ => panic!("invalid time_unit for Date: {time_unit}"),
-                    },
-                    TemporalMetadata::Timestamp(time_unit, tz) => DataType::Timestamp(
-                        match time_unit {
-                            TimeUnit::S => ArrowTimeUnit::Second,
-                            TimeUnit::Ms => ArrowTimeUnit::Millisecond,
-                            TimeUnit::Us => ArrowTimeUnit::Microsecond,
-                            TimeUnit::Ns => ArrowTimeUnit::Nanosecond,
-                            _ => panic!("invalid time_unit for Timestamp: {time_unit}"),
-                        },
-                        tz.map(|s| Arc::from(s.into_boxed_str())),
-                    ),
-                }
-            } else {
-                panic!("unsupported extension type \"{}\"", ext_dtype.id().as_ref())
-            }
+        DType::FixedSizeList(list_dt, size, _) => {
+            let dtype: &DType = list_dt;
+            DataType::FixedSizeList(
+                FieldRef::from(Field::new(
+                    "element",
+                    infer_data_type(dtype),
+                    dtype.is_nullable(),
+                )),
+                *size as i32,
+            )
         }
+        DType::Map(map_dtype, _) => DataType::Map(
+            FieldRef::from(Field::new(
+                "entries",
+                infer_data_type(&map_dtype.entry_dtype()),
+                false,
+            )),
+            map_dtype.keys_sorted(),
+        ),
+        DType::Union(union_dtype, _) => DataType::Union(
+            union_dtype
+                .names()
+                .iter()
+                .zip(union_dtype.dtypes().iter())
+                .enumerate()
+                .map(|(type_id, (name, dtype))| {
+                    (
+                        type_id as i8,
+                        FieldRef::from(Field::new(
+                            name.to_string(),
+                            infer_data_type(dtype),
+                            dtype.is_nullable(),
+                        )),
+                    )
+                })
+                .collect(),
+            match union_dtype.mode() {
+                UnionMode::Sparse => ArrowUnionMode::Sparse,
+                UnionMode::Dense => ArrowUnionMode::Dense,
+            },
+        ),
+        DType::Extension(ext_dtype, _) => find_extension_type(ext_dtype.id())
+            .and_then(|ext_type| ext_type.arrow_data_type(ext_dtype).ok())
+            .unwrap_or_else(|| {
+                panic!("unsupported extension type \"{}\"", ext_dtype.id().as_ref())
+            }),
+    }
+}
+
+/// Create the Arrow field for a Vortex dtype, attaching the Arrow extension metadata of
+/// registered extension types.
+fn infer_field(name: &str, dtype: &DType) -> Field {
+    let field = Field::new(name, infer_data_type(dtype), dtype.is_nullable());
+    match dtype {
+        DType::Extension(ext_dtype, _) => match find_extension_type(ext_dtype.id()) {
+            Some(ext_type) => field.with_metadata(ext_type.arrow_metadata(ext_dtype)),
+            None => field,
+        },
+        _ => field,
     }
 }
 
@@ -133,7 +153,8 @@ mod test {
 
     use arrow_schema::{DataType, Field, FieldRef, Fields, Schema};
     use vortex_dtype::{
-        DType, ExtDType, ExtID, FieldName, FieldNames, Nullability, PType, StructDType,
+        DType, DecimalDType, ExtDType, ExtID, FieldName, FieldNames, Nullability, PType,
+        StructDType,
     };
 
     use super::*;
@@ -152,14 +173,22 @@ mod test {
             DataType::UInt64
         );
 
+        assert_eq!(
+            infer_data_type(&DType::Decimal(
+                DecimalDType::try_new(12, 4).unwrap(),
+                Nullability::NonNullable
+            )),
+            DataType::Decimal128(12, 4)
+        );
+
         assert_eq!(
             infer_data_type(&DType::Utf8(Nullability::NonNullable)),
-            DataType::Utf8
+            DataType::Utf8View
         );
 
         assert_eq!(
             infer_data_type(&DType::Binary(Nullability::NonNullable)),
-            DataType::Binary
+            DataType::BinaryView
         );
 
         assert_eq!(
@@ -184,7 +213,7 @@ mod test {
             )),
             DataType::Struct(Fields::from(vec![
                 FieldRef::from(Field::new("field_a", DataType::Boolean, false)),
-                FieldRef::from(Field::new("field_b", DataType::Utf8, true)),
+                FieldRef::from(Field::new("field_b", DataType::Utf8View, true)),
             ]))
         );
     }
@@ -207,7 +236,7 @@ mod test {
             infer_schema(&schema_nonnull),
             Schema::new(Fields::from(vec![
                 Field::new("field_a", DataType::Boolean, false),
-                Field::new("field_b", DataType::Utf8, false),
+                Field::new("field_b", DataType::Utf8View, false),
                 Field::new("field_c", DataType::Int32, true),
             ]))
         );
@@ -1,12 +1,13 @@
 use datafusion_expr::{Expr, Operator as DFOperator};
 use vortex::array::ConstantArray;
-use vortex::compute::{and, compare, or};
+use vortex::compute::unary::{is_not_null, is_null};
+use vortex::compute::{and, compare, like, or, LikeOptions};
 use vortex::{Array, IntoArray};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_expr::Operator;
 
-use crate::can_be_pushed_down;
 use crate::scalar::dfvalue_to_scalar;
+use crate::{can_be_pushed_down, like_pattern};
 
 pub struct ExpressionEvaluator;
 
@@ -31,6 +32,22 @@ impl ExpressionEvaluator {
                     _ => vortex_bail!("{} is an unsupported operator", expr.op),
                 }
             }
+            Expr::Like(expr) => {
+                let values = ExpressionEvaluator::eval(array, expr.expr.as_ref())?;
+                let pattern = like_pattern(expr.pattern.as_ref())
+                    .ok_or_else(|| vortex_err!("LIKE pattern must be a string literal"))?;
+                like(
+                    &values,
+                    pattern,
+                    LikeOptions {
+                        negated: expr.negated,
+                        case_insensitive: expr.case_insensitive,
+                        escape_char: expr.escape_char.unwrap_or('\\'),
+                    },
+                )
+            }
+            Expr::IsNull(expr) => is_null(&ExpressionEvaluator::eval(array, expr.as_ref())?),
+            Expr::IsNotNull(expr) => is_not_null(&ExpressionEvaluator::eval(array, expr.as_ref())?),
             Expr::Column(col) => array.with_dyn(|a| {
                 let name = col.name();
                 a.as_struct_array()
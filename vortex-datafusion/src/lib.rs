@@ -14,7 +14,7 @@ use arrow_schema::{DataType, SchemaRef};
 use datafusion::execution::{RecordBatchStream, SendableRecordBatchStream, TaskContext};
 use datafusion::prelude::{DataFrame, SessionContext};
 use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
-use datafusion_common::{exec_datafusion_err, DataFusionError, Result as DFResult};
+use datafusion_common::{exec_datafusion_err, DataFusionError, Result as DFResult, ScalarValue};
 use datafusion_execution::object_store::ObjectStoreUrl;
 use datafusion_expr::{Expr, Operator};
 use datafusion_physical_plan::{DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties};
@@ -161,12 +161,28 @@ fn can_be_pushed_down(expr: &Expr) -> bool {
         {
             can_be_pushed_down(expr.left.as_ref()) & can_be_pushed_down(expr.right.as_ref())
         }
+        Expr::Like(like) => {
+            like_pattern(like.pattern.as_ref()).is_some() && can_be_pushed_down(like.expr.as_ref())
+        }
+        Expr::IsNull(expr) | Expr::IsNotNull(expr) => can_be_pushed_down(expr.as_ref()),
         Expr::Column(_) => true,
         Expr::Literal(lit) => supported_data_types(lit.data_type()),
         _ => false,
     }
 }
 
+/// The pattern of a `LIKE` expression, if it is a non-null string literal.
+fn like_pattern(pattern: &Expr) -> Option<&str> {
+    match pattern {
+        Expr::Literal(
+            ScalarValue::Utf8(Some(pattern))
+            | ScalarValue::LargeUtf8(Some(pattern))
+            | ScalarValue::Utf8View(Some(pattern)),
+        ) => Some(pattern),
+        _ => None,
+    }
+}
+
 fn get_filter_projection(exprs: &[Expr], schema: SchemaRef) -> Vec<usize> {
     let referenced_columns: HashSet<String> =
         exprs.iter().flat_map(get_column_references).collect();
@@ -279,6 +279,64 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_like_pushdown() {
+        let ctx = SessionContext::new();
+
+        let df = ctx.read_mem_vortex(presidents_array()).unwrap();
+
+        let distinct_names = df
+            .filter(col("president").like(lit("%a%s%")))
+            .unwrap()
+            .aggregate(vec![], vec![count_distinct(col("president"))])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *distinct_names[0]
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values()
+                .first()
+                .unwrap(),
+            3i64
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_is_null_pushdown() {
+        let ctx = SessionContext::new();
+
+        let df = ctx.read_mem_vortex(presidents_array()).unwrap();
+
+        let distinct_names = df
+            .filter(
+                col("president")
+                    .is_not_null()
+                    .and(col("term_start").is_not_null()),
+            )
+            .unwrap()
+            .aggregate(vec![], vec![count_distinct(col("president"))])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *distinct_names[0]
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values()
+                .first()
+                .unwrap(),
+            5i64
+        );
+    }
+
     #[tokio::test]
     #[cfg_attr(miri, ignore)]
     async fn test_datafusion_no_pushdown() {
@@ -368,4 +426,10 @@ mod test {
         let e = and((col("a")).eq(lit(2u64)), col("b").eq(lit(true)));
         assert!(can_be_pushed_down(&e));
     }
+
+    #[test]
+    fn test_can_be_pushed_down5() {
+        assert!(can_be_pushed_down(&col("a").like(lit("x%"))));
+        assert!(!can_be_pushed_down(&col("a").like(col("b"))));
+    }
 }
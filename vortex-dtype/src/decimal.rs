@@ -0,0 +1,68 @@
+use std::fmt::{Display, Formatter};
+
+use vortex_error::{vortex_bail, VortexResult};
+
+/// The maximum precision of a decimal that can be stored in 128 bits.
+pub const MAX_DECIMAL_PRECISION: u8 = 38;
+
+/// The precision and scale of a fixed-point decimal.
+///
+/// Decimal values are stored as 128-bit integers scaled by `10^scale`, so the precision can be at
+/// most [MAX_DECIMAL_PRECISION] digits.
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecimalDType {
+    precision: u8,
+    scale: u8,
+}
+
+impl DecimalDType {
+    pub fn try_new(precision: u8, scale: u8) -> VortexResult<Self> {
+        if precision == 0 || precision > MAX_DECIMAL_PRECISION {
+            vortex_bail!(
+                "Decimal precision {} must be between 1 and {}",
+                precision,
+                MAX_DECIMAL_PRECISION
+            );
+        }
+        if scale > precision {
+            vortex_bail!(
+                "Decimal scale {} must not exceed precision {}",
+                scale,
+                precision
+            );
+        }
+        Ok(Self { precision, scale })
+    }
+
+    /// Total number of decimal digits.
+    #[inline]
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Number of digits after the decimal point.
+    #[inline]
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+}
+
+impl Display for DecimalDType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.precision, self.scale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::DecimalDType;
+
+    #[test]
+    fn invalid_decimal() {
+        assert!(DecimalDType::try_new(0, 0).is_err());
+        assert!(DecimalDType::try_new(39, 2).is_err());
+        assert!(DecimalDType::try_new(5, 6).is_err());
+        assert!(DecimalDType::try_new(38, 38).is_ok());
+    }
+}
@@ -6,8 +6,9 @@ use itertools::Itertools;
 use vortex_error::{vortex_bail, VortexResult};
 use DType::*;
 
+use crate::field::FieldMask;
 use crate::nullability::Nullability;
-use crate::{ExtDType, PType};
+use crate::{DecimalDType, ExtDType, MapDType, PType, UnionDType};
 
 pub type FieldName = Arc<str>;
 pub type FieldNames = Arc<[FieldName]>;
@@ -24,10 +25,17 @@ pub enum DType {
     Null,
     Bool(Nullability),
     Primitive(PType, Nullability),
+    Decimal(DecimalDType, Nullability),
     Utf8(Nullability),
     Binary(Nullability),
     Struct(StructDType, Nullability),
     List(Arc<DType>, Nullability),
+    /// A list where every element has exactly `size` children.
+    FixedSizeList(Arc<DType>, u32, Nullability),
+    /// A value of exactly one of several variant types.
+    Union(UnionDType, Nullability),
+    /// A list of key-value entries, stored as a list of `{key, value}` structs.
+    Map(MapDType, Nullability),
     Extension(ExtDType, Nullability),
 }
 
@@ -48,10 +56,14 @@ impl DType {
             Null => true,
             Bool(n) => matches!(n, Nullable),
             Primitive(_, n) => matches!(n, Nullable),
+            Decimal(_, n) => matches!(n, Nullable),
             Utf8(n) => matches!(n, Nullable),
             Binary(n) => matches!(n, Nullable),
-            Struct(st, _) => st.dtypes().iter().all(|f| f.is_nullable()),
+            Struct(_, n) => matches!(n, Nullable),
             List(_, n) => matches!(n, Nullable),
+            FixedSizeList(_, _, n) => matches!(n, Nullable),
+            Union(_, n) => matches!(n, Nullable),
+            Map(_, n) => matches!(n, Nullable),
             Extension(_, n) => matches!(n, Nullable),
         }
     }
@@ -69,10 +81,14 @@ impl DType {
             Null => Null,
             Bool(_) => Bool(nullability),
             Primitive(p, _) => Primitive(*p, nullability),
+            Decimal(d, _) => Decimal(*d, nullability),
             Utf8(_) => Utf8(nullability),
             Binary(_) => Binary(nullability),
             Struct(st, _) => Struct(st.clone(), nullability),
             List(c, _) => List(c.clone(), nullability),
+            FixedSizeList(c, size, _) => FixedSizeList(c.clone(), *size, nullability),
+            Union(u, _) => Union(u.clone(), nullability),
+            Map(m, _) => Map(m.clone(), nullability),
             Extension(ext, _) => Extension(ext.clone(), nullability),
         }
     }
@@ -113,6 +129,18 @@ impl DType {
         matches!(self, Bool(_))
     }
 
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, Decimal(..))
+    }
+
+    pub fn is_union(&self) -> bool {
+        matches!(self, Union(..))
+    }
+
+    pub fn is_map(&self) -> bool {
+        matches!(self, Map(..))
+    }
+
     pub fn as_struct(&self) -> Option<&StructDType> {
         match self {
             Struct(s, _) => Some(s),
@@ -127,6 +155,7 @@ impl Display for DType {
             Null => write!(f, "null"),
             Bool(n) => write!(f, "bool{}", n),
             Primitive(p, n) => write!(f, "{}{}", p, n),
+            Decimal(d, n) => write!(f, "decimal({}){}", d, n),
             Utf8(n) => write!(f, "utf8{}", n),
             Binary(n) => write!(f, "binary{}", n),
             Struct(st, n) => write!(
@@ -140,6 +169,9 @@ impl Display for DType {
                 n
             ),
             List(c, n) => write!(f, "list({}){}", c, n),
+            FixedSizeList(c, size, n) => write!(f, "fixed_size_list({}, {}){}", c, size, n),
+            Union(u, n) => write!(f, "{}{}", u, n),
+            Map(m, n) => write!(f, "{}{}", m, n),
             Extension(ext, n) => write!(
                 f,
                 "ext({}{}){}",
@@ -195,6 +227,29 @@ impl StructDType {
 
         Ok(StructDType::new(names.into(), dtypes))
     }
+
+    /// Project the fields selected by `mask`, which must have been resolved against this type.
+    pub fn project_mask(&self, mask: &FieldMask) -> VortexResult<Self> {
+        let FieldMask::Fields(fields) = mask else {
+            return Ok(self.clone());
+        };
+
+        let mut names = Vec::with_capacity(fields.len());
+        let mut dtypes = Vec::with_capacity(fields.len());
+        for (idx, child_mask) in fields {
+            let Some(dtype) = self.dtypes.get(*idx) else {
+                vortex_bail!("Projection column is out of bounds");
+            };
+            names.push(self.names[*idx].clone());
+            dtypes.push(match (dtype, child_mask) {
+                (_, FieldMask::All) => dtype.clone(),
+                (Struct(child, n), _) => Struct(child.project_mask(child_mask)?, *n),
+                _ => vortex_bail!("Cannot project fields of non-struct type {}", dtype),
+            });
+        }
+
+        Ok(StructDType::new(names.into(), dtypes))
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,10 @@
 use core::fmt;
 use std::fmt::{Display, Formatter};
 
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::{DType, StructDType};
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Field {
@@ -84,3 +88,150 @@ impl Display for FieldPath {
         write!(f, "{}", formatted)
     }
 }
+
+/// A selection of the possibly nested fields of a struct, resolved from a set of [FieldPath]s.
+///
+/// Selecting a field keeps all of its children, while selecting a path through a struct field
+/// keeps only the selected children of that struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldMask {
+    /// Every field is selected.
+    All,
+    /// The selected fields by index, in the order they were first referenced.
+    Fields(Vec<(usize, FieldMask)>),
+}
+
+impl FieldMask {
+    /// Resolve `paths` against the fields of `dtype`.
+    ///
+    /// Fails if a path references a missing field, or a child of a field that is not a struct.
+    pub fn try_new(dtype: &StructDType, paths: &[FieldPath]) -> VortexResult<Self> {
+        let mut mask = FieldMask::Fields(Vec::new());
+        for path in paths {
+            mask.insert(dtype, path.path())?;
+        }
+        Ok(mask)
+    }
+
+    fn insert(&mut self, dtype: &StructDType, path: &[Field]) -> VortexResult<()> {
+        let FieldMask::Fields(fields) = self else {
+            return Ok(());
+        };
+        let Some((field, rest)) = path.split_first() else {
+            *self = FieldMask::All;
+            return Ok(());
+        };
+
+        let idx = resolve_field(dtype, field)?;
+        let position = match fields.iter().position(|(i, _)| *i == idx) {
+            Some(position) => position,
+            None => {
+                fields.push((idx, FieldMask::Fields(Vec::new())));
+                fields.len() - 1
+            }
+        };
+        let child_mask = &mut fields[position].1;
+        if rest.is_empty() {
+            *child_mask = FieldMask::All;
+            return Ok(());
+        }
+        match &dtype.dtypes()[idx] {
+            DType::Struct(child_dtype, _) => child_mask.insert(child_dtype, rest),
+            child_dtype => vortex_bail!(
+                "Cannot select {} from field {} of type {}",
+                FieldPath::from(rest.to_vec()),
+                field,
+                child_dtype
+            ),
+        }
+    }
+}
+
+fn resolve_field(dtype: &StructDType, field: &Field) -> VortexResult<usize> {
+    match field {
+        Field::Name(name) => dtype
+            .find_name(name)
+            .ok_or_else(|| vortex_err!("Field {} not found", field)),
+        Field::Index(idx) => usize::try_from(*idx)
+            .ok()
+            .filter(|idx| *idx < dtype.dtypes().len())
+            .ok_or_else(|| vortex_err!(OutOfBounds: *idx as usize, 0, dtype.dtypes().len())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::field::{Field, FieldMask, FieldPath};
+    use crate::{DType, Nullability, PType, StructDType};
+
+    fn nested_dtype() -> StructDType {
+        let inner = StructDType::new(
+            ["c".into(), "d".into()].into(),
+            vec![
+                DType::Primitive(PType::I32, Nullability::NonNullable),
+                DType::Utf8(Nullability::Nullable),
+            ],
+        );
+        StructDType::new(
+            ["a".into(), "b".into()].into(),
+            vec![
+                DType::Bool(Nullability::NonNullable),
+                DType::Struct(inner, Nullability::Nullable),
+            ],
+        )
+    }
+
+    #[test]
+    fn nested_mask() {
+        let dtype = nested_dtype();
+        let paths = [
+            FieldPath::from(vec![Field::from("b"), Field::from("d")]),
+            FieldPath::from_name("a"),
+            FieldPath::from(vec![Field::from(1), Field::from(1)]),
+        ];
+        let mask = FieldMask::try_new(&dtype, &paths).unwrap();
+        assert_eq!(
+            mask,
+            FieldMask::Fields(vec![
+                (1, FieldMask::Fields(vec![(1, FieldMask::All)])),
+                (0, FieldMask::All),
+            ])
+        );
+
+        let projected = dtype.project_mask(&mask).unwrap();
+        assert_eq!(projected.names().as_ref(), &["b".into(), "a".into()]);
+        let DType::Struct(inner, Nullability::Nullable) = &projected.dtypes()[0] else {
+            panic!("expected nullable struct");
+        };
+        assert_eq!(inner.names().as_ref(), &["d".into()]);
+    }
+
+    #[test]
+    fn whole_field_wins() {
+        let dtype = nested_dtype();
+        let paths = [
+            FieldPath::from(vec![Field::from("b"), Field::from("c")]),
+            FieldPath::from_name("b"),
+        ];
+        assert_eq!(
+            FieldMask::try_new(&dtype, &paths).unwrap(),
+            FieldMask::Fields(vec![(1, FieldMask::All)])
+        );
+        assert_eq!(
+            FieldMask::try_new(&dtype, &[FieldPath::root()]).unwrap(),
+            FieldMask::All
+        );
+    }
+
+    #[test]
+    fn invalid_paths() {
+        let dtype = nested_dtype();
+        assert!(FieldMask::try_new(&dtype, &[FieldPath::from_name("z")]).is_err());
+        assert!(FieldMask::try_new(&dtype, &[FieldPath::from(Field::Index(-1))]).is_err());
+        assert!(FieldMask::try_new(
+            &dtype,
+            &[FieldPath::from(vec![Field::from("a"), Field::from("c")])]
+        )
+        .is_err());
+    }
+}
@@ -1,17 +1,23 @@
 #![cfg(target_endian = "little")]
 
+pub use decimal::*;
 pub use dtype::*;
 pub use extension::*;
 pub use half;
+pub use map::*;
 pub use nullability::*;
 pub use ptype::*;
+pub use union::*;
 
+mod decimal;
 mod dtype;
 mod extension;
 pub mod field;
+mod map;
 mod nullability;
 mod ptype;
 mod serde;
+mod union;
 
 #[cfg(feature = "proto")]
 pub mod proto {
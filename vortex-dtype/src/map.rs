@@ -0,0 +1,106 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::{DType, FieldNames, Nullability, StructDType};
+
+/// The name of the key field of a map entry.
+pub const MAP_KEY_FIELD: &str = "key";
+/// The name of the value field of a map entry.
+pub const MAP_VALUE_FIELD: &str = "value";
+
+/// The key and value types of a map. A map value is a list of entries, where each entry is a
+/// `{key, value}` struct.
+///
+/// The types are kept behind a single pointer so that [`DType`] does not grow.
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MapDType(Arc<MapEntry>);
+
+#[derive(Debug, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct MapEntry {
+    key: DType,
+    value: DType,
+    keys_sorted: bool,
+}
+
+impl MapDType {
+    /// Create a new map type. Map keys can never be null.
+    pub fn try_new(key: DType, value: DType, keys_sorted: bool) -> VortexResult<Self> {
+        if key.is_nullable() {
+            vortex_bail!("Map keys must be non-nullable, got {}", key);
+        }
+        Ok(Self(Arc::new(MapEntry {
+            key,
+            value,
+            keys_sorted,
+        })))
+    }
+
+    #[inline]
+    pub fn key_dtype(&self) -> &DType {
+        &self.0.key
+    }
+
+    #[inline]
+    pub fn value_dtype(&self) -> &DType {
+        &self.0.value
+    }
+
+    /// Whether the keys within each map are sorted.
+    #[inline]
+    pub fn keys_sorted(&self) -> bool {
+        self.0.keys_sorted
+    }
+
+    /// The non-nullable `{key, value}` struct type of a single map entry.
+    pub fn entry_dtype(&self) -> DType {
+        let names: FieldNames = [MAP_KEY_FIELD.into(), MAP_VALUE_FIELD.into()].into();
+        DType::Struct(
+            StructDType::new(names, vec![self.0.key.clone(), self.0.value.clone()]),
+            Nullability::NonNullable,
+        )
+    }
+}
+
+impl Display for MapDType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "map({}, {}", self.0.key, self.0.value)?;
+        if self.0.keys_sorted {
+            write!(f, ", sorted")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DType, MapDType, Nullability, PType};
+
+    #[test]
+    fn nullable_keys() {
+        assert!(MapDType::try_new(
+            DType::Utf8(Nullability::Nullable),
+            DType::Primitive(PType::I64, Nullability::Nullable),
+            false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn display() {
+        let map = MapDType::try_new(
+            DType::Utf8(Nullability::NonNullable),
+            DType::Primitive(PType::I64, Nullability::Nullable),
+            true,
+        )
+        .unwrap();
+        assert_eq!(map.to_string(), "map(utf8, i64?, sorted)");
+        assert_eq!(
+            DType::Map(map, Nullability::Nullable).to_string(),
+            "map(utf8, i64?, sorted)?"
+        );
+    }
+}
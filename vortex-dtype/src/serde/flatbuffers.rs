@@ -5,7 +5,10 @@ use itertools::Itertools;
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_flatbuffers::{FlatBufferRoot, WriteFlatBuffer};
 
-use crate::{flatbuffers as fb, DType, ExtDType, ExtID, ExtMetadata, PType, StructDType};
+use crate::{
+    flatbuffers as fb, DType, DecimalDType, ExtDType, ExtID, ExtMetadata, MapDType, PType,
+    StructDType, UnionDType, UnionMode,
+};
 
 impl TryFrom<fb::DType<'_>> for DType {
     type Error = VortexError;
@@ -28,6 +31,15 @@ impl TryFrom<fb::DType<'_>> for DType {
                     fb_primitive.nullable().into(),
                 ))
             }
+            fb::Type::Decimal => {
+                let fb_decimal = fb
+                    .type__as_decimal()
+                    .ok_or_else(|| vortex_err!("failed to parse decimal from flatbuffer"))?;
+                Ok(Self::Decimal(
+                    DecimalDType::try_new(fb_decimal.precision(), fb_decimal.scale())?,
+                    fb_decimal.nullable().into(),
+                ))
+            }
             fb::Type::Binary => Ok(Self::Binary(
                 fb.type__as_binary()
                     .ok_or_else(|| vortex_err!("failed to parse binary from flatbuffer"))?
@@ -52,6 +64,19 @@ impl TryFrom<fb::DType<'_>> for DType {
                     fb_list.nullable().into(),
                 ))
             }
+            fb::Type::FixedSizeList => {
+                let fb_list = fb.type__as_fixed_size_list().ok_or_else(|| {
+                    vortex_err!("failed to parse fixed size list from flatbuffer")
+                })?;
+                let element_dtype = Self::try_from(fb_list.element_type().ok_or_else(|| {
+                    vortex_err!("failed to parse fixed size list element type from flatbuffer")
+                })?)?;
+                Ok(Self::FixedSizeList(
+                    Arc::new(element_dtype),
+                    fb_list.size(),
+                    fb_list.nullable().into(),
+                ))
+            }
             fb::Type::Struct_ => {
                 let fb_struct = fb
                     .type__as_struct_()
@@ -74,6 +99,44 @@ impl TryFrom<fb::DType<'_>> for DType {
                     fb_struct.nullable().into(),
                 ))
             }
+            fb::Type::Union_ => {
+                let fb_union = fb
+                    .type__as_union_()
+                    .ok_or_else(|| vortex_err!("failed to parse union from flatbuffer"))?;
+                let names = fb_union
+                    .names()
+                    .ok_or_else(|| vortex_err!("failed to parse union names from flatbuffer"))?
+                    .iter()
+                    .map(|n| (*n).into())
+                    .collect_vec()
+                    .into();
+                let dtypes: Vec<Self> = fb_union
+                    .dtypes()
+                    .ok_or_else(|| vortex_err!("failed to parse union dtypes from flatbuffer"))?
+                    .iter()
+                    .map(Self::try_from)
+                    .collect::<VortexResult<Vec<_>>>()?;
+                Ok(Self::Union(
+                    UnionDType::try_new(names, dtypes, fb_union.mode().try_into()?)?,
+                    fb_union.nullable().into(),
+                ))
+            }
+            fb::Type::Map => {
+                let fb_map = fb
+                    .type__as_map()
+                    .ok_or_else(|| vortex_err!("failed to parse map from flatbuffer"))?;
+                let key_dtype =
+                    Self::try_from(fb_map.key_type().ok_or_else(|| {
+                        vortex_err!("failed to parse map key type from flatbuffer")
+                    })?)?;
+                let value_dtype = Self::try_from(fb_map.value_type().ok_or_else(|| {
+                    vortex_err!("failed to parse map value type from flatbuffer")
+                })?)?;
+                Ok(Self::Map(
+                    MapDType::try_new(key_dtype, value_dtype, fb_map.keys_sorted())?,
+                    fb_map.nullable().into(),
+                ))
+            }
             fb::Type::Extension => {
                 let fb_ext = fb
                     .type__as_extension()
@@ -118,6 +181,15 @@ impl WriteFlatBuffer for DType {
                 },
             )
             .as_union_value(),
+            Self::Decimal(d, n) => fb::Decimal::create(
+                fbb,
+                &fb::DecimalArgs {
+                    precision: d.precision(),
+                    scale: d.scale(),
+                    nullable: (*n).into(),
+                },
+            )
+            .as_union_value(),
             Self::Utf8(n) => fb::Utf8::create(
                 fbb,
                 &fb::Utf8Args {
@@ -168,6 +240,58 @@ impl WriteFlatBuffer for DType {
                 )
                 .as_union_value()
             }
+            Self::FixedSizeList(e, size, n) => {
+                let element_type = Some(e.as_ref().write_flatbuffer(fbb));
+                fb::FixedSizeList::create(
+                    fbb,
+                    &fb::FixedSizeListArgs {
+                        element_type,
+                        size: *size,
+                        nullable: (*n).into(),
+                    },
+                )
+                .as_union_value()
+            }
+            Self::Union(u, n) => {
+                let names = u
+                    .names()
+                    .iter()
+                    .map(|n| fbb.create_string(n.as_ref()))
+                    .collect_vec();
+                let names = Some(fbb.create_vector(&names));
+
+                let dtypes = u
+                    .dtypes()
+                    .iter()
+                    .map(|dtype| dtype.write_flatbuffer(fbb))
+                    .collect_vec();
+                let dtypes = Some(fbb.create_vector(&dtypes));
+
+                fb::Union_::create(
+                    fbb,
+                    &fb::Union_Args {
+                        names,
+                        dtypes,
+                        mode: u.mode().into(),
+                        nullable: (*n).into(),
+                    },
+                )
+                .as_union_value()
+            }
+            Self::Map(m, n) => {
+                let key_type = Some(m.key_dtype().write_flatbuffer(fbb));
+                let value_type = Some(m.value_dtype().write_flatbuffer(fbb));
+                fb::Map::create(
+                    fbb,
+                    &fb::MapArgs {
+                        key_type,
+                        value_type,
+                        keys_sorted: m.keys_sorted(),
+                        nullable: (*n).into(),
+                    },
+                )
+                .as_union_value()
+            }
             Self::Extension(ext, n) => {
                 let id = Some(fbb.create_string(ext.id().as_ref()));
                 let metadata = ext.metadata().map(|m| fbb.create_vector(m.as_ref()));
@@ -187,10 +311,14 @@ impl WriteFlatBuffer for DType {
             Self::Null => fb::Type::Null,
             Self::Bool(_) => fb::Type::Bool,
             Self::Primitive(..) => fb::Type::Primitive,
+            Self::Decimal(..) => fb::Type::Decimal,
             Self::Utf8(_) => fb::Type::Utf8,
             Self::Binary(_) => fb::Type::Binary,
             Self::Struct(..) => fb::Type::Struct_,
             Self::List(..) => fb::Type::List,
+            Self::FixedSizeList(..) => fb::Type::FixedSizeList,
+            Self::Union(..) => fb::Type::Union_,
+            Self::Map(..) => fb::Type::Map,
             Self::Extension { .. } => fb::Type::Extension,
         };
 
@@ -243,6 +371,27 @@ impl TryFrom<fb::PType> for PType {
     }
 }
 
+impl From<UnionMode> for fb::UnionMode {
+    fn from(value: UnionMode) -> Self {
+        match value {
+            UnionMode::Sparse => Self::Sparse,
+            UnionMode::Dense => Self::Dense,
+        }
+    }
+}
+
+impl TryFrom<fb::UnionMode> for UnionMode {
+    type Error = VortexError;
+
+    fn try_from(value: fb::UnionMode) -> Result<Self, Self::Error> {
+        Ok(match value {
+            fb::UnionMode::Sparse => Self::Sparse,
+            fb::UnionMode::Dense => Self::Dense,
+            _ => vortex_bail!(InvalidSerde: "Unknown UnionMode variant"),
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
@@ -251,7 +400,9 @@ mod test {
     use vortex_flatbuffers::FlatBufferToBytes;
 
     use crate::nullability::Nullability;
-    use crate::{flatbuffers as fb, DType, PType, StructDType};
+    use crate::{
+        flatbuffers as fb, DType, DecimalDType, MapDType, PType, StructDType, UnionDType, UnionMode,
+    };
 
     fn roundtrip_dtype(dtype: DType) {
         let bytes = dtype.with_flatbuffer_bytes(|bytes| bytes.to_vec());
@@ -264,12 +415,42 @@ mod test {
         roundtrip_dtype(DType::Null);
         roundtrip_dtype(DType::Bool(Nullability::NonNullable));
         roundtrip_dtype(DType::Primitive(PType::U64, Nullability::NonNullable));
+        roundtrip_dtype(DType::Decimal(
+            DecimalDType::try_new(38, 10).unwrap(),
+            Nullability::Nullable,
+        ));
         roundtrip_dtype(DType::Binary(Nullability::NonNullable));
         roundtrip_dtype(DType::Utf8(Nullability::NonNullable));
         roundtrip_dtype(DType::List(
             Arc::new(DType::Primitive(PType::F32, Nullability::Nullable)),
             Nullability::NonNullable,
         ));
+        roundtrip_dtype(DType::FixedSizeList(
+            Arc::new(DType::Primitive(PType::F32, Nullability::NonNullable)),
+            768,
+            Nullability::Nullable,
+        ));
+        roundtrip_dtype(DType::Union(
+            UnionDType::try_new(
+                ["int".into(), "str".into()].into(),
+                vec![
+                    DType::Primitive(PType::I64, Nullability::Nullable),
+                    DType::Utf8(Nullability::Nullable),
+                ],
+                UnionMode::Dense,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        ));
+        roundtrip_dtype(DType::Map(
+            MapDType::try_new(
+                DType::Utf8(Nullability::NonNullable),
+                DType::Primitive(PType::I32, Nullability::Nullable),
+                true,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        ));
         roundtrip_dtype(DType::Struct(
             StructDType::new(
                 ["strings".into(), "ints".into()].into(),
@@ -6,7 +6,10 @@ use crate::field::{Field, FieldPath};
 use crate::proto::dtype as pb;
 use crate::proto::dtype::d_type::DtypeType;
 use crate::proto::dtype::field::FieldType;
-use crate::{DType, ExtDType, ExtID, ExtMetadata, PType, StructDType};
+use crate::{
+    DType, DecimalDType, ExtDType, ExtID, ExtMetadata, MapDType, PType, StructDType, UnionDType,
+    UnionMode,
+};
 
 impl TryFrom<&pb::DType> for DType {
     type Error = VortexError;
@@ -20,7 +23,15 @@ impl TryFrom<&pb::DType> for DType {
             DtypeType::Null(_) => Ok(Self::Null),
             DtypeType::Bool(b) => Ok(Self::Bool(b.nullable.into())),
             DtypeType::Primitive(p) => Ok(Self::Primitive(p.r#type().into(), p.nullable.into())),
-            DtypeType::Decimal(_) => todo!("Not Implemented"),
+            DtypeType::Decimal(d) => Ok(Self::Decimal(
+                DecimalDType::try_new(
+                    u8::try_from(d.precision)
+                        .map_err(|_| vortex_err!(InvalidSerde: "Invalid decimal precision"))?,
+                    u8::try_from(d.scale)
+                        .map_err(|_| vortex_err!(InvalidSerde: "Invalid decimal scale"))?,
+                )?,
+                d.nullable.into(),
+            )),
             DtypeType::Utf8(u) => Ok(Self::Utf8(u.nullable.into())),
             DtypeType::Binary(b) => Ok(Self::Binary(b.nullable.into())),
             DtypeType::Struct(s) => Ok(Self::Struct(
@@ -45,6 +56,50 @@ impl TryFrom<&pb::DType> for DType {
                     nullable,
                 ))
             }
+            DtypeType::FixedSizeList(l) => {
+                let nullable = l.nullable.into();
+                Ok(Self::FixedSizeList(
+                    l.element_type
+                        .as_ref()
+                        .ok_or_else(
+                            || vortex_err!(InvalidSerde: "Invalid fixed size list element type"),
+                        )?
+                        .as_ref()
+                        .try_into()
+                        .map(Arc::new)?,
+                    l.size,
+                    nullable,
+                ))
+            }
+            DtypeType::Union(u) => Ok(Self::Union(
+                UnionDType::try_new(
+                    u.names.iter().map(|s| s.as_str().into()).collect(),
+                    u.dtypes
+                        .iter()
+                        .map(TryInto::<Self>::try_into)
+                        .collect::<VortexResult<Vec<_>>>()?,
+                    pb::UnionMode::try_from(u.mode)
+                        .map_err(|_| vortex_err!(InvalidSerde: "Invalid union mode"))?
+                        .into(),
+                )?,
+                u.nullable.into(),
+            )),
+            DtypeType::Map(m) => Ok(Self::Map(
+                MapDType::try_new(
+                    m.key_type
+                        .as_ref()
+                        .ok_or_else(|| vortex_err!(InvalidSerde: "Invalid map key type"))?
+                        .as_ref()
+                        .try_into()?,
+                    m.value_type
+                        .as_ref()
+                        .ok_or_else(|| vortex_err!(InvalidSerde: "Invalid map value type"))?
+                        .as_ref()
+                        .try_into()?,
+                    m.keys_sorted,
+                )?,
+                m.nullable.into(),
+            )),
             DtypeType::Extension(e) => Ok(Self::Extension(
                 ExtDType::new(
                     ExtID::from(e.id.as_str()),
@@ -68,6 +123,11 @@ impl From<&DType> for pb::DType {
                     r#type: pb::PType::from(*ptype).into(),
                     nullable: (*n).into(),
                 }),
+                DType::Decimal(d, n) => DtypeType::Decimal(pb::Decimal {
+                    precision: d.precision().into(),
+                    scale: d.scale().into(),
+                    nullable: (*n).into(),
+                }),
                 DType::Utf8(n) => DtypeType::Utf8(pb::Utf8 {
                     nullable: (*n).into(),
                 }),
@@ -83,6 +143,25 @@ impl From<&DType> for pb::DType {
                     element_type: Some(Box::new(l.as_ref().into())),
                     nullable: (*n).into(),
                 })),
+                DType::FixedSizeList(l, size, n) => {
+                    DtypeType::FixedSizeList(Box::new(pb::FixedSizeList {
+                        element_type: Some(Box::new(l.as_ref().into())),
+                        size: *size,
+                        nullable: (*n).into(),
+                    }))
+                }
+                DType::Union(u, n) => DtypeType::Union(pb::Union {
+                    names: u.names().iter().map(|s| s.as_ref().to_string()).collect(),
+                    dtypes: u.dtypes().iter().map(Into::into).collect(),
+                    mode: pb::UnionMode::from(u.mode()).into(),
+                    nullable: (*n).into(),
+                }),
+                DType::Map(m, n) => DtypeType::Map(Box::new(pb::Map {
+                    key_type: Some(Box::new(m.key_dtype().into())),
+                    value_type: Some(Box::new(m.value_dtype().into())),
+                    keys_sorted: m.keys_sorted(),
+                    nullable: (*n).into(),
+                })),
                 DType::Extension(e, n) => DtypeType::Extension(pb::Extension {
                     id: e.id().as_ref().into(),
                     metadata: e.metadata().map(|m| m.as_ref().into()),
@@ -131,6 +210,24 @@ impl From<PType> for pb::PType {
     }
 }
 
+impl From<pb::UnionMode> for UnionMode {
+    fn from(value: pb::UnionMode) -> Self {
+        match value {
+            pb::UnionMode::Sparse => Self::Sparse,
+            pb::UnionMode::Dense => Self::Dense,
+        }
+    }
+}
+
+impl From<UnionMode> for pb::UnionMode {
+    fn from(value: UnionMode) -> Self {
+        match value {
+            UnionMode::Sparse => Self::Sparse,
+            UnionMode::Dense => Self::Dense,
+        }
+    }
+}
+
 impl TryFrom<&pb::FieldPath> for FieldPath {
     type Error = VortexError;
 
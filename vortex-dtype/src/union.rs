@@ -0,0 +1,131 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use itertools::Itertools;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::{DType, FieldNames};
+
+/// The maximum number of variants in a union, matching the range of Arrow's `i8` type ids.
+pub const MAX_UNION_VARIANTS: usize = i8::MAX as usize + 1;
+
+/// How the children of a union are laid out.
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnionMode {
+    /// Every variant child has the same length as the union itself.
+    Sparse,
+    /// Variant children only hold the values of their own variant, addressed through offsets.
+    Dense,
+}
+
+impl Display for UnionMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnionMode::Sparse => write!(f, "sparse"),
+            UnionMode::Dense => write!(f, "dense"),
+        }
+    }
+}
+
+/// The named variants of a union type. A union value holds exactly one of the variants, whose
+/// index is the type id of the value.
+///
+/// The variants are kept behind a single pointer so that [`DType`] does not grow.
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnionDType(Arc<UnionVariants>);
+
+#[derive(Debug, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct UnionVariants {
+    names: FieldNames,
+    dtypes: Vec<DType>,
+    mode: UnionMode,
+}
+
+impl UnionDType {
+    pub fn try_new(names: FieldNames, dtypes: Vec<DType>, mode: UnionMode) -> VortexResult<Self> {
+        if names.len() != dtypes.len() {
+            vortex_bail!(
+                "Union has {} names but {} variant types",
+                names.len(),
+                dtypes.len()
+            );
+        }
+        if dtypes.len() > MAX_UNION_VARIANTS {
+            vortex_bail!(
+                "Union has {} variants, at most {} are supported",
+                dtypes.len(),
+                MAX_UNION_VARIANTS
+            );
+        }
+        Ok(Self(Arc::new(UnionVariants {
+            names,
+            dtypes,
+            mode,
+        })))
+    }
+
+    pub fn names(&self) -> &FieldNames {
+        &self.0.names
+    }
+
+    pub fn find_name(&self, name: &str) -> Option<usize> {
+        self.0.names.iter().position(|n| n.as_ref() == name)
+    }
+
+    pub fn dtypes(&self) -> &[DType] {
+        &self.0.dtypes
+    }
+
+    #[inline]
+    pub fn mode(&self) -> UnionMode {
+        self.0.mode
+    }
+}
+
+impl Display for UnionDType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}_union{{{}}}",
+            self.0.mode,
+            self.0
+                .names
+                .iter()
+                .zip(self.0.dtypes.iter())
+                .map(|(n, dt)| format!("{}={}", n, dt))
+                .join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DType, Nullability, PType, UnionDType, UnionMode};
+
+    #[test]
+    fn mismatched_names() {
+        assert!(UnionDType::try_new(
+            ["a".into(), "b".into()].into(),
+            vec![DType::Utf8(Nullability::Nullable)],
+            UnionMode::Sparse
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn display() {
+        let union = UnionDType::try_new(
+            ["int".into(), "str".into()].into(),
+            vec![
+                DType::Primitive(PType::I64, Nullability::Nullable),
+                DType::Utf8(Nullability::Nullable),
+            ],
+            UnionMode::Dense,
+        )
+        .unwrap();
+        assert_eq!(union.to_string(), "dense_union{int=i64?, str=utf8?}");
+    }
+}
@@ -131,12 +131,97 @@ impl<'a> flatbuffers::Verifiable for PType {
 
 impl flatbuffers::SimpleToVerifyInSlice for PType {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_UNION_MODE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_UNION_MODE: u8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_UNION_MODE: [UnionMode; 2] = [
+  UnionMode::Sparse,
+  UnionMode::Dense,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct UnionMode(pub u8);
+#[allow(non_upper_case_globals)]
+impl UnionMode {
+  pub const Sparse: Self = Self(0);
+  pub const Dense: Self = Self(1);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Sparse,
+    Self::Dense,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Sparse => Some("Sparse"),
+      Self::Dense => Some("Dense"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for UnionMode {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for UnionMode {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for UnionMode {
+    type Output = UnionMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for UnionMode {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for UnionMode {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for UnionMode {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_TYPE: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_TYPE: u8 = 9;
+pub const ENUM_MAX_TYPE: u8 = 12;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_TYPE: [Type; 10] = [
+pub const ENUM_VALUES_TYPE: [Type; 13] = [
   Type::NONE,
   Type::Null,
   Type::Bool,
@@ -147,6 +232,9 @@ pub const ENUM_VALUES_TYPE: [Type; 10] = [
   Type::Struct_,
   Type::List,
   Type::Extension,
+  Type::FixedSizeList,
+  Type::Union_,
+  Type::Map,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -164,9 +252,12 @@ impl Type {
   pub const Struct_: Self = Self(7);
   pub const List: Self = Self(8);
   pub const Extension: Self = Self(9);
+  pub const FixedSizeList: Self = Self(10);
+  pub const Union_: Self = Self(11);
+  pub const Map: Self = Self(12);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 9;
+  pub const ENUM_MAX: u8 = 12;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::Null,
@@ -178,6 +269,9 @@ impl Type {
     Self::Struct_,
     Self::List,
     Self::Extension,
+    Self::FixedSizeList,
+    Self::Union_,
+    Self::Map,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -192,6 +286,9 @@ impl Type {
       Self::Struct_ => Some("Struct_"),
       Self::List => Some("List"),
       Self::Extension => Some("Extension"),
+      Self::FixedSizeList => Some("FixedSizeList"),
+      Self::Union_ => Some("Union_"),
+      Self::Map => Some("Map"),
       _ => None,
     }
   }
@@ -1111,6 +1208,137 @@ impl core::fmt::Debug for List<'_> {
       ds.finish()
   }
 }
+pub enum FixedSizeListOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FixedSizeList<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FixedSizeList<'a> {
+  type Inner = FixedSizeList<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FixedSizeList<'a> {
+  pub const VT_ELEMENT_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_SIZE: flatbuffers::VOffsetT = 6;
+  pub const VT_NULLABLE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FixedSizeList { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args FixedSizeListArgs<'args>
+  ) -> flatbuffers::WIPOffset<FixedSizeList<'bldr>> {
+    let mut builder = FixedSizeListBuilder::new(_fbb);
+    builder.add_size(args.size);
+    if let Some(x) = args.element_type { builder.add_element_type(x); }
+    builder.add_nullable(args.nullable);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn element_type(&self) -> Option<DType<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<DType>>(FixedSizeList::VT_ELEMENT_TYPE, None)}
+  }
+  #[inline]
+  pub fn size(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(FixedSizeList::VT_SIZE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn nullable(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(FixedSizeList::VT_NULLABLE, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FixedSizeList<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<DType>>("element_type", Self::VT_ELEMENT_TYPE, false)?
+     .visit_field::<u32>("size", Self::VT_SIZE, false)?
+     .visit_field::<bool>("nullable", Self::VT_NULLABLE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FixedSizeListArgs<'a> {
+    pub element_type: Option<flatbuffers::WIPOffset<DType<'a>>>,
+    pub size: u32,
+    pub nullable: bool,
+}
+impl<'a> Default for FixedSizeListArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FixedSizeListArgs {
+      element_type: None,
+      size: 0,
+      nullable: false,
+    }
+  }
+}
+
+pub struct FixedSizeListBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FixedSizeListBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_element_type(&mut self, element_type: flatbuffers::WIPOffset<DType<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<DType>>(FixedSizeList::VT_ELEMENT_TYPE, element_type);
+  }
+  #[inline]
+  pub fn add_size(&mut self, size: u32) {
+    self.fbb_.push_slot::<u32>(FixedSizeList::VT_SIZE, size, 0);
+  }
+  #[inline]
+  pub fn add_nullable(&mut self, nullable: bool) {
+    self.fbb_.push_slot::<bool>(FixedSizeList::VT_NULLABLE, nullable, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FixedSizeListBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    FixedSizeListBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FixedSizeList<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FixedSizeList<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FixedSizeList");
+      ds.field("element_type", &self.element_type());
+      ds.field("size", &self.size());
+      ds.field("nullable", &self.nullable());
+      ds.finish()
+  }
+}
 pub enum ExtensionOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -1242,6 +1470,302 @@ impl core::fmt::Debug for Extension<'_> {
       ds.finish()
   }
 }
+pub enum Union_Offset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Union_<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Union_<'a> {
+  type Inner = Union_<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Union_<'a> {
+  pub const VT_NAMES: flatbuffers::VOffsetT = 4;
+  pub const VT_DTYPES: flatbuffers::VOffsetT = 6;
+  pub const VT_MODE: flatbuffers::VOffsetT = 8;
+  pub const VT_NULLABLE: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Union_ { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args Union_Args<'args>
+  ) -> flatbuffers::WIPOffset<Union_<'bldr>> {
+    let mut builder = Union_Builder::new(_fbb);
+    if let Some(x) = args.dtypes { builder.add_dtypes(x); }
+    if let Some(x) = args.names { builder.add_names(x); }
+    builder.add_nullable(args.nullable);
+    builder.add_mode(args.mode);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn names(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Union_::VT_NAMES, None)}
+  }
+  #[inline]
+  pub fn dtypes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DType<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DType>>>>(Union_::VT_DTYPES, None)}
+  }
+  #[inline]
+  pub fn mode(&self) -> UnionMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<UnionMode>(Union_::VT_MODE, Some(UnionMode::Sparse)).unwrap()}
+  }
+  #[inline]
+  pub fn nullable(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Union_::VT_NULLABLE, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Union_<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("names", Self::VT_NAMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<DType>>>>("dtypes", Self::VT_DTYPES, false)?
+     .visit_field::<UnionMode>("mode", Self::VT_MODE, false)?
+     .visit_field::<bool>("nullable", Self::VT_NULLABLE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct Union_Args<'a> {
+    pub names: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub dtypes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DType<'a>>>>>,
+    pub mode: UnionMode,
+    pub nullable: bool,
+}
+impl<'a> Default for Union_Args<'a> {
+  #[inline]
+  fn default() -> Self {
+    Union_Args {
+      names: None,
+      dtypes: None,
+      mode: UnionMode::Sparse,
+      nullable: false,
+    }
+  }
+}
+
+pub struct Union_Builder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> Union_Builder<'a, 'b, A> {
+  #[inline]
+  pub fn add_names(&mut self, names: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Union_::VT_NAMES, names);
+  }
+  #[inline]
+  pub fn add_dtypes(&mut self, dtypes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<DType<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Union_::VT_DTYPES, dtypes);
+  }
+  #[inline]
+  pub fn add_mode(&mut self, mode: UnionMode) {
+    self.fbb_.push_slot::<UnionMode>(Union_::VT_MODE, mode, UnionMode::Sparse);
+  }
+  #[inline]
+  pub fn add_nullable(&mut self, nullable: bool) {
+    self.fbb_.push_slot::<bool>(Union_::VT_NULLABLE, nullable, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> Union_Builder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    Union_Builder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Union_<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Union_<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Union_");
+      ds.field("names", &self.names());
+      ds.field("dtypes", &self.dtypes());
+      ds.field("mode", &self.mode());
+      ds.field("nullable", &self.nullable());
+      ds.finish()
+  }
+}
+pub enum MapOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Map<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Map<'a> {
+  type Inner = Map<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Map<'a> {
+  pub const VT_KEY_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_KEYS_SORTED: flatbuffers::VOffsetT = 8;
+  pub const VT_NULLABLE: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Map { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MapArgs<'args>
+  ) -> flatbuffers::WIPOffset<Map<'bldr>> {
+    let mut builder = MapBuilder::new(_fbb);
+    if let Some(x) = args.value_type { builder.add_value_type(x); }
+    if let Some(x) = args.key_type { builder.add_key_type(x); }
+    builder.add_nullable(args.nullable);
+    builder.add_keys_sorted(args.keys_sorted);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn key_type(&self) -> Option<DType<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<DType>>(Map::VT_KEY_TYPE, None)}
+  }
+  #[inline]
+  pub fn value_type(&self) -> Option<DType<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<DType>>(Map::VT_VALUE_TYPE, None)}
+  }
+  #[inline]
+  pub fn keys_sorted(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Map::VT_KEYS_SORTED, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn nullable(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Map::VT_NULLABLE, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Map<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<DType>>("key_type", Self::VT_KEY_TYPE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<DType>>("value_type", Self::VT_VALUE_TYPE, false)?
+     .visit_field::<bool>("keys_sorted", Self::VT_KEYS_SORTED, false)?
+     .visit_field::<bool>("nullable", Self::VT_NULLABLE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MapArgs<'a> {
+    pub key_type: Option<flatbuffers::WIPOffset<DType<'a>>>,
+    pub value_type: Option<flatbuffers::WIPOffset<DType<'a>>>,
+    pub keys_sorted: bool,
+    pub nullable: bool,
+}
+impl<'a> Default for MapArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MapArgs {
+      key_type: None,
+      value_type: None,
+      keys_sorted: false,
+      nullable: false,
+    }
+  }
+}
+
+pub struct MapBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MapBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_key_type(&mut self, key_type: flatbuffers::WIPOffset<DType<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<DType>>(Map::VT_KEY_TYPE, key_type);
+  }
+  #[inline]
+  pub fn add_value_type(&mut self, value_type: flatbuffers::WIPOffset<DType<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<DType>>(Map::VT_VALUE_TYPE, value_type);
+  }
+  #[inline]
+  pub fn add_keys_sorted(&mut self, keys_sorted: bool) {
+    self.fbb_.push_slot::<bool>(Map::VT_KEYS_SORTED, keys_sorted, false);
+  }
+  #[inline]
+  pub fn add_nullable(&mut self, nullable: bool) {
+    self.fbb_.push_slot::<bool>(Map::VT_NULLABLE, nullable, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MapBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MapBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Map<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Map<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Map");
+      ds.field("key_type", &self.key_type());
+      ds.field("value_type", &self.value_type());
+      ds.field("keys_sorted", &self.keys_sorted());
+      ds.field("nullable", &self.nullable());
+      ds.finish()
+  }
+}
 pub enum DTypeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -1426,6 +1950,51 @@ impl<'a> DType<'a> {
     }
   }
 
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn type__as_fixed_size_list(&self) -> Option<FixedSizeList<'a>> {
+    if self.type_type() == Type::FixedSizeList {
+      self.type_().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { FixedSizeList::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn type__as_union_(&self) -> Option<Union_<'a>> {
+    if self.type_type() == Type::Union_ {
+      self.type_().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Union_::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn type__as_map(&self) -> Option<Map<'a>> {
+    if self.type_type() == Type::Map {
+      self.type_().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Map::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
 }
 
 impl flatbuffers::Verifiable for DType<'_> {
@@ -1446,6 +2015,9 @@ impl flatbuffers::Verifiable for DType<'_> {
           Type::Struct_ => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Struct_>>("Type::Struct_", pos),
           Type::List => v.verify_union_variant::<flatbuffers::ForwardsUOffset<List>>("Type::List", pos),
           Type::Extension => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Extension>>("Type::Extension", pos),
+          Type::FixedSizeList => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FixedSizeList>>("Type::FixedSizeList", pos),
+          Type::Union_ => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Union_>>("Type::Union_", pos),
+          Type::Map => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Map>>("Type::Map", pos),
           _ => Ok(()),
         }
      })?
@@ -1563,6 +2135,27 @@ impl core::fmt::Debug for DType<'_> {
             ds.field("type_", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        Type::FixedSizeList => {
+          if let Some(x) = self.type__as_fixed_size_list() {
+            ds.field("type_", &x)
+          } else {
+            ds.field("type_", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Type::Union_ => {
+          if let Some(x) = self.type__as_union_() {
+            ds.field("type_", &x)
+          } else {
+            ds.field("type_", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Type::Map => {
+          if let Some(x) = self.type__as_map() {
+            ds.field("type_", &x)
+          } else {
+            ds.field("type_", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("type_", &x)
@@ -58,6 +58,16 @@ pub struct List {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FixedSizeList {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub element_type: ::core::option::Option<::prost::alloc::boxed::Box<DType>>,
+    #[prost(uint32, tag = "2")]
+    pub size: u32,
+    #[prost(bool, tag = "3")]
+    pub nullable: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Extension {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
@@ -68,8 +78,32 @@ pub struct Extension {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Union {
+    #[prost(string, repeated, tag = "1")]
+    pub names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "2")]
+    pub dtypes: ::prost::alloc::vec::Vec<DType>,
+    #[prost(enumeration = "UnionMode", tag = "3")]
+    pub mode: i32,
+    #[prost(bool, tag = "4")]
+    pub nullable: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Map {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub key_type: ::core::option::Option<::prost::alloc::boxed::Box<DType>>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub value_type: ::core::option::Option<::prost::alloc::boxed::Box<DType>>,
+    #[prost(bool, tag = "3")]
+    pub keys_sorted: bool,
+    #[prost(bool, tag = "4")]
+    pub nullable: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DType {
-    #[prost(oneof = "d_type::DtypeType", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9")]
+    #[prost(oneof = "d_type::DtypeType", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12")]
     pub dtype_type: ::core::option::Option<d_type::DtypeType>,
 }
 /// Nested message and enum types in `DType`.
@@ -95,6 +129,12 @@ pub mod d_type {
         List(::prost::alloc::boxed::Box<super::List>),
         #[prost(message, tag = "9")]
         Extension(super::Extension),
+        #[prost(message, tag = "10")]
+        FixedSizeList(::prost::alloc::boxed::Box<super::FixedSizeList>),
+        #[prost(message, tag = "11")]
+        Union(super::Union),
+        #[prost(message, tag = "12")]
+        Map(::prost::alloc::boxed::Box<super::Map>),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -173,3 +213,29 @@ impl PType {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum UnionMode {
+    Sparse = 0,
+    Dense = 1,
+}
+impl UnionMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            UnionMode::Sparse => "Sparse",
+            UnionMode::Dense => "Dense",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Sparse" => Some(Self::Sparse),
+            "Dense" => Some(Self::Dense),
+            _ => None,
+        }
+    }
+}
@@ -23,8 +23,9 @@ impl EncodingCompressor for DateTimePartsCompressor {
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         if let Ok(temporal_array) = TemporalArray::try_from(array) {
             match temporal_array.temporal_metadata() {
-                // We only attempt to compress Timestamp arrays.
-                TemporalMetadata::Timestamp(..) => Some(self),
+                // We only attempt to compress Timestamp and Duration arrays, the other kinds
+                // have no sub-day structure to split out.
+                TemporalMetadata::Timestamp(..) | TemporalMetadata::Duration(_) => Some(self),
                 _ => None,
             }
         } else {
@@ -1,10 +1,13 @@
 use std::collections::HashSet;
 
-use vortex::array::{Primitive, PrimitiveArray, VarBin, VarBinArray};
+use vortex::array::{Primitive, PrimitiveArray, VarBin, VarBinArray, VarBinView, VarBinViewArray};
 use vortex::encoding::EncodingRef;
 use vortex::stats::ArrayStatistics;
 use vortex::{Array, ArrayDef, IntoArray};
-use vortex_dict::{dict_encode_primitive, dict_encode_varbin, Dict, DictArray, DictEncoding};
+use vortex_dict::{
+    dict_encode_primitive, dict_encode_varbin, dict_encode_varbinview, Dict, DictArray,
+    DictEncoding,
+};
 use vortex_error::VortexResult;
 
 use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
@@ -19,8 +22,10 @@ impl EncodingCompressor for DictCompressor {
     }
 
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
-        // TODO(robert): Add support for VarBinView
-        if array.encoding().id() != Primitive::ID && array.encoding().id() != VarBin::ID {
+        if array.encoding().id() != Primitive::ID
+            && array.encoding().id() != VarBin::ID
+            && array.encoding().id() != VarBinView::ID
+        {
             return None;
         };
 
@@ -54,6 +59,11 @@ impl EncodingCompressor for DictCompressor {
                 let (codes, values) = dict_encode_varbin(&vb);
                 (codes.into_array(), values.into_array())
             }
+            VarBinView::ID => {
+                let vbv = VarBinViewArray::try_from(array)?;
+                let (codes, values) = dict_encode_varbinview(&vbv);
+                (codes.into_array(), values.into_array())
+            }
 
             _ => unreachable!("This array kind should have been filtered out"),
         };
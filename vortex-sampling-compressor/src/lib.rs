@@ -2,7 +2,10 @@ use std::collections::HashSet;
 use std::fmt::{Debug, Display, Formatter};
 
 use log::{debug, info, warn};
-use vortex::array::{Chunked, ChunkedArray, Constant, Struct, StructArray};
+use vortex::array::{
+    Chunked, ChunkedArray, Constant, FixedSizeList, FixedSizeListArray, List, ListArray, Map,
+    MapArray, Struct, StructArray, Union, UnionArray,
+};
 use vortex::compress::{check_dtype_unchanged, check_validity_unchanged, CompressionStrategy};
 use vortex::compute::slice;
 use vortex::encoding::EncodingRef;
@@ -208,6 +211,27 @@ impl<'a> SamplingCompressor<'a> {
                 // Not much better we can do than constant!
                 Ok(CompressedArray::uncompressed(arr.clone()))
             }
+            List::ID => {
+                // For list arrays, we compress the offsets and the flat elements individually
+                let list = ListArray::try_from(arr)?;
+                let offsets = self.compress_array(&list.offsets())?.into_array();
+                let elements = self.compress_array(&list.elements())?.into_array();
+                let validity = self.compress_validity(list.validity())?;
+                Ok(CompressedArray::uncompressed(
+                    ListArray::try_new(offsets, elements, validity)?.into_array(),
+                ))
+            }
+            FixedSizeList::ID => {
+                // Fixed size lists only hold their flat elements, which we compress like any other
+                // column
+                let list = FixedSizeListArray::try_from(arr)?;
+                let elements = self.compress_array(&list.elements())?.into_array();
+                let validity = self.compress_validity(list.validity())?;
+                Ok(CompressedArray::uncompressed(
+                    FixedSizeListArray::try_new(elements, list.list_size(), list.len(), validity)?
+                        .into_array(),
+                ))
+            }
             Struct::ID => {
                 // For struct arrays, we compress each field individually
                 let strct = StructArray::try_from(arr)?;
@@ -226,6 +250,48 @@ impl<'a> SamplingCompressor<'a> {
                     .into_array(),
                 ))
             }
+            Map::ID => {
+                // For map arrays, we compress the offsets, keys and values individually
+                let map = MapArray::try_from(arr)?;
+                let offsets = self.compress_array(&map.offsets())?.into_array();
+                let keys = self.compress_array(&map.keys())?.into_array();
+                let values = self.compress_array(&map.values())?.into_array();
+                let validity = self.compress_validity(map.validity())?;
+                Ok(CompressedArray::uncompressed(
+                    MapArray::try_new(
+                        offsets,
+                        keys,
+                        values,
+                        map.map_dtype().keys_sorted(),
+                        validity,
+                    )?
+                    .into_array(),
+                ))
+            }
+            Union::ID => {
+                // For union arrays, we compress the type ids, offsets and each variant individually
+                let union = UnionArray::try_from(arr)?;
+                let type_ids = self.compress_array(&union.type_ids())?.into_array();
+                let offsets = union
+                    .offsets()
+                    .map(|offsets| self.compress_array(&offsets).map(|a| a.into_array()))
+                    .transpose()?;
+                let variants = union
+                    .variants()
+                    .map(|variant| self.compress_array(&variant).map(|a| a.into_array()))
+                    .collect::<VortexResult<Vec<_>>>()?;
+                let validity = self.compress_validity(union.validity())?;
+                Ok(CompressedArray::uncompressed(
+                    UnionArray::try_new(
+                        union.dtype().clone(),
+                        type_ids,
+                        offsets,
+                        variants,
+                        validity,
+                    )?
+                    .into_array(),
+                ))
+            }
             _ => {
                 // Otherwise, we run sampled compression over pluggable encodings
                 let sampled = sampled_compression(arr, self)?;
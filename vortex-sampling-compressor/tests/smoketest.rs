@@ -3,7 +3,9 @@ use std::ops::Add;
 
 use chrono::TimeDelta;
 use vortex::array::builder::VarBinBuilder;
-use vortex::array::{BoolArray, PrimitiveArray, StructArray, TemporalArray, TimeUnit};
+use vortex::array::{
+    BoolArray, FixedSizeListArray, ListArray, PrimitiveArray, StructArray, TemporalArray, TimeUnit,
+};
 use vortex::validity::Validity;
 use vortex::{Array, ArrayDType, IntoArray};
 use vortex_dtype::{DType, FieldName, FieldNames, Nullability};
@@ -51,6 +53,8 @@ mod tests {
             ("varbin_col", make_string_column(65536)),
             ("binary_col", make_binary_column(65536)),
             ("timestamp_col", make_timestamp_column(65536)),
+            ("list_col", make_list_column(65536)),
+            ("fixed_size_list_col", make_fixed_size_list_column(65536)),
         ];
 
         let fields: Vec<Array> = def.iter().map(|(_, arr)| arr.clone()).collect();
@@ -112,6 +116,41 @@ mod tests {
             .into_array()
     }
 
+    fn make_list_column(count: usize) -> Array {
+        // Lists of length 0, 1, 2, 3 repeating.
+        let mut offsets = Vec::with_capacity(count + 1);
+        offsets.push(0u32);
+        for i in 0..count {
+            offsets.push(offsets[i] + (i % 4) as u32);
+        }
+        let elements = PrimitiveArray::from_vec(
+            (0..offsets[count]).map(|i| i as i64).collect::<Vec<i64>>(),
+            Validity::NonNullable,
+        );
+
+        ListArray::try_new(
+            PrimitiveArray::from(offsets).into_array(),
+            elements.into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    fn make_fixed_size_list_column(count: usize) -> Array {
+        // Embeddings of width 4.
+        let elements = PrimitiveArray::from_vec(
+            (0..count * 4)
+                .map(|i| (i % 64) as f32)
+                .collect::<Vec<f32>>(),
+            Validity::NonNullable,
+        );
+
+        FixedSizeListArray::try_new(elements.into_array(), 4, count, Validity::NonNullable)
+            .unwrap()
+            .into_array()
+    }
+
     fn make_timestamp_column(count: usize) -> Array {
         // Make new timestamps in incrementing order from EPOCH.
         let t0 = chrono::NaiveDateTime::default().and_utc();
@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
 use arrow_array::*;
-use vortex_dtype::{DType, PType};
+use arrow_buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow_schema::{Field, Fields, UnionFields};
+use arrow_select::concat::concat;
+use vortex_dtype::{DType, MapDType, PType, UnionMode, MAP_KEY_FIELD, MAP_VALUE_FIELD};
 
-use crate::{PValue, Scalar};
+use crate::{MapScalar, PValue, Scalar, UnionScalar};
 
 impl From<&Scalar> for Arc<dyn Datum> {
     fn from(value: &Scalar) -> Arc<dyn Datum> {
@@ -44,14 +47,25 @@ impl From<&Scalar> for Arc<dyn Datum> {
                     },
                 }
             }
+            DType::Decimal(decimal, _) => {
+                let array = match value.value.as_decimal().expect("should be decimal") {
+                    Some(v) => Decimal128Array::from(vec![v]),
+                    None => Decimal128Array::new_null(1),
+                };
+                Arc::new(arrow_array::Scalar::new(
+                    array
+                        .with_precision_and_scale(decimal.precision(), decimal.scale() as i8)
+                        .expect("valid decimal precision and scale"),
+                ))
+            }
             DType::Utf8(_) => {
                 match value
                     .value
                     .as_buffer_string()
                     .expect("should be buffer string")
                 {
-                    Some(s) => Arc::new(StringArray::new_scalar(s.as_str())),
-                    None => Arc::new(StringArray::new_null(1)),
+                    Some(s) => Arc::new(StringViewArray::new_scalar(s.as_str())),
+                    None => Arc::new(StringViewArray::new_null(1)),
                 }
             }
             DType::Binary(_) => {
@@ -60,19 +74,92 @@ impl From<&Scalar> for Arc<dyn Datum> {
                     .as_buffer_string()
                     .expect("should be buffer string")
                 {
-                    Some(s) => Arc::new(BinaryArray::new_scalar(s.as_bytes())),
-                    None => Arc::new(BinaryArray::new_null(1)),
+                    Some(s) => Arc::new(BinaryViewArray::new_scalar(s.as_bytes())),
+                    None => Arc::new(BinaryViewArray::new_null(1)),
                 }
             }
             DType::Struct(..) => {
                 todo!("struct scalar conversion")
             }
-            DType::List(..) => {
+            DType::List(..) | DType::FixedSizeList(..) => {
                 todo!("list scalar conversion")
             }
+            DType::Union(ref u, _) => {
+                let union = UnionScalar::try_from(value).expect("should be union");
+                // Every child holds a single element, null unless it is the child of the held variant.
+                let children = u
+                    .dtypes()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, dtype)| {
+                        let child = match union.value() {
+                            Some(v) if union.type_id() == Some(i as u8) => v,
+                            _ => Scalar::null(dtype.as_nullable()),
+                        };
+                        make_array(Arc::<dyn Datum>::from(&child).get().0.to_data())
+                    })
+                    .collect::<Vec<_>>();
+                let fields = UnionFields::new(
+                    0..u.dtypes().len() as i8,
+                    u.names().iter().zip(children.iter()).map(|(name, child)| {
+                        Field::new(name.as_ref(), child.data_type().clone(), true)
+                    }),
+                );
+                let offsets = match u.mode() {
+                    UnionMode::Sparse => None,
+                    UnionMode::Dense => Some(ScalarBuffer::from(vec![0i32])),
+                };
+                Arc::new(arrow_array::Scalar::new(
+                    UnionArray::try_new(
+                        fields,
+                        ScalarBuffer::from(vec![union.type_id().unwrap_or(0) as i8]),
+                        offsets,
+                        children,
+                    )
+                    .expect("valid union array"),
+                ))
+            }
+            DType::Map(ref m, _) => Arc::new(arrow_array::Scalar::new(map_to_arrow(value, m))),
             DType::Extension(..) => {
                 todo!("extension scalar conversion")
             }
         }
     }
 }
+
+fn to_arrow_array(value: &Scalar) -> ArrayRef {
+    make_array(Arc::<dyn Datum>::from(value).get().0.to_data())
+}
+
+/// Convert a map scalar into an Arrow [MapArray] holding a single map.
+pub(crate) fn map_to_arrow(value: &Scalar, m: &MapDType) -> MapArray {
+    let map = MapScalar::try_from(value).expect("should be map");
+    let (keys, values): (Vec<_>, Vec<_>) = map
+        .entries()
+        .map(|(k, v)| (to_arrow_array(&k), to_arrow_array(&v)))
+        .unzip();
+    let concat_or_empty = |arrays: Vec<ArrayRef>, dtype: &DType| {
+        if arrays.is_empty() {
+            to_arrow_array(&Scalar::null(dtype.as_nullable())).slice(0, 0)
+        } else {
+            concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())
+                .expect("map entries of the same type")
+        }
+    };
+    let keys = concat_or_empty(keys, m.key_dtype());
+    let values = concat_or_empty(values, m.value_dtype());
+
+    let fields = Fields::from(vec![
+        Field::new(MAP_KEY_FIELD, keys.data_type().clone(), false),
+        Field::new(MAP_VALUE_FIELD, values.data_type().clone(), true),
+    ]);
+    let entries = StructArray::new(fields, vec![keys, values], None);
+    MapArray::try_new(
+        Arc::new(Field::new("entries", entries.data_type().clone(), false)),
+        OffsetBuffer::from_lengths([map.len()]),
+        entries,
+        value.is_null().then(|| NullBuffer::new_null(1)),
+        m.keys_sorted(),
+    )
+    .expect("valid map array")
+}
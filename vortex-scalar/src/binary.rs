@@ -20,8 +20,14 @@ impl<'a> BinaryScalar<'a> {
         self.value.as_ref().cloned()
     }
 
-    pub fn cast(&self, _dtype: &DType) -> VortexResult<Scalar> {
-        todo!()
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        match dtype {
+            DType::Binary(_) => Ok(match self.value() {
+                None => Scalar::null(dtype.clone()),
+                Some(value) => Scalar::binary(value, dtype.nullability()),
+            }),
+            _ => vortex_bail!("Can't cast {} to binary", dtype),
+        }
     }
 }
 
@@ -1,8 +1,12 @@
 #![cfg(feature = "datafusion")]
+use std::sync::Arc;
+
+use datafusion_common::arrow::datatypes::{Field, UnionFields, UnionMode as ArrowUnionMode};
 use datafusion_common::ScalarValue;
-use vortex_dtype::{DType, PType};
+use vortex_dtype::{DType, DecimalDType, Nullability, PType, UnionMode};
 
-use crate::{PValue, Scalar};
+use crate::arrow::map_to_arrow;
+use crate::{PValue, Scalar, UnionScalar};
 
 impl From<Scalar> for ScalarValue {
     fn from(value: Scalar) -> Self {
@@ -40,6 +44,11 @@ impl From<Scalar> for ScalarValue {
                     },
                 }
             }
+            DType::Decimal(decimal, _) => ScalarValue::Decimal128(
+                value.value.as_decimal().expect("should be decimal"),
+                decimal.precision(),
+                decimal.scale() as i8,
+            ),
             DType::Utf8(_) => ScalarValue::Utf8(
                 value
                     .value
@@ -57,9 +66,35 @@ impl From<Scalar> for ScalarValue {
             DType::Struct(..) => {
                 todo!("struct scalar conversion")
             }
-            DType::List(..) => {
+            DType::List(..) | DType::FixedSizeList(..) => {
                 todo!("list scalar conversion")
             }
+            DType::Union(ref u, _) => {
+                let union = UnionScalar::try_from(&value).expect("should be union");
+                let fields = UnionFields::new(
+                    0..u.dtypes().len() as i8,
+                    u.names().iter().zip(u.dtypes()).map(|(name, dtype)| {
+                        Field::new(
+                            name.as_ref(),
+                            ScalarValue::from(Scalar::null(dtype.as_nullable())).data_type(),
+                            true,
+                        )
+                    }),
+                );
+                let mode = match u.mode() {
+                    UnionMode::Sparse => ArrowUnionMode::Sparse,
+                    UnionMode::Dense => ArrowUnionMode::Dense,
+                };
+                ScalarValue::Union(
+                    union
+                        .type_id()
+                        .zip(union.value())
+                        .map(|(type_id, v)| (type_id as i8, Box::new(ScalarValue::from(v)))),
+                    fields,
+                    mode,
+                )
+            }
+            DType::Map(ref m, _) => ScalarValue::Map(Arc::new(map_to_arrow(&value, m))),
             DType::Extension(..) => {
                 todo!("extension scalar conversion")
             }
@@ -83,6 +118,10 @@ impl From<ScalarValue> for Scalar {
             ScalarValue::UInt16(i) => i.map(Scalar::from),
             ScalarValue::UInt32(i) => i.map(Scalar::from),
             ScalarValue::UInt64(i) => i.map(Scalar::from),
+            ScalarValue::Decimal128(v, precision, scale) => v.and_then(|v| {
+                let decimal = DecimalDType::try_new(precision, u8::try_from(scale).ok()?).ok()?;
+                Some(Scalar::decimal(v, decimal, Nullability::NonNullable))
+            }),
             ScalarValue::Utf8(s) => s.as_ref().map(|s| Scalar::from(s.as_str())),
             ScalarValue::Utf8View(s) => s.as_ref().map(|s| Scalar::from(s.as_str())),
             ScalarValue::LargeUtf8(s) => s.as_ref().map(|s| Scalar::from(s.as_str())),
@@ -0,0 +1,213 @@
+use std::fmt::{Display, Formatter};
+
+use vortex_dtype::{DType, DecimalDType, Nullability, MAX_DECIMAL_PRECISION};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::value::ScalarValue;
+use crate::Scalar;
+
+/// A fixed-point decimal scalar, holding the unscaled 128-bit integer value.
+#[derive(Debug, Clone)]
+pub struct DecimalScalar<'a> {
+    dtype: &'a DType,
+    decimal_dtype: DecimalDType,
+    value: Option<i128>,
+}
+
+impl<'a> DecimalScalar<'a> {
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    #[inline]
+    pub fn decimal_dtype(&self) -> DecimalDType {
+        self.decimal_dtype
+    }
+
+    /// The unscaled value, i.e. the decimal multiplied by `10^scale`.
+    #[inline]
+    pub fn value(&self) -> Option<i128> {
+        self.value
+    }
+
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        match dtype {
+            DType::Decimal(target, nullability) => match self.value {
+                None => Ok(Scalar::null(dtype.clone())),
+                Some(v) => Ok(Scalar::decimal(
+                    rescale(v, self.decimal_dtype, *target)?,
+                    *target,
+                    *nullability,
+                )),
+            },
+            DType::Primitive(ptype, nullability) if ptype.is_float() => {
+                let value = self
+                    .value
+                    .map(|v| v as f64 / 10f64.powi(self.decimal_dtype.scale() as i32));
+                match value {
+                    None => Ok(Scalar::null(dtype.clone())),
+                    Some(v) => Scalar::primitive(v, *nullability).cast(dtype),
+                }
+            }
+            _ => vortex_bail!("Can't cast {} to {}", self.dtype, dtype),
+        }
+    }
+}
+
+/// Rescale an unscaled decimal value between two decimal types, failing if the value does not fit
+/// into the target precision or would lose digits.
+fn rescale(value: i128, from: DecimalDType, to: DecimalDType) -> VortexResult<i128> {
+    let rescaled = if to.scale() >= from.scale() {
+        10i128
+            .checked_pow((to.scale() - from.scale()) as u32)
+            .and_then(|factor| value.checked_mul(factor))
+    } else {
+        let factor = 10i128.pow((from.scale() - to.scale()) as u32);
+        (value % factor == 0).then_some(value / factor)
+    }
+    .ok_or_else(|| {
+        vortex_err!(
+            "Can't cast decimal value {} from ({}) to ({})",
+            value,
+            from,
+            to
+        )
+    })?;
+
+    if rescaled.unsigned_abs() >= 10u128.pow(to.precision() as u32) {
+        vortex_bail!(
+            "Decimal value {} does not fit in precision {}",
+            rescaled,
+            to.precision()
+        );
+    }
+    Ok(rescaled)
+}
+
+/// The unscaled value of an integer in the given decimal type.
+pub(crate) fn integer_to_decimal(value: i128, to: DecimalDType) -> VortexResult<i128> {
+    rescale(value, DecimalDType::try_new(MAX_DECIMAL_PRECISION, 0)?, to)
+}
+
+impl Scalar {
+    pub fn decimal(value: i128, decimal_dtype: DecimalDType, nullability: Nullability) -> Self {
+        Self {
+            dtype: DType::Decimal(decimal_dtype, nullability),
+            value: ScalarValue::Decimal(value),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for DecimalScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        let DType::Decimal(decimal_dtype, _) = value.dtype() else {
+            vortex_bail!("Expected decimal scalar, found {}", value.dtype())
+        };
+
+        Ok(Self {
+            dtype: value.dtype(),
+            decimal_dtype: *decimal_dtype,
+            value: value.value.as_decimal()?,
+        })
+    }
+}
+
+impl Display for DecimalScalar<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(value) = self.value else {
+            return write!(f, "null");
+        };
+
+        let scale = self.decimal_dtype.scale() as usize;
+        if scale == 0 {
+            return write!(f, "{}", value);
+        }
+
+        let digits = format!("{:0>width$}", value.unsigned_abs(), width = scale + 1);
+        let (integral, fractional) = digits.split_at(digits.len() - scale);
+        let sign = if value < 0 { "-" } else { "" };
+        write!(f, "{}{}.{}", sign, integral, fractional)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, DecimalDType, Nullability, PType};
+
+    use crate::{DecimalScalar, Scalar};
+
+    fn decimal(value: i128, precision: u8, scale: u8) -> Scalar {
+        Scalar::decimal(
+            value,
+            DecimalDType::try_new(precision, scale).unwrap(),
+            Nullability::NonNullable,
+        )
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", decimal(12345, 10, 2)), "123.45");
+        assert_eq!(format!("{}", decimal(-5, 10, 3)), "-0.005");
+        assert_eq!(format!("{}", decimal(42, 10, 0)), "42");
+    }
+
+    #[test]
+    fn cast_rescale() {
+        let target = DType::Decimal(
+            DecimalDType::try_new(10, 4).unwrap(),
+            Nullability::NonNullable,
+        );
+        let cast = decimal(12345, 10, 2).cast(&target).unwrap();
+        assert_eq!(
+            DecimalScalar::try_from(&cast).unwrap().value(),
+            Some(1234500)
+        );
+
+        // Dropping non-zero digits is not allowed
+        assert!(decimal(12345, 10, 2)
+            .cast(&DType::Decimal(
+                DecimalDType::try_new(10, 1).unwrap(),
+                Nullability::NonNullable
+            ))
+            .is_err());
+
+        // Nor is overflowing the target precision
+        assert!(decimal(12345, 10, 2)
+            .cast(&DType::Decimal(
+                DecimalDType::try_new(4, 2).unwrap(),
+                Nullability::NonNullable
+            ))
+            .is_err());
+    }
+
+    #[test]
+    fn cast_from_primitive() {
+        let target = DType::Decimal(DecimalDType::try_new(5, 2).unwrap(), Nullability::Nullable);
+        let cast = Scalar::from(-123i32).cast(&target).unwrap();
+        assert_eq!(
+            DecimalScalar::try_from(&cast).unwrap().value(),
+            Some(-12300)
+        );
+        assert!(
+            Scalar::null(DType::Primitive(PType::I64, Nullability::Nullable))
+                .cast(&target)
+                .unwrap()
+                .is_null()
+        );
+
+        // Integers must fit the precision, and floats are rejected
+        assert!(Scalar::from(1234i32).cast(&target).is_err());
+        assert!(Scalar::from(1.5f64).cast(&target).is_err());
+    }
+
+    #[test]
+    fn cast_float() {
+        let cast = decimal(12345, 10, 2)
+            .cast(&DType::Primitive(PType::F64, Nullability::NonNullable))
+            .unwrap();
+        assert_eq!(f64::try_from(&cast).unwrap(), 123.45);
+    }
+}
@@ -3,8 +3,9 @@ use std::fmt::{Display, Formatter};
 use vortex_dtype::{match_each_native_ptype, DType};
 
 use crate::bool::BoolScalar;
+use crate::decimal::DecimalScalar;
 use crate::primitive::PrimitiveScalar;
-use crate::Scalar;
+use crate::{MapScalar, Scalar, UnionScalar};
 
 impl Display for Scalar {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -30,10 +31,36 @@ impl Display for Scalar {
                     Some(v) => write!(f, "{}", v),
                 }
             }),
+            DType::Decimal(..) => write!(
+                f,
+                "{}",
+                DecimalScalar::try_from(self).map_err(|_| std::fmt::Error)?
+            ),
             DType::Utf8(_) => todo!(),
             DType::Binary(_) => todo!(),
             DType::Struct(..) => todo!(),
-            DType::List(..) => todo!(),
+            DType::List(..) | DType::FixedSizeList(..) => todo!(),
+            DType::Union(..) => match UnionScalar::try_from(self)
+                .map_err(|_| std::fmt::Error)?
+                .value()
+            {
+                None => write!(f, "null"),
+                Some(v) => write!(f, "{}", v),
+            },
+            DType::Map(..) => {
+                let map = MapScalar::try_from(self).map_err(|_| std::fmt::Error)?;
+                if self.is_null() {
+                    return write!(f, "null");
+                }
+                write!(f, "{{")?;
+                for (i, (key, value)) in map.entries().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", key, value)?;
+                }
+                write!(f, "}}")
+            }
             DType::Extension(..) => todo!(),
         }
     }
@@ -41,6 +68,8 @@ impl Display for Scalar {
 
 #[cfg(test)]
 mod tests {
+    use vortex_dtype::{DType, MapDType, Nullability, PType, UnionDType, UnionMode};
+
     use crate::Scalar;
 
     #[test]
@@ -48,4 +77,49 @@ mod tests {
         let scalar = Scalar::from(false);
         assert_eq!(format!("{}", scalar), "false");
     }
+
+    #[test]
+    fn display_union() {
+        let dtype = DType::Union(
+            UnionDType::try_new(
+                ["int".into(), "bool".into()].into(),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::Nullable),
+                    DType::Bool(Nullability::Nullable),
+                ],
+                UnionMode::Sparse,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        );
+        let scalar = Scalar::union(dtype.clone(), 1, Scalar::from(true)).unwrap();
+        assert_eq!(format!("{}", scalar), "true");
+        assert_eq!(format!("{}", Scalar::null(dtype)), "null");
+    }
+
+    #[test]
+    fn display_map() {
+        let dtype = DType::Map(
+            MapDType::try_new(
+                DType::Primitive(PType::I32, Nullability::NonNullable),
+                DType::Bool(Nullability::Nullable),
+                false,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        );
+        let scalar = Scalar::map(
+            dtype.clone(),
+            vec![
+                (Scalar::from(1i32), Scalar::from(true)),
+                (
+                    Scalar::from(2i32),
+                    Scalar::null(DType::Bool(Nullability::Nullable)),
+                ),
+            ],
+        )
+        .unwrap();
+        assert_eq!(format!("{}", scalar), "{1: true, 2: null}");
+        assert_eq!(format!("{}", Scalar::null(dtype)), "null");
+    }
 }
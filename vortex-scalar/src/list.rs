@@ -34,10 +34,12 @@ impl<'a> ListScalar<'a> {
     }
 
     pub fn element_dtype(&self) -> DType {
-        let DType::List(element_type, _) = self.dtype() else {
-            unreachable!();
-        };
-        (*element_type).deref().clone()
+        match self.dtype() {
+            DType::List(element_type, _) | DType::FixedSizeList(element_type, ..) => {
+                (*element_type).deref().clone()
+            }
+            _ => unreachable!(),
+        }
     }
 
     pub fn element(&self, idx: usize) -> Option<Scalar> {
@@ -80,7 +82,7 @@ impl<'a> TryFrom<&'a Scalar> for ListScalar<'a> {
     type Error = VortexError;
 
     fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
-        if !matches!(value.dtype(), DType::List(..)) {
+        if !matches!(value.dtype(), DType::List(..) | DType::FixedSizeList(..)) {
             vortex_bail!("Expected list scalar, found {}", value.dtype())
         }
 
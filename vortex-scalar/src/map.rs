@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use vortex_dtype::{DType, MapDType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::value::ScalarValue;
+use crate::Scalar;
+
+/// A scalar of a map type.
+///
+/// Non-null map values are stored as a list of entries, where each entry is a two element list of
+/// the key and the value.
+pub struct MapScalar<'a> {
+    dtype: &'a DType,
+    entries: Option<Arc<[ScalarValue]>>,
+}
+
+impl<'a> MapScalar<'a> {
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    fn map_dtype(&self) -> &'a MapDType {
+        let DType::Map(m, _) = self.dtype() else {
+            unreachable!()
+        };
+        m
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.as_ref().map(|e| e.len()).unwrap_or(0)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The key and value of every entry in the map.
+    pub fn entries(&self) -> impl Iterator<Item = (Scalar, Scalar)> + '_ {
+        let map_dtype = self.map_dtype();
+        self.entries
+            .as_ref()
+            .map(|e| e.as_ref())
+            .unwrap_or_else(|| &[] as &[ScalarValue])
+            .iter()
+            .map(move |entry| {
+                let (key, value) = entry_parts(entry).expect("Invalid map entry");
+                (
+                    Scalar {
+                        dtype: map_dtype.key_dtype().clone(),
+                        value: key.clone(),
+                    },
+                    Scalar {
+                        dtype: map_dtype.value_dtype().clone(),
+                        value: value.clone(),
+                    },
+                )
+            })
+    }
+
+    /// The value of the first entry whose key equals `key`.
+    pub fn get(&self, key: &Scalar) -> Option<Scalar> {
+        self.entries()
+            .find(|(k, _)| k.value() == key.value())
+            .map(|(_, v)| v)
+    }
+
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        if !self.dtype().eq_ignore_nullability(dtype) {
+            vortex_bail!("Can't cast {} scalar to {}", self.dtype(), dtype)
+        }
+        Ok(Scalar {
+            dtype: dtype.clone(),
+            value: self
+                .entries
+                .clone()
+                .map(ScalarValue::List)
+                .unwrap_or(ScalarValue::Null),
+        })
+    }
+}
+
+fn entry_parts(entry: &ScalarValue) -> VortexResult<(&ScalarValue, &ScalarValue)> {
+    match entry {
+        ScalarValue::List(kv) if kv.len() == 2 => Ok((&kv[0], &kv[1])),
+        _ => Err(vortex_err!("Expected a map entry, found {:?}", entry)),
+    }
+}
+
+impl Scalar {
+    /// Create a map scalar from its entries, casting every key and value to the types of `dtype`.
+    pub fn map(dtype: DType, entries: Vec<(Scalar, Scalar)>) -> VortexResult<Self> {
+        let DType::Map(m, _) = &dtype else {
+            vortex_bail!("Expected map dtype, found {}", dtype)
+        };
+        let entries = entries
+            .into_iter()
+            .map(|(key, value)| {
+                Ok(ScalarValue::List(
+                    vec![
+                        key.cast(m.key_dtype())?.value,
+                        value.cast(m.value_dtype())?.value,
+                    ]
+                    .into(),
+                ))
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(Self {
+            dtype,
+            value: ScalarValue::List(entries.into()),
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for MapScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        if !matches!(value.dtype(), DType::Map(..)) {
+            vortex_bail!("Expected map scalar, found {}", value.dtype())
+        }
+        let entries = value.value.as_list()?.cloned();
+        if let Some(entries) = entries.as_ref() {
+            for entry in entries.iter() {
+                entry_parts(entry)?;
+            }
+        }
+
+        Ok(Self {
+            dtype: value.dtype(),
+            entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{Array, Datum};
+    use vortex_dtype::{DType, MapDType, Nullability, PType};
+
+    use crate::{MapScalar, Scalar};
+
+    fn map_dtype() -> DType {
+        DType::Map(
+            MapDType::try_new(
+                DType::Primitive(PType::I32, Nullability::NonNullable),
+                DType::Primitive(PType::I64, Nullability::Nullable),
+                false,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        )
+    }
+
+    #[test]
+    fn get() {
+        let scalar = Scalar::map(
+            map_dtype(),
+            vec![
+                (Scalar::from(1i32), Scalar::from(10i64)),
+                (Scalar::from(2i32), Scalar::from(20i64)),
+            ],
+        )
+        .unwrap();
+        let map = MapScalar::try_from(&scalar).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(
+            map.get(&Scalar::from(2i32)).unwrap(),
+            Scalar::primitive(20i64, Nullability::Nullable)
+        );
+        assert!(map.get(&Scalar::from(3i32)).is_none());
+    }
+
+    #[test]
+    fn null_map() {
+        let scalar = Scalar::null(map_dtype());
+        let map = MapScalar::try_from(&scalar).unwrap();
+        assert!(map.is_empty());
+        assert!(map.get(&Scalar::from(1i32)).is_none());
+    }
+
+    #[test]
+    fn to_arrow_datum() {
+        let scalar = Scalar::map(
+            map_dtype(),
+            vec![
+                (Scalar::from(1i32), Scalar::from(10i64)),
+                (Scalar::from(2i32), Scalar::from(20i64)),
+            ],
+        )
+        .unwrap();
+        let datum = Arc::<dyn Datum>::from(&scalar);
+        let map = datum.get().0.as_map();
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.value_length(0), 2);
+        assert_eq!(
+            map.values().as_primitive::<Int64Type>().values(),
+            &[10i64, 20]
+        );
+    }
+}
@@ -2,9 +2,12 @@ use core::any::type_name;
 
 use num_traits::NumCast;
 use vortex_dtype::half::f16;
-use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability, PType};
+use vortex_dtype::{
+    match_each_integer_ptype, match_each_native_ptype, DType, NativePType, Nullability, PType,
+};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
+use crate::decimal::integer_to_decimal;
 use crate::pvalue::PValue;
 use crate::value::ScalarValue;
 use crate::Scalar;
@@ -44,6 +47,23 @@ impl<'a> PrimitiveScalar<'a> {
     }
 
     pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        if let DType::Decimal(decimal_dtype, nullability) = dtype {
+            if !self.ptype().is_int() {
+                vortex_bail!("Can't cast {} scalar to {}", self.ptype, dtype);
+            }
+            let value = match_each_integer_ptype!(self.ptype(), |$T| {
+                self.typed_value::<$T>().map(<i128 as From<$T>>::from)
+            });
+            return Ok(match value {
+                None => Scalar::null(dtype.clone()),
+                Some(value) => Scalar::decimal(
+                    integer_to_decimal(value, *decimal_dtype)?,
+                    *decimal_dtype,
+                    *nullability,
+                ),
+            });
+        }
+
         let ptype = PType::try_from(dtype)?;
         match_each_native_ptype!(ptype, |$Q| {
             match_each_native_ptype!(self.ptype(), |$T| {
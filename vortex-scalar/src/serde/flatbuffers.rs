@@ -17,7 +17,7 @@ impl TryFrom<fb::Scalar<'_>> for Scalar {
         let reader = flexbuffers::Reader::get_root(value.value().flex().bytes())?;
         let value = ScalarValue::deserialize(reader)?;
 
-        Ok(Self { dtype, value })
+        Self { dtype, value }.with_canonical_value()
     }
 }
 
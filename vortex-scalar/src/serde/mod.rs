@@ -1,6 +1,33 @@
+#[cfg(any(feature = "flatbuffers", feature = "proto"))]
+use vortex_dtype::DType;
+#[cfg(any(feature = "flatbuffers", feature = "proto"))]
+use vortex_error::VortexResult;
+
+#[cfg(any(feature = "flatbuffers", feature = "proto"))]
+use crate::{Scalar, ScalarValue};
+
 #[cfg(feature = "flatbuffers")]
 mod flatbuffers;
 #[cfg(feature = "proto")]
 mod proto;
 #[allow(clippy::module_inception)]
 mod serde;
+
+#[cfg(any(feature = "flatbuffers", feature = "proto"))]
+impl Scalar {
+    /// Values read back from their serialized form are not always in the representation expected
+    /// by the DType, e.g. decimals are serialized as bytes.
+    fn with_canonical_value(self) -> VortexResult<Self> {
+        match self.dtype {
+            DType::Decimal(..) => Ok(Self {
+                value: self
+                    .value
+                    .as_decimal()?
+                    .map(ScalarValue::Decimal)
+                    .unwrap_or(ScalarValue::Null),
+                dtype: self.dtype,
+            }),
+            _ => Ok(self),
+        }
+    }
+}
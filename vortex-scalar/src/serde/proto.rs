@@ -27,6 +27,9 @@ impl From<&ScalarValue> for pb::ScalarValue {
                 kind: Some(Kind::BoolValue(*v)),
             },
             ScalarValue::Primitive(v) => v.into(),
+            ScalarValue::Decimal(v) => pb::ScalarValue {
+                kind: Some(Kind::BytesValue(v.to_le_bytes().to_vec())),
+            },
             ScalarValue::Buffer(v) => pb::ScalarValue {
                 kind: Some(Kind::BytesValue(v.as_slice().to_vec())),
             },
@@ -104,7 +107,7 @@ impl TryFrom<&pb::Scalar> for Scalar {
                 .ok_or_else(|| vortex_err!(InvalidSerde: "Scalar missing value"))?,
         )?;
 
-        Ok(Self { dtype, value })
+        Self { dtype, value }.with_canonical_value()
     }
 }
 
@@ -145,7 +148,7 @@ mod test {
 
     use vortex_buffer::BufferString;
     use vortex_dtype::PType::I32;
-    use vortex_dtype::{DType, Nullability};
+    use vortex_dtype::{DType, DecimalDType, Nullability};
     use vortex_proto::scalar as pb;
 
     use crate::{Scalar, ScalarValue};
@@ -207,4 +210,17 @@ mod test {
             ),
         ));
     }
+
+    #[test]
+    fn test_decimal() {
+        let scalar = Scalar::decimal(
+            -12345,
+            DecimalDType::try_new(10, 2).unwrap(),
+            Nullability::Nullable,
+        );
+        assert_eq!(
+            Scalar::try_from(&pb::Scalar::from(&scalar)).unwrap(),
+            scalar
+        );
+    }
 }
@@ -16,6 +16,8 @@ impl Serialize for ScalarValue {
             Self::Null => ().serialize(serializer),
             Self::Bool(b) => b.serialize(serializer),
             Self::Primitive(p) => p.serialize(serializer),
+            // Not all serializers support i128, so decimals are written as little-endian bytes.
+            Self::Decimal(d) => d.to_le_bytes().as_slice().serialize(serializer),
             Self::Buffer(buffer) => buffer.as_ref().serialize(serializer),
             Self::BufferString(buffer) => buffer.as_str().serialize(serializer),
             Self::List(l) => l.serialize(serializer),
@@ -0,0 +1,168 @@
+use vortex_dtype::{DType, UnionDType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::pvalue::PValue;
+use crate::value::ScalarValue;
+use crate::Scalar;
+
+/// A scalar of a union type, holding the type id of its variant alongside the variant value.
+///
+/// Non-null union values are stored as a two element list of the type id and the variant value.
+pub struct UnionScalar<'a> {
+    dtype: &'a DType,
+    variant: Option<(u8, ScalarValue)>,
+}
+
+impl<'a> UnionScalar<'a> {
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    fn union_dtype(&self) -> &'a UnionDType {
+        let DType::Union(u, _) = self.dtype() else {
+            unreachable!()
+        };
+        u
+    }
+
+    /// The type id of the variant held by this scalar, or `None` if the scalar is null.
+    #[inline]
+    pub fn type_id(&self) -> Option<u8> {
+        self.variant.as_ref().map(|(type_id, _)| *type_id)
+    }
+
+    /// The value of the variant held by this scalar, typed with the variant's dtype.
+    pub fn value(&self) -> Option<Scalar> {
+        self.variant.as_ref().map(|(type_id, value)| Scalar {
+            dtype: self.union_dtype().dtypes()[*type_id as usize].clone(),
+            value: value.clone(),
+        })
+    }
+
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        if !self.dtype().eq_ignore_nullability(dtype) {
+            vortex_bail!("Can't cast {} scalar to {}", self.dtype(), dtype)
+        }
+        Ok(Scalar {
+            dtype: dtype.clone(),
+            value: match &self.variant {
+                None => ScalarValue::Null,
+                Some((type_id, value)) => union_value(*type_id, value.clone()),
+            },
+        })
+    }
+}
+
+fn union_value(type_id: u8, value: ScalarValue) -> ScalarValue {
+    ScalarValue::List(vec![ScalarValue::Primitive(PValue::U8(type_id)), value].into())
+}
+
+impl Scalar {
+    /// Create a union scalar holding `value` as the variant with the given type id.
+    pub fn union(dtype: DType, type_id: u8, value: Scalar) -> VortexResult<Self> {
+        let DType::Union(u, _) = &dtype else {
+            vortex_bail!("Expected union dtype, found {}", dtype)
+        };
+        let variant_dtype = u
+            .dtypes()
+            .get(type_id as usize)
+            .ok_or_else(|| vortex_err!("Type id {} out of bounds for {}", type_id, dtype))?;
+        let value = value.cast(variant_dtype)?;
+        Ok(Self {
+            value: union_value(type_id, value.value),
+            dtype,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for UnionScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        let DType::Union(u, _) = value.dtype() else {
+            vortex_bail!("Expected union scalar, found {}", value.dtype())
+        };
+
+        let variant = match &value.value {
+            ScalarValue::Null => None,
+            ScalarValue::List(l) if l.len() == 2 => {
+                let type_id = l[0]
+                    .as_pvalue()?
+                    .ok_or_else(|| vortex_err!("Union scalar is missing its type id"))
+                    .and_then(u8::try_from)?;
+                if type_id as usize >= u.dtypes().len() {
+                    vortex_bail!("Type id {} out of bounds for {}", type_id, value.dtype())
+                }
+                Some((type_id, l[1].clone()))
+            }
+            _ => vortex_bail!("Expected a union scalar value, found {:?}", value.value),
+        };
+
+        Ok(Self {
+            dtype: value.dtype(),
+            variant,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Datum, UnionArray};
+    use vortex_dtype::{DType, Nullability, PType, UnionDType, UnionMode};
+
+    use crate::{Scalar, UnionScalar};
+
+    fn union_dtype() -> DType {
+        DType::Union(
+            UnionDType::try_new(
+                ["int".into(), "str".into()].into(),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::Nullable),
+                    DType::Utf8(Nullability::Nullable),
+                ],
+                UnionMode::Sparse,
+            )
+            .unwrap(),
+            Nullability::Nullable,
+        )
+    }
+
+    #[test]
+    fn variant_value() {
+        let scalar = Scalar::union(union_dtype(), 0, Scalar::from(5i32)).unwrap();
+        let union = UnionScalar::try_from(&scalar).unwrap();
+        assert_eq!(union.type_id(), Some(0));
+        assert_eq!(
+            union.value().unwrap(),
+            Scalar::primitive(5i32, Nullability::Nullable)
+        );
+    }
+
+    #[test]
+    fn null_union() {
+        let scalar = Scalar::null(union_dtype());
+        let union = UnionScalar::try_from(&scalar).unwrap();
+        assert_eq!(union.type_id(), None);
+        assert!(union.value().is_none());
+    }
+
+    #[test]
+    fn invalid_type_id() {
+        assert!(Scalar::union(union_dtype(), 2, Scalar::from(5i32)).is_err());
+    }
+
+    #[test]
+    fn to_arrow_datum() {
+        let scalar = Scalar::union(union_dtype(), 1, Scalar::from("a")).unwrap();
+        let datum = Arc::<dyn Datum>::from(&scalar);
+        let (array, is_scalar) = datum.get();
+        assert!(is_scalar);
+        let union = array.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(union.type_id(0), 1);
+        assert_eq!(union.value(0).as_string_view().value(0), "a");
+    }
+}
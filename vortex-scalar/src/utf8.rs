@@ -21,8 +21,14 @@ impl<'a> Utf8Scalar<'a> {
         self.value.as_ref().cloned()
     }
 
-    pub fn cast(&self, _dtype: &DType) -> VortexResult<Scalar> {
-        todo!()
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        match dtype {
+            DType::Utf8(_) => Ok(match self.value() {
+                None => Scalar::null(dtype.clone()),
+                Some(value) => Scalar::utf8(value, dtype.nullability()),
+            }),
+            _ => vortex_bail!("Can't cast {} to utf8", dtype),
+        }
     }
 }
 
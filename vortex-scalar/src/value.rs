@@ -16,6 +16,7 @@ pub enum ScalarValue {
     Null,
     Bool(bool),
     Primitive(PValue),
+    Decimal(i128),
     Buffer(Buffer),
     BufferString(BufferString),
     List(Arc<[ScalarValue]>),
@@ -42,6 +43,20 @@ impl ScalarValue {
         }
     }
 
+    /// Returns the unscaled value of a decimal scalar.
+    ///
+    /// Decimals are serialized as 16 little-endian bytes, so they may also be read from a buffer.
+    pub fn as_decimal(&self) -> VortexResult<Option<i128>> {
+        match self {
+            Self::Null => Ok(None),
+            Self::Decimal(v) => Ok(Some(*v)),
+            Self::Buffer(b) => <[u8; 16]>::try_from(b.as_slice())
+                .map(|bytes| Some(i128::from_le_bytes(bytes)))
+                .map_err(|_| vortex_err!("Expected a 16 byte decimal buffer, found {:?}", self)),
+            _ => Err(vortex_err!("Expected a decimal scalar, found {:?}", self)),
+        }
+    }
+
     pub fn as_buffer(&self) -> VortexResult<Option<Buffer>> {
         match self {
             Self::Null => Ok(None),
@@ -61,6 +76,7 @@ impl ScalarValue {
 
     pub fn as_list(&self) -> VortexResult<Option<&Arc<[Self]>>> {
         match self {
+            Self::Null => Ok(None),
             Self::List(l) => Ok(Some(l)),
             _ => Err(vortex_err!("Expected a list scalar, found {:?}", self)),
         }
@@ -10,7 +10,7 @@ use vortex::compute::{search_sorted, slice, take, SearchResult, SearchSortedSide
 use vortex::stats::ArrayStatistics;
 use vortex::stream::{ArrayStream, ArrayStreamExt};
 use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
-use vortex_dtype::PType;
+use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
@@ -150,9 +150,17 @@ impl<R: VortexReadAt> ChunkedArrayReader<R> {
 fn find_chunks(row_offsets: &Array, indices: &Array) -> VortexResult<Vec<ChunkIndices>> {
     // TODO(ngates): lots of optimizations to be had here, potentially lots of push-down.
     //  For now, we just flatten everything into primitive arrays and iterate.
-    let row_offsets = try_cast(row_offsets, PType::U64.into())?.into_primitive()?;
+    let row_offsets = try_cast(
+        row_offsets,
+        &DType::Primitive(PType::U64, row_offsets.dtype().nullability()),
+    )?
+    .into_primitive()?;
     let _rows = format!("{:?}", row_offsets.maybe_null_slice::<u64>());
-    let indices = try_cast(indices, PType::U64.into())?.into_primitive()?;
+    let indices = try_cast(
+        indices,
+        &DType::Primitive(PType::U64, indices.dtype().nullability()),
+    )?
+    .into_primitive()?;
     let _indices = format!("{:?}", indices.maybe_null_slice::<u64>());
 
     if let (Some(last_idx), Some(num_rows)) = (
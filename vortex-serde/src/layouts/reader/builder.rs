@@ -2,6 +2,8 @@ use std::sync::{Arc, RwLock};
 
 use bytes::BytesMut;
 use vortex::{Array, ArrayDType};
+use vortex_dtype::field::FieldMask;
+use vortex_dtype::DType;
 use vortex_error::{vortex_bail, VortexResult};
 
 use crate::io::VortexReadAt;
@@ -75,9 +77,34 @@ impl<R: VortexReadAt> VortexLayoutReaderBuilder<R> {
         let projection = self.projection.unwrap_or_default();
         let batch_size = self.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
 
-        let projected_dtype = match &projection {
+        // Layouts only project top-level columns, nested fields are projected from each batch.
+        let (projection, field_mask) = match projection {
+            Projection::Fields(paths) => {
+                let DType::Struct(struct_dtype, _) = footer.dtype()? else {
+                    vortex_bail!("Can't project fields of non struct types")
+                };
+                match FieldMask::try_new(&struct_dtype, &paths)? {
+                    FieldMask::All => (Projection::All, None),
+                    FieldMask::Fields(fields) => {
+                        let (indices, masks): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
+                        let batch_mask = FieldMask::Fields(masks.into_iter().enumerate().collect());
+                        (Projection::Partial(indices), Some(batch_mask))
+                    }
+                }
+            }
+            projection => (projection, None),
+        };
+
+        let layout_dtype = match &projection {
             Projection::All => footer.dtype()?,
             Projection::Partial(projection) => footer.projected_dtype(projection)?,
+            Projection::Fields(_) => unreachable!("field projections are resolved above"),
+        };
+        let projected_dtype = match (&layout_dtype, &field_mask) {
+            (DType::Struct(struct_dtype, n), Some(mask)) => {
+                DType::Struct(struct_dtype.project_mask(mask)?, *n)
+            }
+            _ => layout_dtype.clone(),
         };
 
         let scan = Scan {
@@ -88,12 +115,18 @@ impl<R: VortexReadAt> VortexLayoutReaderBuilder<R> {
         };
 
         let message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
-        let layouts_cache =
-            RelativeLayoutCache::new(message_cache.clone(), projected_dtype.clone());
+        let layouts_cache = RelativeLayoutCache::new(message_cache.clone(), layout_dtype);
 
         let layout = footer.layout(scan.clone(), layouts_cache)?;
 
-        VortexLayoutBatchStream::try_new(self.reader, layout, message_cache, projected_dtype, scan)
+        VortexLayoutBatchStream::try_new(
+            self.reader,
+            layout,
+            message_cache,
+            projected_dtype,
+            scan,
+            field_mask,
+        )
     }
 
     async fn len(&self) -> usize {
@@ -201,6 +201,9 @@ impl Layout for ColumnLayout {
                             )
                         })
                         .collect::<VortexResult<Vec<_>>>()?,
+                    Projection::Fields(_) => {
+                        vortex_bail!("Field projections must be resolved to column indices")
+                    }
                 };
 
                 let reader = BatchReader::new(s.names().clone(), column_layouts);
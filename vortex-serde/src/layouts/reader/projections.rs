@@ -1,14 +1,22 @@
+use vortex_dtype::field::FieldPath;
+
 #[derive(Debug, Clone, Default)]
 pub enum Projection {
     #[default]
     All,
     Partial(Vec<usize>),
+    /// Possibly nested fields, only keeping the selected children of struct fields.
+    Fields(Vec<FieldPath>),
 }
 
 impl Projection {
     pub fn new(indices: impl AsRef<[usize]>) -> Self {
         Self::Partial(Vec::from(indices.as_ref()))
     }
+
+    pub fn fields(paths: impl AsRef<[FieldPath]>) -> Self {
+        Self::Fields(Vec::from(paths.as_ref()))
+    }
 }
 
 impl From<Vec<usize>> for Projection {
@@ -16,3 +24,9 @@ impl From<Vec<usize>> for Projection {
         Self::Partial(indices)
     }
 }
+
+impl From<Vec<FieldPath>> for Projection {
+    fn from(paths: Vec<FieldPath>) -> Self {
+        Self::Fields(paths)
+    }
+}
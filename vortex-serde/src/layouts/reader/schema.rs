@@ -1,3 +1,4 @@
+use vortex_dtype::field::FieldMask;
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, VortexResult};
 
@@ -17,6 +18,13 @@ impl Schema {
                 s.project(indices.as_ref())
                     .map(|p| Self(DType::Struct(p, *n)))
             }
+            Projection::Fields(paths) => {
+                let DType::Struct(s, n) = &self.0 else {
+                    vortex_bail!("Can't project non struct types")
+                };
+                s.project_mask(&FieldMask::try_new(s, &paths)?)
+                    .map(|p| Self(DType::Struct(p, *n)))
+            }
         }
     }
 }
@@ -10,6 +10,7 @@ use futures_util::{stream, FutureExt, StreamExt, TryStreamExt};
 use vortex::compute::unary::subtract_scalar;
 use vortex::compute::{filter, filter_indices, search_sorted, slice, take, SearchSortedSide};
 use vortex::{Array, IntoArray, IntoArrayVariant};
+use vortex_dtype::field::FieldMask;
 use vortex_dtype::{match_each_integer_ptype, DType};
 use vortex_error::{vortex_err, VortexError, VortexResult};
 use vortex_scalar::Scalar;
@@ -26,6 +27,7 @@ pub struct VortexLayoutBatchStream<R> {
     messages_cache: Arc<RwLock<LayoutMessageCache>>,
     state: StreamingState<R>,
     dtype: DType,
+    field_mask: Option<FieldMask>,
     current_offset: usize,
 }
 
@@ -36,6 +38,7 @@ impl<R: VortexReadAt> VortexLayoutBatchStream<R> {
         messages_cache: Arc<RwLock<LayoutMessageCache>>,
         dtype: DType,
         scan: Scan,
+        field_mask: Option<FieldMask>,
     ) -> VortexResult<Self> {
         Ok(VortexLayoutBatchStream {
             reader: Some(reader),
@@ -44,6 +47,7 @@ impl<R: VortexReadAt> VortexLayoutBatchStream<R> {
             messages_cache,
             state: Default::default(),
             dtype,
+            field_mask,
             current_offset: 0,
         })
     }
@@ -118,6 +122,10 @@ impl<R: VortexReadAt + Unpin + Send + 'static> Stream for VortexLayoutBatchStrea
                         batch = filter(&batch, &mask)?;
                     }
 
+                    if let Some(field_mask) = &self.field_mask {
+                        batch = batch.into_struct()?.project_mask(field_mask)?.into_array();
+                    }
+
                     self.state = StreamingState::Init;
                     return Poll::Ready(Some(Ok(batch)));
                 }
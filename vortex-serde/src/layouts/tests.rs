@@ -1,7 +1,9 @@
 use futures::StreamExt;
 use vortex::array::{ChunkedArray, PrimitiveArray, StructArray, VarBinArray};
+use vortex::variants::StructArrayTrait;
 use vortex::{ArrayDType, IntoArray, IntoArrayVariant};
-use vortex_dtype::PType;
+use vortex_dtype::field::{Field, FieldPath};
+use vortex_dtype::{FieldName, PType};
 
 use crate::layouts::reader::builder::VortexLayoutReaderBuilder;
 use crate::layouts::reader::context::LayoutDeserializer;
@@ -91,6 +93,54 @@ async fn test_read_projection() {
     assert_eq!(batch_count, 2);
 }
 
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_read_nested_projection() {
+    let inner = StructArray::from_fields(&[
+        (
+            "numbers",
+            PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        ),
+        (
+            "strings",
+            VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
+        ),
+    ]);
+    let st = StructArray::from_fields(&[
+        (
+            "id",
+            PrimitiveArray::from(vec![10u64, 11, 12, 13]).into_array(),
+        ),
+        ("inner", inner.into_array()),
+    ]);
+    let buf = Vec::new();
+    let mut writer = LayoutWriter::new(buf);
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let mut stream = VortexLayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_projection(Projection::fields([FieldPath::from(vec![
+            Field::from("inner"),
+            Field::from("strings"),
+        ])]))
+        .build()
+        .await
+        .unwrap();
+    let expected_dtype = stream.schema().0;
+
+    let mut item_count = 0;
+    while let Some(array) = stream.next().await {
+        let array = array.unwrap().into_struct().unwrap();
+        item_count += array.len();
+        assert_eq!(array.dtype(), &expected_dtype);
+        assert_eq!(array.names().as_ref(), [FieldName::from("inner")]);
+
+        let inner = array.field(0).unwrap().into_struct().unwrap();
+        assert_eq!(inner.names().as_ref(), [FieldName::from("strings")]);
+    }
+    assert_eq!(item_count, 4);
+}
+
 #[tokio::test]
 #[cfg_attr(miri, ignore)]
 async fn unequal_batches() {